@@ -133,6 +133,29 @@ where
     multi_polygon("nl_plots.wkt")
 }
 
+/// `depth` geometry collections as WKT, each holding the next, the innermost one empty.
+pub fn nested_collections_wkt(depth: usize) -> String {
+    let depth = depth.checked_sub(1).expect("at least one collection");
+    "GEOMETRYCOLLECTION (".repeat(depth) + "GEOMETRYCOLLECTION EMPTY" + &")".repeat(depth)
+}
+
+/// `depth` geometry collections as little-endian WKB, each holding the next, the innermost one
+/// empty.
+pub fn nested_collections_wkb(depth: usize) -> Vec<u8> {
+    let depth = depth.checked_sub(1).expect("at least one collection");
+    let mut wkb = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+    wkb.extend([1, 7, 0, 0, 0, 0, 0, 0, 0]);
+    wkb
+}
+
+/// `depth` geometry collections as TWKB, each holding the next, the innermost one empty.
+pub fn nested_collections_twkb(depth: usize) -> Vec<u8> {
+    let depth = depth.checked_sub(1).expect("at least one collection");
+    let mut twkb = [7, 0, 1].repeat(depth);
+    twkb.extend([7, 0x10]);
+    twkb
+}
+
 fn line_string<T>(name: &str) -> LineString<T>
 where
     T: WktFloat + Default + FromStr,
@@ -0,0 +1,218 @@
+//! Traits for writing geometries, the counterparts of the traits for reading them.
+//!
+//! An algorithm which produces geometries can write its output through these traits, so that the
+//! caller chooses where it goes, e.g. into `geo_types` geometries, an Arrow array builder or a
+//! WKB buffer, without copying it from an intermediate geometry.
+//!
+//! Builders of collections take a closure which builds each member in place, so that
+//! implementations which store their members contiguously, like Arrow's, can hand out a view of
+//! their own buffers.
+//!
+//! ```
+//! # #[cfg(feature = "geo-types")]
+//! # {
+//! use geo_traits::{CoordTrait, LineStringBuilderTrait, LineStringTrait};
+//! use geo_types::{line_string, LineString};
+//!
+//! /// Write the coordinates of a line string in reverse order.
+//! fn reverse<L: LineStringTrait>(
+//!     line_string: &L,
+//!     output: &mut impl LineStringBuilderTrait<T = L::T>,
+//! ) {
+//!     output.reserve(line_string.num_coords());
+//!     for coord in line_string.coords().rev() {
+//!         output.push_coord(&coord);
+//!     }
+//! }
+//!
+//! let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 2.)];
+//! let mut reversed = LineString::new(vec![]);
+//! reverse(&line_string, &mut reversed);
+//! assert_eq!(reversed, line_string![(x: 1., y: 2.), (x: 0., y: 0.)]);
+//! # }
+//! ```
+
+#[cfg(feature = "geo-types")]
+use geo_types::{Coord, CoordNum, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+
+use crate::{CoordTrait, LineStringTrait, PointTrait, PolygonTrait};
+
+/// A trait for writing a generic LineString, one coordinate at a time.
+pub trait LineStringBuilderTrait {
+    /// The coordinate type of this geometry
+    type T;
+
+    /// Reserve capacity for at least `additional` more coordinates. This is only a hint, which
+    /// does nothing by default.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Append a coordinate to this LineString
+    fn push_coord(&mut self, coord: &impl CoordTrait<T = Self::T>);
+
+    /// Append all the coordinates of a LineString to this one
+    fn extend_from_line_string(&mut self, line_string: &impl LineStringTrait<T = Self::T>) {
+        self.reserve(line_string.num_coords());
+        for coord in line_string.coords() {
+            self.push_coord(&coord);
+        }
+    }
+}
+
+/// A trait for writing a generic Polygon, one ring at a time, starting with the exterior.
+pub trait PolygonBuilderTrait {
+    /// The coordinate type of this geometry
+    type T;
+
+    /// The type of the builder of each ring, which implements [LineStringBuilderTrait]
+    type RingBuilder: LineStringBuilderTrait<T = Self::T>;
+
+    /// Append a ring to this Polygon, whose coordinates are written by `build`. The first ring is
+    /// the exterior, and the rest are interiors.
+    fn push_ring(&mut self, build: impl FnOnce(&mut Self::RingBuilder));
+
+    /// Append all the rings of a Polygon to this one
+    fn extend_from_polygon(&mut self, polygon: &impl PolygonTrait<T = Self::T>) {
+        for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+            self.push_ring(|builder| builder.extend_from_line_string(&ring));
+        }
+    }
+}
+
+/// A trait for writing a generic MultiPoint, one point at a time.
+pub trait MultiPointBuilderTrait {
+    /// The coordinate type of this geometry
+    type T;
+
+    /// Append a point to this MultiPoint.
+    fn push_point(&mut self, point: &impl PointTrait<T = Self::T>);
+}
+
+/// A trait for writing a generic MultiLineString, one LineString at a time.
+pub trait MultiLineStringBuilderTrait {
+    /// The coordinate type of this geometry
+    type T;
+
+    /// The type of the builder of each LineString, which implements [LineStringBuilderTrait]
+    type LineStringBuilder: LineStringBuilderTrait<T = Self::T>;
+
+    /// Append a LineString to this MultiLineString, whose coordinates are written by `build`
+    fn push_line_string(&mut self, build: impl FnOnce(&mut Self::LineStringBuilder));
+}
+
+/// A trait for writing a generic MultiPolygon, one Polygon at a time.
+///
+/// ```
+/// # #[cfg(feature = "geo-types")]
+/// # {
+/// use geo_traits::{MultiPolygonBuilderTrait, PolygonBuilderTrait};
+/// use geo_types::{polygon, MultiPolygon};
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+/// );
+/// let mut multi_polygon = MultiPolygon::new(vec![]);
+/// multi_polygon.push_polygon(|builder| builder.extend_from_polygon(&polygon));
+/// assert_eq!(multi_polygon, MultiPolygon::new(vec![polygon]));
+/// # }
+/// ```
+pub trait MultiPolygonBuilderTrait {
+    /// The coordinate type of this geometry
+    type T;
+
+    /// The type of the builder of each Polygon, which implements [PolygonBuilderTrait]
+    type PolygonBuilder: PolygonBuilderTrait<T = Self::T>;
+
+    /// Append a Polygon to this MultiPolygon, whose rings are written by `build`
+    fn push_polygon(&mut self, build: impl FnOnce(&mut Self::PolygonBuilder));
+}
+
+#[cfg(feature = "geo-types")]
+fn to_coord<T: CoordNum>(coord: &impl CoordTrait<T = T>) -> Coord<T> {
+    Coord {
+        x: coord.x(),
+        y: coord.y(),
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> LineStringBuilderTrait for LineString<T> {
+    type T = T;
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn push_coord(&mut self, coord: &impl CoordTrait<T = Self::T>) {
+        self.0.push(to_coord(coord));
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> LineStringBuilderTrait for Vec<Coord<T>> {
+    type T = T;
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn push_coord(&mut self, coord: &impl CoordTrait<T = Self::T>) {
+        self.push(to_coord(coord));
+    }
+}
+
+/// The first ring pushed into a Polygon with an empty exterior and no interiors becomes its
+/// exterior. Rings are closed, as by [`Polygon::new`].
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> PolygonBuilderTrait for Polygon<T> {
+    type T = T;
+    type RingBuilder = LineString<T>;
+
+    fn push_ring(&mut self, build: impl FnOnce(&mut Self::RingBuilder)) {
+        let mut ring = LineString::new(vec![]);
+        build(&mut ring);
+        if self.exterior().0.is_empty() && self.interiors().is_empty() {
+            self.exterior_mut(|exterior| *exterior = ring);
+        } else {
+            self.interiors_push(ring);
+        }
+    }
+}
+
+/// Points without coordinates are skipped, since [`geo_types::Point`] can't be empty.
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> MultiPointBuilderTrait for MultiPoint<T> {
+    type T = T;
+
+    fn push_point(&mut self, point: &impl PointTrait<T = Self::T>) {
+        if let Some(coord) = point.coord() {
+            self.0.push(to_coord(&coord).into());
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> MultiLineStringBuilderTrait for MultiLineString<T> {
+    type T = T;
+    type LineStringBuilder = LineString<T>;
+
+    fn push_line_string(&mut self, build: impl FnOnce(&mut Self::LineStringBuilder)) {
+        let mut line_string = LineString::new(vec![]);
+        build(&mut line_string);
+        self.0.push(line_string);
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T: CoordNum> MultiPolygonBuilderTrait for MultiPolygon<T> {
+    type T = T;
+    type PolygonBuilder = Polygon<T>;
+
+    fn push_polygon(&mut self, build: impl FnOnce(&mut Self::PolygonBuilder)) {
+        let mut polygon = Polygon::new(LineString::new(vec![]), vec![]);
+        build(&mut polygon);
+        self.0.push(polygon);
+    }
+}
@@ -43,6 +43,53 @@ pub trait CoordTrait {
         (self.x(), self.y())
     }
 
+    /// z component of this coord, or `None` if [`dim()`](Self::dim) has no z dimension.
+    ///
+    /// ```
+    /// use geo_traits::{CoordTrait, Dimensions};
+    ///
+    /// struct CoordZm([f64; 4]);
+    ///
+    /// impl CoordTrait for CoordZm {
+    ///     type T = f64;
+    ///
+    ///     fn dim(&self) -> Dimensions {
+    ///         Dimensions::Xyzm
+    ///     }
+    ///
+    ///     fn x(&self) -> f64 {
+    ///         self.0[0]
+    ///     }
+    ///
+    ///     fn y(&self) -> f64 {
+    ///         self.0[1]
+    ///     }
+    ///
+    ///     fn nth_or_panic(&self, n: usize) -> f64 {
+    ///         self.0[n]
+    ///     }
+    /// }
+    ///
+    /// let coord = CoordZm([1., 2., 3., 4.]);
+    /// assert_eq!((coord.z(), coord.m()), (Some(3.), Some(4.)));
+    /// assert_eq!((1., 2.).z(), None);
+    /// ```
+    fn z(&self) -> Option<Self::T> {
+        match self.dim() {
+            Dimensions::Xyz | Dimensions::Xyzm => Some(self.nth_or_panic(2)),
+            _ => None,
+        }
+    }
+
+    /// m component of this coord, or `None` if [`dim()`](Self::dim) has no m dimension.
+    fn m(&self) -> Option<Self::T> {
+        match self.dim() {
+            Dimensions::Xym => Some(self.nth_or_panic(2)),
+            Dimensions::Xyzm => Some(self.nth_or_panic(3)),
+            _ => None,
+        }
+    }
+
     /// Access the n'th (0-based) element of the CoordinateTuple.
     /// May panic if n >= DIMENSION.
     /// See also [`nth()`](Self::nth).
@@ -0,0 +1,369 @@
+//! Implementations of the geometry traits for the geometries of the [`geojson`] crate.
+//!
+//! [`geojson::Geometry`] and [`geojson::Value`] implement [`GeometryTrait`] directly. GeoJSON
+//! stores coordinates in nested `Vec`s, e.g. a polygon is a `Vec<Vec<Vec<f64>>>`, so its parts
+//! are borrowed as the transparent wrappers of this module, which give each level of nesting its
+//! meaning, without copying anything.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::{CoordTrait, GeometryTrait, GeometryType, LineStringTrait, PolygonTrait};
+//!
+//! let geometry: geojson::Geometry = r#"{
+//!     "type": "Polygon",
+//!     "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 0]], [[1, 1], [2, 1], [2, 2], [1, 1]]]
+//! }"#
+//! .parse()
+//! .unwrap();
+//! let GeometryType::Polygon(polygon) = geometry.as_type() else {
+//!     panic!("expected a polygon");
+//! };
+//! assert_eq!(polygon.num_interiors(), 1);
+//! assert_eq!(polygon.exterior().unwrap().coord(2).unwrap().x_y(), (4., 4.));
+//! ```
+
+use geojson::Value;
+
+use crate::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+
+macro_rules! wrapper {
+    ($(#[$attr:meta])* $name:ident($inner:ty)) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name(pub $inner);
+
+        impl $name {
+            /// Borrow the GeoJSON value as this type.
+            pub fn from_ref(inner: &$inner) -> &Self {
+                // SAFETY: `Self` is a `#[repr(transparent)]` wrapper of `$inner`
+                unsafe { &*(inner as *const $inner).cast::<Self>() }
+            }
+        }
+    };
+}
+
+wrapper!(
+    /// A GeoJSON position, with two or more values, which implements [`CoordTrait`].
+    Position(geojson::Position)
+);
+wrapper!(
+    /// The position of a GeoJSON point, which implements [`PointTrait`].
+    Point(geojson::PointType)
+);
+wrapper!(
+    /// The positions of a GeoJSON line string, or of a ring of a polygon, which implements
+    /// [`LineStringTrait`].
+    LineString(geojson::LineStringType)
+);
+wrapper!(
+    /// The rings of a GeoJSON polygon, starting with the exterior, which implements
+    /// [`PolygonTrait`].
+    Polygon(geojson::PolygonType)
+);
+wrapper!(
+    /// The positions of a GeoJSON multi point, which implements [`MultiPointTrait`].
+    MultiPoint(Vec<geojson::PointType>)
+);
+wrapper!(
+    /// The line strings of a GeoJSON multi line string, which implements
+    /// [`MultiLineStringTrait`].
+    MultiLineString(Vec<geojson::LineStringType>)
+);
+wrapper!(
+    /// The polygons of a GeoJSON multi polygon, which implements [`MultiPolygonTrait`].
+    MultiPolygon(Vec<geojson::PolygonType>)
+);
+wrapper!(
+    /// The geometries of a GeoJSON geometry collection, which implements
+    /// [`GeometryCollectionTrait`].
+    GeometryCollection(Vec<geojson::Geometry>)
+);
+
+/// The dimensions of a position, which has a z value if it has three values.
+fn position_dim(position: &[f64]) -> Dimensions {
+    match position.len() {
+        2 => Dimensions::Xy,
+        3 => Dimensions::Xyz,
+        size => Dimensions::Unknown(size),
+    }
+}
+
+/// The dimensions of the first position, or XY if there are no positions.
+fn first_dim<'a>(mut positions: impl Iterator<Item = &'a geojson::Position>) -> Dimensions {
+    positions
+        .next()
+        .map_or(Dimensions::Xy, |position| position_dim(position))
+}
+
+impl CoordTrait for &Position {
+    type T = f64;
+
+    fn dim(&self) -> Dimensions {
+        position_dim(&self.0)
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        self.0[n]
+    }
+
+    fn x(&self) -> Self::T {
+        self.0[0]
+    }
+
+    fn y(&self) -> Self::T {
+        self.0[1]
+    }
+}
+
+impl PointTrait for Point {
+    type T = f64;
+    type CoordType<'a> = &'a Position;
+
+    fn dim(&self) -> Dimensions {
+        position_dim(&self.0)
+    }
+
+    /// GeoJSON points aren't empty, but the empty position `[]` is treated as an empty point.
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        (!self.0.is_empty()).then(|| Position::from_ref(&self.0))
+    }
+}
+
+impl<'a> PointTrait for &'a Point {
+    type T = f64;
+    type CoordType<'b> = &'a Position where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        position_dim(&self.0)
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        (!self.0.is_empty()).then(|| Position::from_ref(&self.0))
+    }
+}
+
+impl LineStringTrait for LineString {
+    type T = f64;
+    type CoordType<'a> = &'a Position;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        Position::from_ref(self.0.get_unchecked(i))
+    }
+}
+
+impl<'a> LineStringTrait for &'a LineString {
+    type T = f64;
+    type CoordType<'b> = &'a Position where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        Position::from_ref(self.0.get_unchecked(i))
+    }
+}
+
+impl PolygonTrait for Polygon {
+    type T = f64;
+    type RingType<'a> = &'a LineString;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flatten())
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.first().map(LineString::from_ref)
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        LineString::from_ref(self.0.get_unchecked(i + 1))
+    }
+}
+
+impl<'a> PolygonTrait for &'a Polygon {
+    type T = f64;
+    type RingType<'b> = &'a LineString where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flatten())
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.first().map(LineString::from_ref)
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        LineString::from_ref(self.0.get_unchecked(i + 1))
+    }
+}
+
+impl MultiPointTrait for MultiPoint {
+    type T = f64;
+    type PointType<'a> = &'a Point;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn num_points(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        Point::from_ref(self.0.get_unchecked(i))
+    }
+}
+
+impl MultiLineStringTrait for MultiLineString {
+    type T = f64;
+    type LineStringType<'a> = &'a LineString;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flatten())
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        LineString::from_ref(self.0.get_unchecked(i))
+    }
+}
+
+impl MultiPolygonTrait for MultiPolygon {
+    type T = f64;
+    type PolygonType<'a> = &'a Polygon;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flatten().flatten())
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        Polygon::from_ref(self.0.get_unchecked(i))
+    }
+}
+
+impl GeometryCollectionTrait for GeometryCollection {
+    type T = f64;
+    type GeometryType<'a> = &'a geojson::Geometry;
+
+    fn dim(&self) -> Dimensions {
+        self.0
+            .first()
+            .map_or(Dimensions::Xy, |geometry| value_dim(&geometry.value))
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+/// The [`GeometryType`] of GeoJSON geometries, which have no rects, triangles or lines.
+type GeoJsonGeometryType<'a> = GeometryType<
+    'a,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    UnimplementedRect<f64>,
+    UnimplementedTriangle<f64>,
+    UnimplementedLine<f64>,
+>;
+
+fn value_dim(value: &Value) -> Dimensions {
+    match value_type(value) {
+        GeometryType::Point(g) => g.dim(),
+        GeometryType::LineString(g) => g.dim(),
+        GeometryType::Polygon(g) => g.dim(),
+        GeometryType::MultiPoint(g) => g.dim(),
+        GeometryType::MultiLineString(g) => g.dim(),
+        GeometryType::MultiPolygon(g) => g.dim(),
+        GeometryType::GeometryCollection(g) => g.dim(),
+        _ => unreachable!("GeoJSON has no rects, triangles or lines"),
+    }
+}
+
+fn value_type(value: &Value) -> GeoJsonGeometryType<'_> {
+    match value {
+        Value::Point(g) => GeometryType::Point(Point::from_ref(g)),
+        Value::LineString(g) => GeometryType::LineString(LineString::from_ref(g)),
+        Value::Polygon(g) => GeometryType::Polygon(Polygon::from_ref(g)),
+        Value::MultiPoint(g) => GeometryType::MultiPoint(MultiPoint::from_ref(g)),
+        Value::MultiLineString(g) => {
+            GeometryType::MultiLineString(MultiLineString::from_ref(g))
+        }
+        Value::MultiPolygon(g) => GeometryType::MultiPolygon(MultiPolygon::from_ref(g)),
+        Value::GeometryCollection(g) => {
+            GeometryType::GeometryCollection(GeometryCollection::from_ref(g))
+        }
+    }
+}
+
+macro_rules! impl_geometry_trait {
+    (<$($lifetime:lifetime)?> $type:ty, $self:ident => $value:expr) => {
+        impl<$($lifetime)?> GeometryTrait for $type {
+            type T = f64;
+            type PointType<'b> = Point where Self: 'b;
+            type LineStringType<'b> = LineString where Self: 'b;
+            type PolygonType<'b> = Polygon where Self: 'b;
+            type MultiPointType<'b> = MultiPoint where Self: 'b;
+            type MultiLineStringType<'b> = MultiLineString where Self: 'b;
+            type MultiPolygonType<'b> = MultiPolygon where Self: 'b;
+            type GeometryCollectionType<'b> = GeometryCollection where Self: 'b;
+            type RectType<'b> = UnimplementedRect<f64> where Self: 'b;
+            type TriangleType<'b> = UnimplementedTriangle<f64> where Self: 'b;
+            type LineType<'b> = UnimplementedLine<f64> where Self: 'b;
+
+            fn dim(&$self) -> Dimensions {
+                value_dim($value)
+            }
+
+            fn as_type(&$self) -> GeoJsonGeometryType<'_> {
+                value_type($value)
+            }
+        }
+    };
+}
+
+impl_geometry_trait!(<> Value, self => self);
+impl_geometry_trait!(<> geojson::Geometry, self => &self.value);
+impl_geometry_trait!(<'a> &'a geojson::Geometry, self => &self.value);
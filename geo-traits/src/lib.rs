@@ -14,15 +14,65 @@
 //! constant-time but not necessarily _free_. For example, WKB is not aligned and may use a
 //! different endianness than the current machine, so individual values may need to be cloned on
 //! read.
+//!
+//! Geometries behind a [`Box`], [`Rc`](std::rc::Rc), [`Arc`](std::sync::Arc) or
+//! [`Cow`](std::borrow::Cow) implement the same traits as the geometry they point to, so they can
+//! be passed to generic code as they are:
+//!
+//! ```
+//! # #[cfg(feature = "geo-types")]
+//! # {
+//! use std::sync::Arc;
+//!
+//! use geo_traits::{LineStringTrait, PolygonTrait};
+//! use geo_types::polygon;
+//!
+//! fn num_exterior_coords(polygon: &impl PolygonTrait) -> usize {
+//!     polygon.exterior().map_or(0, |exterior| exterior.num_coords())
+//! }
+//!
+//! let cached = Arc::new(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)]);
+//! assert_eq!(num_exterior_coords(&cached), 4);
+//! assert_eq!(num_exterior_coords(&Box::new(cached)), 4);
+//! # }
+//! ```
+//!
+//! With the `wkt` feature, the traits are also implemented for the types of the [`wkt`](https://docs.rs/wkt)
+//! crate, so that parsed WKT can be passed to generic code without converting it:
+//!
+//! ```
+//! # #[cfg(feature = "wkt")]
+//! # {
+//! use std::str::FromStr;
+//!
+//! use geo_traits::{CoordTrait, GeometryTrait, GeometryType, LineStringTrait, PolygonTrait};
+//!
+//! let wkt = wkt::Wkt::<f64>::from_str("POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))").unwrap();
+//! let GeometryType::Polygon(polygon) = wkt.as_type() else {
+//!     panic!("expected a polygon");
+//! };
+//! assert_eq!(polygon.num_interiors(), 1);
+//! assert_eq!(polygon.exterior().unwrap().coord(2).unwrap().x_y(), (4., 4.));
+//! # }
+//! ```
+//!
+//! Likewise, the `geojson` feature implements [`GeometryTrait`] for the geometries of the
+//! [`geojson`](https://docs.rs/geojson) crate, see the `geojson_types` module. The `geozero`
+//! feature streams any implementer into the processors of the [`geozero`](https://docs.rs/geozero)
+//! crate, see the `to_geozero` module.
 
 #![deny(missing_docs)]
 
+pub use builder::{
+    LineStringBuilderTrait, MultiLineStringBuilderTrait, MultiPointBuilderTrait,
+    MultiPolygonBuilderTrait, PolygonBuilderTrait,
+};
 pub use coord::{CoordTrait, UnimplementedCoord};
 pub use dimension::Dimensions;
 pub use geometry::{GeometryTrait, GeometryType};
 pub use geometry_collection::GeometryCollectionTrait;
 pub use line::{LineTrait, UnimplementedLine};
-pub use line_string::{LineStringTrait, UnimplementedLineString};
+pub use line_string::{LineStringSegment, LineStringTrait, UnimplementedLineString};
 pub use multi_line_string::{MultiLineStringTrait, UnimplementedMultiLineString};
 pub use multi_point::{MultiPointTrait, UnimplementedMultiPoint};
 pub use multi_polygon::{MultiPolygonTrait, UnimplementedMultiPolygon};
@@ -31,17 +81,33 @@ pub use polygon::{PolygonTrait, UnimplementedPolygon};
 pub use rect::{RectTrait, UnimplementedRect};
 pub use triangle::{TriangleTrait, UnimplementedTriangle};
 
+mod builder;
 mod coord;
 mod dimension;
+#[cfg(feature = "geojson")]
+pub mod geojson_types;
 mod geometry;
 mod geometry_collection;
 mod iterator;
 mod line;
 mod line_string;
+#[cfg(feature = "geo-types")]
+pub mod map_coords;
 mod multi_line_string;
 mod multi_point;
 mod multi_polygon;
+#[cfg(feature = "geo-types")]
+pub mod orient;
 mod point;
+mod pointer;
 mod polygon;
 mod rect;
+#[cfg(feature = "geo-types")]
+pub mod to_geo;
+#[cfg(feature = "geozero")]
+pub mod to_geozero;
 mod triangle;
+pub mod visitor;
+pub mod wkt_str;
+#[cfg(feature = "wkt")]
+mod wkt_types;
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use crate::iterator::LineStringIterator;
-use crate::{CoordTrait, Dimensions, UnimplementedCoord};
+use crate::{CoordTrait, Dimensions, LineTrait, UnimplementedCoord};
 #[cfg(feature = "geo-types")]
 use geo_types::{Coord, CoordNum, LineString};
 
@@ -48,6 +48,62 @@ pub trait LineStringTrait: Sized {
     ///
     /// Accessing an index out of bounds is UB.
     unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_>;
+
+    /// An iterator over the segments between consecutive coordinates in this LineString, which
+    /// implement [LineTrait] when the coordinates can be cloned
+    ///
+    /// ```
+    /// use geo_traits::{CoordTrait, LineStringTrait, LineTrait};
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 0f64, y: 0.), (x: 3., y: 4.), (x: 3., y: 0.)];
+    /// let length: f64 = line_string
+    ///     .lines()
+    ///     .map(|line| {
+    ///         let (start, end) = (line.start(), line.end());
+    ///         (end.x() - start.x()).hypot(end.y() - start.y())
+    ///     })
+    ///     .sum();
+    /// assert_eq!(length, 9.);
+    /// ```
+    fn lines(
+        &self,
+    ) -> impl DoubleEndedIterator + ExactSizeIterator<Item = LineStringSegment<Self::CoordType<'_>>>
+    {
+        (0..self.num_coords().saturating_sub(1)).map(|i| unsafe {
+            LineStringSegment {
+                start: self.coord_unchecked(i),
+                end: self.coord_unchecked(i + 1),
+            }
+        })
+    }
+}
+
+/// A segment between two consecutive coordinates of a LineString, from
+/// [`LineStringTrait::lines`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineStringSegment<C> {
+    /// The first coordinate
+    pub start: C,
+    /// The second coordinate
+    pub end: C,
+}
+
+impl<C: CoordTrait + Clone> LineTrait for LineStringSegment<C> {
+    type T = C::T;
+    type CoordType<'a> = C where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        self.start.dim()
+    }
+
+    fn start(&self) -> Self::CoordType<'_> {
+        self.start.clone()
+    }
+
+    fn end(&self) -> Self::CoordType<'_> {
+        self.end.clone()
+    }
 }
 
 #[cfg(feature = "geo-types")]
@@ -0,0 +1,187 @@
+//! Convert any geometry trait implementer to a [`geo_types`] geometry, mapping a function over its
+//! coordinates on the way.
+//!
+//! This allows e.g. reprojecting or shifting a geometry which is stored in another format, like
+//! WKB or Arrow, without first converting it to `geo_types` and then mapping it in a second pass.
+//! Only the x and y components of each coordinate are kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::map_coords::map_coords;
+//! use geo_types::{coord, line_string, Geometry};
+//!
+//! let line_string = Geometry::LineString(line_string![(x: 1, y: 2), (x: 3, y: 4)]);
+//! let shifted = map_coords(&line_string, |c| coord! { x: c.x as f64 + 0.5, y: c.y as f64 });
+//!
+//! assert_eq!(
+//!     shifted,
+//!     Geometry::LineString(line_string![(x: 1.5, y: 2.), (x: 3.5, y: 4.)])
+//! );
+//! ```
+
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+use crate::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+fn map_coord<T: CoordNum, NT: CoordNum>(
+    coord: &impl CoordTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT>,
+) -> Coord<NT> {
+    f(Coord {
+        x: coord.x(),
+        y: coord.y(),
+    })
+}
+
+/// Map `f` over the coordinate of a point, or return `None` if the point is empty.
+pub fn map_point<T: CoordNum, NT: CoordNum>(
+    point: &impl PointTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT>,
+) -> Option<Point<NT>> {
+    point.coord().map(|coord| Point(map_coord(&coord, f)))
+}
+
+/// Map `f` over the coordinates of a line string.
+pub fn map_line_string<T: CoordNum, NT: CoordNum>(
+    line_string: &impl LineStringTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> LineString<NT> {
+    LineString(
+        line_string
+            .coords()
+            .map(|coord| map_coord(&coord, f))
+            .collect(),
+    )
+}
+
+/// Map `f` over the coordinates of a polygon.
+pub fn map_polygon<T: CoordNum, NT: CoordNum>(
+    polygon: &impl PolygonTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> Polygon<NT> {
+    let exterior = polygon
+        .exterior()
+        .map_or_else(|| LineString::new(vec![]), |ring| map_line_string(&ring, f));
+    let interiors = polygon
+        .interiors()
+        .map(|ring| map_line_string(&ring, f))
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+/// Map `f` over the coordinates of a multi point. Empty points are skipped.
+pub fn map_multi_point<T: CoordNum, NT: CoordNum>(
+    multi_point: &impl MultiPointTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> MultiPoint<NT> {
+    MultiPoint(
+        multi_point
+            .points()
+            .filter_map(|point| map_point(&point, f))
+            .collect(),
+    )
+}
+
+/// Map `f` over the coordinates of a multi line string.
+pub fn map_multi_line_string<T: CoordNum, NT: CoordNum>(
+    multi_line_string: &impl MultiLineStringTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> MultiLineString<NT> {
+    MultiLineString(
+        multi_line_string
+            .line_strings()
+            .map(|line_string| map_line_string(&line_string, f))
+            .collect(),
+    )
+}
+
+/// Map `f` over the coordinates of a multi polygon.
+pub fn map_multi_polygon<T: CoordNum, NT: CoordNum>(
+    multi_polygon: &impl MultiPolygonTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> MultiPolygon<NT> {
+    MultiPolygon(
+        multi_polygon
+            .polygons()
+            .map(|polygon| map_polygon(&polygon, f))
+            .collect(),
+    )
+}
+
+/// Map `f` over the coordinates of every geometry in a geometry collection.
+pub fn map_geometry_collection<T: CoordNum, NT: CoordNum>(
+    geometry_collection: &impl GeometryCollectionTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> GeometryCollection<NT> {
+    GeometryCollection(
+        geometry_collection
+            .geometries()
+            .map(|geometry| map_coords(&geometry, f))
+            .collect(),
+    )
+}
+
+/// Map `f` over the corners of a rect.
+///
+/// The result is a valid [`Rect`] even if `f` swaps the order of the corners.
+pub fn map_rect<T: CoordNum, NT: CoordNum>(
+    rect: &impl RectTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> Rect<NT> {
+    Rect::new(map_coord(&rect.min(), f), map_coord(&rect.max(), f))
+}
+
+/// Map `f` over the vertices of a triangle.
+pub fn map_triangle<T: CoordNum, NT: CoordNum>(
+    triangle: &impl TriangleTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> Triangle<NT> {
+    Triangle(
+        map_coord(&triangle.first(), f),
+        map_coord(&triangle.second(), f),
+        map_coord(&triangle.third(), f),
+    )
+}
+
+/// Map `f` over the endpoints of a line.
+pub fn map_line<T: CoordNum, NT: CoordNum>(
+    line: &impl LineTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> Line<NT> {
+    Line::new(map_coord(&line.start(), f), map_coord(&line.end(), f))
+}
+
+/// Map `f` over the coordinates of any geometry.
+///
+/// `geo_types` can't represent an empty point, so one is converted to an empty
+/// [`MultiPoint`].
+pub fn map_coords<T: CoordNum, NT: CoordNum>(
+    geometry: &impl GeometryTrait<T = T>,
+    f: impl Fn(Coord<T>) -> Coord<NT> + Copy,
+) -> Geometry<NT> {
+    match geometry.as_type() {
+        GeometryType::Point(g) => match map_point(g, f) {
+            Some(point) => Geometry::Point(point),
+            None => Geometry::MultiPoint(MultiPoint(vec![])),
+        },
+        GeometryType::LineString(g) => Geometry::LineString(map_line_string(g, f)),
+        GeometryType::Polygon(g) => Geometry::Polygon(map_polygon(g, f)),
+        GeometryType::MultiPoint(g) => Geometry::MultiPoint(map_multi_point(g, f)),
+        GeometryType::MultiLineString(g) => Geometry::MultiLineString(map_multi_line_string(g, f)),
+        GeometryType::MultiPolygon(g) => Geometry::MultiPolygon(map_multi_polygon(g, f)),
+        GeometryType::GeometryCollection(g) => {
+            Geometry::GeometryCollection(map_geometry_collection(g, f))
+        }
+        GeometryType::Rect(g) => Geometry::Rect(map_rect(g, f)),
+        GeometryType::Triangle(g) => Geometry::Triangle(map_triangle(g, f)),
+        GeometryType::Line(g) => Geometry::Line(map_line(g, f)),
+    }
+}
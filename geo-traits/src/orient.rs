@@ -0,0 +1,124 @@
+//! Convert any geometry trait implementer to a [`geo_types`] geometry, orienting the rings of its
+//! polygons on the way.
+//!
+//! Data from different sources follows different winding order conventions, and some algorithms,
+//! like geodesic area, depend on it. This allows normalizing geometries stored in another format,
+//! like WKB or Arrow, while converting them. Only the x and y components of each coordinate are
+//! kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::orient::{orient, RingConvention};
+//! use geo_types::{polygon, Geometry};
+//!
+//! // a clockwise shell, as written by ESRI shapefiles
+//! let square = Geometry::Polygon(polygon![
+//!     (x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.),
+//! ]);
+//!
+//! assert_eq!(
+//!     orient(&square, RingConvention::Ogc),
+//!     Geometry::Polygon(polygon![
+//!         (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.),
+//!     ])
+//! );
+//! assert_eq!(orient(&square, RingConvention::Esri), square);
+//! ```
+
+use geo_types::{CoordNum, Geometry, GeometryCollection, LineString, MultiPolygon, Polygon};
+
+use crate::map_coords::{map_coords, map_line_string};
+use crate::{
+    GeometryCollectionTrait, GeometryTrait, GeometryType, MultiPolygonTrait, PolygonTrait,
+};
+
+/// The winding order of polygon rings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingConvention {
+    /// Exterior rings are counter-clockwise and interior rings clockwise, as in the OGC Simple
+    /// Features specification and GeoJSON.
+    Ogc,
+    /// Exterior rings are clockwise and interior rings counter-clockwise, as in ESRI shapefiles.
+    Esri,
+}
+
+/// Twice the signed area of a ring, which is positive if the ring is counter-clockwise.
+fn twice_signed_area<T: CoordNum>(ring: &LineString<T>) -> T {
+    ring.0.windows(2).fold(T::zero(), |area, segment| {
+        area + segment[0].x * segment[1].y - segment[1].x * segment[0].y
+    })
+}
+
+/// Reverse `ring` unless it's already counter-clockwise if `ccw`, or clockwise otherwise. Rings
+/// without area are left as they are.
+fn orient_ring<T: CoordNum>(mut ring: LineString<T>, ccw: bool) -> LineString<T> {
+    let area = twice_signed_area(&ring);
+    if (ccw && area < T::zero()) || (!ccw && area > T::zero()) {
+        ring.0.reverse();
+    }
+    ring
+}
+
+/// Orient the rings of a polygon.
+pub fn orient_polygon<T: CoordNum>(
+    polygon: &impl PolygonTrait<T = T>,
+    convention: RingConvention,
+) -> Polygon<T> {
+    let ccw = convention == RingConvention::Ogc;
+    let exterior = polygon.exterior().map_or_else(
+        || LineString::new(vec![]),
+        |ring| orient_ring(map_line_string(&ring, |c| c), ccw),
+    );
+    let interiors = polygon
+        .interiors()
+        .map(|ring| orient_ring(map_line_string(&ring, |c| c), !ccw))
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+/// Orient the rings of every polygon in a multi polygon.
+pub fn orient_multi_polygon<T: CoordNum>(
+    multi_polygon: &impl MultiPolygonTrait<T = T>,
+    convention: RingConvention,
+) -> MultiPolygon<T> {
+    MultiPolygon(
+        multi_polygon
+            .polygons()
+            .map(|polygon| orient_polygon(&polygon, convention))
+            .collect(),
+    )
+}
+
+/// Orient the rings of every polygon in a geometry collection.
+pub fn orient_geometry_collection<T: CoordNum>(
+    geometry_collection: &impl GeometryCollectionTrait<T = T>,
+    convention: RingConvention,
+) -> GeometryCollection<T> {
+    GeometryCollection(
+        geometry_collection
+            .geometries()
+            .map(|geometry| orient(&geometry, convention))
+            .collect(),
+    )
+}
+
+/// Orient the rings of any geometry's polygons. Other geometries are converted as they are.
+///
+/// `geo_types` can't represent an empty point, so one is converted to an empty
+/// [`MultiPoint`](geo_types::MultiPoint).
+pub fn orient<T: CoordNum>(
+    geometry: &impl GeometryTrait<T = T>,
+    convention: RingConvention,
+) -> Geometry<T> {
+    match geometry.as_type() {
+        GeometryType::Polygon(g) => Geometry::Polygon(orient_polygon(g, convention)),
+        GeometryType::MultiPolygon(g) => {
+            Geometry::MultiPolygon(orient_multi_polygon(g, convention))
+        }
+        GeometryType::GeometryCollection(g) => {
+            Geometry::GeometryCollection(orient_geometry_collection(g, convention))
+        }
+        _ => map_coords(geometry, |c| c),
+    }
+}
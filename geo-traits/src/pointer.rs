@@ -0,0 +1,291 @@
+//! Implementations of the geometry traits for geometries behind smart pointers, which forward to
+//! the geometry they point to.
+
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
+};
+
+macro_rules! impl_traits_for_pointer {
+    ([$($generics:tt)*] $pointer:ty) => {
+        impl<$($generics)*> CoordTrait for $pointer
+        where
+            G: CoordTrait,
+        {
+            type T = G::T;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn x(&self) -> Self::T {
+                (**self).x()
+            }
+
+            fn y(&self) -> Self::T {
+                (**self).y()
+            }
+
+            fn nth_or_panic(&self, n: usize) -> Self::T {
+                (**self).nth_or_panic(n)
+            }
+
+            unsafe fn nth_unchecked(&self, n: usize) -> Self::T {
+                (**self).nth_unchecked(n)
+            }
+        }
+
+        impl<$($generics)*> PointTrait for $pointer
+        where
+            G: PointTrait,
+        {
+            type T = G::T;
+            type CoordType<'a> = G::CoordType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn coord(&self) -> Option<Self::CoordType<'_>> {
+                (**self).coord()
+            }
+        }
+
+        impl<$($generics)*> LineStringTrait for $pointer
+        where
+            G: LineStringTrait,
+        {
+            type T = G::T;
+            type CoordType<'a> = G::CoordType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn num_coords(&self) -> usize {
+                (**self).num_coords()
+            }
+
+            unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+                (**self).coord_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> PolygonTrait for $pointer
+        where
+            G: PolygonTrait,
+        {
+            type T = G::T;
+            type RingType<'a> = G::RingType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn exterior(&self) -> Option<Self::RingType<'_>> {
+                (**self).exterior()
+            }
+
+            fn num_interiors(&self) -> usize {
+                (**self).num_interiors()
+            }
+
+            unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+                (**self).interior_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> MultiPointTrait for $pointer
+        where
+            G: MultiPointTrait,
+        {
+            type T = G::T;
+            type PointType<'a> = G::PointType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn num_points(&self) -> usize {
+                (**self).num_points()
+            }
+
+            unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+                (**self).point_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> MultiLineStringTrait for $pointer
+        where
+            G: MultiLineStringTrait,
+        {
+            type T = G::T;
+            type LineStringType<'a> = G::LineStringType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn num_line_strings(&self) -> usize {
+                (**self).num_line_strings()
+            }
+
+            unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+                (**self).line_string_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> MultiPolygonTrait for $pointer
+        where
+            G: MultiPolygonTrait,
+        {
+            type T = G::T;
+            type PolygonType<'a> = G::PolygonType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn num_polygons(&self) -> usize {
+                (**self).num_polygons()
+            }
+
+            unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+                (**self).polygon_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> GeometryCollectionTrait for $pointer
+        where
+            G: GeometryCollectionTrait,
+        {
+            type T = G::T;
+            type GeometryType<'a> = G::GeometryType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn num_geometries(&self) -> usize {
+                (**self).num_geometries()
+            }
+
+            unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+                (**self).geometry_unchecked(i)
+            }
+        }
+
+        impl<$($generics)*> RectTrait for $pointer
+        where
+            G: RectTrait,
+        {
+            type T = G::T;
+            type CoordType<'a> = G::CoordType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn min(&self) -> Self::CoordType<'_> {
+                (**self).min()
+            }
+
+            fn max(&self) -> Self::CoordType<'_> {
+                (**self).max()
+            }
+        }
+
+        impl<$($generics)*> TriangleTrait for $pointer
+        where
+            G: TriangleTrait,
+        {
+            type T = G::T;
+            type CoordType<'a> = G::CoordType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn first(&self) -> Self::CoordType<'_> {
+                (**self).first()
+            }
+
+            fn second(&self) -> Self::CoordType<'_> {
+                (**self).second()
+            }
+
+            fn third(&self) -> Self::CoordType<'_> {
+                (**self).third()
+            }
+        }
+
+        impl<$($generics)*> LineTrait for $pointer
+        where
+            G: LineTrait,
+        {
+            type T = G::T;
+            type CoordType<'a> = G::CoordType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn start(&self) -> Self::CoordType<'_> {
+                (**self).start()
+            }
+
+            fn end(&self) -> Self::CoordType<'_> {
+                (**self).end()
+            }
+        }
+
+        impl<$($generics)*> GeometryTrait for $pointer
+        where
+            G: GeometryTrait,
+        {
+            type T = G::T;
+            type PointType<'a> = G::PointType<'a> where Self: 'a;
+            type LineStringType<'a> = G::LineStringType<'a> where Self: 'a;
+            type PolygonType<'a> = G::PolygonType<'a> where Self: 'a;
+            type MultiPointType<'a> = G::MultiPointType<'a> where Self: 'a;
+            type MultiLineStringType<'a> = G::MultiLineStringType<'a> where Self: 'a;
+            type MultiPolygonType<'a> = G::MultiPolygonType<'a> where Self: 'a;
+            type GeometryCollectionType<'a> = G::GeometryCollectionType<'a> where Self: 'a;
+            type RectType<'a> = G::RectType<'a> where Self: 'a;
+            type TriangleType<'a> = G::TriangleType<'a> where Self: 'a;
+            type LineType<'a> = G::LineType<'a> where Self: 'a;
+
+            fn dim(&self) -> Dimensions {
+                (**self).dim()
+            }
+
+            fn as_type(
+                &self,
+            ) -> GeometryType<
+                '_,
+                Self::PointType<'_>,
+                Self::LineStringType<'_>,
+                Self::PolygonType<'_>,
+                Self::MultiPointType<'_>,
+                Self::MultiLineStringType<'_>,
+                Self::MultiPolygonType<'_>,
+                Self::GeometryCollectionType<'_>,
+                Self::RectType<'_>,
+                Self::TriangleType<'_>,
+                Self::LineType<'_>,
+            > {
+                (**self).as_type()
+            }
+        }
+    };
+}
+
+impl_traits_for_pointer!([G: ?Sized] Box<G>);
+impl_traits_for_pointer!([G: ?Sized] Rc<G>);
+impl_traits_for_pointer!([G: ?Sized] Arc<G>);
+impl_traits_for_pointer!(['c, G: ToOwned + ?Sized] Cow<'c, G>);
@@ -0,0 +1,142 @@
+//! Convert any geometry trait implementer to the corresponding [`geo_types`] geometry, e.g. to
+//! use an algorithm which isn't generic over the traits yet.
+//!
+//! Each trait is implemented for every implementer of the corresponding read trait. Only the x
+//! and y components of each coordinate are kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::to_geo::{ToGeoGeometry, ToGeoLineString};
+//! use geo_traits::wkt_str::WktStr;
+//! use geo_types::{line_string, Geometry};
+//!
+//! let wkt = WktStr::new("LINESTRING (1 2, 3 4)").unwrap();
+//! assert_eq!(
+//!     wkt.geometry().to_geometry(),
+//!     Geometry::LineString(line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+//! );
+//!
+//! let line_string = line_string![(x: 1, y: 2), (x: 3, y: 4)];
+//! assert_eq!((&line_string).to_line_string(), line_string);
+//! ```
+
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+use crate::map_coords::{
+    map_coords, map_geometry_collection, map_line, map_line_string, map_multi_line_string,
+    map_multi_point, map_multi_polygon, map_point, map_polygon, map_rect, map_triangle,
+};
+use crate::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+/// Convert any [`CoordTrait`] implementer to a [`Coord`].
+pub trait ToGeoCoord<T: CoordNum> {
+    /// Convert to a [`Coord`].
+    fn to_coord(&self) -> Coord<T>;
+}
+
+impl<T: CoordNum, G: CoordTrait<T = T>> ToGeoCoord<T> for G {
+    fn to_coord(&self) -> Coord<T> {
+        Coord {
+            x: self.x(),
+            y: self.y(),
+        }
+    }
+}
+
+/// Convert any [`PointTrait`] implementer to a [`Point`].
+pub trait ToGeoPoint<T: CoordNum> {
+    /// Convert to a [`Point`], or return `None` if the point is empty, which `geo_types` can't
+    /// represent.
+    fn to_point(&self) -> Option<Point<T>>;
+}
+
+impl<T: CoordNum, G: PointTrait<T = T>> ToGeoPoint<T> for G {
+    fn to_point(&self) -> Option<Point<T>> {
+        map_point(self, |coord| coord)
+    }
+}
+
+macro_rules! impl_to_geo {
+    ($to_geo:ident, $read:ident, $method:ident, $map:ident, $output:ident) => {
+        #[doc = concat!("Convert any [`", stringify!($read), "`] implementer to a [`", stringify!($output), "`].")]
+        pub trait $to_geo<T: CoordNum> {
+            #[doc = concat!("Convert to a [`", stringify!($output), "`].")]
+            fn $method(&self) -> $output<T>;
+        }
+
+        impl<T: CoordNum, G: $read<T = T>> $to_geo<T> for G {
+            fn $method(&self) -> $output<T> {
+                $map(self, |coord| coord)
+            }
+        }
+    };
+}
+
+impl_to_geo!(
+    ToGeoLineString,
+    LineStringTrait,
+    to_line_string,
+    map_line_string,
+    LineString
+);
+impl_to_geo!(ToGeoPolygon, PolygonTrait, to_polygon, map_polygon, Polygon);
+impl_to_geo!(
+    ToGeoMultiPoint,
+    MultiPointTrait,
+    to_multi_point,
+    map_multi_point,
+    MultiPoint
+);
+impl_to_geo!(
+    ToGeoMultiLineString,
+    MultiLineStringTrait,
+    to_multi_line_string,
+    map_multi_line_string,
+    MultiLineString
+);
+impl_to_geo!(
+    ToGeoMultiPolygon,
+    MultiPolygonTrait,
+    to_multi_polygon,
+    map_multi_polygon,
+    MultiPolygon
+);
+impl_to_geo!(
+    ToGeoGeometryCollection,
+    GeometryCollectionTrait,
+    to_geometry_collection,
+    map_geometry_collection,
+    GeometryCollection
+);
+impl_to_geo!(ToGeoRect, RectTrait, to_rect, map_rect, Rect);
+impl_to_geo!(
+    ToGeoTriangle,
+    TriangleTrait,
+    to_triangle,
+    map_triangle,
+    Triangle
+);
+impl_to_geo!(ToGeoLine, LineTrait, to_line, map_line, Line);
+
+/// Convert any [`GeometryTrait`] implementer to a [`Geometry`].
+pub trait ToGeoGeometry<T: CoordNum> {
+    /// Convert to a [`Geometry`].
+    ///
+    /// `geo_types` can't represent an empty point, so one is converted to an empty
+    /// [`MultiPoint`].
+    fn to_geometry(&self) -> Geometry<T>;
+}
+
+impl<T: CoordNum, G: GeometryTrait<T = T>> ToGeoGeometry<T> for G {
+    fn to_geometry(&self) -> Geometry<T> {
+        map_coords(self, |coord| coord)
+    }
+}
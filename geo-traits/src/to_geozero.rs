@@ -0,0 +1,280 @@
+//! Stream any geometry trait implementer into the processors of the [`geozero`] crate, e.g. to
+//! write it as GeoJSON, SVG or WKB, without converting it to a `geo_types` geometry first.
+//!
+//! [`GeozeroGeometry`] is a foreign trait, so it's implemented for the [`Geozero`] wrapper rather
+//! than for every [`GeometryTrait`] implementer directly. Geometries are processed the way
+//! `geozero` processes `geo_types` geometries: lines are processed as line strings, and rects and
+//! triangles as polygons.
+//!
+//! Processors which build geometries work as builders too, e.g. `geozero::ToGeo`, from the
+//! `with-geo` feature of `geozero`, builds `geo_types` geometries.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::to_geozero::Geozero;
+//! use geo_traits::wkt_str::WktStr;
+//! use geozero::ToJson;
+//!
+//! let wkt = WktStr::new("LINESTRING (1 2, 3 4)").unwrap();
+//! assert_eq!(
+//!     Geozero(wkt.geometry()).to_json().unwrap(),
+//!     r#"{"type": "LineString", "coordinates": [[1,2],[3,4]]}"#
+//! );
+//!
+//! # #[cfg(feature = "geo-types")]
+//! # {
+//! use geo_types::{line_string, Geometry};
+//! use geozero::ToGeo;
+//!
+//! assert_eq!(
+//!     Geozero(wkt.geometry()).to_geo().unwrap(),
+//!     Geometry::LineString(line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+//! );
+//! # }
+//! ```
+
+use geozero::error::Result;
+use geozero::{CoordDimensions, GeomProcessor, GeozeroGeometry};
+
+use crate::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
+};
+
+/// A geometry trait implementer, which implements [`GeozeroGeometry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geozero<G>(pub G);
+
+impl<G: GeometryTrait> GeozeroGeometry for Geozero<G>
+where
+    G::T: Into<f64>,
+{
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        process_geom(&self.0, processor)
+    }
+
+    /// The dimensions of the geometry, or XY if they're unknown.
+    fn dims(&self) -> CoordDimensions {
+        match self.0.dim() {
+            Dimensions::Xy | Dimensions::Unknown(_) => CoordDimensions::xy(),
+            Dimensions::Xyz => CoordDimensions::xyz(),
+            Dimensions::Xym => CoordDimensions::xym(),
+            Dimensions::Xyzm => CoordDimensions::xyzm(),
+        }
+    }
+}
+
+/// Process any [`GeometryTrait`] implementer with a [`GeomProcessor`].
+///
+/// Empty points are processed with [`GeomProcessor::empty_point`], except within multi points,
+/// whose coordinates are processed directly, where their coordinates are NaN.
+pub fn process_geom<T: Into<f64>, P: GeomProcessor>(
+    geom: &impl GeometryTrait<T = T>,
+    processor: &mut P,
+) -> Result<()> {
+    process_geom_n(geom, 0, processor)
+}
+
+fn process_geom_n<T: Into<f64>, P: GeomProcessor>(
+    geom: &impl GeometryTrait<T = T>,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    match geom.as_type() {
+        GeometryType::Point(g) => match g.coord() {
+            Some(coord) => {
+                processor.point_begin(idx)?;
+                process_coord(&coord, 0, processor)?;
+                processor.point_end(idx)
+            }
+            None => processor.empty_point(idx),
+        },
+        GeometryType::LineString(g) => process_line_string(g, true, idx, processor),
+        GeometryType::Polygon(g) => process_polygon(g, true, idx, processor),
+        GeometryType::MultiPoint(g) => {
+            processor.multipoint_begin(g.num_points(), idx)?;
+            for (i, point) in g.points().enumerate() {
+                match point.coord() {
+                    Some(coord) => process_coord(&coord, i, processor)?,
+                    None => process_xy(f64::NAN, f64::NAN, i, processor)?,
+                }
+            }
+            processor.multipoint_end(idx)
+        }
+        GeometryType::MultiLineString(g) => {
+            processor.multilinestring_begin(g.num_line_strings(), idx)?;
+            for (i, line_string) in g.line_strings().enumerate() {
+                process_line_string(&line_string, false, i, processor)?;
+            }
+            processor.multilinestring_end(idx)
+        }
+        GeometryType::MultiPolygon(g) => {
+            processor.multipolygon_begin(g.num_polygons(), idx)?;
+            for (i, polygon) in g.polygons().enumerate() {
+                process_polygon(&polygon, false, i, processor)?;
+            }
+            processor.multipolygon_end(idx)
+        }
+        GeometryType::GeometryCollection(g) => {
+            processor.geometrycollection_begin(g.num_geometries(), idx)?;
+            for (i, geometry) in g.geometries().enumerate() {
+                process_geom_n(&geometry, i, processor)?;
+            }
+            processor.geometrycollection_end(idx)
+        }
+        GeometryType::Rect(g) => {
+            let (min, max) = (g.min(), g.max());
+            let (min_x, min_y) = (min.x().into(), min.y().into());
+            let (max_x, max_y) = (max.x().into(), max.y().into());
+            processor.polygon_begin(true, 1, idx)?;
+            processor.linestring_begin(false, 5, 0)?;
+            process_xy(min_x, min_y, 0, processor)?;
+            process_xy(min_x, max_y, 1, processor)?;
+            process_xy(max_x, max_y, 2, processor)?;
+            process_xy(max_x, min_y, 3, processor)?;
+            process_xy(min_x, min_y, 4, processor)?;
+            processor.linestring_end(false, 0)?;
+            processor.polygon_end(true, idx)
+        }
+        GeometryType::Triangle(g) => {
+            processor.polygon_begin(true, 1, idx)?;
+            processor.linestring_begin(false, 4, 0)?;
+            let coords = g.coords();
+            for (i, coord) in coords.iter().chain(coords.first()).enumerate() {
+                process_coord(coord, i, processor)?;
+            }
+            processor.linestring_end(false, 0)?;
+            processor.polygon_end(true, idx)
+        }
+        GeometryType::Line(g) => {
+            processor.linestring_begin(true, 2, idx)?;
+            process_coord(&g.start(), 0, processor)?;
+            process_coord(&g.end(), 1, processor)?;
+            processor.linestring_end(true, idx)
+        }
+    }
+}
+
+fn process_coord<T: Into<f64>, P: GeomProcessor>(
+    coord: &impl CoordTrait<T = T>,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    if processor.multi_dim() {
+        let (z, m) = (coord.z().map(Into::into), coord.m().map(Into::into));
+        processor.coordinate(coord.x().into(), coord.y().into(), z, m, None, None, idx)
+    } else {
+        processor.xy(coord.x().into(), coord.y().into(), idx)
+    }
+}
+
+fn process_xy<P: GeomProcessor>(x: f64, y: f64, idx: usize, processor: &mut P) -> Result<()> {
+    if processor.multi_dim() {
+        processor.coordinate(x, y, None, None, None, None, idx)
+    } else {
+        processor.xy(x, y, idx)
+    }
+}
+
+fn process_line_string<T: Into<f64>, P: GeomProcessor>(
+    line_string: &impl LineStringTrait<T = T>,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    processor.linestring_begin(tagged, line_string.num_coords(), idx)?;
+    for (i, coord) in line_string.coords().enumerate() {
+        process_coord(&coord, i, processor)?;
+    }
+    processor.linestring_end(tagged, idx)
+}
+
+fn process_polygon<T: Into<f64>, P: GeomProcessor>(
+    polygon: &impl PolygonTrait<T = T>,
+    tagged: bool,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    let exterior = polygon.exterior();
+    let size = usize::from(exterior.is_some()) + polygon.num_interiors();
+    processor.polygon_begin(tagged, size, idx)?;
+    for (i, ring) in exterior.into_iter().chain(polygon.interiors()).enumerate() {
+        process_line_string(&ring, false, i, processor)?;
+    }
+    processor.polygon_end(tagged, idx)
+}
+
+#[cfg(test)]
+mod test {
+    use geozero::geojson::GeoJsonWriter;
+    use geozero::ToJson;
+
+    use super::*;
+    use crate::wkt_str::WktStr;
+
+    fn to_json(wkt: &str) -> String {
+        Geozero(WktStr::new(wkt).unwrap().geometry())
+            .to_json()
+            .unwrap()
+    }
+
+    #[test]
+    fn geometries() {
+        assert_eq!(
+            to_json("MULTIPOINT (1 2, 3 4)"),
+            r#"{"type": "MultiPoint", "coordinates": [[1,2],[3,4]]}"#
+        );
+        assert_eq!(
+            to_json("POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))"),
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[4,0],[4,4],[0,0]],[[1,1],[2,1],[2,2],[1,1]]]}"#
+        );
+        assert_eq!(
+            to_json("GEOMETRYCOLLECTION (POINT (1 2), MULTILINESTRING ((0 0, 1 1)))"),
+            r#"{"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": [1,2]},{"type": "MultiLineString", "coordinates": [[[0,0],[1,1]]]}]}"#
+        );
+        assert_eq!(
+            to_json("POINT EMPTY"),
+            r#"{"type": "Point", "coordinates": []}"#
+        );
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn rect_triangle_and_line() {
+        use geo_types::{coord, Line, Rect, Triangle};
+
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(
+            Geozero(&rect).to_json().unwrap(),
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[0,2],[1,2],[1,0],[0,0]]]}"#
+        );
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        assert_eq!(
+            Geozero(&triangle).to_json().unwrap(),
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[1,0],[0,1],[0,0]]]}"#
+        );
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        assert_eq!(
+            Geozero(&line).to_json().unwrap(),
+            r#"{"type": "LineString", "coordinates": [[0,0],[1,1]]}"#
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        let wkt = WktStr::new("LINESTRING ZM (1 2 3 4, 5 6 7 8)").unwrap();
+        let geometry = Geozero(wkt.geometry());
+        let dims = geometry.dims();
+        assert!(dims.z && dims.m && !dims.t && !dims.tm);
+
+        let mut json = Vec::new();
+        let mut writer = GeoJsonWriter::with_dims(&mut json, CoordDimensions::xyz());
+        geometry.process_geom(&mut writer).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"type": "LineString", "coordinates": [[1,2,3],[5,6,7]]}"#
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Walk through any geometry trait implementer, calling back a [`GeometryVisitor`] for each
+//! part of it, like geozero's processors.
+//!
+//! Serializers, statistics collectors and validators can be written once as visitors, and then
+//! [`process`] geometries from any backend, without converting them first.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use geo_traits::visitor::{process, GeometryVisitor};
+//! use geo_traits::wkt_str::WktStr;
+//! use geo_traits::CoordTrait;
+//!
+//! /// Counts the coordinates and rings of a geometry.
+//! #[derive(Default)]
+//! struct Counter {
+//!     coords: usize,
+//!     rings: usize,
+//! }
+//!
+//! impl GeometryVisitor<f64> for Counter {
+//!     type Error = Infallible;
+//!
+//!     fn coord(&mut self, _idx: usize, _coord: &impl CoordTrait<T = f64>) -> Result<(), Infallible> {
+//!         self.coords += 1;
+//!         Ok(())
+//!     }
+//!
+//!     fn polygon_begin(&mut self, size: usize, _idx: usize) -> Result<(), Infallible> {
+//!         self.rings += size;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let wkt = WktStr::new("GEOMETRYCOLLECTION (POINT (0 0), POLYGON ((0 0, 1 0, 0 1, 0 0)))").unwrap();
+//! let mut counter = Counter::default();
+//! process(&wkt.geometry(), &mut counter).unwrap();
+//! assert_eq!((counter.coords, counter.rings), (5, 1));
+//! ```
+
+use crate::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+/// Callbacks for each part of a geometry, called by [`process`].
+///
+/// Every method does nothing by default, so a visitor only implements the ones it's interested
+/// in. `size` is the number of parts which follow a `_begin` call, e.g. the number of rings of a
+/// polygon, and `idx` is the index of the part within its parent, or zero for the geometry being
+/// processed. Returning an error stops processing.
+///
+/// Coordinates are visited through [`coord`](Self::coord), between the `_begin` and `_end` calls
+/// of the point, line string, ring, rect, triangle or line they belong to. The rings of a polygon
+/// are visited as line strings, starting with the exterior.
+#[allow(unused_variables)]
+pub trait GeometryVisitor<T> {
+    /// The error which stops processing
+    type Error;
+
+    /// A coordinate
+    fn coord(&mut self, idx: usize, coord: &impl CoordTrait<T = T>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a point, which is followed by its coordinate unless it's empty
+    fn point_begin(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a point
+    fn point_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a line string, or of a ring of a polygon, with `size` coordinates
+    fn line_string_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a line string, or of a ring of a polygon
+    fn line_string_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a polygon, with `size` rings
+    fn polygon_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a polygon
+    fn polygon_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a multi point, with `size` points
+    fn multi_point_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a multi point
+    fn multi_point_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a multi line string, with `size` line strings
+    fn multi_line_string_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a multi line string
+    fn multi_line_string_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a multi polygon, with `size` polygons
+    fn multi_polygon_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a multi polygon
+    fn multi_polygon_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a geometry collection, with `size` geometries
+    fn geometry_collection_begin(&mut self, size: usize, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a geometry collection
+    fn geometry_collection_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a rect, which is followed by its min and max coordinates
+    fn rect_begin(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a rect
+    fn rect_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a triangle, which is followed by its three coordinates
+    fn triangle_begin(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a triangle
+    fn triangle_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The start of a line, which is followed by its start and end coordinates
+    fn line_begin(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The end of a line
+    fn line_end(&mut self, idx: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Call `visitor` back for each part of `geometry`, in order.
+pub fn process<T, V: GeometryVisitor<T>>(
+    geometry: &impl GeometryTrait<T = T>,
+    visitor: &mut V,
+) -> Result<(), V::Error> {
+    process_geometry(geometry, 0, visitor)
+}
+
+fn process_geometry<T, V: GeometryVisitor<T>>(
+    geometry: &impl GeometryTrait<T = T>,
+    idx: usize,
+    visitor: &mut V,
+) -> Result<(), V::Error> {
+    match geometry.as_type() {
+        GeometryType::Point(g) => process_point(g, idx, visitor),
+        GeometryType::LineString(g) => process_line_string(g, idx, visitor),
+        GeometryType::Polygon(g) => process_polygon(g, idx, visitor),
+        GeometryType::MultiPoint(g) => {
+            visitor.multi_point_begin(g.num_points(), idx)?;
+            for (i, point) in g.points().enumerate() {
+                process_point(&point, i, visitor)?;
+            }
+            visitor.multi_point_end(idx)
+        }
+        GeometryType::MultiLineString(g) => {
+            visitor.multi_line_string_begin(g.num_line_strings(), idx)?;
+            for (i, line_string) in g.line_strings().enumerate() {
+                process_line_string(&line_string, i, visitor)?;
+            }
+            visitor.multi_line_string_end(idx)
+        }
+        GeometryType::MultiPolygon(g) => {
+            visitor.multi_polygon_begin(g.num_polygons(), idx)?;
+            for (i, polygon) in g.polygons().enumerate() {
+                process_polygon(&polygon, i, visitor)?;
+            }
+            visitor.multi_polygon_end(idx)
+        }
+        GeometryType::GeometryCollection(g) => {
+            visitor.geometry_collection_begin(g.num_geometries(), idx)?;
+            for (i, geometry) in g.geometries().enumerate() {
+                process_geometry(&geometry, i, visitor)?;
+            }
+            visitor.geometry_collection_end(idx)
+        }
+        GeometryType::Rect(g) => {
+            visitor.rect_begin(idx)?;
+            visitor.coord(0, &g.min())?;
+            visitor.coord(1, &g.max())?;
+            visitor.rect_end(idx)
+        }
+        GeometryType::Triangle(g) => {
+            visitor.triangle_begin(idx)?;
+            for (i, coord) in g.coords().iter().enumerate() {
+                visitor.coord(i, coord)?;
+            }
+            visitor.triangle_end(idx)
+        }
+        GeometryType::Line(g) => {
+            visitor.line_begin(idx)?;
+            visitor.coord(0, &g.start())?;
+            visitor.coord(1, &g.end())?;
+            visitor.line_end(idx)
+        }
+    }
+}
+
+fn process_point<T, V: GeometryVisitor<T>>(
+    point: &impl PointTrait<T = T>,
+    idx: usize,
+    visitor: &mut V,
+) -> Result<(), V::Error> {
+    visitor.point_begin(idx)?;
+    if let Some(coord) = point.coord() {
+        visitor.coord(0, &coord)?;
+    }
+    visitor.point_end(idx)
+}
+
+fn process_line_string<T, V: GeometryVisitor<T>>(
+    line_string: &impl LineStringTrait<T = T>,
+    idx: usize,
+    visitor: &mut V,
+) -> Result<(), V::Error> {
+    visitor.line_string_begin(line_string.num_coords(), idx)?;
+    for (i, coord) in line_string.coords().enumerate() {
+        visitor.coord(i, &coord)?;
+    }
+    visitor.line_string_end(idx)
+}
+
+fn process_polygon<T, V: GeometryVisitor<T>>(
+    polygon: &impl PolygonTrait<T = T>,
+    idx: usize,
+    visitor: &mut V,
+) -> Result<(), V::Error> {
+    let exterior = polygon.exterior();
+    let size = usize::from(exterior.is_some()) + polygon.num_interiors();
+    visitor.polygon_begin(size, idx)?;
+    for (i, ring) in exterior.into_iter().chain(polygon.interiors()).enumerate() {
+        process_line_string(&ring, i, visitor)?;
+    }
+    visitor.polygon_end(idx)
+}
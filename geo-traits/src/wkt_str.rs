@@ -0,0 +1,913 @@
+//! Access [WKT] text through the geometry traits, without parsing it into owned geometries.
+//!
+//! [`WktStr::new`] makes a single pass over the text to check its syntax and record where each
+//! coordinate starts. After that, each coordinate value is read from the text when it's
+//! accessed, so the only allocations are the offset tables.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_traits::wkt_str::WktStr;
+//! use geo_traits::{CoordTrait, GeometryTrait, GeometryType, LineStringTrait, PolygonTrait};
+//!
+//! let wkt = WktStr::new("POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))").unwrap();
+//! let geometry = wkt.geometry();
+//! let GeometryType::Polygon(polygon) = geometry.as_type() else {
+//!     panic!("expected a polygon");
+//! };
+//! assert_eq!(polygon.num_interiors(), 1);
+//!
+//! let exterior = polygon.exterior().unwrap();
+//! assert_eq!(exterior.num_coords(), 4);
+//! assert_eq!(exterior.coord(2).unwrap().x_y(), (4., 4.));
+//! ```
+//!
+//! Combined with [`map_coords`](crate::map_coords::map_coords), WKT can be converted to
+//! `geo_types` geometries:
+//!
+//! ```
+//! use geo_traits::{map_coords::map_coords, wkt_str::WktStr, Dimensions, GeometryTrait};
+//! use geo_types::{point, Geometry, GeometryCollection};
+//!
+//! let wkt = WktStr::new("GEOMETRYCOLLECTION Z (POINT (1 2 3), MULTIPOINT ((4 5 6)))").unwrap();
+//! assert_eq!(wkt.geometry().dim(), Dimensions::Xyz);
+//!
+//! assert_eq!(
+//!     map_coords(&wkt.geometry(), |c| c),
+//!     Geometry::GeometryCollection(GeometryCollection(vec![
+//!         Geometry::Point(point!(x: 1., y: 2.)),
+//!         Geometry::MultiPoint(vec![point!(x: 4., y: 5.)].into()),
+//!     ]))
+//! );
+//! ```
+//!
+//! [WKT]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
+
+use std::fmt;
+
+use crate::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+
+/// WKT text which has been checked and indexed, so that it can be accessed through the geometry
+/// traits with [`WktStr::geometry`].
+///
+/// Every geometry in the text, including those nested in a `GEOMETRYCOLLECTION`, must have the
+/// same dimensions. They're taken from the `Z`, `M` or `ZM` tag if there is one, and otherwise
+/// from the number of values in the first coordinate.
+#[derive(Debug, Clone)]
+pub struct WktStr<'a> {
+    text: &'a str,
+    dim: Dimensions,
+    /// The byte offset in `text` of each coordinate
+    coords: Vec<usize>,
+    /// Spans of `coords`, each of which is a line string or ring
+    parts: Vec<Span>,
+    /// Spans of `parts`, each of which is the rings of a polygon
+    polygons: Vec<Span>,
+    /// The members of every geometry collection, each stored as a contiguous span
+    geometries: Vec<Node>,
+    root: Node,
+}
+
+/// A half-open range of indices into one of the tables of a [`WktStr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    fn len(self) -> usize {
+        self.end - self.start
+    }
+
+    fn get(self, i: usize) -> usize {
+        self.start + i
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    /// An index into `coords`, or `None` if empty
+    Point(Option<usize>),
+    /// A span of `coords`
+    LineString(Span),
+    /// A span of `parts`
+    Polygon(Span),
+    /// A span of `coords`
+    MultiPoint(Span),
+    /// A span of `parts`
+    MultiLineString(Span),
+    /// A span of `polygons`
+    MultiPolygon(Span),
+    /// A span of `geometries`
+    GeometryCollection(Span),
+}
+
+/// An error returned by [`WktStr::new`] for text which isn't valid WKT, or which this module
+/// doesn't support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WktStrError {
+    /// A description of what was wrong.
+    pub message: &'static str,
+    /// The byte offset in the text at which the problem was found.
+    pub position: usize,
+}
+
+impl fmt::Display for WktStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid WKT at byte {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for WktStrError {}
+
+impl<'a> WktStr<'a> {
+    /// Check the syntax of `text` and index the position of its coordinates.
+    ///
+    /// All geometry types except `TRIANGLE`, `TIN` and the curved types are supported. An empty
+    /// point is only supported at the top level or in a `GEOMETRYCOLLECTION`, not in a
+    /// `MULTIPOINT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_traits::wkt_str::WktStr;
+    ///
+    /// assert!(WktStr::new("LINESTRING EMPTY").is_ok());
+    ///
+    /// let error = WktStr::new("LINESTRING (0 0, 1)").unwrap_err();
+    /// assert_eq!(error.position, 18);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "invalid WKT at byte 18: expected a coordinate with 2 to 4 values"
+    /// );
+    /// ```
+    pub fn new(text: &'a str) -> Result<Self, WktStrError> {
+        let mut indexer = Indexer {
+            text,
+            pos: 0,
+            dim: None,
+            coords: Vec::new(),
+            parts: Vec::new(),
+            polygons: Vec::new(),
+            geometries: Vec::new(),
+            depth: 0,
+        };
+        let root = indexer.geometry()?;
+        indexer.skip_whitespace();
+        if indexer.pos != text.len() {
+            return Err(indexer.error("unexpected text after geometry"));
+        }
+        Ok(Self {
+            text,
+            // only empty geometries don't determine their dimensions
+            dim: indexer.dim.unwrap_or(Dimensions::Xy),
+            coords: indexer.coords,
+            parts: indexer.parts,
+            polygons: indexer.polygons,
+            geometries: indexer.geometries,
+            root,
+        })
+    }
+
+    /// The text which was indexed.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// The geometry described by the text, which implements [`GeometryTrait`].
+    pub fn geometry(&self) -> WktGeometry<'_> {
+        self.view(self.root)
+    }
+
+    fn view(&self, node: Node) -> WktGeometry<'_> {
+        match node {
+            Node::Point(coord) => WktGeometry::Point(WktPoint { wkt: self, coord }),
+            Node::LineString(coords) => {
+                WktGeometry::LineString(WktLineString { wkt: self, coords })
+            }
+            Node::Polygon(rings) => WktGeometry::Polygon(WktPolygon { wkt: self, rings }),
+            Node::MultiPoint(coords) => {
+                WktGeometry::MultiPoint(WktMultiPoint { wkt: self, coords })
+            }
+            Node::MultiLineString(parts) => {
+                WktGeometry::MultiLineString(WktMultiLineString { wkt: self, parts })
+            }
+            Node::MultiPolygon(polygons) => WktGeometry::MultiPolygon(WktMultiPolygon {
+                wkt: self,
+                polygons,
+            }),
+            Node::GeometryCollection(geometries) => {
+                WktGeometry::GeometryCollection(WktGeometryCollection {
+                    wkt: self,
+                    geometries,
+                })
+            }
+        }
+    }
+
+    fn coord(&self, i: usize) -> WktCoord<'_> {
+        WktCoord {
+            text: self.text,
+            offset: self.coords[i],
+            dim: self.dim,
+        }
+    }
+
+    fn line_string(&self, part: usize) -> WktLineString<'_> {
+        WktLineString {
+            wkt: self,
+            coords: self.parts[part],
+        }
+    }
+}
+
+/// The single pass over the text made by [`WktStr::new`].
+struct Indexer<'a> {
+    text: &'a str,
+    pos: usize,
+    dim: Option<Dimensions>,
+    coords: Vec<usize>,
+    parts: Vec<Span>,
+    polygons: Vec<Span>,
+    geometries: Vec<Node>,
+    /// The number of `GEOMETRYCOLLECTION`s the indexer is in.
+    depth: usize,
+}
+
+/// The deepest nesting of `GEOMETRYCOLLECTION`s that is indexed, so that crafted text can't
+/// overflow the stack. Every collection counts, including an empty one.
+///
+/// This is the same limit as `geo_types::MAX_COLLECTION_DEPTH`, which the `geo-types` and `geo`
+/// readers use.
+pub const MAX_COLLECTION_DEPTH: usize = 128;
+
+impl Indexer<'_> {
+    fn error(&self, message: &'static str) -> WktStrError {
+        WktStrError {
+            message,
+            position: self.pos,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8, message: &'static str) -> Result<(), WktStrError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Consume a keyword, or return an empty string if there isn't one.
+    fn word(&mut self) -> &str {
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consume a keyword if it matches `expected`, ignoring case.
+    fn eat_word(&mut self, expected: &str) -> bool {
+        let start = self.pos;
+        if self.word().eq_ignore_ascii_case(expected) {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn set_dim(&mut self, dim: Dimensions) -> Result<(), WktStrError> {
+        match self.dim {
+            Some(existing) if existing != dim => {
+                Err(self.error("all geometries must have the same dimensions"))
+            }
+            _ => {
+                self.dim = Some(dim);
+                Ok(())
+            }
+        }
+    }
+
+    fn geometry(&mut self) -> Result<Node, WktStrError> {
+        let start = self.pos;
+        let kind = self.word().to_ascii_uppercase();
+        if self.eat_word("ZM") {
+            self.set_dim(Dimensions::Xyzm)?;
+        } else if self.eat_word("Z") {
+            self.set_dim(Dimensions::Xyz)?;
+        } else if self.eat_word("M") {
+            self.set_dim(Dimensions::Xym)?;
+        }
+        let empty = self.eat_word("EMPTY");
+        let empty_span = Span { start: 0, end: 0 };
+        let node = match kind.as_str() {
+            "POINT" if empty => Node::Point(None),
+            "POINT" => {
+                self.expect(b'(', "expected `(`")?;
+                let coord = self.coord()?;
+                self.expect(b')', "expected `)`")?;
+                Node::Point(Some(coord))
+            }
+            "LINESTRING" if empty => Node::LineString(empty_span),
+            "LINESTRING" => Node::LineString(self.coord_sequence()?),
+            "POLYGON" if empty => Node::Polygon(empty_span),
+            "POLYGON" => Node::Polygon(self.rings()?),
+            "MULTIPOINT" if empty => Node::MultiPoint(empty_span),
+            "MULTIPOINT" => Node::MultiPoint(self.multi_point()?),
+            "MULTILINESTRING" if empty => Node::MultiLineString(empty_span),
+            "MULTILINESTRING" => Node::MultiLineString(self.rings()?),
+            "MULTIPOLYGON" if empty => Node::MultiPolygon(empty_span),
+            "MULTIPOLYGON" => {
+                self.expect(b'(', "expected `(`")?;
+                let mut polygons = Vec::new();
+                loop {
+                    polygons.push(self.rings()?);
+                    if !self.eat(b',') {
+                        break;
+                    }
+                }
+                self.expect(b')', "expected `)` or `,`")?;
+                Node::MultiPolygon(push_all(&mut self.polygons, polygons))
+            }
+            "GEOMETRYCOLLECTION" if self.depth == MAX_COLLECTION_DEPTH => {
+                self.pos = start;
+                return Err(self.error("geometry collections are nested too deeply"));
+            }
+            "GEOMETRYCOLLECTION" if empty => Node::GeometryCollection(empty_span),
+            "GEOMETRYCOLLECTION" => {
+                self.depth += 1;
+                self.expect(b'(', "expected `(`")?;
+                let mut geometries = Vec::new();
+                loop {
+                    geometries.push(self.geometry()?);
+                    if !self.eat(b',') {
+                        break;
+                    }
+                }
+                self.expect(b')', "expected `)` or `,`")?;
+                self.depth -= 1;
+                // nested collections have already pushed their members, so these are contiguous
+                Node::GeometryCollection(push_all(&mut self.geometries, geometries))
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error("expected a supported WKT geometry type"));
+            }
+        };
+        Ok(node)
+    }
+
+    /// A coordinate, returning its index in `coords`.
+    fn coord(&mut self) -> Result<usize, WktStrError> {
+        self.skip_whitespace();
+        let offset = self.pos;
+        let mut count = 0;
+        loop {
+            self.skip_whitespace();
+            let rest = &self.text[self.pos..];
+            let len = rest
+                .find(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')')
+                .unwrap_or(rest.len());
+            if len == 0 {
+                break;
+            }
+            if rest[..len].parse::<f64>().is_err() {
+                return Err(self.error("expected a number"));
+            }
+            self.pos += len;
+            count += 1;
+        }
+        let dim = match count {
+            2 => Dimensions::Xy,
+            3 if self.dim == Some(Dimensions::Xym) => Dimensions::Xym,
+            3 => Dimensions::Xyz,
+            4 => Dimensions::Xyzm,
+            _ => return Err(self.error("expected a coordinate with 2 to 4 values")),
+        };
+        self.set_dim(dim)?;
+        self.coords.push(offset);
+        Ok(self.coords.len() - 1)
+    }
+
+    /// A parenthesized list of coordinates, returning its span of `coords`.
+    fn coord_sequence(&mut self) -> Result<Span, WktStrError> {
+        self.expect(b'(', "expected `(`")?;
+        let start = self.coords.len();
+        loop {
+            self.coord()?;
+            if !self.eat(b',') {
+                break;
+            }
+        }
+        self.expect(b')', "expected `)` or `,`")?;
+        Ok(Span {
+            start,
+            end: self.coords.len(),
+        })
+    }
+
+    /// A parenthesized list of coordinate sequences, returning its span of `parts`.
+    fn rings(&mut self) -> Result<Span, WktStrError> {
+        self.expect(b'(', "expected `(`")?;
+        let mut rings = Vec::new();
+        loop {
+            rings.push(self.coord_sequence()?);
+            if !self.eat(b',') {
+                break;
+            }
+        }
+        self.expect(b')', "expected `)` or `,`")?;
+        Ok(push_all(&mut self.parts, rings))
+    }
+
+    /// The points of a `MULTIPOINT`, which may or may not be parenthesized.
+    fn multi_point(&mut self) -> Result<Span, WktStrError> {
+        self.expect(b'(', "expected `(`")?;
+        let start = self.coords.len();
+        loop {
+            if self.eat(b'(') {
+                self.coord()?;
+                self.expect(b')', "expected `)`")?;
+            } else {
+                self.coord()?;
+            }
+            if !self.eat(b',') {
+                break;
+            }
+        }
+        self.expect(b')', "expected `)` or `,`")?;
+        Ok(Span {
+            start,
+            end: self.coords.len(),
+        })
+    }
+}
+
+fn push_all<T>(table: &mut Vec<T>, items: Vec<T>) -> Span {
+    let start = table.len();
+    table.extend(items);
+    Span {
+        start,
+        end: table.len(),
+    }
+}
+
+/// A coordinate in a [`WktStr`], whose values are parsed from the text when they're accessed.
+#[derive(Debug, Clone, Copy)]
+pub struct WktCoord<'a> {
+    text: &'a str,
+    offset: usize,
+    dim: Dimensions,
+}
+
+impl CoordTrait for WktCoord<'_> {
+    type T = f64;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn x(&self) -> f64 {
+        self.nth_or_panic(0)
+    }
+
+    fn y(&self) -> f64 {
+        self.nth_or_panic(1)
+    }
+
+    fn nth_or_panic(&self, n: usize) -> f64 {
+        let rest = &self.text[self.offset..];
+        let end = rest.find([',', ')']).unwrap_or(rest.len());
+        rest[..end]
+            .split_whitespace()
+            .nth(n)
+            .expect("coordinate index out of bounds")
+            .parse()
+            .expect("coordinate values were checked when indexing")
+    }
+}
+
+/// A point in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktPoint<'a> {
+    wkt: &'a WktStr<'a>,
+    coord: Option<usize>,
+}
+
+impl<'a> PointTrait for WktPoint<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = WktCoord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        self.coord.map(|i| self.wkt.coord(i))
+    }
+}
+
+/// A line string, or polygon ring, in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktLineString<'a> {
+    wkt: &'a WktStr<'a>,
+    coords: Span,
+}
+
+impl<'a> LineStringTrait for WktLineString<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = WktCoord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn num_coords(&self) -> usize {
+        self.coords.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.wkt.coord(self.coords.get(i))
+    }
+}
+
+/// A polygon in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktPolygon<'a> {
+    wkt: &'a WktStr<'a>,
+    rings: Span,
+}
+
+impl<'a> PolygonTrait for WktPolygon<'a> {
+    type T = f64;
+    type RingType<'b>
+        = WktLineString<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        (self.rings.len() > 0).then(|| self.wkt.line_string(self.rings.start))
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.rings.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        self.wkt.line_string(self.rings.get(i + 1))
+    }
+}
+
+/// A multi point in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktMultiPoint<'a> {
+    wkt: &'a WktStr<'a>,
+    coords: Span,
+}
+
+impl<'a> MultiPointTrait for WktMultiPoint<'a> {
+    type T = f64;
+    type PointType<'b>
+        = WktPoint<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn num_points(&self) -> usize {
+        self.coords.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        WktPoint {
+            wkt: self.wkt,
+            coord: Some(self.coords.get(i)),
+        }
+    }
+}
+
+/// A multi line string in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktMultiLineString<'a> {
+    wkt: &'a WktStr<'a>,
+    parts: Span,
+}
+
+impl<'a> MultiLineStringTrait for WktMultiLineString<'a> {
+    type T = f64;
+    type LineStringType<'b>
+        = WktLineString<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.parts.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        self.wkt.line_string(self.parts.get(i))
+    }
+}
+
+/// A multi polygon in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktMultiPolygon<'a> {
+    wkt: &'a WktStr<'a>,
+    polygons: Span,
+}
+
+impl<'a> MultiPolygonTrait for WktMultiPolygon<'a> {
+    type T = f64;
+    type PolygonType<'b>
+        = WktPolygon<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.polygons.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        WktPolygon {
+            wkt: self.wkt,
+            rings: self.wkt.polygons[self.polygons.get(i)],
+        }
+    }
+}
+
+/// A geometry collection in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub struct WktGeometryCollection<'a> {
+    wkt: &'a WktStr<'a>,
+    geometries: Span,
+}
+
+impl<'a> GeometryCollectionTrait for WktGeometryCollection<'a> {
+    type T = f64;
+    type GeometryType<'b>
+        = WktGeometry<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.wkt.dim
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.geometries.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.wkt.view(self.wkt.geometries[self.geometries.get(i)])
+    }
+}
+
+/// Any geometry in a [`WktStr`].
+#[derive(Debug, Clone, Copy)]
+pub enum WktGeometry<'a> {
+    /// A `POINT`
+    Point(WktPoint<'a>),
+    /// A `LINESTRING`
+    LineString(WktLineString<'a>),
+    /// A `POLYGON`
+    Polygon(WktPolygon<'a>),
+    /// A `MULTIPOINT`
+    MultiPoint(WktMultiPoint<'a>),
+    /// A `MULTILINESTRING`
+    MultiLineString(WktMultiLineString<'a>),
+    /// A `MULTIPOLYGON`
+    MultiPolygon(WktMultiPolygon<'a>),
+    /// A `GEOMETRYCOLLECTION`
+    GeometryCollection(WktGeometryCollection<'a>),
+}
+
+impl<'a> GeometryTrait for WktGeometry<'a> {
+    type T = f64;
+    type PointType<'b>
+        = WktPoint<'a>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = WktLineString<'a>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = WktPolygon<'a>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = WktMultiPoint<'a>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = WktMultiLineString<'a>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = WktMultiPolygon<'a>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = WktGeometryCollection<'a>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = UnimplementedRect<f64>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = UnimplementedLine<f64>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        match self {
+            WktGeometry::Point(g) => g.wkt.dim,
+            WktGeometry::LineString(g) => g.wkt.dim,
+            WktGeometry::Polygon(g) => g.wkt.dim,
+            WktGeometry::MultiPoint(g) => g.wkt.dim,
+            WktGeometry::MultiLineString(g) => g.wkt.dim,
+            WktGeometry::MultiPolygon(g) => g.wkt.dim,
+            WktGeometry::GeometryCollection(g) => g.wkt.dim,
+        }
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        WktPoint<'a>,
+        WktLineString<'a>,
+        WktPolygon<'a>,
+        WktMultiPoint<'a>,
+        WktMultiLineString<'a>,
+        WktMultiPolygon<'a>,
+        WktGeometryCollection<'a>,
+        UnimplementedRect<f64>,
+        UnimplementedTriangle<f64>,
+        UnimplementedLine<f64>,
+    > {
+        match self {
+            WktGeometry::Point(g) => GeometryType::Point(g),
+            WktGeometry::LineString(g) => GeometryType::LineString(g),
+            WktGeometry::Polygon(g) => GeometryType::Polygon(g),
+            WktGeometry::MultiPoint(g) => GeometryType::MultiPoint(g),
+            WktGeometry::MultiLineString(g) => GeometryType::MultiLineString(g),
+            WktGeometry::MultiPolygon(g) => GeometryType::MultiPolygon(g),
+            WktGeometry::GeometryCollection(g) => GeometryType::GeometryCollection(g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(text: &str) -> String {
+        WktStr::new(text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn unbalanced_parentheses() {
+        assert_eq!(error("POINT (1 2"), "invalid WKT at byte 10: expected `)`");
+        assert_eq!(
+            error("LINESTRING (0 0, 1 1"),
+            "invalid WKT at byte 20: expected `)` or `,`"
+        );
+        assert_eq!(
+            error("POLYGON ((0 0, 1 0, 0 1, 0 0)"),
+            "invalid WKT at byte 29: expected `)` or `,`"
+        );
+        assert_eq!(error("POINT 1 2)"), "invalid WKT at byte 6: expected `(`");
+        assert_eq!(
+            error("POINT (1 2))"),
+            "invalid WKT at byte 11: unexpected text after geometry"
+        );
+    }
+
+    #[test]
+    fn mixed_dimensions() {
+        assert_eq!(
+            error("LINESTRING (0 0, 1 1 1)"),
+            "invalid WKT at byte 22: all geometries must have the same dimensions"
+        );
+        assert_eq!(
+            error("GEOMETRYCOLLECTION (POINT (1 2), POINT Z (1 2 3))"),
+            "invalid WKT at byte 40: all geometries must have the same dimensions"
+        );
+        assert_eq!(
+            error("POINT M (1 2 3 4)"),
+            "invalid WKT at byte 16: all geometries must have the same dimensions"
+        );
+        assert_eq!(
+            error("POINT (1 2 3 4 5)"),
+            "invalid WKT at byte 16: expected a coordinate with 2 to 4 values"
+        );
+    }
+
+    #[test]
+    fn bad_numbers() {
+        assert_eq!(
+            error("POINT (1 x)"),
+            "invalid WKT at byte 9: expected a number"
+        );
+        assert_eq!(
+            error("POINT (1 2.3.4)"),
+            "invalid WKT at byte 9: expected a number"
+        );
+        assert_eq!(
+            error("MULTIPOINT ((1 2), (3 -))"),
+            "invalid WKT at byte 22: expected a number"
+        );
+    }
+
+    #[test]
+    fn trailing_input() {
+        assert_eq!(
+            error("POINT (1 2) POINT (3 4)"),
+            "invalid WKT at byte 12: unexpected text after geometry"
+        );
+        assert_eq!(
+            error("POINT EMPTY,"),
+            "invalid WKT at byte 11: unexpected text after geometry"
+        );
+        assert_eq!(
+            error("CIRCLE (0 0)"),
+            "invalid WKT at byte 0: expected a supported WKT geometry type"
+        );
+    }
+
+    #[test]
+    fn nested_collections() {
+        use geo_test_fixtures::nested_collections_wkt as nested;
+
+        let text = nested(MAX_COLLECTION_DEPTH);
+        let wkt = WktStr::new(&text).unwrap();
+        assert!(matches!(wkt.geometry(), WktGeometry::GeometryCollection(_)));
+        assert_eq!(
+            error(&nested(MAX_COLLECTION_DEPTH + 1)),
+            "invalid WKT at byte 2560: geometry collections are nested too deeply"
+        );
+        assert_eq!(
+            WktStr::new(&nested(200_000)).unwrap_err().message,
+            "geometry collections are nested too deeply"
+        );
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn same_depth_as_geo_types() {
+        assert_eq!(MAX_COLLECTION_DEPTH, geo_types::MAX_COLLECTION_DEPTH);
+    }
+}
@@ -0,0 +1,438 @@
+//! Implementations of the geometry traits for the parsed types of the [`wkt`] crate.
+
+use wkt::types::{
+    Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use wkt::{Geometry, Wkt, WktNum};
+
+use crate::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+
+/// The dimensions of the first coordinate, or XY if there are no coordinates.
+fn first_dim<'a, T: WktNum + 'a>(mut coords: impl Iterator<Item = &'a Coord<T>>) -> Dimensions {
+    coords.next().map_or(Dimensions::Xy, CoordTrait::dim)
+}
+
+impl<T: WktNum> CoordTrait for Coord<T> {
+    type T = T;
+
+    fn dim(&self) -> Dimensions {
+        match (self.z, self.m) {
+            (None, None) => Dimensions::Xy,
+            (Some(_), None) => Dimensions::Xyz,
+            (None, Some(_)) => Dimensions::Xym,
+            (Some(_), Some(_)) => Dimensions::Xyzm,
+        }
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        let value = match n {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => self.z.or(self.m),
+            3 => self.z.and(self.m),
+            _ => None,
+        };
+        value.unwrap_or_else(|| panic!("coordinate has no dimension {n}"))
+    }
+
+    fn x(&self) -> Self::T {
+        self.x
+    }
+
+    fn y(&self) -> Self::T {
+        self.y
+    }
+}
+
+impl<T: WktNum> CoordTrait for &Coord<T> {
+    type T = T;
+
+    fn dim(&self) -> Dimensions {
+        (*self).dim()
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        (*self).nth_or_panic(n)
+    }
+
+    fn x(&self) -> Self::T {
+        self.x
+    }
+
+    fn y(&self) -> Self::T {
+        self.y
+    }
+}
+
+impl<T: WktNum> PointTrait for Point<T> {
+    type T = T;
+    type CoordType<'a> = &'a Coord<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        self.0.as_ref()
+    }
+}
+
+impl<'a, T: WktNum> PointTrait for &'a Point<T> {
+    type T = T;
+    type CoordType<'b> = &'a Coord<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        self.0.as_ref()
+    }
+}
+
+impl<T: WktNum> LineStringTrait for LineString<T> {
+    type T = T;
+    type CoordType<'a> = &'a Coord<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> LineStringTrait for &'a LineString<T> {
+    type T = T;
+    type CoordType<'b> = &'a Coord<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter())
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<T: WktNum> PolygonTrait for Polygon<T> {
+    type T = T;
+    type RingType<'a> = &'a LineString<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|ring| &ring.0))
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.first()
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        self.0.get_unchecked(i + 1)
+    }
+}
+
+impl<'a, T: WktNum> PolygonTrait for &'a Polygon<T> {
+    type T = T;
+    type RingType<'b> = &'a LineString<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|ring| &ring.0))
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.first()
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        self.0.get_unchecked(i + 1)
+    }
+}
+
+impl<T: WktNum> MultiPointTrait for MultiPoint<T> {
+    type T = T;
+    type PointType<'a> = &'a Point<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|point| &point.0))
+    }
+
+    fn num_points(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> MultiPointTrait for &'a MultiPoint<T> {
+    type T = T;
+    type PointType<'b> = &'a Point<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|point| &point.0))
+    }
+
+    fn num_points(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<T: WktNum> MultiLineStringTrait for MultiLineString<T> {
+    type T = T;
+    type LineStringType<'a> = &'a LineString<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|line_string| &line_string.0))
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> MultiLineStringTrait for &'a MultiLineString<T> {
+    type T = T;
+    type LineStringType<'b> = &'a LineString<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|line_string| &line_string.0))
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<T: WktNum> MultiPolygonTrait for MultiPolygon<T> {
+    type T = T;
+    type PolygonType<'a> = &'a Polygon<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|polygon| &polygon.0).flat_map(|ring| &ring.0))
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> MultiPolygonTrait for &'a MultiPolygon<T> {
+    type T = T;
+    type PolygonType<'b> = &'a Polygon<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        first_dim(self.0.iter().flat_map(|polygon| &polygon.0).flat_map(|ring| &ring.0))
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<T: WktNum> GeometryCollectionTrait for GeometryCollection<T> {
+    type T = T;
+    type GeometryType<'a> = &'a Geometry<T> where Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        self.0.first().map_or(Dimensions::Xy, GeometryTrait::dim)
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> GeometryCollectionTrait for &'a GeometryCollection<T> {
+    type T = T;
+    type GeometryType<'b> = &'a Geometry<T> where Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.0.first().map_or(Dimensions::Xy, GeometryTrait::dim)
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+/// The [`GeometryType`] of WKT geometries, which have no rects, triangles or lines.
+type WktGeometryType<'a, T> = GeometryType<
+    'a,
+    Point<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    UnimplementedRect<T>,
+    UnimplementedTriangle<T>,
+    UnimplementedLine<T>,
+>;
+
+fn geometry_dim<T: WktNum>(geometry: &Geometry<T>) -> Dimensions {
+    match geometry {
+        Geometry::Point(g) => PointTrait::dim(g),
+        Geometry::LineString(g) => LineStringTrait::dim(g),
+        Geometry::Polygon(g) => PolygonTrait::dim(g),
+        Geometry::MultiPoint(g) => MultiPointTrait::dim(g),
+        Geometry::MultiLineString(g) => MultiLineStringTrait::dim(g),
+        Geometry::MultiPolygon(g) => MultiPolygonTrait::dim(g),
+        Geometry::GeometryCollection(g) => GeometryCollectionTrait::dim(g),
+    }
+}
+
+fn geometry_type<T: WktNum>(geometry: &Geometry<T>) -> WktGeometryType<'_, T> {
+    match geometry {
+        Geometry::Point(g) => GeometryType::Point(g),
+        Geometry::LineString(g) => GeometryType::LineString(g),
+        Geometry::Polygon(g) => GeometryType::Polygon(g),
+        Geometry::MultiPoint(g) => GeometryType::MultiPoint(g),
+        Geometry::MultiLineString(g) => GeometryType::MultiLineString(g),
+        Geometry::MultiPolygon(g) => GeometryType::MultiPolygon(g),
+        Geometry::GeometryCollection(g) => GeometryType::GeometryCollection(g),
+    }
+}
+
+macro_rules! impl_geometry_trait {
+    (<$($lifetime:lifetime)?> $type:ty, $self:ident => $geometry:expr) => {
+        impl<$($lifetime,)? T: WktNum> GeometryTrait for $type {
+            type T = T;
+            type PointType<'b> = Point<T> where Self: 'b;
+            type LineStringType<'b> = LineString<T> where Self: 'b;
+            type PolygonType<'b> = Polygon<T> where Self: 'b;
+            type MultiPointType<'b> = MultiPoint<T> where Self: 'b;
+            type MultiLineStringType<'b> = MultiLineString<T> where Self: 'b;
+            type MultiPolygonType<'b> = MultiPolygon<T> where Self: 'b;
+            type GeometryCollectionType<'b> = GeometryCollection<T> where Self: 'b;
+            type RectType<'b> = UnimplementedRect<T> where Self: 'b;
+            type TriangleType<'b> = UnimplementedTriangle<T> where Self: 'b;
+            type LineType<'b> = UnimplementedLine<T> where Self: 'b;
+
+            fn dim(&$self) -> Dimensions {
+                geometry_dim($geometry)
+            }
+
+            fn as_type(&$self) -> WktGeometryType<'_, T> {
+                geometry_type($geometry)
+            }
+        }
+    };
+}
+
+impl_geometry_trait!(<> Geometry<T>, self => self);
+impl_geometry_trait!(<'a> &'a Geometry<T>, self => *self);
+impl_geometry_trait!(<> Wkt<T>, self => &self.item);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn coord(z: Option<f64>, m: Option<f64>) -> Coord<f64> {
+        Coord { x: 1., y: 2., z, m }
+    }
+
+    #[test]
+    fn xym() {
+        let xym = coord(None, Some(4.));
+        assert_eq!(xym.dim(), Dimensions::Xym);
+        assert_eq!(xym.nth_or_panic(2), 4.);
+        assert_eq!(xym.nth(3), None);
+        assert_eq!((xym.z(), xym.m()), (None, Some(4.)));
+
+        let line_string = LineString(vec![xym, coord(None, Some(5.))]);
+        assert_eq!(line_string.dim(), Dimensions::Xym);
+        assert_eq!(line_string.coord(1).unwrap().m(), Some(5.));
+    }
+
+    #[test]
+    fn xyzm() {
+        let xyzm = coord(Some(3.), Some(4.));
+        assert_eq!(xyzm.dim(), Dimensions::Xyzm);
+        assert_eq!(xyzm.nth_or_panic(2), 3.);
+        assert_eq!(xyzm.nth_or_panic(3), 4.);
+        assert_eq!((xyzm.z(), xyzm.m()), (Some(3.), Some(4.)));
+
+        let xyz = coord(Some(3.), None);
+        assert_eq!(xyz.dim(), Dimensions::Xyz);
+        assert_eq!(xyz.nth(3), None);
+
+        let polygon = Geometry::Polygon(Polygon(vec![LineString(vec![
+            xyzm.clone(),
+            coord(Some(5.), Some(6.)),
+            xyzm,
+        ])]));
+        assert_eq!(GeometryTrait::dim(&polygon), Dimensions::Xyzm);
+        let GeometryType::Polygon(polygon) = polygon.as_type() else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(polygon.exterior().unwrap().coord(1).unwrap().z(), Some(5.));
+    }
+
+    #[test]
+    fn empty() {
+        let point = Geometry::<f64>::Point(Point(None));
+        assert_eq!(GeometryTrait::dim(&point), Dimensions::Xy);
+        let GeometryType::Point(point) = point.as_type() else {
+            panic!("expected a point");
+        };
+        assert!(point.coord().is_none());
+
+        let collection = GeometryCollection::<f64>(vec![]);
+        assert_eq!(collection.dim(), Dimensions::Xy);
+        assert_eq!(collection.num_geometries(), 0);
+        assert_eq!(MultiPolygon::<f64>(vec![]).dim(), Dimensions::Xy);
+    }
+}
@@ -0,0 +1,84 @@
+//! Compare with and without the `smallvec` feature:
+//!
+//! ```sh
+//! cargo bench -p geo-types --bench polygon_rings
+//! cargo bench -p geo-types --bench polygon_rings --features smallvec
+//! ```
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use geo_types::{coord, LineString, MultiPolygon, Polygon};
+
+const POLYGONS: usize = 10_000;
+
+fn ring(x: f32, y: f32, size: f32) -> Vec<(f32, f32)> {
+    vec![
+        (x, y),
+        (x + size, y),
+        (x + size, y + size),
+        (x, y + size),
+        (x, y),
+    ]
+}
+
+/// Raw coordinate buffers, as they would come out of a parser: every polygon has one hole.
+fn raw_polygons() -> Vec<Vec<Vec<(f32, f32)>>> {
+    (0..POLYGONS)
+        .map(|i| {
+            let (x, y) = ((i % 100) as f32 * 10., (i / 100) as f32 * 10.);
+            vec![ring(x, y, 8.), ring(x + 2., y + 2., 4.)]
+        })
+        .collect()
+}
+
+fn build(raw: &[Vec<Vec<(f32, f32)>>]) -> MultiPolygon<f32> {
+    raw.iter()
+        .map(|rings| {
+            let mut rings = rings.iter().map(|ring| LineString::from(ring.clone()));
+            let exterior = rings.next().unwrap();
+            let mut polygon = Polygon::new(exterior, vec![]);
+            rings.for_each(|interior| polygon.interiors_push(interior));
+            polygon
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let raw = raw_polygons();
+
+    c.bench_function("build polygons", |bencher| {
+        bencher.iter(|| criterion::black_box(build(criterion::black_box(&raw))));
+    });
+
+    let multi_polygon = build(&raw);
+
+    c.bench_function("clone polygons", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&multi_polygon).clone()));
+    });
+
+    c.bench_function("convert polygons to f64", |bencher| {
+        bencher.iter_batched(
+            || multi_polygon.clone(),
+            |multi_polygon| {
+                let converted: MultiPolygon<f64> = multi_polygon
+                    .into_iter()
+                    .map(|polygon| {
+                        let convert = |ring: &LineString<f32>| -> LineString<f64> {
+                            ring.coords()
+                                .map(|c| coord! { x: c.x as f64, y: c.y as f64 })
+                                .collect()
+                        };
+                        let mut converted = Polygon::new(convert(polygon.exterior()), vec![]);
+                        for interior in polygon.interiors() {
+                            converted.interiors_push(convert(interior));
+                        }
+                        converted
+                    })
+                    .collect();
+                criterion::black_box(converted)
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,151 @@
+//! Accessors for the zero-copy archived geometry types generated by [rkyv].
+//!
+//! Archived geometries can be accessed directly from a byte buffer (for example a memory-mapped
+//! file) with [`rkyv::access`], without deserializing them.
+//!
+//! ```
+//! use geo_types::{polygon, ArchivedGeometry, Geometry};
+//!
+//! let polygon: Geometry = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)].into();
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&polygon).unwrap();
+//!
+//! let archived = rkyv::access::<ArchivedGeometry, rkyv::rancor::Error>(&bytes).unwrap();
+//! let ArchivedGeometry::Polygon(archived_polygon) = archived else {
+//!     unreachable!()
+//! };
+//! assert_eq!(archived_polygon.exterior().0.len(), 4);
+//! assert_eq!(archived_polygon.exterior().0[1].to_coord(), (1., 0.).into());
+//!
+//! let deserialized: Geometry = rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+//! assert_eq!(deserialized, polygon);
+//! ```
+//!
+//! [rkyv]: https://github.com/rkyv/rkyv
+
+use crate::{
+    ArchivedCoord, ArchivedLine, ArchivedLineString, ArchivedPoint, ArchivedRect, ArchivedTriangle,
+    Coord, Line, Point, Rect, Triangle,
+};
+
+macro_rules! impl_to_native {
+    ($($t:ty),+) => {
+        $(
+            impl ArchivedCoord<$t> {
+                /// Copy this archived coordinate into a native `Coord`.
+                pub fn to_coord(&self) -> Coord<$t> {
+                    coord! { x: self.x.to_native(), y: self.y.to_native() }
+                }
+            }
+
+            impl ArchivedPoint<$t> {
+                /// Copy this archived point into a native `Point`.
+                pub fn to_point(&self) -> Point<$t> {
+                    Point(self.0.to_coord())
+                }
+            }
+
+            impl ArchivedLine<$t> {
+                /// Copy this archived line into a native `Line`.
+                pub fn to_line(&self) -> Line<$t> {
+                    Line::new(self.start.to_coord(), self.end.to_coord())
+                }
+            }
+
+            impl ArchivedRect<$t> {
+                /// Copy this archived rectangle into a native `Rect`.
+                pub fn to_rect(&self) -> Rect<$t> {
+                    Rect::new(self.min().to_coord(), self.max().to_coord())
+                }
+            }
+
+            impl ArchivedTriangle<$t> {
+                /// Copy this archived triangle into a native `Triangle`.
+                pub fn to_triangle(&self) -> Triangle<$t> {
+                    Triangle::new(self.0.to_coord(), self.1.to_coord(), self.2.to_coord())
+                }
+            }
+
+            impl ArchivedLineString<$t> {
+                /// Iterate over the archived coordinates, copied into native `Coord`s.
+                pub fn coords(&self) -> impl Iterator<Item = Coord<$t>> + '_ {
+                    self.0.iter().map(|c| c.to_coord())
+                }
+            }
+        )+
+    };
+}
+
+impl_to_native!(f32, f64, i16, i32, i64);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::vec;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn round_trip_all_geometry_types() {
+        let geometries: vec::Vec<Geometry<i32>> = vec![
+            point!(x: 1, y: 2).into(),
+            Line::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 1 }).into(),
+            line_string![(x: 0, y: 0), (x: 1, y: 1)].into(),
+            polygon![
+                exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10)],
+                interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+            ]
+            .into(),
+            MultiPoint::from(vec![(0, 0), (1, 1)]).into(),
+            MultiLineString::new(vec![line_string![(x: 0, y: 0), (x: 1, y: 1)]]).into(),
+            MultiPolygon::new(vec![polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1)]]).into(),
+            Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 1 }).into(),
+            Triangle::new(
+                coord! { x: 0, y: 0 },
+                coord! { x: 1, y: 0 },
+                coord! { x: 0, y: 1 },
+            )
+            .into(),
+        ];
+        let nested = GeometryCollection::new_from(vec![
+            Geometry::GeometryCollection(GeometryCollection::new_from(geometries.clone())),
+            point!(x: 5, y: 5).into(),
+        ]);
+
+        let bytes = rkyv::to_bytes::<Error>(&nested).unwrap();
+        let archived = rkyv::access::<ArchivedGeometryCollection<i32>, Error>(&bytes).unwrap();
+        assert_eq!(archived.0.len(), 2);
+
+        let ArchivedGeometry::Point(point) = &archived.0[1] else {
+            panic!("expected a point")
+        };
+        assert_eq!(point.to_point(), point!(x: 5, y: 5));
+
+        let ArchivedGeometry::GeometryCollection(inner) = &archived.0[0] else {
+            panic!("expected a geometry collection")
+        };
+        match &inner.0[3] {
+            ArchivedGeometry::Polygon(polygon) => {
+                assert_eq!(polygon.exterior().coords().count(), 4);
+                assert_eq!(polygon.interiors().len(), 1);
+            }
+            _ => panic!("expected a polygon"),
+        }
+        match &inner.0[7] {
+            ArchivedGeometry::Rect(rect) => {
+                assert_eq!(rect.to_rect().max(), coord! { x: 1, y: 1 });
+                assert_eq!(rect.min().to_coord(), coord! { x: 0, y: 0 });
+            }
+            _ => panic!("expected a rect"),
+        }
+
+        let deserialized: GeometryCollection<i32> =
+            rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(deserialized, nested);
+    }
+
+    #[test]
+    fn invalid_bytes_are_rejected() {
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let bytes = rkyv::to_bytes::<Error>(&line_string).unwrap();
+        assert!(rkyv::access::<ArchivedLineString<f64>, Error>(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,466 @@
+//! Builders which construct geometries coordinate by coordinate, checking them as they go.
+//!
+//! Each builder remembers the first problem it runs into, and reports it from `finish`, so a
+//! geometry can be built in one chain of calls, or in a loop, with a single error check at the
+//! end.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::geometry::polygon::check_ring;
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, InvalidPolygonError, LineString, Polygon,
+    PolygonRing,
+};
+
+/// The reason a builder couldn't build its geometry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BuildError {
+    /// The coordinate at `index` of a line string, or of a polygon's ring, is NaN or infinite.
+    NonFiniteCoord {
+        ring: Option<PolygonRing>,
+        index: usize,
+    },
+    /// A line string has a single coordinate, but it must have none or at least two.
+    TooFewCoords { len: usize },
+    /// A polygon's rings are invalid.
+    InvalidPolygon(InvalidPolygonError),
+    /// A member of a geometry collection couldn't be built.
+    Member {
+        index: usize,
+        error: Box<BuildError>,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::NonFiniteCoord { ring: None, index } => {
+                write!(f, "coordinate {index} is not finite")
+            }
+            BuildError::NonFiniteCoord {
+                ring: Some(ring),
+                index,
+            } => write!(f, "coordinate {index} of the {ring} is not finite"),
+            BuildError::TooFewCoords { len } => write!(
+                f,
+                "line string has {len} coordinate, but it needs none or at least 2"
+            ),
+            BuildError::InvalidPolygon(error) => write!(f, "{error}"),
+            BuildError::Member { index, error } => {
+                write!(f, "geometry {index} of the collection is invalid: {error}")
+            }
+        }
+    }
+}
+
+impl From<InvalidPolygonError> for BuildError {
+    fn from(error: InvalidPolygonError) -> Self {
+        BuildError::InvalidPolygon(error)
+    }
+}
+
+fn is_finite<T: CoordNum>(coord: Coord<T>) -> bool {
+    let finite = |value: T| value.to_f64().is_some_and(f64::is_finite);
+    finite(coord.x) && finite(coord.y)
+}
+
+/// Builds a [`LineString`].
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{coord, line_string, LineStringBuilder};
+///
+/// let line_string = LineStringBuilder::new()
+///     .push_coord((0., 0.))
+///     .push_coords([coord! { x: 1., y: 1. }, coord! { x: 2., y: 0. }])
+///     .finish()
+///     .unwrap();
+/// assert_eq!(line_string, line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)]);
+///
+/// // a line string needs two coordinates
+/// let error = LineStringBuilder::new().push_coord((0., 0.)).finish().unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "line string has 1 coordinate, but it needs none or at least 2"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineStringBuilder<T: CoordNum = f64> {
+    coords: Vec<Coord<T>>,
+    error: Option<BuildError>,
+}
+
+impl<T: CoordNum> Default for LineStringBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> LineStringBuilder<T> {
+    /// A builder for an empty line string.
+    pub fn new() -> Self {
+        Self {
+            coords: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Appends a coordinate, which must be finite.
+    pub fn push_coord(mut self, coord: impl Into<Coord<T>>) -> Self {
+        let coord = coord.into();
+        if !is_finite(coord) {
+            self.error.get_or_insert(BuildError::NonFiniteCoord {
+                ring: None,
+                index: self.coords.len(),
+            });
+        }
+        self.coords.push(coord);
+        self
+    }
+
+    /// Appends coordinates, which must be finite.
+    pub fn push_coords<C: Into<Coord<T>>>(self, coords: impl IntoIterator<Item = C>) -> Self {
+        coords.into_iter().fold(self, Self::push_coord)
+    }
+
+    /// The number of coordinates so far.
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Whether there are no coordinates yet.
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// The line string, or the first problem found while building it.
+    pub fn finish(self) -> Result<LineString<T>, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.coords.len() == 1 {
+            return Err(BuildError::TooFewCoords { len: 1 });
+        }
+        Ok(LineString::new(self.coords))
+    }
+}
+
+/// Builds a [`Polygon`], one ring at a time, starting with the exterior.
+///
+/// Rings are checked like [`Polygon::try_new`] checks them: each must be closed, and have at
+/// least four coordinates. Rings aren't closed automatically, but [`close_ring`] closes the
+/// current one.
+///
+/// [`close_ring`]: Self::close_ring
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{BuildError, InvalidPolygonError, PolygonBuilder, PolygonRing};
+///
+/// let polygon = PolygonBuilder::new()
+///     .push_coords([(0., 0.), (10., 0.), (10., 10.), (0., 10.)])
+///     .close_ring()
+///     .begin_interior()
+///     .push_coords([(1., 1.), (1., 2.), (2., 2.)])
+///     .close_ring()
+///     .finish()
+///     .unwrap();
+/// assert_eq!(polygon.interiors().len(), 1);
+///
+/// // the exterior wasn't closed before starting the interior
+/// let error = PolygonBuilder::new()
+///     .push_coords([(0., 0.), (10., 0.), (10., 10.), (0., 10.)])
+///     .begin_interior()
+///     .finish()
+///     .unwrap_err();
+/// assert_eq!(
+///     error,
+///     BuildError::InvalidPolygon(InvalidPolygonError::UnclosedRing(PolygonRing::Exterior))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonBuilder<T: CoordNum = f64> {
+    exterior: LineString<T>,
+    interiors: Vec<LineString<T>>,
+    error: Option<BuildError>,
+}
+
+impl<T: CoordNum> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> PolygonBuilder<T> {
+    /// A builder for a polygon, starting with an empty exterior.
+    pub fn new() -> Self {
+        Self {
+            exterior: LineString::new(Vec::new()),
+            interiors: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// The ring being built, and its position.
+    fn current_ring(&mut self) -> (&mut LineString<T>, PolygonRing) {
+        match self.interiors.len() {
+            0 => (&mut self.exterior, PolygonRing::Exterior),
+            n => (&mut self.interiors[n - 1], PolygonRing::Interior(n - 1)),
+        }
+    }
+
+    /// Appends a coordinate, which must be finite, to the current ring.
+    pub fn push_coord(mut self, coord: impl Into<Coord<T>>) -> Self {
+        let coord = coord.into();
+        let (ring, position) = self.current_ring();
+        let index = ring.0.len();
+        ring.0.push(coord);
+        if !is_finite(coord) {
+            self.error.get_or_insert(BuildError::NonFiniteCoord {
+                ring: Some(position),
+                index,
+            });
+        }
+        self
+    }
+
+    /// Appends coordinates, which must be finite, to the current ring.
+    pub fn push_coords<C: Into<Coord<T>>>(self, coords: impl IntoIterator<Item = C>) -> Self {
+        coords.into_iter().fold(self, Self::push_coord)
+    }
+
+    /// Closes the current ring, by appending its first coordinate if it isn't its last one.
+    pub fn close_ring(mut self) -> Self {
+        self.current_ring().0.close();
+        self
+    }
+
+    /// Checks the current ring, and starts a new interior ring.
+    pub fn begin_interior(mut self) -> Self {
+        let (ring, position) = self.current_ring();
+        if let Err(error) = check_ring(ring, position) {
+            self.error.get_or_insert(error.into());
+        }
+        self.interiors.push(LineString::new(Vec::new()));
+        self
+    }
+
+    /// The polygon, or the first problem found while building it.
+    pub fn finish(self) -> Result<Polygon<T>, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        Ok(Polygon::try_new(self.exterior, self.interiors)?)
+    }
+}
+
+/// Builds a [`GeometryCollection`], from geometries or from the results of other builders.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, BuildError, GeometryCollectionBuilder, LineStringBuilder};
+///
+/// let collection = GeometryCollectionBuilder::new()
+///     .push(point!(x: 0., y: 0.))
+///     .push_line_string(LineStringBuilder::new().push_coords([(0., 0.), (1., 1.)]))
+///     .finish()
+///     .unwrap();
+/// assert_eq!(collection.len(), 2);
+///
+/// let error = GeometryCollectionBuilder::new()
+///     .push(point!(x: 0., y: 0.))
+///     .push_line_string(LineStringBuilder::new().push_coords([(0., 0.), (f64::NAN, 1.)]))
+///     .finish()
+///     .unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "geometry 1 of the collection is invalid: coordinate 1 is not finite"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryCollectionBuilder<T: CoordNum = f64> {
+    geometries: Vec<Geometry<T>>,
+    error: Option<BuildError>,
+}
+
+impl<T: CoordNum> Default for GeometryCollectionBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> GeometryCollectionBuilder<T> {
+    /// A builder for an empty geometry collection.
+    pub fn new() -> Self {
+        Self {
+            geometries: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Appends a geometry.
+    pub fn push(mut self, geometry: impl Into<Geometry<T>>) -> Self {
+        self.geometries.push(geometry.into());
+        self
+    }
+
+    /// Appends the result of another builder, keeping its error if it failed.
+    fn push_result<G: Into<Geometry<T>>>(mut self, result: Result<G, BuildError>) -> Self {
+        match result {
+            Ok(geometry) => self.push(geometry),
+            Err(error) => {
+                self.error.get_or_insert(BuildError::Member {
+                    index: self.geometries.len(),
+                    error: Box::new(error),
+                });
+                self
+            }
+        }
+    }
+
+    /// Finishes a line string builder, and appends its line string.
+    pub fn push_line_string(self, builder: LineStringBuilder<T>) -> Self {
+        self.push_result(builder.finish())
+    }
+
+    /// Finishes a polygon builder, and appends its polygon.
+    pub fn push_polygon(self, builder: PolygonBuilder<T>) -> Self {
+        self.push_result(builder.finish())
+    }
+
+    /// Finishes a geometry collection builder, and appends its collection.
+    pub fn push_collection(self, builder: GeometryCollectionBuilder<T>) -> Self {
+        self.push_result(builder.finish().map(Geometry::GeometryCollection))
+    }
+
+    /// The geometry collection, or the first problem found while building it.
+    pub fn finish(self) -> Result<GeometryCollection<T>, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        Ok(GeometryCollection::new_from(self.geometries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+    use alloc::string::ToString;
+
+    #[test]
+    fn line_string() {
+        let empty: LineString = LineStringBuilder::new().finish().unwrap();
+        assert!(empty.0.is_empty());
+        let error = LineStringBuilder::new()
+            .push_coord((0., 0.))
+            .push_coord((f64::INFINITY, 0.))
+            .push_coord((f64::NAN, 0.))
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            BuildError::NonFiniteCoord {
+                ring: None,
+                index: 1
+            }
+        );
+        let integers = LineStringBuilder::<i32>::new()
+            .push_coords([(0, 0), (1, 1)])
+            .finish();
+        assert_eq!(integers.unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn polygon() {
+        let built = PolygonBuilder::new()
+            .push_coords([(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)])
+            .begin_interior()
+            .push_coords([(1., 1.), (1., 2.), (2., 2.)])
+            .close_ring()
+            // closing a closed ring doesn't change it
+            .close_ring()
+            .finish()
+            .unwrap();
+        assert_eq!(
+            built,
+            polygon!(
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.)]],
+            )
+        );
+
+        let error = PolygonBuilder::new()
+            .push_coords([(0., 0.), (4., 0.), (4., 4.)])
+            .close_ring()
+            .begin_interior()
+            .push_coords([(1., 1.), (1., f64::NAN), (2., 2.)])
+            .close_ring()
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            BuildError::NonFiniteCoord {
+                ring: Some(PolygonRing::Interior(0)),
+                index: 1
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "coordinate 1 of the interior ring 0 is not finite"
+        );
+
+        // the last ring is checked when finishing
+        let error = PolygonBuilder::new()
+            .push_coords([(0., 0.), (4., 0.), (4., 4.)])
+            .close_ring()
+            .begin_interior()
+            .push_coords([(1., 1.), (1., 2.)])
+            .close_ring()
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            BuildError::InvalidPolygon(InvalidPolygonError::TooFewCoords {
+                ring: PolygonRing::Interior(0),
+                len: 3
+            })
+        );
+        assert!(PolygonBuilder::<f64>::new().finish().is_ok());
+    }
+
+    #[test]
+    fn collection() {
+        let nested = GeometryCollectionBuilder::new()
+            .push(point!(x: 1., y: 1.))
+            .push_collection(
+                GeometryCollectionBuilder::new()
+                    .push_polygon(PolygonBuilder::new().push_coords([(0., 0.), (1., 0.)])),
+            )
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            nested,
+            BuildError::Member {
+                index: 1,
+                error: Box::new(BuildError::Member {
+                    index: 0,
+                    error: Box::new(BuildError::InvalidPolygon(
+                        InvalidPolygonError::TooFewCoords {
+                            ring: PolygonRing::Exterior,
+                            len: 2
+                        }
+                    )),
+                }),
+            }
+        );
+    }
+}
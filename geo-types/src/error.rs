@@ -23,8 +23,9 @@ impl fmt::Display for Error {
 
 #[cfg(test)]
 mod test {
-    use crate::{Geometry, Point, Rect};
+    use crate::{Geometry, MultiPoint, MultiPolygon, Point, Polygon, Rect};
     use alloc::string::ToString;
+    use alloc::vec;
     use core::convert::TryFrom;
 
     #[test]
@@ -43,4 +44,23 @@ mod test {
             "Expected a geo_types::geometry::point::Point, but found a geo_types::geometry::rect::Rect"
         );
     }
+
+    #[test]
+    fn single_geometry_into_multi() {
+        let polygon = Rect::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0)).to_polygon();
+        let multi_polygon = MultiPolygon::try_from(Geometry::from(polygon.clone())).unwrap();
+        assert_eq!(multi_polygon, MultiPolygon::new(vec![polygon]));
+
+        let multi_point = MultiPoint::try_from(Geometry::from(Point::new(1.0, 2.0))).unwrap();
+        assert_eq!(multi_point, MultiPoint::new(vec![Point::new(1.0, 2.0)]));
+
+        // Only the single variant is accepted, not other convertible ones
+        let failure = Polygon::try_from(Geometry::from(multi_polygon)).unwrap_err();
+        assert_eq!(
+            failure.to_string(),
+            "Expected a geo_types::geometry::polygon::Polygon, but found a geo_types::geometry::multi_polygon::MultiPolygon"
+        );
+        let rect = Rect::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0));
+        assert!(MultiPolygon::try_from(Geometry::from(rect)).is_err());
+    }
 }
@@ -0,0 +1,123 @@
+//! Zero-copy views of coordinates as interleaved `x, y, x, y, …` buffers, for exchanging
+//! geometries with C, Python and other languages.
+//!
+//! [`Coord`] is `#[repr(C)]`, so a slice of coordinates has the same layout as a slice of
+//! interleaved ordinates twice as long. The safe functions of this module convert between the
+//! two without copying, and [`CoordBuffer`] passes them across an FFI boundary as a pointer and a
+//! length.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::ffi::{coords_as_interleaved, coords_from_interleaved, CoordBuffer};
+//! use geo_types::{coord, LineString};
+//!
+//! let values = [0., 0., 1., 2., 3., 4.];
+//! let coords = coords_from_interleaved(&values).unwrap();
+//! assert_eq!(coords[1], coord! { x: 1., y: 2. });
+//!
+//! // one copy of the whole buffer, rather than one per vertex
+//! let line_string = LineString::from(coords.to_vec());
+//! assert_eq!(coords_as_interleaved(&line_string.0), values);
+//!
+//! // e.g. returned from an `extern "C"` function
+//! let buffer = CoordBuffer::from_coords(&line_string.0);
+//! assert_eq!(buffer.len, 3);
+//! assert_eq!(unsafe { buffer.as_coords() }, coords);
+//! ```
+
+use core::slice;
+
+use crate::{Coord, CoordNum};
+
+/// A borrowed buffer of `len` coordinates, stored as `2 * len` interleaved ordinates starting at
+/// `coords`, with a C-compatible layout.
+///
+/// A buffer doesn't own its coordinates: whoever creates it must keep them alive, and unchanged,
+/// while it's in use.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordBuffer<T = f64> {
+    /// The first ordinate, i.e. the `x` of the first coordinate. May be null if `len` is zero.
+    pub coords: *const T,
+    /// The number of coordinates, i.e. half the number of ordinates.
+    pub len: usize,
+}
+
+impl<T: CoordNum> CoordBuffer<T> {
+    /// A buffer borrowing `coords`, which must outlive it.
+    pub fn from_coords(coords: &[Coord<T>]) -> Self {
+        Self {
+            coords: coords.as_ptr().cast(),
+            len: coords.len(),
+        }
+    }
+
+    /// The coordinates of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// Unless `len` is zero, `coords` must point to `2 * len` initialized ordinates, aligned for
+    /// `T`, which stay alive and unchanged for the lifetime `'a`.
+    pub unsafe fn as_coords<'a>(&self) -> &'a [Coord<T>] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: upheld by the caller, and `Coord<T>` has the layout of `[T; 2]`
+        unsafe { slice::from_raw_parts(self.coords.cast(), self.len) }
+    }
+}
+
+/// Views interleaved `x, y, x, y, …` ordinates as coordinates, without copying them, or returns
+/// `None` if there's an odd number of them.
+pub fn coords_from_interleaved<T: CoordNum>(values: &[T]) -> Option<&[Coord<T>]> {
+    if values.len() % 2 != 0 {
+        return None;
+    }
+    // SAFETY: `Coord<T>` is `#[repr(C)]` with two fields of type `T`, so it has the size of two
+    // `T`s and the alignment of one
+    Some(unsafe { slice::from_raw_parts(values.as_ptr().cast(), values.len() / 2) })
+}
+
+/// Views coordinates as interleaved `x, y, x, y, …` ordinates, without copying them, e.g. the
+/// coordinates of a [`LineString`](crate::LineString).
+pub fn coords_as_interleaved<T: CoordNum>(coords: &[Coord<T>]) -> &[T] {
+    // SAFETY: see `coords_from_interleaved`
+    unsafe { slice::from_raw_parts(coords.as_ptr().cast(), coords.len() * 2) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string};
+    use core::ptr;
+
+    #[test]
+    fn round_trip() {
+        let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+        let values = coords_as_interleaved(&line_string.0);
+        assert_eq!(values, [1., 2., 3., 4.]);
+        let coords = coords_from_interleaved(values).unwrap();
+        // both views borrow the line string's own coordinates
+        assert!(ptr::eq(coords, line_string.0.as_slice()));
+
+        assert!(coords_from_interleaved(&[1, 2, 3]).is_none());
+        assert_eq!(
+            coords_from_interleaved(&[1, 2]),
+            Some(&[coord! { x: 1, y: 2 }][..])
+        );
+    }
+
+    #[test]
+    fn buffer() {
+        let coords = [coord! { x: 1f32, y: 2. }, coord! { x: 3., y: 4. }];
+        let buffer = CoordBuffer::from_coords(&coords);
+        assert_eq!(unsafe { buffer.as_coords() }, coords);
+
+        let empty = CoordBuffer::<f64> {
+            coords: ptr::null(),
+            len: 0,
+        };
+        assert!(unsafe { empty.as_coords() }.is_empty());
+    }
+}
@@ -22,9 +22,16 @@ use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 /// is that the coordinates it contains are valid numbers
 /// (for eg. not `f64::NAN`).
 ///
+/// # Layout
+///
+/// A `Coord` is `#[repr(C)]`, with `x` followed by `y`, so a slice of coordinates can be
+/// exchanged with other languages as interleaved ordinates, see the [`ffi`](crate::ffi) module.
+///
 /// [vector space]: //en.wikipedia.org/wiki/Vector_space
+#[repr(C)]
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Coord<T: CoordNum = f64> {
     pub x: T,
     pub y: T,
@@ -78,6 +85,24 @@ impl<T: CoordNum> From<Coord<T>> for [T; 2] {
 }
 
 impl<T: CoordNum> Coord<T> {
+    /// Creates a new coordinate.
+    ///
+    /// This is a `const fn`, so it can be used to define constants and statics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Coord;
+    ///
+    /// const ORIGIN: Coord = Coord::new(0., 0.);
+    ///
+    /// assert_eq!(ORIGIN.x, 0.);
+    /// ```
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
     /// Returns a tuple that contains the x/horizontal & y/vertical component of the coordinate.
     ///
     /// # Examples
@@ -7,6 +7,13 @@ use approx::{AbsDiffEq, RelativeEq};
 use core::iter::FromIterator;
 use core::ops::{Index, IndexMut};
 
+/// The deepest nesting of geometry collections that the WKT, WKB and TWKB readers of the georust
+/// crates accept, so that crafted input can't overflow the stack.
+///
+/// Every collection counts, including an empty one, so
+/// `GEOMETRYCOLLECTION(GEOMETRYCOLLECTION EMPTY)` is nested two deep.
+pub const MAX_COLLECTION_DEPTH: usize = 128;
+
 /// A collection of [`Geometry`](enum.Geometry.html) types.
 ///
 /// It can be created from a `Vec` of Geometries, or from an Iterator which yields Geometries.
@@ -73,7 +80,31 @@ use core::ops::{Index, IndexMut};
 ///
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct GeometryCollection<T: CoordNum = f64>(pub Vec<Geometry<T>>);
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+// `GeometryCollection` is recursive through `Geometry`, so rkyv can't infer the bounds itself.
+#[cfg_attr(
+    feature = "rkyv",
+    rkyv(
+        archive_bounds(T: rkyv::Archive),
+        serialize_bounds(
+            T: rkyv::Serialize<__S>,
+            __S: rkyv::ser::Writer + rkyv::ser::Allocator,
+            __S::Error: rkyv::rancor::Source,
+        ),
+        deserialize_bounds(
+            T::Archived: rkyv::Deserialize<T, __D>,
+            __D::Error: rkyv::rancor::Source,
+        ),
+        bytecheck(bounds(
+            T::Archived: rkyv::bytecheck::CheckBytes<__C>,
+            __C: rkyv::validation::ArchiveContext,
+            <__C as rkyv::rancor::Fallible>::Error: rkyv::rancor::Source,
+        ))
+    )
+)]
+pub struct GeometryCollection<T: CoordNum = f64>(
+    #[cfg_attr(feature = "rkyv", rkyv(omit_bounds))] pub Vec<Geometry<T>>,
+);
 
 // Implementing Default by hand because T does not have Default restriction
 // todo: consider adding Default as a CoordNum requirement
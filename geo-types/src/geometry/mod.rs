@@ -13,7 +13,7 @@ pub(crate) mod triangle;
 // re-export all the geometry variants:
 #[allow(deprecated)]
 pub use coord::{Coord, Coordinate};
-pub use geometry_collection::GeometryCollection;
+pub use geometry_collection::{GeometryCollection, MAX_COLLECTION_DEPTH};
 pub use line::Line;
 pub use line_string::LineString;
 pub use multi_line_string::MultiLineString;
@@ -24,6 +24,14 @@ pub use polygon::Polygon;
 pub use rect::Rect;
 pub use triangle::Triangle;
 
+#[cfg(feature = "rkyv")]
+pub use self::{
+    coord::ArchivedCoord, geometry_collection::ArchivedGeometryCollection, line::ArchivedLine,
+    line_string::ArchivedLineString, multi_line_string::ArchivedMultiLineString,
+    multi_point::ArchivedMultiPoint, multi_polygon::ArchivedMultiPolygon, point::ArchivedPoint,
+    polygon::ArchivedPolygon, rect::ArchivedRect, triangle::ArchivedTriangle,
+};
+
 use crate::{CoordNum, Error};
 
 #[cfg(any(feature = "approx", test))]
@@ -35,7 +43,10 @@ use core::convert::TryFrom;
 ///
 /// All geometry variants ([`Point`], [`LineString`], etc.) can be converted to a `Geometry` using
 /// [`Into::into`]. Conversely, [`TryFrom::try_from`] can be used to convert a [`Geometry`]
-/// _back_ to one of it's specific enum members.
+/// _back_ to one of it's specific enum members. The multi-geometries also accept their single
+/// counterpart, e.g. a [`MultiPolygon`] can be converted from a `Geometry::Polygon`. Conversion
+/// fails with [`Error::MismatchedGeometry`], naming the variant which was found, for any other
+/// variant.
 ///
 /// # Example
 ///
@@ -49,6 +60,7 @@ use core::convert::TryFrom;
 ///
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum Geometry<T: CoordNum = f64> {
     Point(Point<T>),
     Line(Line<T>),
@@ -215,17 +227,23 @@ impl<T: CoordNum> Geometry<T> {
 }
 
 macro_rules! try_from_geometry_impl {
-    ($($type: ident),+) => {
+    ($($type: ident $(<- $single: ident)?),+) => {
         $(
         /// Convert a Geometry enum into its inner type.
         ///
         /// Fails if the enum case does not match the type you are trying to convert it to.
+        $(
+        /// A single
+        #[doc = concat!("[`", stringify!($single), "`]")]
+        /// is also accepted, and wrapped in a collection of one.
+        )?
         impl <T: CoordNum> TryFrom<Geometry<T>> for $type<T> {
             type Error = Error;
 
             fn try_from(geom: Geometry<T>) -> Result<Self, Self::Error> {
                 match geom {
                     Geometry::$type(g) => Ok(g),
+                    $(Geometry::$single(g) => Ok($type::from(g)),)?
                     other => Err(Error::MismatchedGeometry {
                         expected: type_name::<$type<T>>(),
                         found: inner_type_name(other)
@@ -242,9 +260,9 @@ try_from_geometry_impl!(
     Line,
     LineString,
     Polygon,
-    MultiPoint,
-    MultiLineString,
-    MultiPolygon,
+    MultiPoint <- Point,
+    MultiLineString <- LineString,
+    MultiPolygon <- Polygon,
     // Disabled until we remove the deprecated GeometryCollection::from(single_geom) impl.
     // GeometryCollection,
     Rect,
@@ -28,6 +28,7 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAss
 /// ```
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Point<T: CoordNum = f64>(pub Coord<T>);
 
 impl<T: CoordNum> From<Coord<T>> for Point<T> {
@@ -63,6 +64,8 @@ impl<T: CoordNum> From<Point<T>> for [T; 2] {
 impl<T: CoordNum> Point<T> {
     /// Creates a new point.
     ///
+    /// This is a `const fn`, so it can be used to define constants and statics.
+    ///
     /// # Examples
     ///
     /// ```
@@ -72,8 +75,11 @@ impl<T: CoordNum> Point<T> {
     ///
     /// assert_eq!(p.x(), 1.234);
     /// assert_eq!(p.y(), 2.345);
+    ///
+    /// static NULL_ISLAND: Point = Point::new(0., 0.);
+    /// assert_eq!(NULL_ISLAND.x(), 0.);
     /// ```
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         point! { x: x, y: y }
     }
 
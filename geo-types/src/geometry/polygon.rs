@@ -68,11 +68,22 @@ use approx::{AbsDiffEq, RelativeEq};
 /// [`LineString`]: line_string/struct.LineString.html
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Polygon<T: CoordNum = f64> {
     exterior: LineString<T>,
-    interiors: Vec<LineString<T>>,
+    interiors: Interiors<T>,
 }
 
+/// Storage for the interior rings of a `Polygon`. Most polygons have at most one interior ring,
+/// which the `smallvec` feature stores inline rather than in a separate allocation.
+#[cfg(not(feature = "smallvec"))]
+type Interiors<T> = Vec<LineString<T>>;
+#[cfg(feature = "smallvec")]
+type Interiors<T> = smallvec::SmallVec<[LineString<T>; 1]>;
+
 impl<T: CoordNum> Polygon<T> {
     /// Create a new `Polygon` with the provided exterior `LineString` ring and
     /// interior `LineString` rings.
@@ -129,12 +140,88 @@ impl<T: CoordNum> Polygon<T> {
         for interior in &mut interiors {
             interior.close();
         }
+        // a no-op unless the `smallvec` feature is enabled
+        #[allow(clippy::useless_conversion)]
+        let interiors = interiors.into();
         Self {
             exterior,
             interiors,
         }
     }
 
+    /// Create a new `Polygon`, checking that its rings are valid instead of [closing] them.
+    ///
+    /// Unlike [`Polygon::new`], this fails if any ring isn't closed or has fewer than four
+    /// coordinates (i.e. three distinct vertices), or if an empty exterior has interior rings. An
+    /// empty exterior with no interiors is accepted as an empty polygon. Ring self-intersection
+    /// isn't checked.
+    ///
+    /// [closing]: #linestring-closing-operation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{InvalidPolygonError, LineString, Polygon, PolygonRing};
+    ///
+    /// let exterior = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]);
+    /// assert!(Polygon::try_new(exterior, vec![]).is_ok());
+    ///
+    /// let unclosed = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+    /// assert_eq!(
+    ///     Polygon::try_new(unclosed, vec![]),
+    ///     Err(InvalidPolygonError::UnclosedRing(PolygonRing::Exterior))
+    /// );
+    /// ```
+    pub fn try_new(
+        exterior: LineString<T>,
+        interiors: Vec<LineString<T>>,
+    ) -> Result<Self, InvalidPolygonError> {
+        if exterior.0.is_empty() {
+            if interiors.is_empty() {
+                return Ok(Self::new(exterior, interiors));
+            }
+            return Err(InvalidPolygonError::EmptyExterior);
+        }
+        check_ring(&exterior, PolygonRing::Exterior)?;
+        for (i, interior) in interiors.iter().enumerate() {
+            check_ring(interior, PolygonRing::Interior(i))?;
+        }
+        Ok(Self::new(exterior, interiors))
+    }
+
+    /// Create a new `Polygon` like [`Polygon::try_new`], additionally reversing rings as needed
+    /// so that the exterior is wound counter-clockwise and the interiors clockwise, as required
+    /// by e.g. [GeoJSON].
+    ///
+    /// [GeoJSON]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, Polygon};
+    ///
+    /// let clockwise = LineString::from(vec![(0, 0), (0, 1), (1, 1), (0, 0)]);
+    /// let polygon = Polygon::try_new_normalized(clockwise, vec![]).unwrap();
+    /// assert_eq!(
+    ///     polygon.exterior(),
+    ///     &LineString::from(vec![(0, 0), (1, 1), (0, 1), (0, 0)])
+    /// );
+    /// ```
+    pub fn try_new_normalized(
+        mut exterior: LineString<T>,
+        mut interiors: Vec<LineString<T>>,
+    ) -> Result<Self, InvalidPolygonError> {
+        if is_clockwise(&exterior) {
+            exterior.0.reverse();
+        }
+        for interior in &mut interiors {
+            if !is_clockwise(interior) {
+                interior.0.reverse();
+            }
+        }
+        Self::try_new(exterior, interiors)
+    }
+
     /// Consume the `Polygon`, returning the exterior `LineString` ring and
     /// a vector of the interior `LineString` rings.
     ///
@@ -171,7 +258,11 @@ impl<T: CoordNum> Polygon<T> {
     /// );
     /// ```
     pub fn into_inner(self) -> (LineString<T>, Vec<LineString<T>>) {
-        (self.exterior, self.interiors)
+        #[cfg(feature = "smallvec")]
+        let interiors = self.interiors.into_vec();
+        #[cfg(not(feature = "smallvec"))]
+        let interiors = self.interiors;
+        (self.exterior, interiors)
     }
 
     /// Return a reference to the exterior `LineString` ring.
@@ -467,6 +558,82 @@ impl<T: CoordNum> Polygon<T> {
     }
 }
 
+pub(crate) fn check_ring<T: CoordNum>(
+    ring: &LineString<T>,
+    position: PolygonRing,
+) -> Result<(), InvalidPolygonError> {
+    if ring.0.len() < 4 {
+        return Err(InvalidPolygonError::TooFewCoords {
+            ring: position,
+            len: ring.0.len(),
+        });
+    }
+    if !ring.is_closed() {
+        return Err(InvalidPolygonError::UnclosedRing(position));
+    }
+    Ok(())
+}
+
+/// Whether a ring is wound clockwise, based on the sign of its shoelace area. Each sum is
+/// accumulated separately so that this works for unsigned coordinates too.
+fn is_clockwise<T: CoordNum>(ring: &LineString<T>) -> bool {
+    let (positive, negative) =
+        ring.lines()
+            .fold((T::zero(), T::zero()), |(positive, negative), line| {
+                (
+                    positive + line.start.x * line.end.y,
+                    negative + line.end.x * line.start.y,
+                )
+            });
+    positive < negative
+}
+
+/// A ring of a [`Polygon`], as reported by [`InvalidPolygonError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PolygonRing {
+    Exterior,
+    /// The interior ring at this index.
+    Interior(usize),
+}
+
+impl core::fmt::Display for PolygonRing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PolygonRing::Exterior => write!(f, "exterior ring"),
+            PolygonRing::Interior(i) => write!(f, "interior ring {i}"),
+        }
+    }
+}
+
+/// The reason [`Polygon::try_new`] rejected its rings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidPolygonError {
+    /// The ring's first and last coordinates differ.
+    UnclosedRing(PolygonRing),
+    /// The ring has fewer than the 4 coordinates needed to close a ring with 3 vertices.
+    TooFewCoords { ring: PolygonRing, len: usize },
+    /// The exterior ring is empty, but there are interior rings.
+    EmptyExterior,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidPolygonError {}
+
+impl core::fmt::Display for InvalidPolygonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InvalidPolygonError::UnclosedRing(ring) => write!(f, "{ring} is not closed"),
+            InvalidPolygonError::TooFewCoords { ring, len } => write!(
+                f,
+                "{ring} has {len} coordinates, but a closed ring needs at least 4"
+            ),
+            InvalidPolygonError::EmptyExterior => {
+                write!(f, "exterior ring is empty, but there are interior rings")
+            }
+        }
+    }
+}
+
 // used to check the sign of a vec of floats
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum ListSign {
@@ -537,6 +704,19 @@ impl<T: CoordNum> From<Triangle<T>> for Polygon<T> {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl<T: CoordNum + rkyv::Archive> ArchivedPolygon<T> {
+    /// Return a reference to the archived exterior `LineString`.
+    pub fn exterior(&self) -> &crate::ArchivedLineString<T> {
+        &self.exterior
+    }
+
+    /// Return a slice of the archived interior `LineString` rings.
+    pub fn interiors(&self) -> &[crate::ArchivedLineString<T>] {
+        &self.interiors
+    }
+}
+
 #[cfg(any(feature = "approx", test))]
 impl<T> RelativeEq for Polygon<T>
 where
@@ -653,3 +833,69 @@ impl_rstar_polygon!(rstar_0_11);
 
 #[cfg(feature = "rstar_0_12")]
 impl_rstar_polygon!(rstar_0_12);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn try_new_validates_rings() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)]);
+        let hole = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)]);
+        let polygon = Polygon::try_new(exterior.clone(), vec![hole.clone()]).unwrap();
+        assert_eq!(polygon, Polygon::new(exterior.clone(), vec![hole]));
+
+        let short = LineString::from(vec![(1., 1.), (2., 1.), (1., 1.)]);
+        let error = Polygon::try_new(exterior.clone(), vec![short]).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidPolygonError::TooFewCoords {
+                ring: PolygonRing::Interior(0),
+                len: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "interior ring 0 has 3 coordinates, but a closed ring needs at least 4"
+        );
+
+        let unclosed = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 2.)]);
+        assert_eq!(
+            Polygon::try_new(exterior, vec![unclosed]),
+            Err(InvalidPolygonError::UnclosedRing(PolygonRing::Interior(0)))
+        );
+    }
+
+    #[test]
+    fn try_new_empty() {
+        let empty = Polygon::<f64>::try_new(LineString::new(vec![]), vec![]).unwrap();
+        assert!(empty.exterior().0.is_empty());
+
+        let hole = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)]);
+        assert_eq!(
+            Polygon::try_new(LineString::new(vec![]), vec![hole]),
+            Err(InvalidPolygonError::EmptyExterior)
+        );
+    }
+
+    #[test]
+    fn try_new_normalized_winding() {
+        let ccw = LineString::from(vec![(0u32, 0), (4, 0), (4, 4), (0, 4), (0, 0)]);
+        let cw = LineString::from(vec![(1u32, 1), (1, 2), (2, 2), (2, 1), (1, 1)]);
+        let mut cw_exterior = ccw.clone();
+        cw_exterior.0.reverse();
+        let mut ccw_hole = cw.clone();
+        ccw_hole.0.reverse();
+
+        let expected = Polygon::new(ccw.clone(), vec![cw.clone()]);
+        assert_eq!(
+            Polygon::try_new_normalized(ccw, vec![cw]).as_ref(),
+            Ok(&expected)
+        );
+        assert_eq!(
+            Polygon::try_new_normalized(cw_exterior, vec![ccw_hole]),
+            Ok(expected)
+        );
+    }
+}
@@ -39,6 +39,7 @@ use approx::{AbsDiffEq, RelativeEq};
 /// ```
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Rect<T: CoordNum = f64> {
     min: Coord<T>,
     max: Coord<T>,
@@ -81,6 +82,26 @@ impl<T: CoordNum> Rect<T> {
         }
     }
 
+    /// Creates a new rectangle from its minimum and maximum corners, without sorting them.
+    ///
+    /// Unlike [`Rect::new`], this is a `const fn`, so it can be used to define constants and
+    /// statics. The caller is responsible for ensuring that each component of `min` is less than
+    /// or equal to the corresponding component of `max`; otherwise the rectangle is invalid, and
+    /// the results of any operation on it are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// const UNIT_SQUARE: Rect = Rect::new_unchecked(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+    ///
+    /// assert_eq!(UNIT_SQUARE, Rect::new((1., 1.), (0., 0.)));
+    /// ```
+    pub const fn new_unchecked(min: Coord<T>, max: Coord<T>) -> Self {
+        Self { min, max }
+    }
+
     #[deprecated(
         since = "0.6.2",
         note = "Use `Rect::new` instead, since `Rect::try_new` will never Error"
@@ -376,6 +397,35 @@ impl<T: CoordFloat> Rect<T> {
     }
 }
 
+impl<T: CoordNum> Rect<T> {
+    /// The `Rect` whose [`to_polygon`](Self::to_polygon) is `polygon`, if `polygon` is a
+    /// non-degenerate, axis-aligned rectangle without interior rings.
+    #[cfg(any(feature = "serde", feature = "wkt"))]
+    pub(crate) fn try_from_polygon(polygon: &Polygon<T>) -> Option<Self> {
+        let [a, b, c, d, _] = polygon.exterior().0[..] else {
+            return None;
+        };
+        // the edges alternate between horizontal and vertical, starting with either
+        let alternates = (a.y == b.y && b.x == c.x && c.y == d.y && d.x == a.x)
+            || (a.x == b.x && b.y == c.y && c.x == d.x && d.y == a.y);
+        let is_rect = polygon.interiors().is_empty() && alternates && a.x != c.x && a.y != c.y;
+        is_rect.then(|| Rect::new(a, c))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: CoordNum + rkyv::Archive> ArchivedRect<T> {
+    /// Returns the archived minimum `Coord` of the `Rect`.
+    pub fn min(&self) -> &crate::ArchivedCoord<T> {
+        &self.min
+    }
+
+    /// Returns the archived maximum `Coord` of the `Rect`.
+    pub fn max(&self) -> &crate::ArchivedCoord<T> {
+        &self.max
+    }
+}
+
 static RECT_INVALID_BOUNDS_ERROR: &str = "Failed to create Rect: 'min' coordinate's x/y value must be smaller or equal to the 'max' x/y value";
 
 #[cfg(any(feature = "approx", test))]
@@ -64,16 +64,26 @@
 //! - `std`: Enables use of the full `std` library. Enabled by default.
 //! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
 //! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
-//! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `proptest`: Provides [proptest] strategies for generating valid (and optionally invalid)
+//!   geometries, in the [`strategy`] module
+//! - `rkyv`: Allows geometry types to be archived with [rkyv], so that they can be accessed (for
+//!   example from a memory-mapped file) without deserialization
+//! - `serde`: Allows geometry types to be serialized and deserialized with [Serde], either using
+//!   their internal layout or as GeoJSON geometry objects, with the [`serde_geojson`] module
+//! - `smallvec`: Stores a [`Polygon`]'s first interior ring inline, avoiding a heap allocation for
+//!   polygons with at most one hole. This doesn't change the public API.
+//! - `wkt`: Implements [`Display`](core::fmt::Display) and [`FromStr`](core::str::FromStr) for
+//!   geometry types, writing and parsing [WKT]
 //! - `use-rstar_0_8`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.8`)
 //! - `use-rstar_0_9`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.9`)
 //! - `use-rstar_0_10`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.10`)
 //! - `use-rstar_0_11`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.11`)
 //! - `use-rstar_0_12`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.12`)
 //!
-//! This library can be used in `#![no_std]` environments if the default `std` feature is disabled. At
-//! the moment, the `arbitrary` and `use-rstar_0_8` features require `std`. This may change in a
-//! future release.
+//! This library can be used in `#![no_std]` environments if the default `std` feature is disabled,
+//! as long as a global allocator is available through the `alloc` crate. At the moment, the
+//! `arbitrary`, `proptest` and `use-rstar_0_8` features require `std`, since the crates they
+//! integrate with do. This may change in a future release.
 //!
 //! [approx]: https://github.com/brendanzab/approx
 //! [arbitrary]: https://github.com/rust-fuzz/arbitrary
@@ -82,8 +92,11 @@
 //! [GEOS]: https://trac.osgeo.org/geos
 //! [JTS]: https://github.com/locationtech/jts
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
+//! [proptest]: https://github.com/proptest-rs/proptest
+//! [rkyv]: https://github.com/rkyv/rkyv
 //! [rstar]: https://github.com/Stoeoef/rstar
 //! [Serde]: https://serde.rs/
+//! [WKT]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
 extern crate alloc;
 
 use core::fmt::Debug;
@@ -122,12 +135,19 @@ pub use geometry::*;
 
 pub use geometry::line_string::PointsIter;
 
+pub use geometry::polygon::{InvalidPolygonError, PolygonRing};
+
 #[allow(deprecated)]
 pub use geometry::rect::InvalidRectCoordinatesError;
 
 mod error;
 pub use error::Error;
 
+mod builder;
+pub use builder::{BuildError, GeometryCollectionBuilder, LineStringBuilder, PolygonBuilder};
+
+pub mod ffi;
+
 #[macro_use]
 mod macros;
 
@@ -137,6 +157,20 @@ mod wkt_macro;
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "rkyv")]
+mod archived;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+#[cfg(feature = "serde")]
+pub mod serde_geojson;
+
+#[cfg(feature = "wkt")]
+mod wkt;
+#[cfg(feature = "wkt")]
+pub use wkt::ParseWktError;
+
 #[cfg(any(
     feature = "rstar_0_8",
     feature = "rstar_0_9",
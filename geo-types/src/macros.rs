@@ -23,11 +23,19 @@
 /// assert_eq!(p.y(), 51.79);
 /// ```
 ///
+/// Supplying x/y values can be done in `const` contexts:
+///
+/// ```
+/// use geo_types::{point, Point};
+///
+/// const ORIGIN: Point<i32> = point! { x: 0, y: 0 };
+/// ```
+///
 /// [`Point`]: ./struct.Point.html
 #[macro_export]
 macro_rules! point {
     ( $($tag:tt : $val:expr),* $(,)? ) => {
-        $crate::point! ( $crate::coord! { $( $tag: $val , )* } )
+        $crate::Point ( $crate::coord! { $( $tag: $val , )* } )
     };
     ( $coord:expr $(,)? ) => {
         $crate::Point::from($coord)
@@ -52,6 +60,14 @@ macro_rules! point {
 /// assert_eq!(c, geo_types::coord! { x: 181.2, y: 51.79 });
 /// ```
 ///
+/// `coord!` can also be used in `const` contexts:
+///
+/// ```
+/// use geo_types::{coord, Coord};
+///
+/// const ORIGIN: Coord = coord! { x: 0., y: 0. };
+/// ```
+///
 /// [`Coord`]: ./struct.Coord.html
 #[macro_export]
 macro_rules! coord {
@@ -310,6 +326,15 @@ mod test {
         assert_eq!(p.y(), 3.4);
     }
 
+    #[test]
+    fn test_const() {
+        use crate::{Coord, Point, Rect};
+
+        static CORNERS: [Point<i32>; 2] = [point! { x: 1, y: 2 }, Point::new(3, 4)];
+        const BOUNDS: Rect<i32> = Rect::new_unchecked(coord! { x: 1, y: 2 }, Coord::new(3, 4));
+        assert_eq!(Rect::new(CORNERS[1], CORNERS[0]), BOUNDS);
+    }
+
     #[test]
     fn test_line() {
         let ls = line_string![(x: -1.2f32, y: 3.4f32)];
@@ -0,0 +1,568 @@
+//! Serialize and deserialize geometries as [GeoJSON geometry objects], instead of the internal
+//! struct layout used by the derived serde implementations.
+//!
+//! Either wrap a geometry in [`GeoJson`], or annotate a field with
+//! `#[serde(with = "geo_types::serde_geojson")]`:
+//!
+//! ```
+//! use geo_types::serde_geojson::GeoJson;
+//! use geo_types::{point, Geometry, Point, Polygon};
+//!
+//! let json = serde_json::to_string(&GeoJson(point!(x: 1.5, y: 2.))).unwrap();
+//! assert_eq!(json, r#"{"type":"Point","coordinates":[1.5,2.0]}"#);
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Feature {
+//!     #[serde(with = "geo_types::serde_geojson")]
+//!     geometry: Polygon,
+//! }
+//!
+//! let feature: Feature = serde_json::from_str(
+//!     r#"{"geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(feature.geometry.exterior().0.len(), 4);
+//! ```
+//!
+//! A [`Line`] is represented as a two position `LineString`, and a [`Rect`] or [`Triangle`] as a
+//! `Polygon`. Deserializing them accepts the same representations. Positions with more than two
+//! elements (e.g. altitude) are accepted, but only the first two are kept.
+//!
+//! [GeoJSON geometry objects]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// A wrapper which serializes and deserializes a geometry as a GeoJSON geometry object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GeoJson<G>(pub G);
+
+impl<G: GeoJsonGeometry> Serialize for GeoJson<G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_geojson(serializer)
+    }
+}
+
+impl<'de, G: GeoJsonGeometry> Deserialize<'de> for GeoJson<G> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        G::deserialize_geojson(deserializer).map(GeoJson)
+    }
+}
+
+/// Serialize `geometry` as a GeoJSON geometry object, for use with `#[serde(with = "...")]`.
+pub fn serialize<G: GeoJsonGeometry, S: Serializer>(
+    geometry: &G,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    geometry.serialize_geojson(serializer)
+}
+
+/// Deserialize a GeoJSON geometry object, for use with `#[serde(with = "...")]`.
+pub fn deserialize<'de, G: GeoJsonGeometry, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<G, D::Error> {
+    G::deserialize_geojson(deserializer)
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Geometry types which can be represented as a GeoJSON geometry object.
+///
+/// This trait is sealed: it's implemented for every geometry type in this crate.
+pub trait GeoJsonGeometry: Sized + private::Sealed {
+    #[doc(hidden)]
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    #[doc(hidden)]
+    fn deserialize_geojson<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+struct Position<'a, T: CoordNum>(&'a Coord<T>);
+
+impl<T: CoordNum + Serialize> Serialize for Position<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.0.x, self.0.y).serialize(serializer)
+    }
+}
+
+/// Serializes each item of an iterator with `F`, as a sequence.
+struct Seq<I, F>(I, F);
+
+impl<I, F, R> Serialize for Seq<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> R,
+    R: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        for item in self.0.clone() {
+            seq.serialize_element(&(self.1)(item))?;
+        }
+        seq.end()
+    }
+}
+
+fn positions<'a, T: CoordNum + Serialize>(coords: &'a [Coord<T>]) -> impl Serialize + 'a {
+    Seq(coords.iter(), Position)
+}
+
+fn polygon_rings<'a, T: CoordNum + Serialize>(polygon: &'a Polygon<T>) -> impl Serialize + 'a {
+    let rings = core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        // `POLYGON EMPTY` has an empty exterior, and no rings in GeoJSON
+        .filter(|ring| !ring.0.is_empty());
+    Seq(rings, |ring: &'a LineString<T>| positions(&ring.0))
+}
+
+fn point_position<T: CoordNum>(point: &Point<T>) -> Position<'_, T> {
+    Position(&point.0)
+}
+
+fn line_string_positions<T: CoordNum + Serialize>(
+    line_string: &LineString<T>,
+) -> impl Serialize + '_ {
+    positions(&line_string.0)
+}
+
+fn serialize_object<S: Serializer>(
+    serializer: S,
+    type_name: &'static str,
+    coordinates: impl Serialize,
+) -> Result<S::Ok, S::Error> {
+    let mut object = serializer.serialize_struct("Geometry", 2)?;
+    object.serialize_field("type", type_name)?;
+    object.serialize_field("coordinates", &coordinates)?;
+    object.end()
+}
+
+/// The GeoJSON representation, used for deserialization.
+#[derive(Deserialize)]
+#[serde(tag = "type", bound = "T: DeserializeOwned")]
+enum GeoJsonObject<T> {
+    Point {
+        coordinates: DePosition<T>,
+    },
+    LineString {
+        coordinates: Vec<DePosition<T>>,
+    },
+    Polygon {
+        coordinates: Vec<Vec<DePosition<T>>>,
+    },
+    MultiPoint {
+        coordinates: Vec<DePosition<T>>,
+    },
+    MultiLineString {
+        coordinates: Vec<Vec<DePosition<T>>>,
+    },
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<DePosition<T>>>>,
+    },
+    GeometryCollection {
+        geometries: Vec<GeoJsonObject<T>>,
+    },
+}
+
+struct DePosition<T>(T, T);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DePosition<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PositionVisitor<T>(core::marker::PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> de::Visitor<'de> for PositionVisitor<T> {
+            type Value = DePosition<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a GeoJSON position with at least two elements")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                // ignore altitude and any other elements
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+                Ok(DePosition(x, y))
+            }
+        }
+
+        deserializer.deserialize_seq(PositionVisitor(core::marker::PhantomData))
+    }
+}
+
+fn to_coord<T: CoordNum>(position: DePosition<T>) -> Coord<T> {
+    Coord {
+        x: position.0,
+        y: position.1,
+    }
+}
+
+fn to_line_string<T: CoordNum>(positions: Vec<DePosition<T>>) -> LineString<T> {
+    positions.into_iter().map(to_coord).collect()
+}
+
+fn to_polygon<T: CoordNum>(rings: Vec<Vec<DePosition<T>>>) -> Polygon<T> {
+    let mut rings = rings.into_iter().map(to_line_string);
+    let exterior = rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+    Polygon::new(exterior, rings.collect())
+}
+
+fn to_geometry<T: CoordNum>(object: GeoJsonObject<T>) -> Geometry<T> {
+    {
+        match object {
+            GeoJsonObject::Point { coordinates } => Point(to_coord(coordinates)).into(),
+            GeoJsonObject::LineString { coordinates } => to_line_string(coordinates).into(),
+            GeoJsonObject::Polygon { coordinates } => to_polygon(coordinates).into(),
+            GeoJsonObject::MultiPoint { coordinates } => MultiPoint(
+                coordinates
+                    .into_iter()
+                    .map(|p| Point(to_coord(p)))
+                    .collect(),
+            )
+            .into(),
+            GeoJsonObject::MultiLineString { coordinates } => {
+                MultiLineString(coordinates.into_iter().map(to_line_string).collect()).into()
+            }
+            GeoJsonObject::MultiPolygon { coordinates } => {
+                MultiPolygon(coordinates.into_iter().map(to_polygon).collect()).into()
+            }
+            GeoJsonObject::GeometryCollection { geometries } => Geometry::GeometryCollection(
+                GeometryCollection(geometries.into_iter().map(to_geometry).collect()),
+            ),
+        }
+    }
+}
+
+fn deserialize_geometry<'de, T, D>(deserializer: D) -> Result<Geometry<T>, D::Error>
+where
+    T: CoordNum + DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    GeoJsonObject::deserialize(deserializer).map(to_geometry)
+}
+
+impl<T: CoordNum> private::Sealed for Geometry<T> {}
+
+impl<T: CoordNum + Serialize + DeserializeOwned> GeoJsonGeometry for Geometry<T> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Geometry::Point(g) => g.serialize_geojson(serializer),
+            Geometry::Line(g) => g.serialize_geojson(serializer),
+            Geometry::LineString(g) => g.serialize_geojson(serializer),
+            Geometry::Polygon(g) => g.serialize_geojson(serializer),
+            Geometry::MultiPoint(g) => g.serialize_geojson(serializer),
+            Geometry::MultiLineString(g) => g.serialize_geojson(serializer),
+            Geometry::MultiPolygon(g) => g.serialize_geojson(serializer),
+            Geometry::GeometryCollection(g) => g.serialize_geojson(serializer),
+            Geometry::Rect(g) => g.serialize_geojson(serializer),
+            Geometry::Triangle(g) => g.serialize_geojson(serializer),
+        }
+    }
+
+    fn deserialize_geojson<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_geometry(deserializer)
+    }
+}
+
+/// Implement `GeoJsonGeometry` for a geometry type, given how to serialize it and how to convert
+/// it from a deserialized `Geometry`.
+macro_rules! geojson_geometry_impl {
+    ($type:ident, $type_name:literal, |$g:ident, $s:ident| $serialize:expr, $from_geometry:expr) => {
+        impl<T: CoordNum> private::Sealed for $type<T> {}
+
+        impl<T: CoordNum + Serialize + DeserializeOwned> GeoJsonGeometry for $type<T> {
+            fn serialize_geojson<S: Serializer>(&self, $s: S) -> Result<S::Ok, S::Error> {
+                let $g = self;
+                $serialize
+            }
+
+            fn deserialize_geojson<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let geometry = deserialize_geometry(deserializer)?;
+                let from_geometry: fn(Geometry<T>) -> Option<Self> = $from_geometry;
+                from_geometry(geometry)
+                    .ok_or_else(|| de::Error::custom(concat!("expected a GeoJSON ", $type_name)))
+            }
+        }
+    };
+}
+
+geojson_geometry_impl!(
+    Point,
+    "Point",
+    |g, s| serialize_object(s, "Point", Position(&g.0)),
+    |geometry| match geometry {
+        Geometry::Point(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    Line,
+    "LineString",
+    |g, s| serialize_object(s, "LineString", positions(&[g.start, g.end])),
+    |geometry| match geometry {
+        Geometry::LineString(LineString(coords)) => match coords[..] {
+            [start, end] => Some(Line::new(start, end)),
+            _ => None,
+        },
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    LineString,
+    "LineString",
+    |g, s| serialize_object(s, "LineString", positions(&g.0)),
+    |geometry| match geometry {
+        Geometry::LineString(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    Polygon,
+    "Polygon",
+    |g, s| serialize_object(s, "Polygon", polygon_rings(g)),
+    |geometry| match geometry {
+        Geometry::Polygon(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    MultiPoint,
+    "MultiPoint",
+    |g, s| serialize_object(s, "MultiPoint", Seq(g.0.iter(), point_position)),
+    |geometry| match geometry {
+        Geometry::MultiPoint(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    MultiLineString,
+    "MultiLineString",
+    |g, s| serialize_object(s, "MultiLineString", Seq(g.0.iter(), line_string_positions)),
+    |geometry| match geometry {
+        Geometry::MultiLineString(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    MultiPolygon,
+    "MultiPolygon",
+    |g, s| serialize_object(s, "MultiPolygon", Seq(g.0.iter(), polygon_rings)),
+    |geometry| match geometry {
+        Geometry::MultiPolygon(g) => Some(g),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    Rect,
+    "Polygon",
+    |g, s| g.to_polygon().serialize_geojson(s),
+    |geometry| match geometry {
+        Geometry::Polygon(polygon) => Rect::try_from_polygon(&polygon),
+        _ => None,
+    }
+);
+
+geojson_geometry_impl!(
+    Triangle,
+    "Polygon",
+    |g, s| g.to_polygon().serialize_geojson(s),
+    |geometry| match geometry {
+        Geometry::Polygon(polygon) if polygon.interiors().is_empty() => {
+            match polygon.exterior().0[..] {
+                [a, b, c, _] => Some(Triangle::new(a, b, c)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+);
+
+impl<T: CoordNum> private::Sealed for GeometryCollection<T> {}
+
+impl<T: CoordNum + Serialize + DeserializeOwned> GeoJsonGeometry for GeometryCollection<T> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut object = serializer.serialize_struct("Geometry", 2)?;
+        object.serialize_field("type", "GeometryCollection")?;
+        object.serialize_field("geometries", &Seq(self.0.iter(), Borrowed))?;
+        object.end()
+    }
+
+    fn deserialize_geojson<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserialize_geometry(deserializer)? {
+            Geometry::GeometryCollection(g) => Ok(g),
+            _ => Err(de::Error::custom("expected a GeoJSON GeometryCollection")),
+        }
+    }
+}
+
+impl<G: GeoJsonGeometry> GeoJsonGeometry for GeoJson<G> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_geojson(serializer)
+    }
+
+    fn deserialize_geojson<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        G::deserialize_geojson(deserializer).map(GeoJson)
+    }
+}
+
+impl<G: GeoJsonGeometry> private::Sealed for GeoJson<G> {}
+
+/// Serializes a borrowed geometry, e.g. an element of a collection.
+struct Borrowed<'a, G>(&'a G);
+
+impl<G: GeoJsonGeometry> Serialize for Borrowed<'_, G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_geojson(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn round_trip<G>(geometry: G, expected: serde_json::Value)
+    where
+        G: GeoJsonGeometry + PartialEq + core::fmt::Debug + Clone,
+    {
+        let value = serde_json::to_value(GeoJson(geometry.clone())).unwrap();
+        assert_eq!(value, expected);
+        let GeoJson(parsed): GeoJson<G> = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, geometry);
+    }
+
+    #[test]
+    fn round_trip_geometries() {
+        round_trip(
+            point!(x: 1., y: 2.),
+            json!({"type": "Point", "coordinates": [1., 2.]}),
+        );
+        round_trip(
+            line_string![(x: 0., y: 0.), (x: 1., y: 2.)],
+            json!({"type": "LineString", "coordinates": [[0., 0.], [1., 2.]]}),
+        );
+        round_trip(
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            ],
+            json!({"type": "Polygon", "coordinates": [
+                [[0., 0.], [4., 0.], [4., 4.], [0., 0.]],
+                [[1., 1.], [2., 1.], [2., 2.], [1., 1.]],
+            ]}),
+        );
+        round_trip(
+            wkt!(MULTIPOINT(0. 0.,1. 1.)),
+            json!({"type": "MultiPoint", "coordinates": [[0., 0.], [1., 1.]]}),
+        );
+        round_trip(
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. 3.))),
+            json!({"type": "MultiLineString", "coordinates": [
+                [[0., 0.], [1., 1.]],
+                [[2., 2.], [3., 3.]],
+            ]}),
+        );
+        round_trip(
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)))),
+            json!({"type": "MultiPolygon", "coordinates": [
+                [[[0., 0.], [1., 0.], [1., 1.], [0., 0.]]],
+            ]}),
+        );
+    }
+
+    #[test]
+    fn round_trip_geometry_collection() {
+        let collection: GeometryCollection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 1., y: 2.)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::LineString(
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            )])),
+        ]);
+        let expected = json!({"type": "GeometryCollection", "geometries": [
+            {"type": "Point", "coordinates": [1., 2.]},
+            {"type": "GeometryCollection", "geometries": [
+                {"type": "LineString", "coordinates": [[0., 0.], [1., 1.]]},
+            ]},
+        ]});
+        round_trip(collection.clone(), expected.clone());
+        round_trip(Geometry::GeometryCollection(collection), expected);
+    }
+
+    #[test]
+    fn line_rect_and_triangle() {
+        round_trip(
+            Line::new((0., 0.), (1., 2.)),
+            json!({"type": "LineString", "coordinates": [[0., 0.], [1., 2.]]}),
+        );
+        round_trip(
+            Rect::new((0., 0.), (1., 2.)),
+            json!({"type": "Polygon", "coordinates": [
+                [[0., 0.], [0., 2.], [1., 2.], [1., 0.], [0., 0.]],
+            ]}),
+        );
+        round_trip(
+            Triangle::new((0., 0.).into(), (1., 0.).into(), (0., 1.).into()),
+            json!({"type": "Polygon", "coordinates": [
+                [[0., 0.], [1., 0.], [0., 1.], [0., 0.]],
+            ]}),
+        );
+        // The enum variant can't be recovered, so these become their GeoJSON equivalents
+        let geometry: GeoJson<Geometry> = serde_json::from_value(
+            serde_json::to_value(GeoJson(Line::new((0., 0.), (1., 2.)))).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(geometry.0, Geometry::LineString(_)));
+    }
+
+    #[test]
+    fn extra_position_elements_are_ignored() {
+        let GeoJson(point): GeoJson<Point<i32>> =
+            serde_json::from_value(json!({"type": "Point", "coordinates": [1, 2, 3]})).unwrap();
+        assert_eq!(point, point!(x: 1, y: 2));
+        assert!(serde_json::from_value::<GeoJson<Point>>(
+            json!({"type": "Point", "coordinates": [1.]})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn mismatched_type_is_an_error() {
+        let value = json!({"type": "LineString", "coordinates": [[0., 0.], [1., 1.], [2., 2.]]});
+        let error = serde_json::from_value::<GeoJson<Point>>(value.clone()).unwrap_err();
+        assert_eq!(error.to_string(), "expected a GeoJSON Point");
+        assert!(serde_json::from_value::<GeoJson<Line>>(value).is_err());
+
+        let square =
+            json!({"type": "Polygon", "coordinates": [[[0., 0.], [2., 1.], [0., 2.], [0., 0.]]]});
+        assert!(serde_json::from_value::<GeoJson<Rect>>(square).is_err());
+        let degenerate = json!({
+            "type": "Polygon",
+            "coordinates": [[[0., 0.], [2., 0.], [2., 2.], [2., 0.], [0., 0.]]]
+        });
+        assert!(serde_json::from_value::<GeoJson<Rect>>(degenerate).is_err());
+        assert!(serde_json::from_value::<GeoJson<Point>>(json!({"type": "Feature"})).is_err());
+    }
+}
@@ -0,0 +1,499 @@
+//! [proptest] strategies for generating geometries.
+//!
+//! Every geometry type implements [`proptest::arbitrary::Arbitrary`], parameterised by
+//! [`GeometryParams`], so they can be generated with [`any`](proptest::arbitrary::any) or
+//! [`any_with`](proptest::arbitrary::any_with). The functions in
+//! this module return the same strategies, for use with combinators.
+//!
+//! By default, only valid geometries are generated: polygon rings are closed, simple and
+//! counter-clockwise (interior rings clockwise), holes lie inside their exterior, and the
+//! polygons of a `MultiPolygon` don't overlap. Set [`GeometryParams::invalid`] to also generate
+//! deliberately invalid geometries, e.g. self-intersecting or collapsed rings.
+//!
+//! ```
+//! use geo_types::strategy::GeometryParams;
+//! use geo_types::{Polygon, Rect};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn exterior_is_closed(polygon in any_with::<Polygon>(GeometryParams {
+//!         bounds: Rect::new((0., 0.), (10., 10.)),
+//!         max_vertices: 8,
+//!         ..Default::default()
+//!     })) {
+//!         prop_assert!(polygon.exterior().is_closed());
+//!         prop_assert!(polygon.exterior().0.len() <= 9);
+//!     }
+//! }
+//! # exterior_is_closed();
+//! ```
+//!
+//! [proptest]: https://github.com/proptest-rs/proptest
+
+use crate::{
+    Coord, CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use core::f64::consts::TAU;
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::vec::Vec;
+
+/// Controls the size and coordinate range of generated geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryParams {
+    /// All generated coordinates lie within these bounds, which should have a positive area.
+    ///
+    /// Defaults to `(-1000, -1000)` – `(1000, 1000)`.
+    pub bounds: Rect<f64>,
+    /// The maximum number of distinct vertices in a `LineString` or polygon ring. Polygon rings
+    /// always have at least 3. Defaults to `16`.
+    pub max_vertices: usize,
+    /// The maximum number of parts of a multi-geometry or `GeometryCollection`. Defaults to `4`.
+    pub max_parts: usize,
+    /// Whether deliberately invalid geometries may be generated. Defaults to `false`.
+    pub invalid: bool,
+}
+
+impl Default for GeometryParams {
+    fn default() -> Self {
+        Self {
+            bounds: Rect::new((-1000., -1000.), (1000., 1000.)),
+            max_vertices: 16,
+            max_parts: 4,
+            invalid: false,
+        }
+    }
+}
+
+impl GeometryParams {
+    fn with_bounds(&self, bounds: Rect<f64>) -> Self {
+        Self { bounds, ..*self }
+    }
+
+    fn min_vertices(&self, min: usize) -> core::ops::RangeInclusive<usize> {
+        min..=self.max_vertices.max(min)
+    }
+
+    fn parts(&self) -> core::ops::RangeInclusive<usize> {
+        1..=self.max_parts.max(1)
+    }
+}
+
+fn cast<T: CoordFloat>(value: f64) -> T {
+    T::from(value).expect("coordinate is representable")
+}
+
+fn to_coord<T: CoordFloat>((x, y): (f64, f64)) -> Coord<T> {
+    coord! { x: cast(x), y: cast(y) }
+}
+
+fn unit() -> core::ops::Range<f64> {
+    0.0..1.0
+}
+
+/// A star-shaped ring around a random centre, so that it's always simple. Vertex `i` of `n` is at
+/// an angle in `[i, i + 0.25) / n` of a full turn, with a radius in `radius_range` (as a fraction
+/// of the largest radius that fits in `bounds`).
+///
+/// Returns the ring's centre, its maximum radius and its open vertices, counter-clockwise.
+fn star(
+    bounds: Rect<f64>,
+    vertices: core::ops::RangeInclusive<usize>,
+    radius_range: core::ops::RangeInclusive<f64>,
+) -> impl Strategy<Value = ((f64, f64), f64, Vec<(f64, f64)>)> {
+    (
+        (unit(), unit(), 0.1..=0.9f64),
+        vec((0.0..0.25f64, radius_range), vertices),
+    )
+        .prop_map(move |((fx, fy, fr), vertices)| {
+            let radius = fr * bounds.width().min(bounds.height()) / 2.;
+            let centre = (
+                bounds.min().x + radius + fx * (bounds.width() - 2. * radius),
+                bounds.min().y + radius + fy * (bounds.height() - 2. * radius),
+            );
+            let n = vertices.len() as f64;
+            let ring = star_vertices(centre, radius, &vertices, n);
+            (centre, radius, ring)
+        })
+}
+
+fn star_vertices(
+    centre: (f64, f64),
+    radius: f64,
+    vertices: &[(f64, f64)],
+    n: f64,
+) -> Vec<(f64, f64)> {
+    vertices
+        .iter()
+        .enumerate()
+        .map(|(i, (jitter, r))| {
+            let angle = (i as f64 + jitter) / n * TAU;
+            (
+                centre.0 + r * radius * angle.cos(),
+                centre.1 + r * radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Split `bounds` into `n` equal vertical slices.
+fn columns(bounds: Rect<f64>, n: usize) -> impl Iterator<Item = Rect<f64>> {
+    let width = bounds.width() / n as f64;
+    (0..n).map(move |i| {
+        let x = bounds.min().x + i as f64 * width;
+        Rect::new((x, bounds.min().y), (x + width, bounds.max().y))
+    })
+}
+
+/// A strategy for coordinates within `params.bounds`.
+pub fn coord<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Coord<T>> {
+    let (min, max) = (params.bounds.min(), params.bounds.max());
+    (min.x..=max.x, min.y..=max.y).prop_map(to_coord).boxed()
+}
+
+/// A strategy for points within `params.bounds`.
+pub fn point<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Point<T>> {
+    coord(params).prop_map(Point).boxed()
+}
+
+/// A strategy for lines within `params.bounds`.
+///
+/// Invalid lines have identical start and end coordinates.
+pub fn line<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Line<T>> {
+    let valid = (coord(params), coord(params)).prop_map(|(start, end)| Line::new(start, end));
+    if params.invalid {
+        prop_oneof![valid, coord(params).prop_map(|c| Line::new(c, c))].boxed()
+    } else {
+        valid.boxed()
+    }
+}
+
+/// A strategy for line strings with between 2 and `params.max_vertices` coordinates.
+///
+/// Invalid line strings consist of a single coordinate, possibly repeated.
+pub fn line_string<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> BoxedStrategy<LineString<T>> {
+    let valid = vec(coord(params), params.min_vertices(2)).prop_map(LineString::new);
+    if params.invalid {
+        let collapsed = (coord(params), params.min_vertices(1))
+            .prop_map(|(c, n)| LineString::new(std::vec![c; n]));
+        prop_oneof![valid, collapsed].boxed()
+    } else {
+        valid.boxed()
+    }
+}
+
+/// A strategy for simple polygons within `params.bounds`, with up to one hole.
+///
+/// Invalid polygons are "bow-ties", rings which have collapsed to a line, or polygons with a hole
+/// outside of their exterior.
+pub fn polygon<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Polygon<T>> {
+    let valid = (
+        star(params.bounds, params.min_vertices(3), 0.5..=1.0),
+        proptest::option::of(vec((0.0..0.25f64, 0.05..=0.1f64), params.min_vertices(3))),
+    )
+        .prop_map(|((centre, radius, exterior), hole)| {
+            let interiors = hole
+                .map(|hole| {
+                    // Holes are at most a tenth of the exterior's radius from its centre, which
+                    // is always inside the exterior.
+                    let mut ring = star_vertices(centre, radius, &hole, hole.len() as f64);
+                    ring.reverse();
+                    ring_from(ring)
+                })
+                .into_iter()
+                .collect();
+            Polygon::new(ring_from(exterior), interiors)
+        });
+    if params.invalid {
+        prop_oneof![valid, invalid_polygon(params)].boxed()
+    } else {
+        valid.boxed()
+    }
+}
+
+fn ring_from<T: CoordFloat>(vertices: Vec<(f64, f64)>) -> LineString<T> {
+    // `Polygon::new` closes the ring
+    vertices.into_iter().map(to_coord).collect()
+}
+
+fn invalid_polygon<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> impl Strategy<Value = Polygon<T>> {
+    let bow_tie = rect::<T>(params).prop_map(|rect| {
+        let (min, max) = (rect.min(), rect.max());
+        let exterior = LineString::new(std::vec![
+            min,
+            max,
+            coord! { x: max.x, y: min.y },
+            coord! { x: min.x, y: max.y },
+        ]);
+        Polygon::new(exterior, Vec::new())
+    });
+    let collapsed = (coord::<T>(params), coord::<T>(params)).prop_map(|(start, end)| {
+        let mid = (start + end) / cast(2.);
+        Polygon::new(LineString::new(std::vec![start, mid, end]), Vec::new())
+    });
+    let bounds = params.bounds;
+    let outside_hole = star(
+        Rect::new(
+            bounds.min(),
+            coord! { x: bounds.center().x, y: bounds.max().y },
+        ),
+        params.min_vertices(3),
+        0.5..=1.0,
+    )
+    .prop_flat_map(move |(_, _, exterior)| {
+        let right = Rect::new(
+            coord! { x: bounds.center().x, y: bounds.min().y },
+            bounds.max(),
+        );
+        let params = params.with_bounds(right);
+        star(right, params.min_vertices(3), 0.5..=1.0).prop_map(move |(_, _, mut hole)| {
+            hole.reverse();
+            Polygon::new(ring_from(exterior.clone()), std::vec![ring_from(hole)])
+        })
+    });
+    prop_oneof![bow_tie, collapsed, outside_hole]
+}
+
+/// A strategy for multi points with between 1 and `params.max_parts` points.
+pub fn multi_point<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> BoxedStrategy<MultiPoint<T>> {
+    vec(point(params), params.parts())
+        .prop_map(MultiPoint::new)
+        .boxed()
+}
+
+/// A strategy for multi line strings with between 1 and `params.max_parts` line strings.
+pub fn multi_line_string<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> BoxedStrategy<MultiLineString<T>> {
+    vec(line_string(params), params.parts())
+        .prop_map(MultiLineString::new)
+        .boxed()
+}
+
+/// A strategy for multi polygons with between 1 and `params.max_parts` non-overlapping polygons.
+///
+/// Invalid multi polygons contain the same polygon twice.
+pub fn multi_polygon<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> BoxedStrategy<MultiPolygon<T>> {
+    let valid_params = GeometryParams {
+        invalid: false,
+        ..params
+    };
+    let valid = params.parts().prop_flat_map(move |n| {
+        columns(valid_params.bounds, n)
+            .map(|column| polygon(valid_params.with_bounds(column)))
+            .collect::<Vec<_>>()
+            .prop_map(MultiPolygon::new)
+    });
+    if params.invalid {
+        let overlapping = polygon(params).prop_map(|p| MultiPolygon::new(std::vec![p.clone(), p]));
+        prop_oneof![valid, overlapping].boxed()
+    } else {
+        valid.boxed()
+    }
+}
+
+/// A strategy for rects within `params.bounds`.
+pub fn rect<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Rect<T>> {
+    (coord(params), coord(params))
+        .prop_map(|(a, b)| Rect::new(a, b))
+        .boxed()
+}
+
+/// A strategy for non-degenerate, counter-clockwise triangles within `params.bounds`.
+///
+/// Invalid triangles have three collinear vertices.
+pub fn triangle<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Triangle<T>> {
+    let valid = star(params.bounds, 3..=3, 0.5..=1.0)
+        .prop_map(|(_, _, v)| Triangle::new(to_coord(v[0]), to_coord(v[1]), to_coord(v[2])));
+    if params.invalid {
+        let collinear = (coord::<T>(params), coord::<T>(params))
+            .prop_map(|(a, b)| Triangle::new(a, (a + b) / cast(2.), b));
+        prop_oneof![valid, collinear].boxed()
+    } else {
+        valid.boxed()
+    }
+}
+
+/// A strategy for geometries of any type, including (nested) geometry collections.
+pub fn geometry<T: CoordFloat + 'static>(params: GeometryParams) -> BoxedStrategy<Geometry<T>> {
+    let leaf = prop_oneof![
+        point(params).prop_map(Geometry::Point),
+        line(params).prop_map(Geometry::Line),
+        line_string(params).prop_map(Geometry::LineString),
+        polygon(params).prop_map(Geometry::Polygon),
+        multi_point(params).prop_map(Geometry::MultiPoint),
+        multi_line_string(params).prop_map(Geometry::MultiLineString),
+        multi_polygon(params).prop_map(Geometry::MultiPolygon),
+        rect(params).prop_map(Geometry::Rect),
+        triangle(params).prop_map(Geometry::Triangle),
+    ];
+    let max_parts = params.max_parts.max(1) as u32;
+    leaf.prop_recursive(2, 4 * max_parts, max_parts, move |inner| {
+        vec(inner, 0..=max_parts as usize)
+            .prop_map(|geometries| Geometry::GeometryCollection(GeometryCollection(geometries)))
+    })
+    .boxed()
+}
+
+/// A strategy for geometry collections with up to `params.max_parts` geometries.
+pub fn geometry_collection<T: CoordFloat + 'static>(
+    params: GeometryParams,
+) -> BoxedStrategy<GeometryCollection<T>> {
+    vec(geometry(params), 0..=params.max_parts)
+        .prop_map(GeometryCollection)
+        .boxed()
+}
+
+macro_rules! impl_arbitrary {
+    ($($type:ident => $strategy:ident),+ $(,)?) => {
+        $(
+            impl<T: CoordFloat + 'static> Arbitrary for $type<T> {
+                type Parameters = GeometryParams;
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+                    $strategy(params)
+                }
+            }
+        )+
+    };
+}
+
+impl_arbitrary!(
+    Coord => coord,
+    Point => point,
+    Line => line,
+    LineString => line_string,
+    Polygon => polygon,
+    MultiPoint => multi_point,
+    MultiLineString => multi_line_string,
+    MultiPolygon => multi_polygon,
+    Rect => rect,
+    Triangle => triangle,
+    Geometry => geometry,
+    GeometryCollection => geometry_collection,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn twice_signed_area(ring: &LineString<f64>) -> f64 {
+        ring.lines()
+            .map(|l| l.start.x * l.end.y - l.end.x * l.start.y)
+            .sum()
+    }
+
+    fn in_bounds(c: Coord<f64>, bounds: Rect<f64>) -> bool {
+        bounds.min().x <= c.x
+            && c.x <= bounds.max().x
+            && bounds.min().y <= c.y
+            && c.y <= bounds.max().y
+    }
+
+    fn small() -> GeometryParams {
+        GeometryParams {
+            bounds: Rect::new((10., 20.), (30., 25.)),
+            max_vertices: 6,
+            max_parts: 3,
+            invalid: false,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn polygons_are_valid(polygon in any_with::<Polygon>(small())) {
+            let exterior = polygon.exterior();
+            prop_assert!(exterior.is_closed());
+            prop_assert!((4..=7).contains(&exterior.0.len()));
+            prop_assert!(exterior.coords().all(|c| in_bounds(*c, small().bounds)));
+            prop_assert!(twice_signed_area(exterior) > 0.);
+            for hole in polygon.interiors() {
+                prop_assert!(twice_signed_area(hole) < 0.);
+                prop_assert!(twice_signed_area(hole).abs() < twice_signed_area(exterior));
+            }
+        }
+
+        #[test]
+        fn multi_polygons_do_not_overlap(multi_polygon in any_with::<MultiPolygon>(small())) {
+            prop_assert!((1..=3).contains(&multi_polygon.0.len()));
+            let xs: Vec<_> = multi_polygon
+                .iter()
+                .map(|p| {
+                    let xs = || p.exterior().coords().map(|c| c.x);
+                    (xs().fold(f64::INFINITY, f64::min), xs().fold(f64::NEG_INFINITY, f64::max))
+                })
+                .collect();
+            for pair in xs.windows(2) {
+                prop_assert!(pair[0].1 < pair[1].0);
+            }
+        }
+
+        #[test]
+        fn triangles_are_counter_clockwise(triangle in any::<Triangle<f32>>()) {
+            let [a, b, c] = triangle.to_array();
+            prop_assert!((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x) > 0.);
+        }
+
+        #[test]
+        fn geometries_are_in_bounds(geometry in any_with::<Geometry>(small())) {
+            let mut coords = Vec::new();
+            collect_coords(&geometry, &mut coords);
+            prop_assert!(coords.into_iter().all(|c| in_bounds(c, small().bounds)));
+        }
+
+    }
+
+    #[test]
+    fn generates_invalid_polygons() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let params = GeometryParams {
+            invalid: true,
+            ..small()
+        };
+        let strategy = any_with::<Polygon>(params);
+        let mut runner = TestRunner::deterministic();
+        let found_invalid = (0..100).any(|_| {
+            let polygon = strategy.new_tree(&mut runner).unwrap().current();
+            let max_x = |ring: &LineString| ring.coords().map(|c| c.x).fold(f64::MIN, f64::max);
+            twice_signed_area(polygon.exterior()) <= 0.
+                || polygon
+                    .interiors()
+                    .iter()
+                    .any(|hole| max_x(hole) > max_x(polygon.exterior()))
+        });
+        assert!(found_invalid);
+    }
+
+    fn collect_coords(geometry: &Geometry, coords: &mut Vec<Coord>) {
+        match geometry {
+            Geometry::Point(p) => coords.push(p.0),
+            Geometry::Line(l) => coords.extend([l.start, l.end]),
+            Geometry::LineString(ls) => coords.extend(ls.coords()),
+            Geometry::Polygon(p) => {
+                coords.extend(p.exterior().coords());
+                p.interiors().iter().for_each(|i| coords.extend(i.coords()));
+            }
+            Geometry::MultiPoint(mp) => coords.extend(mp.iter().map(|p| p.0)),
+            Geometry::MultiLineString(mls) => mls.iter().for_each(|ls| coords.extend(ls.coords())),
+            Geometry::MultiPolygon(mp) => mp
+                .iter()
+                .for_each(|p| collect_coords(&Geometry::Polygon(p.clone()), coords)),
+            Geometry::Rect(r) => coords.extend([r.min(), r.max()]),
+            Geometry::Triangle(t) => coords.extend(t.to_array()),
+            Geometry::GeometryCollection(gc) => gc.iter().for_each(|g| collect_coords(g, coords)),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! [`Display`] and [`FromStr`] implementations which write and parse
+//! [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry).
+//!
+//! The formatting precision, if any, is applied to every coordinate:
+//!
+//! ```
+//! use geo_types::{point, Geometry, LineString};
+//!
+//! let point = point!(x: 1.0, y: 2.5);
+//! assert_eq!(point.to_string(), "POINT(1 2.5)");
+//! assert_eq!(format!("{point:.2}"), "POINT(1.00 2.50)");
+//!
+//! let line_string: LineString = "LINESTRING (0 0, 1 1.5)".parse().unwrap();
+//! assert_eq!(line_string.0.len(), 2);
+//!
+//! let geometry: Geometry<i32> = "multipoint((1 2),(3 4))".parse().unwrap();
+//! assert_eq!(geometry.to_string(), "MULTIPOINT((1 2),(3 4))");
+//! ```
+//!
+//! Only two-dimensional WKT is supported. A [`Line`] is written as a two point `LINESTRING`,
+//! and a [`Rect`] or [`Triangle`] as a `POLYGON`. Parsing them accepts the same representations.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle, MAX_COLLECTION_DEPTH,
+};
+
+fn write_coord<T: CoordNum + Display>(f: &mut fmt::Formatter<'_>, coord: &Coord<T>) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*} {:.*}", precision, coord.x, precision, coord.y),
+        None => write!(f, "{} {}", coord.x, coord.y),
+    }
+}
+
+/// Write `items` as a parenthesised, comma separated list, or `EMPTY`.
+fn write_list<I: IntoIterator>(
+    f: &mut fmt::Formatter<'_>,
+    items: I,
+    mut write_item: impl FnMut(&mut fmt::Formatter<'_>, I::Item) -> fmt::Result,
+) -> fmt::Result {
+    let mut items = items.into_iter().peekable();
+    if items.peek().is_none() {
+        return f.write_str(" EMPTY");
+    }
+    f.write_char('(')?;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_item(f, item)?;
+    }
+    f.write_char(')')
+}
+
+fn write_coords<'a, T: CoordNum + Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    coords: impl IntoIterator<Item = &'a Coord<T>>,
+) -> fmt::Result {
+    write_list(f, coords, write_coord)
+}
+
+fn write_polygon<T: CoordNum + Display>(
+    f: &mut fmt::Formatter<'_>,
+    polygon: &Polygon<T>,
+) -> fmt::Result {
+    if polygon.exterior().0.is_empty() {
+        return f.write_str(" EMPTY");
+    }
+    let rings = core::iter::once(polygon.exterior()).chain(polygon.interiors());
+    write_list(f, rings, |f, ring| write_coords(f, &ring.0))
+}
+
+impl<T: CoordNum + Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("POINT(")?;
+        write_coord(f, &self.0)?;
+        f.write_char(')')
+    }
+}
+
+impl<T: CoordNum + Display> Display for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LINESTRING")?;
+        write_coords(f, [&self.start, &self.end])
+    }
+}
+
+impl<T: CoordNum + Display> Display for LineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LINESTRING")?;
+        write_coords(f, &self.0)
+    }
+}
+
+impl<T: CoordNum + Display> Display for Polygon<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("POLYGON")?;
+        write_polygon(f, self)
+    }
+}
+
+impl<T: CoordNum + Display> Display for MultiPoint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MULTIPOINT")?;
+        write_list(f, &self.0, |f, point| {
+            f.write_char('(')?;
+            write_coord(f, &point.0)?;
+            f.write_char(')')
+        })
+    }
+}
+
+impl<T: CoordNum + Display> Display for MultiLineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MULTILINESTRING")?;
+        write_list(f, &self.0, |f, line_string| write_coords(f, &line_string.0))
+    }
+}
+
+impl<T: CoordNum + Display> Display for MultiPolygon<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MULTIPOLYGON")?;
+        write_list(f, &self.0, write_polygon)
+    }
+}
+
+impl<T: CoordNum + Display> Display for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_polygon().fmt(f)
+    }
+}
+
+impl<T: CoordNum + Display> Display for Triangle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_polygon().fmt(f)
+    }
+}
+
+impl<T: CoordNum + Display> Display for GeometryCollection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GEOMETRYCOLLECTION")?;
+        write_list(f, &self.0, |f, geometry| geometry.fmt(f))
+    }
+}
+
+impl<T: CoordNum + Display> Display for Geometry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(g) => g.fmt(f),
+            Geometry::Line(g) => g.fmt(f),
+            Geometry::LineString(g) => g.fmt(f),
+            Geometry::Polygon(g) => g.fmt(f),
+            Geometry::MultiPoint(g) => g.fmt(f),
+            Geometry::MultiLineString(g) => g.fmt(f),
+            Geometry::MultiPolygon(g) => g.fmt(f),
+            Geometry::GeometryCollection(g) => g.fmt(f),
+            Geometry::Rect(g) => g.fmt(f),
+            Geometry::Triangle(g) => g.fmt(f),
+        }
+    }
+}
+
+/// An error returned when parsing a geometry from WKT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWktError {
+    /// What was wrong with the input.
+    pub message: &'static str,
+    /// The byte offset into the input at which the error was detected.
+    pub position: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseWktError {}
+
+impl Display for ParseWktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid WKT at byte {}: {}", self.position, self.message)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    /// The number of geometry collections the parser is in.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<R>(&self, message: &'static str) -> Result<R, ParseWktError> {
+        Err(ParseWktError {
+            message,
+            position: self.position,
+        })
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.position..].chars().next()
+    }
+
+    /// Consume `c` if it's the next non-whitespace character.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, message: &'static str) -> Result<(), ParseWktError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.error(message)
+        }
+    }
+
+    /// The next run of characters which aren't whitespace or punctuation.
+    fn word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ','))
+            .unwrap_or(rest.len());
+        self.position += len;
+        &rest[..len]
+    }
+
+    fn finish(&mut self) -> Result<(), ParseWktError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => self.error("unexpected trailing input"),
+        }
+    }
+
+    /// Either `EMPTY` (returning `false`) or an opening parenthesis (returning `true`).
+    fn open(&mut self) -> Result<bool, ParseWktError> {
+        if self.eat('(') {
+            return Ok(true);
+        }
+        let start = self.position;
+        let word = self.word();
+        if word.eq_ignore_ascii_case("EMPTY") {
+            Ok(false)
+        } else {
+            self.position = start;
+            if ["Z", "M", "ZM"]
+                .iter()
+                .any(|tag| word.eq_ignore_ascii_case(tag))
+            {
+                self.error("only two-dimensional coordinates are supported")
+            } else {
+                self.error("expected `(` or `EMPTY`")
+            }
+        }
+    }
+
+    /// Parse a parenthesised, comma separated list, or `EMPTY`.
+    fn list<R>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<R, ParseWktError>,
+    ) -> Result<Vec<R>, ParseWktError> {
+        let mut items = Vec::new();
+        if self.open()? {
+            loop {
+                items.push(item(self)?);
+                if !self.eat(',') {
+                    break;
+                }
+            }
+            self.expect(')', "expected `,` or `)`")?;
+        }
+        Ok(items)
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, ParseWktError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let word = self.word();
+        if word.is_empty() {
+            return self.error("expected a number");
+        }
+        word.parse().or_else(|_| {
+            self.position = start;
+            self.error("invalid number")
+        })
+    }
+
+    fn coord<T: CoordNum + FromStr>(&mut self) -> Result<Coord<T>, ParseWktError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        if !matches!(self.peek(), Some(',' | ')') | None) {
+            return self.error("only two-dimensional coordinates are supported");
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn line_string<T: CoordNum + FromStr>(&mut self) -> Result<LineString<T>, ParseWktError> {
+        self.list(Self::coord).map(LineString)
+    }
+
+    fn polygon<T: CoordNum + FromStr>(&mut self) -> Result<Polygon<T>, ParseWktError> {
+        let mut rings = self.list(Self::line_string)?.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(Vec::new()));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn multi_point<T: CoordNum + FromStr>(&mut self) -> Result<MultiPoint<T>, ParseWktError> {
+        // Both `MULTIPOINT((1 2),(3 4))` and `MULTIPOINT(1 2,3 4)` are in common use
+        self.list(|parser| {
+            if parser.eat('(') {
+                let coord = parser.coord()?;
+                parser.expect(')', "expected `)`")?;
+                Ok(Point(coord))
+            } else {
+                parser.coord().map(Point)
+            }
+        })
+        .map(MultiPoint)
+    }
+
+    fn geometry<T: CoordNum + FromStr>(&mut self) -> Result<Geometry<T>, ParseWktError> {
+        let start = self.position;
+        let keyword = self.word();
+        let geometry = if keyword.eq_ignore_ascii_case("POINT") {
+            if !self.open()? {
+                return self.error("`POINT EMPTY` can't be represented as a `Point`");
+            }
+            let coord = self.coord()?;
+            self.expect(')', "expected `)`")?;
+            Geometry::Point(Point(coord))
+        } else if keyword.eq_ignore_ascii_case("LINESTRING") {
+            Geometry::LineString(self.line_string()?)
+        } else if keyword.eq_ignore_ascii_case("POLYGON") {
+            Geometry::Polygon(self.polygon()?)
+        } else if keyword.eq_ignore_ascii_case("MULTIPOINT") {
+            Geometry::MultiPoint(self.multi_point()?)
+        } else if keyword.eq_ignore_ascii_case("MULTILINESTRING") {
+            Geometry::MultiLineString(MultiLineString(self.list(Self::line_string)?))
+        } else if keyword.eq_ignore_ascii_case("MULTIPOLYGON") {
+            Geometry::MultiPolygon(MultiPolygon(self.list(Self::polygon)?))
+        } else if keyword.eq_ignore_ascii_case("GEOMETRYCOLLECTION") {
+            if self.depth == MAX_COLLECTION_DEPTH {
+                self.position = start;
+                return self.error("geometry collections are nested too deeply");
+            }
+            self.depth += 1;
+            let geometries = self.list(Self::geometry)?;
+            self.depth -= 1;
+            Geometry::GeometryCollection(GeometryCollection(geometries))
+        } else {
+            self.position = start;
+            return self.error("expected a geometry type, e.g. `POINT`");
+        };
+        Ok(geometry)
+    }
+}
+
+fn parse_geometry<T: CoordNum + FromStr>(input: &str) -> Result<Geometry<T>, ParseWktError> {
+    let mut parser = Parser {
+        input,
+        position: 0,
+        depth: 0,
+    };
+    let geometry = parser.geometry()?;
+    parser.finish()?;
+    Ok(geometry)
+}
+
+impl<T: CoordNum + FromStr> FromStr for Geometry<T> {
+    type Err = ParseWktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_geometry(s)
+    }
+}
+
+macro_rules! from_str_impl {
+    ($($type:ident => $message:literal),+ $(,)?) => {
+        $(
+            impl<T: CoordNum + FromStr> FromStr for $type<T> {
+                type Err = ParseWktError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match parse_geometry(s)? {
+                        Geometry::$type(g) => Ok(g),
+                        _ => Err(ParseWktError { message: $message, position: 0 }),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+from_str_impl!(
+    Point => "expected a `POINT`",
+    LineString => "expected a `LINESTRING`",
+    Polygon => "expected a `POLYGON`",
+    MultiPoint => "expected a `MULTIPOINT`",
+    MultiLineString => "expected a `MULTILINESTRING`",
+    MultiPolygon => "expected a `MULTIPOLYGON`",
+    GeometryCollection => "expected a `GEOMETRYCOLLECTION`",
+);
+
+impl<T: CoordNum + FromStr> FromStr for Line<T> {
+    type Err = ParseWktError;
+
+    /// Parses a `LINESTRING` with exactly two coordinates.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line_string: LineString<T> = s.parse()?;
+        match line_string.0[..] {
+            [start, end] => Ok(Line::new(start, end)),
+            _ => Err(ParseWktError {
+                message: "expected a `LINESTRING` with two coordinates",
+                position: 0,
+            }),
+        }
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Triangle<T> {
+    type Err = ParseWktError;
+
+    /// Parses a `POLYGON` with no interior rings and three coordinates.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let polygon: Polygon<T> = s.parse()?;
+        match (&polygon.exterior().0[..], polygon.interiors()) {
+            ([a, b, c, _], []) => Ok(Triangle::new(*a, *b, *c)),
+            _ => Err(ParseWktError {
+                message: "expected a `POLYGON` with a single ring of three coordinates",
+                position: 0,
+            }),
+        }
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Rect<T> {
+    type Err = ParseWktError;
+
+    /// Parses an axis-aligned rectangular `POLYGON` with no interior rings, such as those
+    /// written by `Rect`'s `Display` implementation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let polygon: Polygon<T> = s.parse()?;
+        Rect::try_from_polygon(&polygon).ok_or(ParseWktError {
+            message: "expected an axis-aligned rectangular `POLYGON`",
+            position: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn display() {
+        assert_eq!(point!(x: 1.5, y: -2.).to_string(), "POINT(1.5 -2)");
+        assert_eq!(
+            Line::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }).to_string(),
+            "LINESTRING(0 0,1 2)"
+        );
+        assert_eq!(
+            LineString::<f64>::new(vec![]).to_string(),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(
+            wkt!(POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))).to_string(),
+            "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))"
+        );
+        assert_eq!(
+            Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }).to_string(),
+            "POLYGON((0 0,0 2,1 2,1 0,0 0))"
+        );
+        assert_eq!(
+            wkt!(MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))).to_string(),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))"
+        );
+        assert_eq!(
+            MultiPolygon::<f64>::new(vec![]).to_string(),
+            "MULTIPOLYGON EMPTY"
+        );
+        assert_eq!(
+            wkt!(GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY)).to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY)"
+        );
+    }
+
+    #[test]
+    fn display_precision() {
+        let line_string = wkt!(LINESTRING(0.12345 1.,2. 3.98765));
+        assert_eq!(
+            format!("{line_string:.2}"),
+            "LINESTRING(0.12 1.00,2.00 3.99)"
+        );
+        // precision is ignored for integers
+        assert_eq!(format!("{:.2}", point!(x: 1, y: 2)), "POINT(1 2)");
+    }
+
+    #[test]
+    fn round_trip() {
+        let geometries: [Geometry; 8] = [
+            wkt!(POINT(1.25 - 3.5)).into(),
+            wkt!(LINESTRING(0. 0.,1. 1.,2. 0.5)).into(),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.))).into(),
+            wkt!(POLYGON EMPTY).into(),
+            wkt!(MULTIPOINT(1. 2.,3. 4.)).into(),
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),EMPTY,(2. 2.,3. 3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.)))).into(),
+            Geometry::GeometryCollection(
+                wkt!(GEOMETRYCOLLECTION(POINT(1. 2.),LINESTRING EMPTY,MULTIPOINT EMPTY)),
+            ),
+        ];
+        for geometry in geometries {
+            let parsed: Geometry = geometry.to_string().parse().unwrap();
+            assert_eq!(parsed, geometry);
+        }
+
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(rect.to_string().parse::<Rect<i32>>().unwrap(), rect);
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 2 },
+            coord! { x: 2, y: 0 },
+        );
+        assert_eq!(
+            triangle.to_string().parse::<Triangle<i32>>().unwrap(),
+            triangle
+        );
+        let line = Line::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(line.to_string().parse::<Line<i32>>().unwrap(), line);
+    }
+
+    #[test]
+    fn parse_leniently() {
+        let multi_point: MultiPoint = " MultiPoint ( (1 2) , (3 4) ) ".parse().unwrap();
+        assert_eq!(multi_point, wkt!(MULTIPOINT(1. 2.,3. 4.)));
+        let polygon: Polygon<f32> = "POLYGON((0 0, 1 0, 1 1))".parse().unwrap();
+        assert!(polygon.exterior().is_closed());
+        let point: Point = "POINT(1e3 -2.5E-1)".parse().unwrap();
+        assert_eq!(point, point!(x: 1000., y: -0.25));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |s: &str| s.parse::<Geometry>().unwrap_err().to_string();
+        assert_eq!(error("POINT(1 x)"), "invalid WKT at byte 8: invalid number");
+        assert_eq!(
+            error("POINT Z(1 2 3)"),
+            "invalid WKT at byte 6: only two-dimensional coordinates are supported"
+        );
+        assert_eq!(
+            error("POINT(1 2 3)"),
+            "invalid WKT at byte 10: only two-dimensional coordinates are supported"
+        );
+        assert_eq!(
+            error("POINT EMPTY"),
+            "invalid WKT at byte 11: `POINT EMPTY` can't be represented as a `Point`"
+        );
+        assert_eq!(
+            error("LINESTRING(0 0,1 1"),
+            "invalid WKT at byte 18: expected `,` or `)`"
+        );
+        assert_eq!(
+            error("CIRCLE(0 0)"),
+            "invalid WKT at byte 0: expected a geometry type, e.g. `POINT`"
+        );
+        assert_eq!(
+            error("POINT(1 2) POINT(3 4)"),
+            "invalid WKT at byte 11: unexpected trailing input"
+        );
+        assert!("POINT(1 2)".parse::<LineString>().is_err());
+        assert!("LINESTRING(0 0,1 1,2 2)".parse::<Line>().is_err());
+        assert!("POLYGON((0 0,2 0,1 1,0 0))".parse::<Rect>().is_err());
+        // axis-aligned, but with zero area
+        assert!("POLYGON((0 0,2 0,2 2,2 0,0 0))".parse::<Rect>().is_err());
+        assert!("POLYGON((0 0,2 0,2 0,0 0,0 0))".parse::<Rect>().is_err());
+        assert_eq!(
+            "POLYGON((0 0,0 2,2 2,2 0,0 0))".parse::<Rect>().unwrap(),
+            Rect::new((0., 0.), (2., 2.))
+        );
+        assert!("POINT(1.5 2)".parse::<Point<i32>>().is_err());
+    }
+
+    #[test]
+    fn nesting_limit() {
+        use geo_test_fixtures::nested_collections_wkt as nested;
+
+        assert!(nested(MAX_COLLECTION_DEPTH).parse::<Geometry>().is_ok());
+        assert_eq!(
+            nested(MAX_COLLECTION_DEPTH + 1)
+                .parse::<Geometry>()
+                .unwrap_err()
+                .to_string(),
+            "invalid WKT at byte 2560: geometry collections are nested too deeply"
+        );
+        assert!(nested(200_000).parse::<Geometry>().is_err());
+    }
+}
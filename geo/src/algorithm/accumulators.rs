@@ -0,0 +1,522 @@
+//! Compute the area, length and centroid of a geometry from a stream of coordinates.
+//!
+//! The accumulators in this module are fed one coordinate at a time, and told where each line
+//! string, ring and polygon ends. This lets measures be computed while decoding a format such as
+//! WKB or FlatGeobuf, without allocating the geometry. The results are the same as [`Area`],
+//! [`Length`] and [`Centroid`] on the geometry the coordinates describe.
+//!
+//! Rings should be closed, as they are in geometry formats.
+//!
+//! [`Area`]: crate::Area
+//! [`Length`]: crate::Length
+//! [`Centroid`]: crate::Centroid
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::accumulators::{AreaAccumulator, CentroidAccumulator};
+//! use geo::{point, Coord};
+//!
+//! let square = [(0., 0.), (6., 0.), (6., 6.), (0., 6.), (0., 0.)].map(Coord::from);
+//! let hole = [(3., 3.), (3., 5.), (5., 5.), (5., 3.), (3., 3.)].map(Coord::from);
+//!
+//! let mut area = AreaAccumulator::new();
+//! let mut centroid = CentroidAccumulator::new();
+//! for ring in [square, hole] {
+//!     for coord in ring {
+//!         area.add_coord(coord);
+//!         centroid.add_coord(coord);
+//!     }
+//!     area.end_ring();
+//!     centroid.end_ring();
+//! }
+//! assert_eq!(area.finish(), 32.);
+//! assert_eq!(centroid.finish(), Some(point!(x: 2.875, y: 2.875)));
+//! ```
+
+use crate::centroid::CentroidOperation;
+use crate::dimensions::Dimensions::*;
+use crate::{Coord, CoordFloat, GeoFloat, Line};
+
+/// The planar area of a stream of polygons, like [`Area::unsigned_area`](crate::Area).
+///
+/// The first ring of each polygon is its exterior, and the others are its holes.
+#[derive(Debug, Clone)]
+pub struct AreaAccumulator<T: CoordFloat = f64> {
+    ring: RingArea<T>,
+    /// The signed area of the current polygon's exterior, and the total area of its holes
+    polygon: Option<(T, T)>,
+    area: T,
+}
+
+impl<T: CoordFloat> Default for AreaAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordFloat> AreaAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            ring: RingArea::new(),
+            polygon: None,
+            area: T::zero(),
+        }
+    }
+
+    /// Add the next coordinate of the current ring.
+    pub fn add_coord(&mut self, coord: Coord<T>) {
+        self.ring.add_coord(coord);
+    }
+
+    /// End the current ring.
+    pub fn end_ring(&mut self) {
+        let area = self.ring.area();
+        self.ring = RingArea::new();
+        match &mut self.polygon {
+            None => self.polygon = Some((area, T::zero())),
+            Some((_, holes)) => *holes = *holes + area.abs(),
+        }
+    }
+
+    /// Add a whole ring. This is the same as calling [`Self::add_coord`] with each coordinate, and
+    /// then [`Self::end_ring`].
+    pub fn add_ring(&mut self, ring: impl IntoIterator<Item = Coord<T>>) {
+        for coord in ring {
+            self.add_coord(coord);
+        }
+        self.end_ring();
+    }
+
+    /// End the current polygon, so that the next ring is the exterior of another polygon.
+    pub fn end_polygon(&mut self) {
+        if let Some((exterior, holes)) = self.polygon.take() {
+            self.area = self.area + (exterior.abs() - holes).abs();
+        }
+    }
+
+    /// The total area of all polygons. The current ring and polygon are ended if they haven't
+    /// been already.
+    pub fn finish(mut self) -> T {
+        if self.ring.count > 0 {
+            self.end_ring();
+        }
+        self.end_polygon();
+        self.area
+    }
+}
+
+/// The Euclidean length of a stream of line strings, like [`Length`](crate::Length).
+#[derive(Debug, Clone)]
+pub struct LengthAccumulator<T: CoordFloat = f64> {
+    last: Option<Coord<T>>,
+    length: T,
+}
+
+impl<T: CoordFloat> Default for LengthAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordFloat> LengthAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            length: T::zero(),
+        }
+    }
+
+    /// Add the next coordinate of the current line string.
+    pub fn add_coord(&mut self, coord: Coord<T>) {
+        if let Some(last) = self.last {
+            self.length = self.length + (coord.x - last.x).hypot(coord.y - last.y);
+        }
+        self.last = Some(coord);
+    }
+
+    /// End the current line string, so that the next coordinate starts a new one.
+    pub fn end_line_string(&mut self) {
+        self.last = None;
+    }
+
+    /// Add a whole line string. This is the same as calling [`Self::add_coord`] with each
+    /// coordinate, and then [`Self::end_line_string`].
+    pub fn add_line_string(&mut self, line_string: impl IntoIterator<Item = Coord<T>>) {
+        for coord in line_string {
+            self.add_coord(coord);
+        }
+        self.end_line_string();
+    }
+
+    /// The total length of all line strings.
+    pub fn finish(self) -> T {
+        self.length
+    }
+}
+
+/// The centroid of a stream of points, line strings and polygons, like
+/// [`Centroid`](crate::Centroid) on a [`GeometryCollection`](crate::GeometryCollection) of them.
+///
+/// As with `Centroid`, only the members of the highest dimension contribute, so points are
+/// ignored once a line string with non-zero length has been added, and both are ignored once a
+/// polygon with non-zero area has been added.
+#[derive(Debug, Clone)]
+pub struct CentroidAccumulator<T: GeoFloat = f64> {
+    operation: CentroidOperation<T>,
+    path: Path<T>,
+    polygon: Option<PolygonCentroid<T>>,
+}
+
+/// The centroids of the rings of the current polygon.
+#[derive(Debug, Clone)]
+struct PolygonCentroid<T: GeoFloat> {
+    exterior: CentroidOperation<T>,
+    /// The centroid of the exterior as a line string, in case the holes cover it entirely
+    exterior_line: CentroidOperation<T>,
+    interiors: CentroidOperation<T>,
+}
+
+impl<T: GeoFloat> Default for CentroidAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GeoFloat> CentroidAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            operation: CentroidOperation::new(),
+            path: Path::new(),
+            polygon: None,
+        }
+    }
+
+    /// Add a point.
+    pub fn add_point(&mut self, coord: Coord<T>) {
+        self.operation.add_coord(coord);
+    }
+
+    /// Add the next coordinate of the current line string or ring.
+    pub fn add_coord(&mut self, coord: Coord<T>) {
+        self.path.add_coord(coord);
+    }
+
+    /// End the current line string.
+    pub fn end_line_string(&mut self) {
+        let path = std::mem::replace(&mut self.path, Path::new());
+        if let Some(line) = path.line_centroid().0 {
+            self.operation.add_weighted_centroid(line);
+        }
+    }
+
+    /// Add a whole line string. This is the same as calling [`Self::add_coord`] with each
+    /// coordinate, and then [`Self::end_line_string`].
+    pub fn add_line_string(&mut self, line_string: impl IntoIterator<Item = Coord<T>>) {
+        for coord in line_string {
+            self.add_coord(coord);
+        }
+        self.end_line_string();
+    }
+
+    /// End the current ring. The first ring of each polygon is its exterior, and the others are
+    /// its holes.
+    pub fn end_ring(&mut self) {
+        let path = std::mem::replace(&mut self.path, Path::new());
+        let ring = path.ring_centroid();
+        match &mut self.polygon {
+            None => {
+                self.polygon = Some(PolygonCentroid {
+                    exterior: ring,
+                    exterior_line: path.line_centroid(),
+                    interiors: CentroidOperation::new(),
+                })
+            }
+            Some(polygon) => {
+                if let Some(ring) = ring.0 {
+                    polygon.interiors.add_weighted_centroid(ring);
+                }
+            }
+        }
+    }
+
+    /// Add a whole ring. This is the same as calling [`Self::add_coord`] with each coordinate, and
+    /// then [`Self::end_ring`].
+    pub fn add_ring(&mut self, ring: impl IntoIterator<Item = Coord<T>>) {
+        for coord in ring {
+            self.add_coord(coord);
+        }
+        self.end_ring();
+    }
+
+    /// End the current polygon, so that the next ring is the exterior of another polygon.
+    pub fn end_polygon(&mut self) {
+        let Some(polygon) = self.polygon.take() else {
+            return;
+        };
+        let Some(mut centroid) = polygon.exterior.0 else {
+            return;
+        };
+        if let Some(interiors) = polygon.interiors.0 {
+            centroid.sub_assign(interiors);
+            if centroid.weight.is_zero() {
+                // the holes cover the exterior, so the polygon degenerates to a line string
+                if let Some(line) = polygon.exterior_line.0 {
+                    self.operation.add_weighted_centroid(line);
+                }
+                return;
+            }
+        }
+        self.operation.add_weighted_centroid(centroid);
+    }
+
+    /// The centroid of everything added, or `None` if nothing non-empty was. The current polygon
+    /// is ended if it hasn't been already, but coordinates which haven't been ended as a line
+    /// string or ring are ignored.
+    pub fn finish(mut self) -> Option<crate::Point<T>> {
+        self.end_polygon();
+        self.operation.centroid()
+    }
+}
+
+/// The signed area of a ring, accumulated one coordinate at a time.
+#[derive(Debug, Clone)]
+struct RingArea<T: CoordFloat> {
+    first: Option<Coord<T>>,
+    last: Option<Coord<T>>,
+    count: usize,
+    /// Twice the signed area, relative to the first coordinate to limit rounding errors
+    twice_area: T,
+}
+
+impl<T: CoordFloat> RingArea<T> {
+    fn new() -> Self {
+        Self {
+            first: None,
+            last: None,
+            count: 0,
+            twice_area: T::zero(),
+        }
+    }
+
+    /// Add `coord`, returning the determinant of the segment from the previous coordinate,
+    /// relative to the first.
+    fn add_coord(&mut self, coord: Coord<T>) -> T {
+        let first = *self.first.get_or_insert(coord);
+        let determinant = match self.last {
+            Some(last) => Line::new(last - first, coord - first).determinant(),
+            None => T::zero(),
+        };
+        self.twice_area = self.twice_area + determinant;
+        self.last = Some(coord);
+        self.count += 1;
+        determinant
+    }
+
+    /// The signed area, which is zero for rings which aren't closed or have fewer than three
+    /// coordinates.
+    fn area(&self) -> T {
+        if self.count < 3 || self.first != self.last {
+            return T::zero();
+        }
+        self.twice_area / (T::one() + T::one())
+    }
+}
+
+/// A line string or ring, accumulated one coordinate at a time.
+#[derive(Debug, Clone)]
+struct Path<T: GeoFloat> {
+    area: RingArea<T>,
+    /// The sum of each segment's midpoint, relative to the first coordinate, times twice its
+    /// determinant
+    area_accumulated: Coord<T>,
+    /// The centroid of the segments
+    lines: CentroidOperation<T>,
+    is_point: bool,
+}
+
+impl<T: GeoFloat> Path<T> {
+    fn new() -> Self {
+        Self {
+            area: RingArea::new(),
+            area_accumulated: Coord::zero(),
+            lines: CentroidOperation::new(),
+            is_point: true,
+        }
+    }
+
+    fn add_coord(&mut self, coord: Coord<T>) {
+        let last = self.area.last;
+        let determinant = self.area.add_coord(coord);
+        if let (Some(first), Some(last)) = (self.area.first, last) {
+            self.area_accumulated =
+                self.area_accumulated + ((last - first) + (coord - first)) * determinant;
+            self.lines.add_line(&Line::new(last, coord));
+            self.is_point &= coord == first;
+        }
+    }
+
+    /// The centroid of the path as a line string.
+    fn line_centroid(&self) -> CentroidOperation<T> {
+        let mut operation = CentroidOperation::new();
+        match self.area.first {
+            None => {}
+            Some(first) if self.area.count == 1 => operation.add_coord(first),
+            Some(_) => operation.0 = self.lines.0,
+        }
+        operation
+    }
+
+    /// The centroid of the path as a ring.
+    fn ring_centroid(&self) -> CentroidOperation<T> {
+        let area = self.area.area();
+        let Some(first) = self.area.first else {
+            return CentroidOperation::new();
+        };
+        if area.is_zero() {
+            // the ring degenerates to a point or line string
+            let mut operation = CentroidOperation::new();
+            if self.is_point {
+                operation.add_coord(first);
+            } else {
+                operation.0 = self.lines.0;
+            }
+            return operation;
+        }
+        let six = T::from(6).unwrap();
+        let centroid = self.area_accumulated / (six * area) + first;
+        let mut operation = CentroidOperation::new();
+        operation.add_centroid(TwoDimensional, centroid, area.abs());
+        operation
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        wkt, Area, Centroid, CoordsIter, Euclidean, Geometry, Length, LineString, Polygon,
+    };
+
+    /// Feed `geometry` to the accumulators, as a decoder would.
+    fn accumulate(
+        geometry: &Geometry,
+        area: &mut AreaAccumulator,
+        length: &mut LengthAccumulator,
+        centroid: &mut CentroidAccumulator,
+    ) {
+        let polygons: Vec<&Polygon> = match geometry {
+            Geometry::Polygon(g) => vec![g],
+            Geometry::MultiPolygon(g) => g.iter().collect(),
+            _ => vec![],
+        };
+        for polygon in polygons {
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                for coord in ring.coords_iter() {
+                    area.add_coord(coord);
+                    centroid.add_coord(coord);
+                }
+                area.end_ring();
+                centroid.end_ring();
+            }
+            area.end_polygon();
+            centroid.end_polygon();
+        }
+        let line_strings: Vec<&LineString> = match geometry {
+            Geometry::LineString(g) => vec![g],
+            Geometry::MultiLineString(g) => g.iter().collect(),
+            _ => vec![],
+        };
+        for line_string in line_strings {
+            length.add_line_string(line_string.coords_iter());
+            centroid.add_line_string(line_string.coords_iter());
+        }
+        match geometry {
+            Geometry::Point(point) => centroid.add_point(point.0),
+            Geometry::MultiPoint(points) => points.iter().for_each(|p| centroid.add_point(p.0)),
+            Geometry::GeometryCollection(g) => {
+                for geometry in g {
+                    accumulate(geometry, area, length, centroid);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn line_length(geometry: &Geometry) -> f64 {
+        match geometry {
+            Geometry::LineString(g) => g.length::<Euclidean>(),
+            Geometry::MultiLineString(g) => g.length::<Euclidean>(),
+            Geometry::GeometryCollection(g) => g.iter().map(line_length).sum(),
+            _ => 0.,
+        }
+    }
+
+    fn check(geometry: Geometry) {
+        let mut area = AreaAccumulator::new();
+        let mut length = LengthAccumulator::new();
+        let mut centroid = CentroidAccumulator::new();
+        accumulate(&geometry, &mut area, &mut length, &mut centroid);
+        assert_relative_eq!(area.finish(), geometry.unsigned_area());
+        assert_relative_eq!(length.finish(), line_length(&geometry));
+        match (centroid.finish(), geometry.centroid()) {
+            (Some(actual), Some(expected)) => {
+                assert_relative_eq!(actual, expected, epsilon = 1e-12)
+            }
+            (actual, expected) => assert_eq!(actual, expected, "{geometry:?}"),
+        }
+    }
+
+    #[test]
+    fn matches_geometry_measures() {
+        check(wkt!(POINT(1. 2.)).into());
+        check(wkt!(MULTIPOINT(1. 2.,3. 5.,-1. 0.)).into());
+        check(wkt!(LINESTRING(0. 0.,3. 4.,3. 10.)).into());
+        check(wkt!(LINESTRING(1. 1.)).into());
+        check(wkt!(MULTILINESTRING((0. 0.,3. 4.),(3. 3.,3. 3.),(10. 10.,11. 10.))).into());
+        check(wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(1. 1.,1. 4.,4. 4.,1. 1.))).into());
+        // clockwise exterior
+        check(wkt!(POLYGON((100. 100.,100. 110.,107. 110.,100. 100.))).into());
+        check(
+            wkt!(MULTIPOLYGON(
+                ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)),
+                ((20. 0.,21. 0.,21. 1.,20. 0.)),
+                ((30. 0.,30. 0.,30. 0.,30. 0.))
+            ))
+            .into(),
+        );
+        // a polygon whose hole covers it degenerates to its exterior line string
+        check(
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.),(0. 0.,1. 0.,1. 1.,0. 1.,0. 0.))).into(),
+        );
+        // flat polygons degenerate to line strings
+        check(wkt!(POLYGON((0. 0.,2. 0.,1. 0.,0. 0.))).into());
+        check(Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(
+            POINT(50. 50.),
+            LINESTRING(0. 0.,3. 4.),
+            POLYGON((5. 5.,6. 5.,6. 6.,5. 5.))
+        ))));
+        check(Geometry::GeometryCollection(
+            wkt!(GEOMETRYCOLLECTION(POINT(50. 50.),LINESTRING(0. 0.,3. 4.))),
+        ));
+        check(Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION EMPTY)));
+    }
+
+    #[test]
+    fn finish_ends_open_polygon() {
+        let mut area = AreaAccumulator::new();
+        let mut centroid = CentroidAccumulator::new();
+        area.add_ring([(0., 0.), (2., 0.), (2., 2.), (0., 0.)].map(Coord::from));
+        centroid.add_ring([(0., 0.), (2., 0.), (2., 2.), (0., 0.)].map(Coord::from));
+        // an unfinished ring
+        for coord in [(0., 0.), (2., 0.), (2., 2.)].map(Coord::from) {
+            area.add_coord(coord);
+            centroid.add_coord(coord);
+        }
+        assert_eq!(area.finish(), 2.);
+        assert_relative_eq!(
+            centroid.finish().unwrap(),
+            crate::point!(x: 4. / 3., y: 2. / 3.)
+        );
+    }
+}
@@ -483,6 +483,243 @@ impl<U: CoordFloat> AffineTransform<U> {
     }
 }
 
+/// A 3D affine transformation, for coordinates with a `z` value such as a height.
+///
+/// `geo` geometries are 2D, so this applies to `[x, y, z]` arrays, e.g. the vertices of a mesh
+/// extruded from a footprint, or a coordinate paired with its height. Like [`AffineTransform`],
+/// transforms are composed by applying the transform on the left first.
+///
+/// The matrix is:
+/// ```ignore
+/// [[a, b, c, xoff],
+///  [d, e, f, yoff],
+///  [g, h, i, zoff],
+///  [0, 0, 0, 1]]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo::AffineTransform3D;
+/// use approx::assert_relative_eq;
+///
+/// // tilt a point cloud about the x axis, then georegister it
+/// let transform = AffineTransform3D::rotate_x(90., [0., 0., 0.])
+///     .translated(400_000., 5_000_000., 35.);
+///
+/// let point = transform.apply([1., 2., 3.]);
+/// assert_relative_eq!(point.as_slice(), [400_001., 4_999_997., 37.].as_slice(), epsilon = 1e-9);
+///
+/// let inverse = transform.inverse().unwrap();
+/// assert_relative_eq!(inverse.apply(point).as_slice(), [1., 2., 3.].as_slice(), epsilon = 1e-9);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AffineTransform3D<T: CoordNum = f64>([[T; 4]; 3]);
+
+impl<T: CoordNum> Default for AffineTransform3D<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: CoordNum> AffineTransform3D<T> {
+    /// Create a new custom transform matrix from its first three rows.
+    pub fn new(rows: [[T; 4]; 3]) -> Self {
+        Self(rows)
+    }
+
+    /// The first three rows of the transform matrix.
+    pub fn rows(&self) -> [[T; 4]; 3] {
+        self.0
+    }
+
+    /// Create the identity matrix
+    pub fn identity() -> Self {
+        Self::scale(T::one(), T::one(), T::one(), [T::zero(); 3])
+    }
+
+    /// Whether the transformation is equivalent to the [identity matrix](Self::identity).
+    pub fn is_identity(&self) -> bool {
+        self == &Self::identity()
+    }
+
+    /// Create a new affine transformation by composing two `AffineTransform3D`s.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn compose(&self, other: &Self) -> Self {
+        let (a, b) = (&other.0, &self.0);
+        let mut rows = [[T::zero(); 4]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+            }
+            row[3] = row[3] + a[i][3];
+        }
+        Self(rows)
+    }
+
+    /// **Create** an affine transform for translation, shifted by offsets along the `x`, `y` and
+    /// `z` dimensions.
+    pub fn translate(xoff: T, yoff: T, zoff: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self([
+            [one, zero, zero, xoff],
+            [zero, one, zero, yoff],
+            [zero, zero, one, zoff],
+        ])
+    }
+
+    /// **Add** an affine transform for translation.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn translated(self, xoff: T, yoff: T, zoff: T) -> Self {
+        self.compose(&Self::translate(xoff, yoff, zoff))
+    }
+
+    /// **Create** an affine transform for scaling, scaled by factors along the `x`, `y` and `z`
+    /// dimensions about `origin`.
+    pub fn scale(xfact: T, yfact: T, zfact: T, origin: [T; 3]) -> Self {
+        let zero = T::zero();
+        let [x0, y0, z0] = origin;
+        Self([
+            [xfact, zero, zero, x0 - x0 * xfact],
+            [zero, yfact, zero, y0 - y0 * yfact],
+            [zero, zero, zfact, z0 - z0 * zfact],
+        ])
+    }
+
+    /// **Add** an affine transform for scaling.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn scaled(self, xfact: T, yfact: T, zfact: T, origin: [T; 3]) -> Self {
+        self.compose(&Self::scale(xfact, yfact, zfact, origin))
+    }
+
+    /// Apply the current transform to a point.
+    pub fn apply(&self, [x, y, z]: [T; 3]) -> [T; 3] {
+        self.0
+            .map(|row| row[0] * x + row[1] * y + row[2] * z + row[3])
+    }
+
+    /// The transformation of `x` and `y`, if it doesn't depend on `z`.
+    pub fn to_2d(&self) -> Option<AffineTransform<T>> {
+        let [[a, b, c, xoff], [d, e, f, yoff], _] = self.0;
+        (c == T::zero() && f == T::zero()).then(|| AffineTransform::new(a, b, xoff, d, e, yoff))
+    }
+}
+
+impl<T: CoordNum> From<AffineTransform<T>> for AffineTransform3D<T> {
+    /// Extend a 2D transform, leaving `z` unchanged.
+    fn from(transform: AffineTransform<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let [[a, b, xoff], [d, e, yoff], _] = transform.0;
+        Self([
+            [a, b, zero, xoff],
+            [d, e, zero, yoff],
+            [zero, zero, one, zero],
+        ])
+    }
+}
+
+impl<U: CoordFloat> AffineTransform3D<U> {
+    /// **Create** an affine transform for rotation about an axis parallel to the `x` axis
+    /// through `origin`, by an angle in **degrees**, counter-clockwise when looking from positive
+    /// `x` towards the origin.
+    pub fn rotate_x(degrees: U, origin: [U; 3]) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let (zero, one) = (U::zero(), U::one());
+        Self::about(
+            [[one, zero, zero], [zero, cos, -sin], [zero, sin, cos]],
+            origin,
+        )
+    }
+
+    /// **Create** an affine transform for rotation about an axis parallel to the `y` axis
+    /// through `origin`, by an angle in **degrees**, counter-clockwise when looking from positive
+    /// `y` towards the origin.
+    pub fn rotate_y(degrees: U, origin: [U; 3]) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let (zero, one) = (U::zero(), U::one());
+        Self::about(
+            [[cos, zero, sin], [zero, one, zero], [-sin, zero, cos]],
+            origin,
+        )
+    }
+
+    /// **Create** an affine transform for rotation about an axis parallel to the `z` axis
+    /// through `origin`, by an angle in **degrees**, counter-clockwise when looking from positive
+    /// `z` towards the origin, like [`AffineTransform::rotate`].
+    pub fn rotate_z(degrees: U, origin: [U; 3]) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let (zero, one) = (U::zero(), U::one());
+        Self::about(
+            [[cos, -sin, zero], [sin, cos, zero], [zero, zero, one]],
+            origin,
+        )
+    }
+
+    /// **Add** an affine transform for rotation about an axis parallel to the `x` axis.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn rotated_x(self, degrees: U, origin: [U; 3]) -> Self {
+        self.compose(&Self::rotate_x(degrees, origin))
+    }
+
+    /// **Add** an affine transform for rotation about an axis parallel to the `y` axis.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn rotated_y(self, degrees: U, origin: [U; 3]) -> Self {
+        self.compose(&Self::rotate_y(degrees, origin))
+    }
+
+    /// **Add** an affine transform for rotation about an axis parallel to the `z` axis.
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn rotated_z(self, degrees: U, origin: [U; 3]) -> Self {
+        self.compose(&Self::rotate_z(degrees, origin))
+    }
+
+    /// The linear transformation `matrix`, applied about `origin` rather than `[0, 0, 0]`.
+    fn about(matrix: [[U; 3]; 3], origin: [U; 3]) -> Self {
+        Self(std::array::from_fn(|i| {
+            let row = matrix[i];
+            let offset = origin[i] - row[0] * origin[0] - row[1] * origin[1] - row[2] * origin[2];
+            [row[0], row[1], row[2], offset]
+        }))
+    }
+
+    /// Return the inverse of a given transform, or `None` if it isn't invertible. Composing a
+    /// transform with its inverse yields the [identity matrix](Self::identity).
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.0;
+        let cofactor = |i: usize, j: usize| {
+            let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+            let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let determinant =
+            m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+        if determinant == U::zero() {
+            return None;
+        }
+        // the inverse of the linear part is the transposed cofactor matrix over the determinant
+        let linear: [[U; 3]; 3] =
+            std::array::from_fn(|i| std::array::from_fn(|j| cofactor(j, i) / determinant));
+        Some(Self(std::array::from_fn(|i| {
+            let row = linear[i];
+            let offset = -(row[0] * m[0][3] + row[1] * m[1][3] + row[2] * m[2][3]);
+            [row[0], row[1], row[2], offset]
+        })))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::{AbsDiffEq, RelativeEq};
@@ -642,4 +879,64 @@ mod tests {
 
         assert_eq!(point.affine_transform(&composed), Point::new(8., 0.));
     }
+
+    #[test]
+    fn transform_3d() {
+        let rotate = AffineTransform3D::rotate_x(90., [0., 0., 0.]);
+        assert_relative_eq!(
+            rotate.apply([0., 1., 0.]).as_slice(),
+            [0., 0., 1.].as_slice(),
+            epsilon = 1e-12
+        );
+        let rotate = AffineTransform3D::rotate_y(90., [0., 0., 0.]);
+        assert_relative_eq!(
+            rotate.apply([0., 0., 1.]).as_slice(),
+            [1., 0., 0.].as_slice(),
+            epsilon = 1e-12
+        );
+
+        // rotating about z matches the 2D rotation, and leaves z unchanged
+        let rotate = AffineTransform3D::rotate_z(30., [1., 2., 3.]);
+        assert_relative_eq!(
+            rotate.to_2d().unwrap(),
+            AffineTransform::rotate(30., (1., 2.)),
+            epsilon = 1e-12
+        );
+        assert_eq!(rotate.apply([5., 6., 7.])[2], 7.);
+        assert_eq!(AffineTransform3D::rotate_x(1., [0.; 3]).to_2d(), None);
+
+        let scale = AffineTransform3D::scale(2., 3., 4., [1., 1., 1.]);
+        assert_eq!(scale.apply([2., 2., 2.]), [3., 4., 5.]);
+        let composed = scale.translated(1., 0., -1.);
+        assert_eq!(composed.apply([2., 2., 2.]), [4., 4., 4.]);
+        assert_eq!(
+            AffineTransform3D::from(AffineTransform::translate(1, 2)).apply([0, 0, 3]),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn transform_3d_inverse() {
+        let transform = AffineTransform3D::rotate_x(20., [1., 0., 0.])
+            .rotated_y(-50., [0., 1., 0.])
+            .rotated_z(70., [0., 0., 1.])
+            .scaled(2., 3., 0.5, [1., 2., 3.])
+            .translated(4., 5., 6.);
+        let identity = transform.compose(&transform.inverse().unwrap());
+        for (row, expected) in identity
+            .rows()
+            .iter()
+            .zip(AffineTransform3D::identity().rows())
+        {
+            assert_relative_eq!(row.as_slice(), expected.as_slice(), epsilon = 1e-12);
+        }
+        let point = [1., -2., 3.];
+        let round_trip = transform.inverse().unwrap().apply(transform.apply(point));
+        assert_relative_eq!(round_trip.as_slice(), point.as_slice(), epsilon = 1e-12);
+        assert_eq!(
+            AffineTransform3D::scale(1., 0., 1., [0.; 3]).inverse(),
+            None
+        );
+        assert!(AffineTransform3D::<f64>::default().is_identity());
+    }
 }
@@ -250,31 +250,67 @@ mod f32 {
 pub(super) mod convert {
     use super::super::OpType;
     use super::{BoolOpsNum, OverlayRule};
-    use crate::geometry::{LineString, MultiLineString, MultiPolygon, Polygon};
+    use crate::geometry::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+    use crate::GeoNum;
+    use std::cmp::Ordering;
 
     pub fn line_string_from_path<T: BoolOpsNum>(path: Vec<T::CoordType>) -> LineString<T> {
         let coords = path.into_iter().map(T::to_geo_coord);
         LineString(coords.collect())
     }
 
+    fn cmp_coords<T: GeoNum>(a: &Coord<T>, b: &Coord<T>) -> Ordering {
+        a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+    }
+
+    /// Orders line strings by their coordinates, each ordered by x, then y.
+    fn cmp_line_strings<T: GeoNum>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+        a.0.iter()
+            .zip(&b.0)
+            .map(|(a, b)| cmp_coords(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(a.0.len().cmp(&b.0.len()))
+    }
+
+    /// Converts an implicitly closed path to a ring which starts at its smallest coordinate, so
+    /// that the same ring always starts at the same coordinate.
+    fn ring_from_path<T: BoolOpsNum>(path: Vec<T::CoordType>) -> LineString<T> {
+        let mut ring: LineString<T> = line_string_from_path(path);
+        let start = (0..ring.0.len())
+            .min_by(|&a, &b| cmp_coords(&ring.0[a], &ring.0[b]))
+            .unwrap_or(0);
+        ring.0.rotate_left(start);
+        ring
+    }
+
     pub fn multi_line_string_from_paths<T: BoolOpsNum>(
         paths: Vec<Vec<T::CoordType>>,
     ) -> MultiLineString<T> {
-        let line_strings = paths.into_iter().map(|p| line_string_from_path(p));
-        MultiLineString(line_strings.collect())
+        let mut line_strings: Vec<_> = paths
+            .into_iter()
+            .map(|p| line_string_from_path(p))
+            .collect();
+        line_strings.sort_by(cmp_line_strings);
+        MultiLineString(line_strings)
     }
 
     pub fn polygon_from_shape<T: BoolOpsNum>(shape: Vec<Vec<T::CoordType>>) -> Polygon<T> {
-        let mut rings = shape.into_iter().map(|p| line_string_from_path(p));
+        let mut rings = shape.into_iter().map(|p| ring_from_path(p));
         let exterior = rings.next().unwrap_or(LineString::new(vec![]));
-        Polygon::new(exterior, rings.collect())
+        let mut interiors: Vec<_> = rings.collect();
+        interiors.sort_by(cmp_line_strings);
+        Polygon::new(exterior, interiors)
     }
 
+    /// The polygons, and the interiors of each polygon, are sorted by their coordinates, and each
+    /// ring starts at its smallest coordinate, so that the output doesn't depend on the order in
+    /// which the overlay found them.
     pub fn multi_polygon_from_shapes<T: BoolOpsNum>(
         shapes: Vec<Vec<Vec<T::CoordType>>>,
     ) -> MultiPolygon<T> {
-        let polygons = shapes.into_iter().map(|s| polygon_from_shape(s));
-        MultiPolygon(polygons.collect())
+        let mut polygons: Vec<_> = shapes.into_iter().map(|s| polygon_from_shape(s)).collect();
+        polygons.sort_by(|a, b| cmp_line_strings(a.exterior(), b.exterior()));
+        MultiPolygon(polygons)
     }
 
     pub fn ring_to_shape_path<T: BoolOpsNum>(line_string: &LineString<T>) -> Vec<T::CoordType> {
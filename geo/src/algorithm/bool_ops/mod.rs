@@ -1,10 +1,13 @@
 mod i_overlay_integration;
+mod robust;
 #[cfg(test)]
 mod tests;
 
 pub use i_overlay_integration::BoolOpsNum;
+pub use robust::{OverlayMode, RobustOverlay};
 
 use crate::geometry::{LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::{GeoError, GeoFloat};
 
 /// Boolean Operations on geometry.
 ///
@@ -26,6 +29,14 @@ use crate::geometry::{LineString, MultiLineString, MultiPolygon, Polygon};
 /// In particular, taking `union` with an empty geom should remove degeneracies
 /// and fix invalid polygons as long the interior-exterior requirement above is
 /// satisfied.
+///
+/// # Output order
+///
+/// The output of the operations doesn't depend on the order of the input polygons and rings, or
+/// on the run or platform, so it can be used for test snapshots or cached by content: the
+/// polygons, and the interiors of each polygon, are sorted by their
+/// coordinates, and each ring starts at its smallest coordinate, ordered by x, then y. Clipped
+/// line strings are sorted likewise.
 pub trait BooleanOps {
     type Scalar: BoolOpsNum;
 
@@ -61,6 +72,44 @@ pub trait BooleanOps {
         convert::multi_polygon_from_shapes(shapes)
     }
 
+    /// Like [`boolean_op`](Self::boolean_op), but falls back to snap-rounding when the exact
+    /// overlay fails on pathological input, as GEOS does.
+    ///
+    /// The exact overlay fails if it panics, or if its output has non-finite coordinates or
+    /// edges which cross or overlap. It's then retried with the input coordinates snapped to a
+    /// grid, starting with cells a trillion times smaller than the largest coordinate, and
+    /// making them a hundred times larger on each retry, up to a millionth of the largest
+    /// coordinate. The [`OverlayMode`] of the result says which grid, if any, produced the result.
+    ///
+    /// Checking the output takes about as long as the overlay itself. Panics can only be caught
+    /// when panics unwind, and the panic hook still reports them.
+    ///
+    /// # Errors
+    ///
+    /// [`GeoError::OverlayFailed`] if the overlay fails on every grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{wkt, BooleanOps, OpType, OverlayMode};
+    ///
+    /// let a = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+    /// let b = wkt!(POLYGON((1. 1.,3. 1.,3. 3.,1. 3.,1. 1.)));
+    /// let union = a.boolean_op_robust(&b, OpType::Union).unwrap();
+    /// assert_eq!(union.mode, OverlayMode::Exact);
+    /// assert_eq!(union.result, a.union(&b));
+    /// ```
+    fn boolean_op_robust(
+        &self,
+        other: &impl BooleanOps<Scalar = Self::Scalar>,
+        op: OpType,
+    ) -> Result<RobustOverlay<Self::Scalar>, GeoError>
+    where
+        Self::Scalar: GeoFloat,
+    {
+        robust::boolean_op_robust(self, other, op)
+    }
+
     fn intersection(
         &self,
         other: &impl BooleanOps<Scalar = Self::Scalar>,
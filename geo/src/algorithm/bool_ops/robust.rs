@@ -0,0 +1,220 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use super::{BoolOpsNum, BooleanOps, OpType};
+use crate::geometry::{Coord, LineString, MultiPolygon};
+use crate::sweep::intersections;
+use crate::{CoordNum, CoordsIter, GeoError, GeoFloat, LineIntersection};
+
+/// How [`BooleanOps::boolean_op_robust`] computed its result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverlayMode<T> {
+    /// The overlay of the input coordinates, as by [`BooleanOps::boolean_op`].
+    Exact,
+    /// The overlay of the input coordinates snapped to a grid of cells `grid_size` wide, after
+    /// the exact overlay, and the overlays on any finer grids, failed.
+    SnapRounded { grid_size: T },
+}
+
+/// The output of [`BooleanOps::boolean_op_robust`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobustOverlay<T: CoordNum> {
+    /// The result of the operation.
+    pub result: MultiPolygon<T>,
+    /// Whether the result is exact, or which grid the input was snapped to.
+    pub mode: OverlayMode<T>,
+}
+
+/// The number of grid sizes tried after the exact overlay fails, each a hundred times coarser
+/// than the previous one.
+const SNAP_ATTEMPTS: i32 = 4;
+
+/// The rings of a geometry, snapped to a grid.
+struct SnappedRings<T: CoordNum>(Vec<LineString<T>>);
+
+impl<T: BoolOpsNum> BooleanOps for SnappedRings<T> {
+    type Scalar = T;
+
+    fn rings(&self) -> impl Iterator<Item = &LineString<Self::Scalar>> {
+        self.0.iter()
+    }
+}
+
+pub(super) fn boolean_op_robust<T, A, B>(
+    a: &A,
+    b: &B,
+    op: OpType,
+) -> Result<RobustOverlay<T>, GeoError>
+where
+    T: BoolOpsNum + GeoFloat,
+    A: BooleanOps<Scalar = T> + ?Sized,
+    B: BooleanOps<Scalar = T>,
+{
+    overlay_with_fallback(a, b, op, is_valid_overlay)
+}
+
+/// [`boolean_op_robust`], accepting the outputs for which `is_valid` holds.
+fn overlay_with_fallback<T, A, B>(
+    a: &A,
+    b: &B,
+    op: OpType,
+    is_valid: fn(&MultiPolygon<T>) -> bool,
+) -> Result<RobustOverlay<T>, GeoError>
+where
+    T: BoolOpsNum + GeoFloat,
+    A: BooleanOps<Scalar = T> + ?Sized,
+    B: BooleanOps<Scalar = T>,
+{
+    if let Some(result) = checked_overlay(a, b, op, is_valid) {
+        return Ok(RobustOverlay {
+            result,
+            mode: OverlayMode::Exact,
+        });
+    }
+
+    // start a dozen orders of magnitude below the largest coordinate, where snapping only
+    // removes the noise of floating point arithmetic
+    let magnitude = a
+        .rings()
+        .chain(b.rings())
+        .flat_map(|ring| ring.coords())
+        .flat_map(|coord| [coord.x.abs(), coord.y.abs()])
+        .filter(|value| value.is_finite())
+        .fold(T::one(), T::max);
+    let ten = T::from(10).unwrap();
+    let exponent = magnitude.log10().floor().to_i32().unwrap_or(0);
+    for attempt in 0..SNAP_ATTEMPTS {
+        let grid_size = ten.powi(exponent - 12 + 2 * attempt);
+        let snapped_a = SnappedRings(a.rings().map(|r| snap_ring(r, grid_size)).collect());
+        let snapped_b = SnappedRings(b.rings().map(|r| snap_ring(r, grid_size)).collect());
+        if let Some(result) = checked_overlay(&snapped_a, &snapped_b, op, is_valid) {
+            return Ok(RobustOverlay {
+                result,
+                mode: OverlayMode::SnapRounded { grid_size },
+            });
+        }
+    }
+    Err(GeoError::OverlayFailed)
+}
+
+/// The overlay of `a` and `b`, unless it panicked or its output is invalid.
+fn checked_overlay<T, A, B>(
+    a: &A,
+    b: &B,
+    op: OpType,
+    is_valid: fn(&MultiPolygon<T>) -> bool,
+) -> Option<MultiPolygon<T>>
+where
+    T: BoolOpsNum + GeoFloat,
+    A: BooleanOps<Scalar = T> + ?Sized,
+    B: BooleanOps<Scalar = T>,
+{
+    let result = catch_unwind(AssertUnwindSafe(|| a.boolean_op(b, op))).ok()?;
+    is_valid(&result).then_some(result)
+}
+
+fn snap_ring<T: GeoFloat>(ring: &LineString<T>, grid_size: T) -> LineString<T> {
+    let snap = |value: T| (value / grid_size).round() * grid_size;
+    let mut snapped: LineString<T> = ring
+        .coords()
+        .map(|coord| Coord {
+            x: snap(coord.x),
+            y: snap(coord.y),
+        })
+        .collect();
+    snapped.0.dedup();
+    snapped
+}
+
+/// Whether the output of an overlay has finite coordinates and closed rings whose edges only
+/// meet at their endpoints.
+fn is_valid_overlay<T: GeoFloat>(multi_polygon: &MultiPolygon<T>) -> bool {
+    let finite = multi_polygon
+        .coords_iter()
+        .all(|coord| coord.x.is_finite() && coord.y.is_finite());
+    let rings = || {
+        multi_polygon
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+    };
+    if !finite || rings().any(|ring| ring.0.len() < 4) {
+        return false;
+    }
+    let lines = rings().flat_map(|ring| ring.lines());
+    !intersections(lines).any(|(_, _, intersection)| match intersection {
+        LineIntersection::SinglePoint { is_proper, .. } => is_proper,
+        LineIntersection::Collinear { intersection } => intersection.start != intersection.end,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area};
+
+    #[test]
+    fn exact() {
+        let a = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+        let b = wkt!(POLYGON((1. 1.,3. 1.,3. 3.,1. 3.,1. 1.)));
+        let union = a.boolean_op_robust(&b, OpType::Union).unwrap();
+        assert_eq!(union.mode, OverlayMode::Exact);
+        assert_eq!(union.result, a.union(&b));
+    }
+
+    /// Whether all coordinates are on a grid of cells `1e-8` wide, up to the rounding of the
+    /// overlay, which only the output of the overlay of inputs snapped to that grid is.
+    fn on_coarse_grid(multi_polygon: &MultiPolygon) -> bool {
+        let on_grid = |value: f64| ((value / 1e-8).round() * 1e-8 - value).abs() < 1e-9;
+        multi_polygon
+            .coords_iter()
+            .all(|coord| on_grid(coord.x) && on_grid(coord.y))
+    }
+
+    #[test]
+    fn fallback() {
+        let a = wkt!(POLYGON((0. 0.,0.1234567549 0.,0.1234567549 0.1234567549,0. 0.)));
+        let b = wkt!(POLYGON((0. 0.,0.1 0.,0.1 0.1,0. 0.1,0. 0.)));
+
+        // the exact overlay, and those snapped to the grids of 1e-12 and 1e-10, are rejected
+        let union = overlay_with_fallback(&a, &b, OpType::Union, on_coarse_grid).unwrap();
+        let OverlayMode::SnapRounded { grid_size } = union.mode else {
+            panic!("expected a snap-rounded overlay, got {:?}", union.mode);
+        };
+        assert_relative_eq!(grid_size, 1e-8, max_relative = 1e-12);
+        assert!(on_coarse_grid(&union.result));
+        assert_relative_eq!(
+            union.result.unsigned_area(),
+            a.union(&b).unsigned_area(),
+            epsilon = 1e-8
+        );
+
+        assert_eq!(
+            overlay_with_fallback(&a, &b, OpType::Union, |_| false),
+            Err(GeoError::OverlayFailed)
+        );
+    }
+
+    #[test]
+    fn validity() {
+        let square = wkt!(MULTIPOLYGON(((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))));
+        assert!(is_valid_overlay(&square));
+        let bowtie = wkt!(MULTIPOLYGON(((0. 0.,2. 2.,2. 0.,0. 2.,0. 0.))));
+        assert!(!is_valid_overlay(&bowtie));
+        let overlapping = wkt!(MULTIPOLYGON(
+            ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+            ((1. 0.,3. 0.,3. 2.,1. 2.,1. 0.))
+        ));
+        assert!(!is_valid_overlay(&overlapping));
+        let mut not_finite = square;
+        not_finite.0[0].exterior_mut(|exterior| exterior.0[1].x = f64::NAN);
+        assert!(!is_valid_overlay(&not_finite));
+    }
+
+    #[test]
+    fn snapping() {
+        let ring = wkt!(LINESTRING(0. 0.,1.04 0.,1. 0.01,1. 1.,0. 0.));
+        assert_eq!(
+            snap_ring(&ring, 0.5),
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.))
+        );
+    }
+}
@@ -53,6 +53,35 @@ fn jts_test_overlay_la_1() {
     assert!(im.is_equal_topo());
 }
 
+#[test]
+fn deterministic_output_order() {
+    let a: MultiPolygon = wkt!(MULTIPOLYGON(
+        ((10. 0.,14. 0.,14. 4.,10. 4.,10. 0.)),
+        ((0. 0.,6. 0.,6. 6.,0. 6.,0. 0.),(1. 1.,1. 2.,2. 2.,2. 1.,1. 1.),(3. 3.,3. 4.,4. 4.,4. 3.,3. 3.))
+    ));
+    let b: MultiPolygon = wkt!(MULTIPOLYGON(((5. 5.,12. 5.,12. 8.,5. 8.,5. 5.))));
+    let union = a.union(&b);
+
+    // the same polygons in another order, with rings starting elsewhere
+    let mut shuffled = a.clone();
+    shuffled.0.reverse();
+    shuffled.0[0].interiors_mut(|interiors| interiors.reverse());
+    shuffled.0[1].exterior_mut(|exterior| {
+        exterior.0.pop();
+        exterior.0.rotate_left(2);
+    });
+    assert_eq!(shuffled.union(&b), union);
+    assert_eq!(b.union(&a), union);
+
+    // polygons and interiors are sorted, and rings start at their smallest coordinate
+    assert_eq!(union.0[0].exterior().0[0], crate::coord! { x: 0., y: 0. });
+    assert_eq!(
+        union.0[0].interiors()[0].0[0],
+        crate::coord! { x: 1., y: 1. }
+    );
+    assert_eq!(union.0[1].exterior().0[0], crate::coord! { x: 10., y: 0. });
+}
+
 mod gh_issues {
     use super::super::{BooleanOps, OpType};
     use crate::{geometry::*, wkt};
@@ -259,11 +288,11 @@ mod gh_issues {
                 -22.058823 - 3.623188
             )),
             ((
+                -17.60358 - 8.013863,
                 -17.60358 - 8.013862,
                 -14.705883 - 7.649791,
                 -14.705883 - 7.6497912,
-                -17.60358 - 8.013863,
-                -17.60358 - 8.013862
+                -17.60358 - 8.013863
             ))
         ));
         assert_eq!(c, expected_c);
@@ -1,5 +1,5 @@
 use crate::utils::{partial_max, partial_min};
-use crate::{coord, geometry::*, CoordNum, GeometryCow};
+use crate::{coord, geometry::*, Bounds, CoordNum, GeometryCow};
 use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 
 /// Calculation of the bounding rectangle of a geometry.
@@ -187,20 +187,17 @@ where
     type Output = Option<Rect<T>>;
 
     fn bounding_rect(&self) -> Self::Output {
-        self.iter().fold(None, |acc, next| {
-            let next_bounding_rect = next.bounding_rect();
-
-            match (acc, next_bounding_rect) {
-                (None, None) => None,
-                (Some(r), None) | (None, Some(r)) => Some(r),
-                (Some(r1), Some(r2)) => Some(bounding_rect_merge(r1, r2)),
-            }
-        })
+        self.iter()
+            .fold(Bounds::new(), |mut bounds, next| {
+                bounds.merge(Bounds::of(next));
+                bounds
+            })
+            .rect()
     }
 }
 
 // Return a new rectangle that encompasses the provided rectangles
-fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
+pub(crate) fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
         coord! {
             x: partial_min(a.min().x, b.min().x),
@@ -0,0 +1,168 @@
+use crate::{BoundingRect, Contains, CoordNum, GeoFloat, GeoNum, Intersects, Rect};
+
+/// A geometry together with its precomputed bounding rectangle.
+///
+/// Predicates between two `CachedEnvelope`s first compare the envelopes, which takes constant
+/// time, and only run the full predicate on the geometries when the envelopes can't decide it.
+/// This pays off in tight loops where the same geometries are compared against many others, e.g.
+/// filtering a list of candidates, since [`BoundingRect::bounding_rect`] is linear in the number
+/// of coordinates and would otherwise be recomputed on every call.
+///
+/// The envelope is computed once, when the `CachedEnvelope` is created, so the geometry can't be
+/// mutated through it. Use [`CachedEnvelope::into_inner`] to get the geometry back.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, CachedEnvelope, Distance, Euclidean, Intersects, Rect};
+///
+/// let parcels: Vec<_> = (0..10)
+///     .map(|i| {
+///         let x = f64::from(i) * 10.;
+///         CachedEnvelope::new(Rect::new((x, 0.), (x + 5., 5.)).to_polygon())
+///     })
+///     .collect();
+/// let road = CachedEnvelope::new(wkt!(LINESTRING(12. -1.,12. 10.)));
+///
+/// let crossed = parcels.iter().filter(|parcel| parcel.intersects(&road)).count();
+/// assert_eq!(crossed, 1);
+///
+/// // envelopes give a lower bound on the distance, which is enough to skip far away parcels
+/// let near = parcels.iter().filter(|parcel| {
+///     parcel.envelope_distance(&road).is_some_and(|d| d <= 2.)
+///         && Euclidean::distance(parcel.geometry(), road.geometry()) <= 2.
+/// });
+/// assert_eq!(near.count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedEnvelope<G, T: CoordNum = f64> {
+    geometry: G,
+    envelope: Option<Rect<T>>,
+}
+
+impl<G, T: CoordNum> CachedEnvelope<G, T> {
+    /// Wrap `geometry`, computing its bounding rectangle.
+    pub fn new(geometry: G) -> Self
+    where
+        G: BoundingRect<T>,
+    {
+        let envelope = geometry.bounding_rect().into();
+        Self { geometry, envelope }
+    }
+
+    /// The wrapped geometry.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// The bounding rectangle of the geometry, or `None` if it's empty.
+    pub fn envelope(&self) -> Option<Rect<T>> {
+        self.envelope
+    }
+
+    /// Unwrap the geometry.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+}
+
+impl<G, T: GeoFloat> CachedEnvelope<G, T> {
+    /// The Euclidean distance between the envelopes of two geometries, which is a lower bound on
+    /// the distance between the geometries themselves. `None` if either geometry is empty.
+    pub fn envelope_distance<G2>(&self, other: &CachedEnvelope<G2, T>) -> Option<T> {
+        let (a, b) = (self.envelope?, other.envelope?);
+        let gap = |a_min: T, a_max: T, b_min: T, b_max: T| {
+            (b_min - a_max).max(a_min - b_max).max(T::zero())
+        };
+        let dx = gap(a.min().x, a.max().x, b.min().x, b.max().x);
+        let dy = gap(a.min().y, a.max().y, b.min().y, b.max().y);
+        Some(dx.hypot(dy))
+    }
+}
+
+impl<G, T: CoordNum> BoundingRect<T> for CachedEnvelope<G, T> {
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.envelope
+    }
+}
+
+impl<G, G2, T> Intersects<CachedEnvelope<G2, T>> for CachedEnvelope<G, T>
+where
+    G: Intersects<G2>,
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &CachedEnvelope<G2, T>) -> bool {
+        match (self.envelope, rhs.envelope) {
+            (Some(a), Some(b)) => a.intersects(&b) && self.geometry.intersects(&rhs.geometry),
+            _ => false,
+        }
+    }
+}
+
+impl<G, G2, T> Contains<CachedEnvelope<G2, T>> for CachedEnvelope<G, T>
+where
+    G: Contains<G2>,
+    T: GeoNum,
+{
+    fn contains(&self, rhs: &CachedEnvelope<G2, T>) -> bool {
+        match (self.envelope, rhs.envelope) {
+            (Some(a), Some(b)) => {
+                a.min().x <= b.min().x
+                    && a.min().y <= b.min().y
+                    && b.max().x <= a.max().x
+                    && b.max().y <= a.max().y
+                    && self.geometry.contains(&rhs.geometry)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, LineString, Polygon};
+
+    #[test]
+    fn predicates() {
+        let square = CachedEnvelope::new(wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))));
+        let inside = CachedEnvelope::new(wkt!(LINESTRING(1. 1.,3. 3.)));
+        let crossing = CachedEnvelope::new(wkt!(LINESTRING(1. 1.,5. 5.)));
+        let outside = CachedEnvelope::new(wkt!(LINESTRING(5. 0.,6. 1.)));
+        // envelopes overlap, but the geometries don't
+        let corner = CachedEnvelope::new(wkt!(POLYGON((3. 5.,5. 3.,5. 5.,3. 5.))));
+        let triangle = CachedEnvelope::new(wkt!(POLYGON((0. 0.,4. 0.,0. 4.,0. 0.))));
+
+        assert!(square.intersects(&inside));
+        assert!(square.intersects(&crossing));
+        assert!(!square.intersects(&outside));
+        assert!(!triangle.intersects(&corner));
+        assert!(square.contains(&inside));
+        assert!(!square.contains(&crossing));
+        assert!(!square.contains(&outside));
+        assert!(!triangle.contains(&CachedEnvelope::new(point!(x: 3., y: 3.))));
+    }
+
+    #[test]
+    fn envelope_distance() {
+        let a = CachedEnvelope::new(wkt!(LINESTRING(0. 0.,1. 1.)));
+        let b = CachedEnvelope::new(wkt!(LINESTRING(4. 5.,5. 6.)));
+        let c = CachedEnvelope::new(wkt!(LINESTRING(0.5 -3.,0.5 3.)));
+        assert_relative_eq!(a.envelope_distance(&b).unwrap(), 5.);
+        assert_relative_eq!(b.envelope_distance(&a).unwrap(), 5.);
+        assert_eq!(a.envelope_distance(&c), Some(0.));
+    }
+
+    #[test]
+    fn empty() {
+        let empty = CachedEnvelope::new(LineString::<f64>::new(vec![]));
+        let square = CachedEnvelope::new(Polygon::from(Rect::new((0., 0.), (1., 1.))));
+        assert_eq!(empty.bounding_rect(), None);
+        assert!(!empty.intersects(&square));
+        assert!(!square.contains(&empty));
+        assert_eq!(square.envelope_distance(&empty), None);
+        assert_eq!(square.into_inner().exterior().0.len(), 5);
+    }
+}
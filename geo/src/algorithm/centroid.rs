@@ -4,7 +4,7 @@ use crate::area::{get_linestring_area, Area};
 use crate::dimensions::{Dimensions, Dimensions::*, HasDimensions};
 use crate::geometry::*;
 use crate::line_measures::{Euclidean, Length};
-use crate::GeoFloat;
+use crate::{CoordNum, CoordsIter, GeoError, GeoFloat};
 
 /// Calculation of the centroid.
 /// The centroid is the arithmetic mean position of all points in the shape.
@@ -55,6 +55,17 @@ pub trait Centroid {
     /// );
     /// ```
     fn centroid(&self) -> Self::Output;
+
+    /// Like [`Self::centroid`], but returns an error instead of `None` for an empty geometry, or
+    /// a `NaN` point for one with a NaN or infinite coordinate.
+    fn try_centroid<T: CoordNum>(&self) -> Result<Point<T>, GeoError>
+    where
+        Self: CoordsIter<Scalar = T>,
+        Self::Output: Into<Option<Point<T>>>,
+    {
+        GeoError::check(self)?;
+        self.centroid().into().ok_or(GeoError::Empty)
+    }
 }
 
 impl<T> Centroid for Line<T>
@@ -439,13 +450,14 @@ where
     }
 }
 
-struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
+#[derive(Debug, Clone)]
+pub(crate) struct CentroidOperation<T: GeoFloat>(pub(crate) Option<WeightedCentroid<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         CentroidOperation(None)
     }
 
-    fn centroid(&self) -> Option<Point<T>> {
+    pub(crate) fn centroid(&self) -> Option<Point<T>> {
         self.0.as_ref().map(|weighted_centroid| {
             Point::from(weighted_centroid.accumulated / weighted_centroid.weight)
         })
@@ -458,11 +470,11 @@ impl<T: GeoFloat> CentroidOperation<T> {
             .unwrap_or(Empty)
     }
 
-    fn add_coord(&mut self, coord: Coord<T>) {
+    pub(crate) fn add_coord(&mut self, coord: Coord<T>) {
         self.add_centroid(ZeroDimensional, coord, T::one());
     }
 
-    fn add_line(&mut self, line: &Line<T>) {
+    pub(crate) fn add_line(&mut self, line: &Line<T>) {
         match line.dimensions() {
             ZeroDimensional => self.add_coord(line.start),
             OneDimensional => self.add_centroid(
@@ -633,7 +645,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         self.add_centroid(TwoDimensional, centroid, weight);
     }
 
-    fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coord<T>, weight: T) {
+    pub(crate) fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coord<T>, weight: T) {
         let weighted_centroid = WeightedCentroid {
             dimensions,
             weight,
@@ -642,7 +654,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         self.add_weighted_centroid(weighted_centroid);
     }
 
-    fn add_weighted_centroid(&mut self, other: WeightedCentroid<T>) {
+    pub(crate) fn add_weighted_centroid(&mut self, other: WeightedCentroid<T>) {
         match self.0.as_mut() {
             Some(centroid) => centroid.add_assign(other),
             None => self.0 = Some(other),
@@ -651,8 +663,9 @@ impl<T: GeoFloat> CentroidOperation<T> {
 }
 
 // Aggregated state for accumulating the centroid of a geometry or collection of geometries.
-struct WeightedCentroid<T: GeoFloat> {
-    weight: T,
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeightedCentroid<T: GeoFloat> {
+    pub(crate) weight: T,
     accumulated: Coord<T>,
     /// Collections of Geometries can have different dimensionality. Centroids must be considered
     /// separately by dimensionality.
@@ -679,7 +692,7 @@ impl<T: GeoFloat> WeightedCentroid<T> {
         }
     }
 
-    fn sub_assign(&mut self, b: WeightedCentroid<T>) {
+    pub(crate) fn sub_assign(&mut self, b: WeightedCentroid<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
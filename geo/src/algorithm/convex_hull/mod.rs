@@ -1,6 +1,6 @@
 use crate::geometry::{Coord, LineString, Polygon};
 use crate::kernels::*;
-use crate::GeoNum;
+use crate::{GeoError, GeoNum};
 
 /// Returns the convex hull of a geometry. The hull is always oriented counter-clockwise.
 ///
@@ -42,6 +42,18 @@ use crate::GeoNum;
 pub trait ConvexHull<'a, T> {
     type Scalar: GeoNum;
     fn convex_hull(&'a self) -> Polygon<Self::Scalar>;
+
+    /// Like [`Self::convex_hull`], but returns an error instead of an empty polygon for an empty
+    /// geometry, or of panicking for one with a NaN or infinite coordinate.
+    fn try_convex_hull(
+        &'a self,
+    ) -> Result<Polygon<<Self as ConvexHull<'a, T>>::Scalar>, GeoError>
+    where
+        Self: CoordsIter<Scalar = <Self as ConvexHull<'a, T>>::Scalar>,
+    {
+        GeoError::check(self)?;
+        Ok(self.convex_hull())
+    }
 }
 
 use crate::algorithm::CoordsIter;
@@ -0,0 +1,234 @@
+use crate::bool_ops::BoolOpsNum;
+use crate::{Area, BooleanOps, Coord, CoordFloat, CoordNum, LineString, MultiPolygon, Polygon};
+
+/// Compare two versions of a geometry, e.g. to detect what changed between releases of a
+/// dataset.
+///
+/// Polygons are compared by area, in a [`PolygonDiff`], and line strings vertex by vertex, in a
+/// [`LineStringDiff`]. Both report a similarity between zero, for unrelated geometries, and one,
+/// for identical ones.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, Diff, VertexEdit};
+///
+/// let old = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+/// let new = wkt!(POLYGON((2. 0.,6. 0.,6. 4.,2. 4.,2. 0.)));
+/// let diff = old.diff(&new);
+/// assert_eq!(diff.added.unsigned_area(), 8.);
+/// assert_eq!(diff.removed.unsigned_area(), 8.);
+/// assert_eq!(diff.similarity, 1. / 3.);
+///
+/// let old = wkt!(LINESTRING(0. 0.,1. 0.,2. 0.,3. 0.,4. 0.));
+/// let new = wkt!(LINESTRING(0. 0.,2. 0.,3. 1.,4. 0.,5. 0.));
+/// let diff = old.diff(&new);
+/// assert_eq!(
+///     diff.edits,
+///     vec![
+///         VertexEdit::Delete { index: 1 },
+///         VertexEdit::Replace { index: 3, coord: (3., 1.).into() },
+///         VertexEdit::Insert { index: 4, coord: (5., 0.).into() },
+///     ]
+/// );
+/// assert_eq!(diff.similarity, 0.4);
+/// ```
+pub trait Diff<Rhs = Self> {
+    type Output;
+
+    /// Compare `self`, the old version, to `new`.
+    fn diff(&self, new: &Rhs) -> Self::Output;
+}
+
+/// The difference between two versions of a polygonal geometry, from [`Diff::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonDiff<T: CoordNum> {
+    /// The area covered by the new version but not the old one.
+    pub added: MultiPolygon<T>,
+    /// The area covered by the old version but not the new one.
+    pub removed: MultiPolygon<T>,
+    /// The area covered by both versions.
+    pub unchanged: MultiPolygon<T>,
+    /// The area covered by both versions, divided by the area covered by either, i.e. their
+    /// Jaccard index. Two empty versions are identical.
+    pub similarity: T,
+}
+
+impl<T: BoolOpsNum + CoordFloat> PolygonDiff<T> {
+    /// The area covered by only one of the versions, i.e. their symmetric difference.
+    pub fn changed(&self) -> MultiPolygon<T> {
+        self.added.union(&self.removed)
+    }
+}
+
+fn diff_areas<T, A, B>(old: &A, new: &B) -> PolygonDiff<T>
+where
+    T: BoolOpsNum + CoordFloat,
+    A: BooleanOps<Scalar = T>,
+    B: BooleanOps<Scalar = T>,
+{
+    let added = new.difference(old);
+    let removed = old.difference(new);
+    let unchanged = old.intersection(new);
+    let union = unchanged.unsigned_area() + added.unsigned_area() + removed.unsigned_area();
+    let similarity = if union > T::zero() {
+        unchanged.unsigned_area() / union
+    } else {
+        T::one()
+    };
+    PolygonDiff {
+        added,
+        removed,
+        unchanged,
+        similarity,
+    }
+}
+
+impl<T: BoolOpsNum + CoordFloat> Diff for Polygon<T> {
+    type Output = PolygonDiff<T>;
+
+    fn diff(&self, new: &Self) -> Self::Output {
+        diff_areas(self, new)
+    }
+}
+
+impl<T: BoolOpsNum + CoordFloat> Diff for MultiPolygon<T> {
+    type Output = PolygonDiff<T>;
+
+    fn diff(&self, new: &Self) -> Self::Output {
+        diff_areas(self, new)
+    }
+}
+
+/// An edit of a line string's vertices, from [`Diff::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexEdit<T: CoordNum> {
+    /// The vertex at `index` of the new version was inserted.
+    Insert { index: usize, coord: Coord<T> },
+    /// The vertex at `index` of the old version was deleted.
+    Delete { index: usize },
+    /// The vertex at `index` of the old version was replaced by `coord`.
+    Replace { index: usize, coord: Coord<T> },
+}
+
+/// The difference between two versions of a line string, from [`Diff::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineStringDiff<T: CoordNum> {
+    /// The fewest edits which turn the old version into the new one, in order along both.
+    /// Vertices are only unchanged if their coordinates are exactly equal.
+    pub edits: Vec<VertexEdit<T>>,
+    /// One minus the number of edits divided by the number of vertices of the longer version.
+    /// Two empty versions are identical.
+    pub similarity: T,
+}
+
+impl<T: CoordFloat> Diff for LineString<T> {
+    type Output = LineStringDiff<T>;
+
+    /// Finds the edits with the Wagner–Fischer algorithm, in `O(old × new)` time and memory.
+    fn diff(&self, new: &Self) -> Self::Output {
+        let (old, new) = (&self.0, &new.0);
+        let width = new.len() + 1;
+        // `costs[i * width + j]` is the number of edits from `old[..i]` to `new[..j]`
+        let mut costs = vec![0; (old.len() + 1) * width];
+        for i in 0..=old.len() {
+            for j in 0..=new.len() {
+                costs[i * width + j] = match (i, j) {
+                    (0, _) => j,
+                    (_, 0) => i,
+                    _ => {
+                        let replace = usize::from(old[i - 1] != new[j - 1]);
+                        (costs[(i - 1) * width + j - 1] + replace)
+                            .min(costs[(i - 1) * width + j] + 1)
+                            .min(costs[i * width + j - 1] + 1)
+                    }
+                };
+            }
+        }
+
+        let mut edits = Vec::with_capacity(costs[costs.len() - 1]);
+        let (mut i, mut j) = (old.len(), new.len());
+        while i > 0 || j > 0 {
+            let cost = costs[i * width + j];
+            if i > 0 && j > 0 {
+                let replace = usize::from(old[i - 1] != new[j - 1]);
+                if cost == costs[(i - 1) * width + j - 1] + replace {
+                    if replace == 1 {
+                        edits.push(VertexEdit::Replace {
+                            index: i - 1,
+                            coord: new[j - 1],
+                        });
+                    }
+                    i -= 1;
+                    j -= 1;
+                    continue;
+                }
+            }
+            if i > 0 && cost == costs[(i - 1) * width + j] + 1 {
+                edits.push(VertexEdit::Delete { index: i - 1 });
+                i -= 1;
+            } else {
+                edits.push(VertexEdit::Insert {
+                    index: j - 1,
+                    coord: new[j - 1],
+                });
+                j -= 1;
+            }
+        }
+        edits.reverse();
+
+        let longest = old.len().max(new.len());
+        let similarity = if longest == 0 {
+            T::one()
+        } else {
+            T::one() - T::from(edits.len()).unwrap() / T::from(longest).unwrap()
+        };
+        LineStringDiff { edits, similarity }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn polygons() {
+        let old = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let diff = old.diff(&old);
+        assert!(diff.added.0.is_empty() && diff.removed.0.is_empty());
+        assert_eq!(diff.similarity, 1.);
+
+        let new = wkt!(POLYGON((10. 10.,11. 10.,11. 11.,10. 10.)));
+        let diff = old.diff(&new);
+        assert_eq!(diff.similarity, 0.);
+        assert_eq!(diff.changed().unsigned_area(), 16.5);
+
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert_eq!(empty.diff(&empty).similarity, 1.);
+    }
+
+    #[test]
+    fn line_strings() {
+        let line_string = wkt!(LINESTRING(0. 0.,1. 1.,2. 2.));
+        let diff = line_string.diff(&line_string);
+        assert!(diff.edits.is_empty());
+        assert_eq!(diff.similarity, 1.);
+
+        let diff = line_string.diff(&wkt!(LINESTRING(-1. -1.,0. 0.,1. 1.,2. 2.)));
+        assert_eq!(
+            diff.edits,
+            vec![VertexEdit::Insert {
+                index: 0,
+                coord: (-1., -1.).into()
+            }]
+        );
+        assert_eq!(diff.similarity, 0.75);
+
+        let empty = LineString::new(vec![]);
+        let diff = line_string.diff(&empty);
+        assert_eq!(diff.edits.len(), 3);
+        assert_eq!(diff.similarity, 0.);
+        assert_eq!(empty.diff(&empty).similarity, 1.);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::Point;
+
+/// An Earth-centered, Earth-fixed ([ECEF]) position, in meters: the Cartesian coordinates of a
+/// point relative to the center of the WGS 84 ellipsoid.
+///
+/// The x axis points to longitude 0° on the equator, the y axis to longitude 90° E on the
+/// equator, and the z axis to the north pole. Unlike longitude, latitude and height, ECEF
+/// coordinates can be used for vector arithmetic, e.g. for sensor fusion, or to check the line
+/// of sight between two points.
+///
+/// [ECEF]: https://en.wikipedia.org/wiki/Earth-centered,_Earth-fixed_coordinate_system
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Ecef};
+/// use approx::assert_relative_eq;
+///
+/// let null_island = Ecef::from_geodetic(point!(x: 0., y: 0.), 0.);
+/// assert_eq!(null_island, Ecef::new(6_378_137., 0., 0.));
+///
+/// let summit = Ecef::from_geodetic(point!(x: 86.925, y: 27.9881), 8_848.86);
+/// let (lon_lat, height) = summit.to_geodetic();
+/// assert_relative_eq!(lon_lat, point!(x: 86.925, y: 27.9881), epsilon = 1e-12);
+/// assert_relative_eq!(height, 8_848.86, epsilon = 1e-6);
+///
+/// // the straight-line distance through the Earth
+/// let antipode = Ecef::from_geodetic(point!(x: 180., y: 0.), 0.);
+/// assert_relative_eq!(null_island.distance(&antipode), 2. * 6_378_137., epsilon = 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+pub struct Ecef {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// The square of the WGS 84 ellipsoid's first eccentricity.
+const ECCENTRICITY_SQUARED: f64 = crate::EARTH_FLATTENING * (2. - crate::EARTH_FLATTENING);
+
+impl Ecef {
+    /// An ECEF position from its coordinates in meters.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The ECEF position of a longitude/latitude in degrees, at `height` meters above the
+    /// ellipsoid.
+    pub fn from_geodetic(lon_lat: Point, height: f64) -> Self {
+        let (sin_lon, cos_lon) = lon_lat.x().to_radians().sin_cos();
+        let (sin_lat, cos_lat) = lon_lat.y().to_radians().sin_cos();
+        let n = prime_vertical_radius(sin_lat);
+        Self {
+            x: (n + height) * cos_lat * cos_lon,
+            y: (n + height) * cos_lat * sin_lon,
+            z: (n * (1. - ECCENTRICITY_SQUARED) + height) * sin_lat,
+        }
+    }
+
+    /// The longitude/latitude in degrees of this position, and its height in meters above the
+    /// ellipsoid.
+    pub fn to_geodetic(&self) -> (Point, f64) {
+        let p = self.x.hypot(self.y);
+        let lon = self.y.atan2(self.x);
+        // iterate on the latitude, starting from the latitude for a height of 0, which converges
+        // to within rounding errors in a few iterations anywhere near the surface
+        let mut lat = self.z.atan2(p * (1. - ECCENTRICITY_SQUARED));
+        for _ in 0..10 {
+            let sin_lat = lat.sin();
+            let next =
+                (self.z + ECCENTRICITY_SQUARED * prime_vertical_radius(sin_lat) * sin_lat).atan2(p);
+            let converged = (next - lat).abs() < 1e-15;
+            lat = next;
+            if converged {
+                break;
+            }
+        }
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let height = p * cos_lat + self.z * sin_lat
+            - crate::EQUATORIAL_EARTH_RADIUS
+                * (1. - ECCENTRICITY_SQUARED * sin_lat * sin_lat).sqrt();
+        (Point::new(lon.to_degrees(), lat.to_degrees()), height)
+    }
+
+    /// The straight-line (chord) distance to `other`, in meters.
+    pub fn distance(&self, other: &Ecef) -> f64 {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+/// The ellipsoid's radius of curvature in the prime vertical at a latitude with sine `sin_lat`.
+fn prime_vertical_radius(sin_lat: f64) -> f64 {
+    crate::EQUATORIAL_EARTH_RADIUS / (1. - ECCENTRICITY_SQUARED * sin_lat * sin_lat).sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Distance, Geodesic};
+
+    #[test]
+    fn axes() {
+        let a = crate::EQUATORIAL_EARTH_RADIUS;
+        let east = Ecef::from_geodetic(point!(x: 90., y: 0.), 10.);
+        assert_relative_eq!(east.x, 0., epsilon = 1e-6);
+        assert_relative_eq!(east.y, a + 10.);
+        let pole = Ecef::from_geodetic(point!(x: 0., y: -90.), 0.);
+        assert_relative_eq!(pole.z, -crate::POLAR_EARTH_RADIUS, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn round_trip() {
+        for (lon, lat) in [
+            (0., 0.),
+            (-70., -33.),
+            (179.9, 60.),
+            (12., 89.9999),
+            (-5., -90.),
+        ] {
+            for height in [-400., 0., 1_000., 400_000.] {
+                let (lon_lat, round_trip_height) =
+                    Ecef::from_geodetic(point!(x: lon, y: lat), height).to_geodetic();
+                assert_relative_eq!(lon_lat.y(), lat, epsilon = 1e-12);
+                if lat.abs() < 90. {
+                    assert_relative_eq!(lon_lat.x(), lon, epsilon = 1e-9);
+                }
+                assert_relative_eq!(round_trip_height, height, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn chord_distance() {
+        // over a short distance, the chord is as long as the geodesic
+        let a = point!(x: 13.4, y: 52.5);
+        let b = point!(x: 13.41, y: 52.505);
+        let chord = Ecef::from_geodetic(a, 0.).distance(&Ecef::from_geodetic(b, 0.));
+        assert_relative_eq!(chord, Geodesic::distance(a, b), max_relative = 1e-6);
+
+        let low = Ecef::from_geodetic(a, 0.);
+        let high = Ecef::from_geodetic(a, 100.);
+        assert_relative_eq!(low.distance(&high), 100., epsilon = 1e-6);
+    }
+}
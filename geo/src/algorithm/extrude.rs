@@ -0,0 +1,233 @@
+use crate::orient::{Direction, Orient};
+use crate::{
+    CoordFloat, GeoFloat, LineString, MultiPolygon, Polygon, Rect, Triangle, TriangulateEarcut,
+};
+
+/// A triangle mesh in 3D, as vertex and index buffers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesh<T: CoordFloat = f64> {
+    /// The `[x, y, z]` coordinates of the vertices.
+    pub vertices: Vec<[T; 3]>,
+    /// The vertex indices of the triangles, three per triangle. Each triangle is
+    /// counter-clockwise when seen from outside the mesh.
+    pub triangle_indices: Vec<usize>,
+}
+
+impl<T: CoordFloat> Mesh<T> {
+    /// The triangles of the mesh, as the coordinates of their vertices.
+    pub fn triangles(&self) -> impl Iterator<Item = [[T; 3]; 3]> + '_ {
+        self.triangle_indices
+            .chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| self.vertices[triangle[i]]))
+    }
+
+    /// Append the vertices and triangles of `other` to `self`.
+    pub fn append(&mut self, other: Mesh<T>) {
+        let offset = self.vertices.len();
+        self.vertices.extend(other.vertices);
+        self.triangle_indices
+            .extend(other.triangle_indices.into_iter().map(|i| i + offset));
+    }
+}
+
+impl<T: CoordFloat> Default for Mesh<T> {
+    fn default() -> Self {
+        Self {
+            vertices: vec![],
+            triangle_indices: vec![],
+        }
+    }
+}
+
+/// Extrude a polygon into a closed 3D mesh between `z = 0` and `z = height`, e.g. to generate
+/// buildings from their footprints.
+///
+/// The floor and the roof are triangulated with [`TriangulateEarcut`], and each edge of the
+/// polygon becomes a rectangular wall of two triangles. Vertices are shared between the floor or
+/// roof and the walls, so the mesh is watertight, and every triangle faces outwards.
+///
+/// Requires the `"earcutr"` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Extrude};
+///
+/// let footprint = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)));
+/// let mesh = footprint.extrude(3.);
+///
+/// // 4 corners at the bottom and the top
+/// assert_eq!(mesh.vertices.len(), 8);
+/// assert!(mesh.vertices.contains(&[10., 10., 3.]));
+/// // 2 triangles for the floor, the roof and each of the 4 walls
+/// assert_eq!(mesh.triangles().count(), 12);
+/// ```
+pub trait Extrude<T: CoordFloat> {
+    /// Extrude the geometry to a mesh between `z = 0` and `z = height`, which may be negative.
+    fn extrude(&self, height: T) -> Mesh<T>;
+}
+
+impl<T: GeoFloat> Extrude<T> for Polygon<T> {
+    fn extrude(&self, height: T) -> Mesh<T> {
+        let (bottom, top) = if height < T::zero() {
+            (height, T::zero())
+        } else {
+            (T::zero(), height)
+        };
+        if self.exterior().0.len() < 4 {
+            return Mesh::default();
+        }
+        let polygon = self.orient(Direction::Default);
+        let rings: Vec<&LineString<T>> = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors().iter().filter(|ring| ring.0.len() >= 4))
+            .collect();
+
+        // every ring vertex once, without the closing coordinate, first at the bottom and then
+        // at the top; `ring_vertex` maps the index of a coordinate in the rings, including closing
+        // coordinates, to its bottom vertex
+        let mut vertices = vec![];
+        let mut ring_vertex = vec![];
+        let mut walls = vec![];
+        for ring in &rings {
+            let start = vertices.len();
+            let len = ring.0.len() - 1;
+            vertices.extend(ring.0[..len].iter().map(|c| [c.x, c.y, bottom]));
+            ring_vertex.extend((0..len).map(|i| start + i));
+            ring_vertex.push(start);
+            walls.extend((0..len).map(|i| (start + i, start + (i + 1) % len)));
+        }
+        let count = vertices.len();
+        vertices.extend_from_within(..);
+        for vertex in &mut vertices[count..] {
+            vertex[2] = top;
+        }
+
+        let mut triangle_indices = vec![];
+        let triangulation = Polygon::new(
+            rings[0].clone(),
+            rings[1..].iter().map(|&ring| ring.clone()).collect(),
+        )
+        .earcut_triangles_raw();
+        for triangle in triangulation.triangle_indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| ring_vertex[triangle[i]]);
+            let [pa, pb, pc] = [a, b, c].map(|i| vertices[i]);
+            let cross = (pb[0] - pa[0]) * (pc[1] - pa[1]) - (pb[1] - pa[1]) * (pc[0] - pa[0]);
+            let (a, b) = if cross > T::zero() { (a, b) } else { (b, a) };
+            // the roof faces up, and the floor down
+            triangle_indices.extend([a + count, b + count, c + count]);
+            triangle_indices.extend([b, a, c]);
+        }
+        // the interior of the polygon is to the left of each edge, so walls face to the right
+        for (a, b) in walls {
+            triangle_indices.extend([a, b, b + count]);
+            triangle_indices.extend([a, b + count, a + count]);
+        }
+        Mesh {
+            vertices,
+            triangle_indices,
+        }
+    }
+}
+
+impl<T: GeoFloat> Extrude<T> for MultiPolygon<T> {
+    fn extrude(&self, height: T) -> Mesh<T> {
+        let mut mesh = Mesh::default();
+        for polygon in self {
+            mesh.append(polygon.extrude(height));
+        }
+        mesh
+    }
+}
+
+impl<T: GeoFloat> Extrude<T> for Rect<T> {
+    fn extrude(&self, height: T) -> Mesh<T> {
+        self.to_polygon().extrude(height)
+    }
+}
+
+impl<T: GeoFloat> Extrude<T> for Triangle<T> {
+    fn extrude(&self, height: T) -> Mesh<T> {
+        self.to_polygon().extrude(height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area};
+    use std::collections::HashMap;
+
+    /// The volume enclosed by a mesh, which is positive if its triangles face outwards.
+    fn volume(mesh: &Mesh) -> f64 {
+        mesh.triangles()
+            .map(|[a, b, c]| {
+                let cross = [
+                    b[1] * c[2] - b[2] * c[1],
+                    b[2] * c[0] - b[0] * c[2],
+                    b[0] * c[1] - b[1] * c[0],
+                ];
+                (a[0] * cross[0] + a[1] * cross[1] + a[2] * cross[2]) / 6.
+            })
+            .sum()
+    }
+
+    /// Whether every edge is shared by exactly two triangles, in opposite directions.
+    fn is_watertight(mesh: &Mesh) -> bool {
+        let mut edges = HashMap::new();
+        for triangle in mesh.triangle_indices.chunks_exact(3) {
+            for i in 0..3 {
+                *edges
+                    .entry((triangle[i], triangle[(i + 1) % 3]))
+                    .or_insert(0) += 1;
+            }
+        }
+        edges
+            .iter()
+            .all(|(&(a, b), &count)| count == 1 && edges.get(&(b, a)) == Some(&1))
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        // clockwise exterior and counter-clockwise interior
+        let polygon = wkt!(POLYGON(
+            (0. 0.,0. 10.,5. 12.,10. 10.,10. 0.,0. 0.),
+            (2. 2.,4. 2.,4. 4.,2. 4.,2. 2.)
+        ));
+        let mesh = polygon.extrude(3.);
+        assert_eq!(mesh.vertices.len(), 18);
+        assert!(is_watertight(&mesh));
+        assert_relative_eq!(volume(&mesh), polygon.unsigned_area() * 3.);
+
+        let mesh = polygon.extrude(-3.);
+        assert!(is_watertight(&mesh));
+        assert_relative_eq!(volume(&mesh), polygon.unsigned_area() * 3.);
+        assert!(mesh.vertices.iter().all(|v| v[2] == 0. || v[2] == -3.));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,1. 1.,0. 0.)),
+            ((5. 5.,7. 5.,7. 7.,5. 7.,5. 5.))
+        ));
+        let mesh = multi_polygon.extrude(2.);
+        assert_eq!(mesh.vertices.len(), 14);
+        assert!(is_watertight(&mesh));
+        assert_relative_eq!(volume(&mesh), multi_polygon.unsigned_area() * 2.);
+
+        let rect = Rect::new((0., 0.), (2., 3.));
+        assert_relative_eq!(volume(&rect.extrude(1.)), 6.);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).extrude(1.),
+            Mesh::default()
+        );
+        assert_eq!(
+            MultiPolygon::<f64>::new(vec![]).extrude(1.),
+            Mesh::default()
+        );
+    }
+}
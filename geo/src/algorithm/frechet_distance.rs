@@ -1,6 +1,8 @@
+use std::sync::atomic::AtomicBool;
+
 use crate::coords_iter::CoordsIter;
 use crate::line_measures::{Distance, Euclidean};
-use crate::{GeoFloat, LineString};
+use crate::{GeoError, GeoFloat, LineString};
 use num_traits::FromPrimitive;
 
 /// Determine the similarity between two `LineStrings` using the [Frechet distance].
@@ -39,6 +41,31 @@ pub trait FrechetDistance<T, Rhs = Self> {
     ///
     /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
     fn frechet_distance(&self, rhs: &Rhs) -> T;
+
+    /// Like [`frechet_distance`](Self::frechet_distance), which takes time proportional to the
+    /// product of the numbers of coordinates, but returns [`GeoError::Interrupted`] as soon as
+    /// `cancel` is set, e.g. by another thread when a request times out.
+    ///
+    /// The default implementation only checks `cancel` before computing the whole distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::AtomicBool;
+    /// use geo::{wkt, FrechetDistance, GeoError};
+    ///
+    /// let a = wkt!(LINESTRING(1. 1.,2. 1.));
+    /// let b = wkt!(LINESTRING(2. 2.,2. 3.));
+    /// assert_eq!(a.frechet_distance_cancellable(&b, &AtomicBool::new(false)), Ok(2.));
+    /// assert_eq!(
+    ///     a.frechet_distance_cancellable(&b, &AtomicBool::new(true)),
+    ///     Err(GeoError::Interrupted)
+    /// );
+    /// ```
+    fn frechet_distance_cancellable(&self, rhs: &Rhs, cancel: &AtomicBool) -> Result<T, GeoError> {
+        GeoError::check_cancel(cancel)?;
+        Ok(self.frechet_distance(rhs))
+    }
 }
 
 impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
@@ -46,15 +73,24 @@ where
     T: GeoFloat + FromPrimitive,
 {
     fn frechet_distance(&self, ls: &LineString<T>) -> T {
+        self.frechet_distance_cancellable(ls, &AtomicBool::new(false))
+            .expect("the distance can't be cancelled")
+    }
+
+    fn frechet_distance_cancellable(
+        &self,
+        ls: &LineString<T>,
+        cancel: &AtomicBool,
+    ) -> Result<T, GeoError> {
         if self.coords_count() != 0 && ls.coords_count() != 0 {
             Data {
                 cache: vec![T::zero(); self.coords_count() * ls.coords_count()],
                 ls_a: self,
                 ls_b: ls,
             }
-            .compute_linear()
+            .compute_linear(cancel)
         } else {
-            T::zero()
+            Ok(T::zero())
         }
     }
 }
@@ -73,10 +109,11 @@ where
     T: GeoFloat + FromPrimitive,
 {
     /// [Reference implementation]: https://github.com/joaofig/discrete-frechet/tree/master
-    fn compute_linear(&mut self) -> T {
+    fn compute_linear(&mut self, cancel: &AtomicBool) -> Result<T, GeoError> {
         let columns_count = self.ls_b.coords_count();
 
         for (i, &a) in self.ls_a.coords().enumerate() {
+            GeoError::check_cancel(cancel)?;
             for (j, &b) in self.ls_b.coords().enumerate() {
                 let dist = Euclidean::distance(a, b);
 
@@ -92,7 +129,7 @@ where
             }
         }
 
-        self.cache[self.cache.len() - 1]
+        Ok(self.cache[self.cache.len() - 1])
     }
 }
 
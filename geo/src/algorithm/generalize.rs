@@ -0,0 +1,298 @@
+use crate::geometry::*;
+use crate::line_measures::Length;
+use crate::{Area, Euclidean, GeoFloat, MinimumRotatedRect, Simplify};
+
+/// Options for [`Generalize::generalize_with`].
+///
+/// Sizes are measured on the map, in metres, and multiplied by the scale denominator to get the
+/// sizes on the ground, in the units of the coordinates, which should be metres too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneralizeOptions<T> {
+    /// The [simplification](crate::Simplify) tolerance.
+    pub tolerance: T,
+    /// The area of the smallest polygon, or hole, which is kept.
+    pub min_area: T,
+    /// The length of the shortest line which is kept.
+    pub min_length: T,
+    /// If set, polygons narrower than this, e.g. rivers, are collapsed to lines along their
+    /// length.
+    pub collapse_width: Option<T>,
+}
+
+impl<T: GeoFloat> Default for GeneralizeOptions<T> {
+    /// Simplify to 0.2 mm, remove polygons smaller than 0.5 × 0.5 mm and lines shorter than
+    /// 0.5 mm, and don't collapse polygons.
+    fn default() -> Self {
+        Self {
+            tolerance: T::from(0.2e-3).unwrap(),
+            min_area: T::from(0.25e-6).unwrap(),
+            min_length: T::from(0.5e-3).unwrap(),
+            collapse_width: None,
+        }
+    }
+}
+
+/// Derive the geometry drawn on a map at a given scale, e.g. for each zoom level of a
+/// multi-scale map product.
+///
+/// In one pass, this collapses thin polygons to lines if asked to, removes the polygons, holes
+/// and lines too small to see, and simplifies what's left, all with sizes derived from the
+/// scale. Coordinates should be in metres, e.g. in a UTM zone, rather than longitude and
+/// latitude.
+///
+/// Points are kept as they are. Returns `None` if nothing is left.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Generalize, GeneralizeOptions, Geometry};
+///
+/// // a 10 × 200 m reservoir, with a wiggle of 5 cm
+/// let reservoir = wkt!(POLYGON((0. 0.,100. 0.05,200. 0.,200. 10.,0. 10.,0. 0.)));
+///
+/// // at 1:1,000 it's simplified
+/// let Some(Geometry::Polygon(polygon)) = reservoir.generalize(1_000.) else { panic!() };
+/// assert_eq!(polygon.exterior().0.len(), 5);
+///
+/// // at 1:100,000 it's 0.1 × 2 mm, which is collapsed to a line if asked
+/// let options = GeneralizeOptions { collapse_width: Some(0.5e-3), ..Default::default() };
+/// let Some(Geometry::LineString(line_string)) = reservoir.generalize_with(100_000., &options) else {
+///     panic!()
+/// };
+/// assert_eq!(line_string.0.len(), 2);
+///
+/// // and at 1:10,000,000 it's too small to see
+/// assert_eq!(reservoir.generalize(10_000_000.), None);
+/// ```
+pub trait Generalize<T: GeoFloat> {
+    /// Generalize with the [default options](GeneralizeOptions::default) for a map at 1 :
+    /// `scale_denominator`.
+    fn generalize(&self, scale_denominator: T) -> Option<Geometry<T>> {
+        self.generalize_with(scale_denominator, &GeneralizeOptions::default())
+    }
+
+    /// Generalize for a map at 1 : `scale_denominator`.
+    fn generalize_with(
+        &self,
+        scale_denominator: T,
+        options: &GeneralizeOptions<T>,
+    ) -> Option<Geometry<T>>;
+}
+
+/// The options scaled to sizes on the ground.
+fn ground<T: GeoFloat>(scale: T, options: &GeneralizeOptions<T>) -> GeneralizeOptions<T> {
+    GeneralizeOptions {
+        tolerance: options.tolerance * scale,
+        min_area: options.min_area * scale * scale,
+        min_length: options.min_length * scale,
+        collapse_width: options.collapse_width.map(|width| width * scale),
+    }
+}
+
+fn line_string<T: GeoFloat>(
+    line_string: &LineString<T>,
+    options: &GeneralizeOptions<T>,
+) -> Option<LineString<T>> {
+    if line_string.length::<Euclidean>() < options.min_length {
+        return None;
+    }
+    Some(line_string.simplify(&options.tolerance))
+}
+
+fn polygon<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    options: &GeneralizeOptions<T>,
+) -> Option<Geometry<T>> {
+    let area = polygon.unsigned_area();
+    // before removing small polygons, since thin ones can be long enough to keep as lines
+    if let Some(collapse_width) = options.collapse_width {
+        // twice the area over the perimeter is the width of a long, thin polygon
+        let perimeter = polygon.exterior().length::<Euclidean>();
+        if area * T::from(2).unwrap() / perimeter < collapse_width {
+            return line_string(&centerline(polygon)?, options).map(Geometry::LineString);
+        }
+    }
+    if area < options.min_area {
+        return None;
+    }
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter(|interior| {
+            Polygon::new((*interior).clone(), vec![]).unsigned_area() >= options.min_area
+        })
+        .cloned()
+        .collect();
+    let polygon = Polygon::new(polygon.exterior().clone(), interiors);
+    Some(Geometry::Polygon(polygon.simplify(&options.tolerance)))
+}
+
+/// The line through the middle of a polygon's minimum rotated rectangle, along its long sides.
+fn centerline<T: GeoFloat>(polygon: &Polygon<T>) -> Option<LineString<T>> {
+    let rect = polygon.minimum_rotated_rect()?;
+    let [a, b, c, d] = [0, 1, 2, 3].map(|index| rect.exterior().0[index]);
+    let two = T::from(2).unwrap();
+    let length = |from: Coord<T>, to: Coord<T>| (to - from).x.hypot((to - from).y);
+    Some(if length(a, b) >= length(b, c) {
+        LineString::new(vec![(a + d) / two, (b + c) / two])
+    } else {
+        LineString::new(vec![(a + b) / two, (d + c) / two])
+    })
+}
+
+/// Generalizes each member, keeping them together as a multi-geometry of the same kind if they
+/// all are, and in a collection otherwise.
+fn members<T: GeoFloat>(members: impl Iterator<Item = Option<Geometry<T>>>) -> Option<Geometry<T>> {
+    let members: Vec<Geometry<T>> = members.flatten().collect();
+    if members.is_empty() {
+        return None;
+    }
+    if members
+        .iter()
+        .all(|member| matches!(member, Geometry::Polygon(_)))
+    {
+        let polygons = members
+            .into_iter()
+            .filter_map(|member| Polygon::try_from(member).ok());
+        return Some(Geometry::MultiPolygon(polygons.collect()));
+    }
+    if members
+        .iter()
+        .all(|member| matches!(member, Geometry::LineString(_)))
+    {
+        let line_strings = members
+            .into_iter()
+            .filter_map(|member| LineString::try_from(member).ok());
+        return Some(Geometry::MultiLineString(line_strings.collect()));
+    }
+    Some(Geometry::GeometryCollection(GeometryCollection(members)))
+}
+
+fn geometry<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    options: &GeneralizeOptions<T>,
+) -> Option<Geometry<T>> {
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => Some(geometry.clone()),
+        Geometry::Line(line) => {
+            let line = LineString::new(vec![line.start, line.end]);
+            self::line_string(&line, options).map(Geometry::LineString)
+        }
+        Geometry::LineString(line) => self::line_string(line, options).map(Geometry::LineString),
+        Geometry::MultiLineString(multi) => members(
+            multi
+                .iter()
+                .map(|line| self::line_string(line, options).map(Geometry::LineString)),
+        ),
+        Geometry::Polygon(polygon) => self::polygon(polygon, options),
+        Geometry::MultiPolygon(multi) => {
+            members(multi.iter().map(|polygon| self::polygon(polygon, options)))
+        }
+        Geometry::Rect(rect) => self::polygon(&rect.to_polygon(), options),
+        Geometry::Triangle(triangle) => self::polygon(&triangle.to_polygon(), options),
+        Geometry::GeometryCollection(collection) => members(
+            collection
+                .iter()
+                .map(|member| self::geometry(member, options)),
+        ),
+    }
+}
+
+impl<T: GeoFloat> Generalize<T> for Geometry<T> {
+    fn generalize_with(
+        &self,
+        scale_denominator: T,
+        options: &GeneralizeOptions<T>,
+    ) -> Option<Geometry<T>> {
+        geometry(self, &ground(scale_denominator, options))
+    }
+}
+
+impl<T: GeoFloat> Generalize<T> for LineString<T> {
+    fn generalize_with(
+        &self,
+        scale_denominator: T,
+        options: &GeneralizeOptions<T>,
+    ) -> Option<Geometry<T>> {
+        line_string(self, &ground(scale_denominator, options)).map(Geometry::LineString)
+    }
+}
+
+impl<T: GeoFloat> Generalize<T> for Polygon<T> {
+    fn generalize_with(
+        &self,
+        scale_denominator: T,
+        options: &GeneralizeOptions<T>,
+    ) -> Option<Geometry<T>> {
+        polygon(self, &ground(scale_denominator, options))
+    }
+}
+
+impl<T: GeoFloat> Generalize<T> for MultiPolygon<T> {
+    fn generalize_with(
+        &self,
+        scale_denominator: T,
+        options: &GeneralizeOptions<T>,
+    ) -> Option<Geometry<T>> {
+        let options = ground(scale_denominator, options);
+        members(self.iter().map(|member| polygon(member, &options)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn small_features() {
+        // a 1 km lake with a 20 m island, and a 60 m pond
+        let lakes = wkt!(MULTIPOLYGON(
+            ((0. 0.,1000. 0.,1000. 1000.,0. 1000.,0. 0.),(500. 500.,520. 500.,520. 520.,500. 500.)),
+            ((2000. 0.,2060. 0.,2060. 60.,2000. 60.,2000. 0.))
+        ));
+        let Some(Geometry::MultiPolygon(multi)) = lakes.generalize(10_000.) else {
+            panic!()
+        };
+        assert_eq!(multi, lakes);
+        let Some(Geometry::MultiPolygon(multi)) = lakes.generalize(100_000.) else {
+            panic!()
+        };
+        assert_eq!(multi.0.len(), 2);
+        assert_eq!(multi.0[0].interiors().len(), 0);
+        let Some(Geometry::MultiPolygon(multi)) = lakes.generalize(1_000_000.) else {
+            panic!()
+        };
+        assert_eq!(multi.0.len(), 1);
+        assert_eq!(lakes.generalize(10_000_000.), None);
+    }
+
+    #[test]
+    fn collections() {
+        let collection = Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(
+            POINT(0. 0.),
+            LINESTRING(0. 0.,1. 0.),
+            LINESTRING(0. 0.,1000. 0.,2000. 0.1),
+            POLYGON((0. 0.,1000. 0.,1000. 1000.,0. 1000.,0. 0.))
+        )));
+        let Some(Geometry::GeometryCollection(generalized)) = collection.generalize(10_000.) else {
+            panic!()
+        };
+        assert_eq!(
+            generalized,
+            wkt!(GEOMETRYCOLLECTION(
+                POINT(0. 0.),
+                LINESTRING(0. 0.,2000. 0.1),
+                POLYGON((0. 0.,1000. 0.,1000. 1000.,0. 1000.,0. 0.))
+            ))
+        );
+        let lines =
+            Geometry::MultiLineString(wkt!(MULTILINESTRING((0. 0.,1. 0.),(0. 0.,0. 5000.))));
+        assert_eq!(
+            lines.generalize(10_000.),
+            Some(Geometry::MultiLineString(
+                wkt!(MULTILINESTRING((0. 0.,0. 5000.)))
+            ))
+        );
+    }
+}
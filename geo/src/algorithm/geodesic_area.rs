@@ -1,5 +1,5 @@
 use crate::geometry::*;
-use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+use crate::utils::fold_members;
 
 /// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
 ///
@@ -91,7 +91,10 @@ pub trait GeodesicArea<T> {
     /// let area = polygon.geodesic_area_unsigned();
     ///
     /// // Over 5 trillion square meters!
+    /// # #[cfg(feature = "geographiclib")]
     /// assert_eq!(area, 510053312945726.94);
+    /// # #[cfg(not(feature = "geographiclib"))]
+    /// # approx::assert_relative_eq!(area, 510053312945726.94, max_relative = 0.01);
     /// ```
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn geodesic_area_unsigned(&self) -> T;
@@ -187,23 +190,8 @@ impl GeodesicArea<f64> for Polygon {
 }
 
 fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool) -> (f64, f64) {
-    let g = Geodesic::wgs84();
-
-    let (exterior_winding, interior_winding) = if reverse {
-        (Winding::Clockwise, Winding::CounterClockwise)
-    } else {
-        (Winding::CounterClockwise, Winding::Clockwise)
-    };
-
     // Add the exterior ring
-    let (outer_perimeter, outer_area) = {
-        let mut pa = PolygonArea::new(&g, exterior_winding);
-        poly.exterior().points().for_each(|p| {
-            pa.add_point(p.y(), p.x());
-        });
-        let (perimeter, area, _) = pa.compute(sign);
-        (perimeter, area)
-    };
+    let (outer_perimeter, outer_area) = ring_perimeter_area(poly.exterior(), !reverse, sign);
 
     // Add the interior rings
     let (interior_perimeter, mut inner_area) = if exterior_only {
@@ -212,11 +200,7 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
         let mut inner_area = 0.;
         let mut inner_perimeter = 0.;
         poly.interiors().iter().for_each(|ring| {
-            let mut pa = PolygonArea::new(&g, interior_winding);
-            ring.points().for_each(|p| {
-                pa.add_point(p.y(), p.x());
-            });
-            let (perimeter, area, _) = pa.compute(sign);
+            let (perimeter, area) = ring_perimeter_area(ring, reverse, sign);
             inner_area += area.abs();
             inner_perimeter += perimeter;
         });
@@ -233,6 +217,51 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
     )
 }
 
+/// The perimeter and area of a ring, where the area is positive if the ring is counter-clockwise
+/// and `counter_clockwise` is true, or clockwise and it's false.
+#[cfg(feature = "geographiclib")]
+fn ring_perimeter_area(ring: &LineString, counter_clockwise: bool, sign: bool) -> (f64, f64) {
+    use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+
+    let g = Geodesic::wgs84();
+    let winding = if counter_clockwise {
+        Winding::CounterClockwise
+    } else {
+        Winding::Clockwise
+    };
+    let mut pa = PolygonArea::new(&g, winding);
+    ring.points().for_each(|p| {
+        pa.add_point(p.y(), p.x());
+    });
+    let (perimeter, area, _) = pa.compute(sign);
+    (perimeter, area)
+}
+
+/// Without geographiclib, the perimeter and area are approximated on a sphere.
+///
+/// Like geographiclib, the unsigned area of a ring wound the other way is the area of the rest of
+/// the sphere.
+#[cfg(not(feature = "geographiclib"))]
+fn ring_perimeter_area(ring: &LineString, counter_clockwise: bool, sign: bool) -> (f64, f64) {
+    use crate::algorithm::chamberlain_duquette_area::ring_area;
+    use crate::{Distance, Haversine, EQUATORIAL_EARTH_RADIUS};
+
+    let perimeter = ring
+        .lines()
+        .map(|line| Haversine::distance(line.start_point(), line.end_point()))
+        .sum();
+    let area = if counter_clockwise {
+        ring_area(ring)
+    } else {
+        -ring_area(ring)
+    };
+    if !sign && area < 0.0 {
+        let sphere_area = 4.0 * std::f64::consts::PI * EQUATORIAL_EARTH_RADIUS.powi(2);
+        return (perimeter, sphere_area + area);
+    }
+    (perimeter, area)
+}
+
 /// Generate a `GeodesicArea` implementation where the result is zero.
 macro_rules! zero_impl {
     ($type:ident) => {
@@ -290,38 +319,53 @@ macro_rules! to_polygon_impl {
 
 /// Generate a `GeodesicArea` implementation which calculates the area for each of its
 /// sub-components and sums them up.
+///
+/// With the `parallel` feature, large collections are summed in parallel.
 macro_rules! sum_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
             fn geodesic_perimeter(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_perimeter())
+                fold_members(&self.0, 0.0, |next| next.geodesic_perimeter(), |a, b| a + b)
             }
 
             fn geodesic_area_signed(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_area_signed())
+                fold_members(
+                    &self.0,
+                    0.0,
+                    |next| next.geodesic_area_signed(),
+                    |a, b| a + b,
+                )
             }
 
             fn geodesic_area_unsigned(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_area_unsigned())
+                fold_members(
+                    &self.0,
+                    0.0,
+                    |next| next.geodesic_area_unsigned(),
+                    |a, b| a + b,
+                )
             }
 
             fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
-                self.iter()
-                    .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
-                        let (perimeter, area) = next.geodesic_perimeter_area_signed();
-                        (total_perimeter + perimeter, total_area + area)
-                    })
+                fold_members(
+                    &self.0,
+                    (0.0, 0.0),
+                    |next| next.geodesic_perimeter_area_signed(),
+                    |(perimeter_a, area_a), (perimeter_b, area_b)| {
+                        (perimeter_a + perimeter_b, area_a + area_b)
+                    },
+                )
             }
 
             fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
-                self.iter()
-                    .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
-                        let (perimeter, area) = next.geodesic_perimeter_area_unsigned();
-                        (total_perimeter + perimeter, total_area + area)
-                    })
+                fold_members(
+                    &self.0,
+                    (0.0, 0.0),
+                    |next| next.geodesic_perimeter_area_unsigned(),
+                    |(perimeter_a, area_a), (perimeter_b, area_b)| {
+                        (perimeter_a + perimeter_b, area_a + area_b)
+                    },
+                )
             }
         }
     };
@@ -347,7 +391,8 @@ impl GeodesicArea<f64> for Geometry<f64> {
     }
 }
 
-#[cfg(test)]
+// the expected values are those of the ellipsoidal model
+#[cfg(all(test, feature = "geographiclib"))]
 mod test {
     use super::*;
     use crate::algorithm::line_measures::{Geodesic, Length};
@@ -664,4 +709,64 @@ mod test {
         let area = polygon_large_with_hole.geodesic_area_unsigned();
         assert_relative_eq!(area, 46154562709.8, epsilon = 0.1);
     }
+
+    #[test]
+    fn test_large_multi_polygon() {
+        // enough members to be summed in parallel with the `parallel` feature
+        let polygon: Polygon<f64> = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+            (x: 0.0, y: 1.0),
+            (x: 0.0, y: 0.0),
+        ];
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(); 100]);
+
+        let (perimeter, area) = polygon.geodesic_perimeter_area_signed();
+        let (total_perimeter, total_area) = multi_polygon.geodesic_perimeter_area_signed();
+        assert_relative_eq!(total_perimeter, 100.0 * perimeter, max_relative = 1e-12);
+        assert_relative_eq!(total_area, 100.0 * area, max_relative = 1e-12);
+        assert_relative_eq!(
+            multi_polygon.geodesic_area_unsigned(),
+            100.0 * polygon.geodesic_area_unsigned(),
+            max_relative = 1e-12
+        );
+    }
+}
+
+// without geographiclib, the results are those of a sphere, within 0.6% of the ellipsoidal ones
+#[cfg(all(test, not(feature = "geographiclib")))]
+mod spherical_test {
+    use super::*;
+    use crate::algorithm::line_measures::{Geodesic, Length};
+    use crate::polygon;
+
+    #[test]
+    fn orientation() {
+        let polygon = polygon![
+            (x: 125., y: -15.),
+            (x: 113., y: -22.),
+            (x: 117., y: -37.),
+            (x: 130., y: -33.),
+            (x: 148., y: -39.),
+            (x: 154., y: -27.),
+            (x: 144., y: -15.),
+            (x: 125., y: -15.),
+        ];
+        let area = polygon.geodesic_area_signed();
+        assert_relative_eq!(7786102826806.07, area, max_relative = 0.006);
+        assert_relative_eq!(area, polygon.geodesic_area_unsigned());
+        assert_relative_eq!(
+            polygon.exterior().length::<Geodesic>(),
+            polygon.geodesic_perimeter()
+        );
+
+        let reversed = Polygon::new(
+            polygon.exterior().clone().into_iter().rev().collect(),
+            vec![],
+        );
+        assert_relative_eq!(-area, reversed.geodesic_area_signed());
+        let sphere_area = 4.0 * std::f64::consts::PI * crate::EQUATORIAL_EARTH_RADIUS.powi(2);
+        assert_relative_eq!(sphere_area - area, reversed.geodesic_area_unsigned());
+    }
 }
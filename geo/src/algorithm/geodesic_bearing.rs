@@ -1,6 +1,6 @@
+use crate::line_measures::metric_spaces::geodesic::inverse;
 use crate::Point;
 use geo_types::CoordNum;
-use geographiclib_rs::{Geodesic, InverseGeodesic};
 
 /// Returns the bearing to another Point in degrees on a geodesic.
 ///
@@ -55,18 +55,16 @@ pub trait GeodesicBearing<T: CoordNum> {
 
 impl GeodesicBearing<f64> for Point<f64> {
     fn geodesic_bearing(&self, rhs: Point<f64>) -> f64 {
-        let (azi1, _, _) = Geodesic::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x());
-        azi1
+        inverse(*self, rhs).1
     }
 
     fn geodesic_bearing_distance(&self, rhs: Point<f64>) -> (f64, f64) {
-        let (distance, azi1, _, _) =
-            Geodesic::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x());
+        let (distance, azi1) = inverse(*self, rhs);
         (azi1, distance)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "geographiclib"))]
 mod test {
     use super::*;
     use crate::point;
@@ -47,7 +47,8 @@ impl GeodesicDestination<f64> for Point<f64> {
     }
 }
 
-#[cfg(test)]
+// the expected values are those of the ellipsoidal model
+#[cfg(all(test, feature = "geographiclib"))]
 mod test {
     use super::*;
     #[allow(deprecated)]
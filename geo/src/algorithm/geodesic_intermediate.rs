@@ -65,7 +65,8 @@ impl GeodesicIntermediate<f64> for Point {
     }
 }
 
-#[cfg(test)]
+// the expected values are those of the ellipsoidal model
+#[cfg(all(test, feature = "geographiclib"))]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
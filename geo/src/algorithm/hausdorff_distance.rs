@@ -1,6 +1,8 @@
+use std::sync::atomic::AtomicBool;
+
 use crate::algorithm::{Distance, Euclidean};
 use crate::CoordsIter;
-use crate::GeoFloat;
+use crate::{GeoError, GeoFloat};
 use geo_types::{Coord, Point};
 use num_traits::Bounded;
 
@@ -19,6 +21,23 @@ where
     fn hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
     where
         Rhs: CoordsIter<Scalar = T>;
+
+    /// Like `hausdorff_distance`, which takes time proportional to the product of the numbers of
+    /// coordinates, but returns [`GeoError::Interrupted`] as soon as `cancel` is set, e.g. by
+    /// another thread when a request times out.
+    ///
+    /// The default implementation only checks `cancel` before computing the whole distance.
+    fn hausdorff_distance_cancellable<Rhs>(
+        &self,
+        rhs: &Rhs,
+        cancel: &AtomicBool,
+    ) -> Result<T, GeoError>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        GeoError::check_cancel(cancel)?;
+        Ok(self.hausdorff_distance(rhs))
+    }
 }
 
 impl<T, G> HausdorffDistance<T> for G
@@ -27,31 +46,47 @@ where
     G: CoordsIter<Scalar = T>,
 {
     fn hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        self.hausdorff_distance_cancellable(rhs, &AtomicBool::new(false))
+            .expect("the distance can't be cancelled")
+    }
+
+    fn hausdorff_distance_cancellable<Rhs>(
+        &self,
+        rhs: &Rhs,
+        cancel: &AtomicBool,
+    ) -> Result<T, GeoError>
     where
         Rhs: CoordsIter<Scalar = T>,
     {
         // calculate from A -> B
         let hd1 = self
             .coords_iter()
-            .map(|c| {
-                rhs.coords_iter()
+            .try_fold(<T as Bounded>::min_value(), |accum, c| {
+                GeoError::check_cancel(cancel)?;
+                let val = rhs
+                    .coords_iter()
                     .map(|c2| Euclidean::distance(c, c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
+                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val));
+                Ok(accum.max(val))
+            })?;
 
         // Calculate from B -> A
         let hd2 = rhs
             .coords_iter()
-            .map(|c| {
-                self.coords_iter()
+            .try_fold(<T as Bounded>::min_value(), |accum, c| {
+                GeoError::check_cancel(cancel)?;
+                let val = self
+                    .coords_iter()
                     .map(|c2| Euclidean::distance(c, c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
+                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val));
+                Ok(accum.max(val))
+            })?;
 
         // The max of the two
-        hd1.max(hd2)
+        Ok(hd1.max(hd2))
     }
 }
 
@@ -69,6 +104,17 @@ where
     {
         Point::from(*self).hausdorff_distance(rhs)
     }
+
+    fn hausdorff_distance_cancellable<Rhs>(
+        &self,
+        rhs: &Rhs,
+        cancel: &AtomicBool,
+    ) -> Result<T, GeoError>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        Point::from(*self).hausdorff_distance_cancellable(rhs, cancel)
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +179,23 @@ mod test {
             epsilon = 1.0e-6
         )
     }
+
+    #[test]
+    fn cancellable() {
+        use crate::GeoError;
+        use std::sync::atomic::AtomicBool;
+
+        let a: MultiPoint<_> = vec![(0., 0.), (1., 2.)].into();
+        let b = line_string![(x: 0., y: 1.), (x: 4., y: 1.)];
+        let cancel = AtomicBool::new(false);
+        assert_eq!(
+            a.hausdorff_distance_cancellable(&b, &cancel),
+            Ok(a.hausdorff_distance(&b))
+        );
+        cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(
+            a.hausdorff_distance_cancellable(&b, &cancel),
+            Err(GeoError::Interrupted)
+        );
+    }
 }
@@ -0,0 +1,534 @@
+//! Exact algorithms for geometries with integer coordinates.
+//!
+//! Many of `geo`'s algorithms, such as [`Intersects`](crate::Intersects) and
+//! [`ConvexHull`](crate::ConvexHull), are generic over [`GeoNum`](crate::GeoNum) and already
+//! produce exact results for integer coordinates. The traits in this module cover the remaining
+//! operations whose floating point counterparts either require [`GeoFloat`](crate::GeoFloat) or
+//! would lose precision: all intermediate values are computed using `i128` (or wider)
+//! arithmetic.
+//!
+//! This is useful for processing quantized, tile-local geometries, where the output must be
+//! exactly reproducible.
+
+use crate::geometry::*;
+use crate::GeoNum;
+
+/// An integer coordinate type supported by the exact algorithms in this module.
+///
+/// Intermediate values are computed as `i128` (or wider), so results are exact for any `i16` or
+/// `i32` input. `i64` input is exact within the bounds documented on each trait, beyond which
+/// the intermediate values overflow.
+pub trait IntegerCoord: GeoNum + Into<i128> {}
+
+impl IntegerCoord for i16 {}
+impl IntegerCoord for i32 {}
+impl IntegerCoord for i64 {}
+
+/// Twice the signed area of a ring, computed with the shoelace formula.
+///
+/// Returns zero for rings which are not closed or have fewer than 3 coordinates, mirroring
+/// [`Area`](crate::Area).
+fn twice_signed_ring_area<T: IntegerCoord>(ring: &LineString<T>) -> i128 {
+    if ring.0.len() < 3 || ring.0.first() != ring.0.last() {
+        return 0;
+    }
+    // Shift by the first coordinate to keep the intermediate products small.
+    let shift_x: i128 = ring.0[0].x.into();
+    let shift_y: i128 = ring.0[0].y.into();
+    ring.lines().fold(0, |acc, line| {
+        let (x0, y0) = (line.start.x.into() - shift_x, line.start.y.into() - shift_y);
+        let (x1, y1) = (line.end.x.into() - shift_x, line.end.y.into() - shift_y);
+        acc + (x0 * y1 - x1 * y0)
+    })
+}
+
+/// Exact planar area of a geometry with integer coordinates.
+///
+/// The area of a polygon with integer vertices is always a multiple of one half, so this
+/// trait reports _twice_ the area, which is always an integer and therefore exact.
+///
+/// Like [`Area`](crate::Area), the sign of a polygon's area follows the winding of its
+/// exterior ring, and interior rings are always subtracted regardless of their orientation.
+///
+/// Each edge adds at most `2 * R^2` to the sum, where `R` is the width of the range of the
+/// geometry's coordinates along either axis, so the result is exact while `n * R^2 < 2^126`
+/// for `n` edges in all. That holds for any `i16` or `i32` geometry which fits in memory, but
+/// for `i64` coordinates spanning `2^60`, e.g., only for fewer than 64 edges.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::IntegerArea;
+///
+/// let polygon = polygon![
+///     (x: 0_i32, y: 0),
+///     (x: 3, y: 0),
+///     (x: 0, y: 3),
+///     (x: 0, y: 0),
+/// ];
+///
+/// // The area is 4.5
+/// assert_eq!(polygon.twice_signed_area(), 9);
+/// assert_eq!(polygon.twice_unsigned_area(), 9);
+/// ```
+pub trait IntegerArea {
+    /// Twice the signed area of the geometry.
+    fn twice_signed_area(&self) -> i128;
+
+    /// Twice the unsigned area of the geometry.
+    fn twice_unsigned_area(&self) -> i128;
+}
+
+macro_rules! zero_integer_area_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<T: IntegerCoord> IntegerArea for $type<T> {
+                fn twice_signed_area(&self) -> i128 {
+                    0
+                }
+
+                fn twice_unsigned_area(&self) -> i128 {
+                    0
+                }
+            }
+        )+
+    };
+}
+
+zero_integer_area_impl!(Point, Line, LineString, MultiPoint, MultiLineString);
+
+impl<T: IntegerCoord> IntegerArea for Polygon<T> {
+    fn twice_signed_area(&self) -> i128 {
+        let area = twice_signed_ring_area(self.exterior());
+        let area_abs = self.interiors().iter().fold(area.abs(), |total, next| {
+            total - twice_signed_ring_area(next).abs()
+        });
+        if area < 0 {
+            -area_abs
+        } else {
+            area_abs
+        }
+    }
+
+    fn twice_unsigned_area(&self) -> i128 {
+        self.twice_signed_area().abs()
+    }
+}
+
+impl<T: IntegerCoord> IntegerArea for MultiPolygon<T> {
+    fn twice_signed_area(&self) -> i128 {
+        self.iter().map(|p| p.twice_signed_area()).sum()
+    }
+
+    fn twice_unsigned_area(&self) -> i128 {
+        self.iter().map(|p| p.twice_unsigned_area()).sum()
+    }
+}
+
+impl<T: IntegerCoord> IntegerArea for Rect<T> {
+    fn twice_signed_area(&self) -> i128 {
+        let width: i128 = self.max().x.into() - self.min().x.into();
+        let height: i128 = self.max().y.into() - self.min().y.into();
+        2 * width * height
+    }
+
+    fn twice_unsigned_area(&self) -> i128 {
+        self.twice_signed_area()
+    }
+}
+
+impl<T: IntegerCoord> IntegerArea for Triangle<T> {
+    fn twice_signed_area(&self) -> i128 {
+        let (x0, y0): (i128, i128) = (self.0.x.into(), self.0.y.into());
+        let (x1, y1): (i128, i128) = (self.1.x.into() - x0, self.1.y.into() - y0);
+        let (x2, y2): (i128, i128) = (self.2.x.into() - x0, self.2.y.into() - y0);
+        x1 * y2 - x2 * y1
+    }
+
+    fn twice_unsigned_area(&self) -> i128 {
+        self.twice_signed_area().abs()
+    }
+}
+
+impl<T: IntegerCoord> IntegerArea for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn twice_signed_area(&self) -> i128;
+        fn twice_unsigned_area(&self) -> i128;
+    }
+}
+
+impl<T: IntegerCoord> IntegerArea for GeometryCollection<T> {
+    fn twice_signed_area(&self) -> i128 {
+        self.iter().map(|g| g.twice_signed_area()).sum()
+    }
+
+    fn twice_unsigned_area(&self) -> i128 {
+        self.iter().map(|g| g.twice_unsigned_area()).sum()
+    }
+}
+
+/// An unsigned 256 bit integer, stored as `(high, low)` so that the derived tuple ordering is
+/// numeric ordering.
+type U256 = (u128, u128);
+
+/// Full 256 bit product of two `u128`s.
+fn widening_mul(a: u128, b: u128) -> U256 {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (cross << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    (high, low)
+}
+
+/// The squared distance from `p` to the segment `a`-`b`, scaled by the squared length of the
+/// segment so that it can be represented exactly as an integer.
+///
+/// Returns the scaled squared distance along with the scale factor.
+fn scaled_segment_distance_sq<T: IntegerCoord>(
+    p: Coord<T>,
+    a: Coord<T>,
+    b: Coord<T>,
+) -> (U256, u128) {
+    let (px, py): (i128, i128) = (p.x.into(), p.y.into());
+    let (ax, ay): (i128, i128) = (a.x.into(), a.y.into());
+    let (bx, by): (i128, i128) = (b.x.into(), b.y.into());
+
+    let (dx, dy) = (bx - ax, by - ay);
+    let len_sq = (dx * dx + dy * dy) as u128;
+    let dist_sq = |x: i128, y: i128| ((px - x) * (px - x) + (py - y) * (py - y)) as u128;
+
+    if len_sq == 0 {
+        return ((0, dist_sq(ax, ay)), 1);
+    }
+
+    let dot = (px - ax) * dx + (py - ay) * dy;
+    let scaled = if dot <= 0 {
+        widening_mul(dist_sq(ax, ay), len_sq)
+    } else if dot as u128 >= len_sq {
+        widening_mul(dist_sq(bx, by), len_sq)
+    } else {
+        let cross = ((px - ax) * dy - (py - ay) * dx).unsigned_abs();
+        widening_mul(cross, cross)
+    };
+    (scaled, len_sq)
+}
+
+/// Ramer–Douglas–Peucker simplification using exact integer arithmetic, returning the retained
+/// indices.
+fn rdp_indices<T: IntegerCoord>(coords: &[Coord<T>], epsilon: T, initial_min: usize) -> Vec<usize> {
+    if epsilon <= T::zero() || coords.len() < 3 {
+        return (0..coords.len()).collect();
+    }
+    let epsilon: i128 = epsilon.into();
+    let epsilon_sq = (epsilon * epsilon) as u128;
+
+    let mut simplified_len = coords.len();
+    let mut retained = vec![0];
+    compute_rdp(
+        coords,
+        0,
+        coords.len() - 1,
+        epsilon_sq,
+        initial_min,
+        &mut simplified_len,
+        &mut retained,
+    );
+    retained
+}
+
+/// Simplify `coords[first..=last]`, pushing every retained index after `first` onto `retained`.
+fn compute_rdp<T: IntegerCoord>(
+    coords: &[Coord<T>],
+    first: usize,
+    last: usize,
+    epsilon_sq: u128,
+    initial_min: usize,
+    simplified_len: &mut usize,
+    retained: &mut Vec<usize>,
+) {
+    if last - first < 2 {
+        retained.extend(first + 1..=last);
+        return;
+    }
+
+    let mut farthest_index = first + 1;
+    let mut farthest_distance = (0, 0);
+    let mut scale = 1;
+    for (index, coord) in coords.iter().enumerate().take(last).skip(first + 1) {
+        let (distance, len_sq) = scaled_segment_distance_sq(*coord, coords[first], coords[last]);
+        if distance >= farthest_distance {
+            farthest_index = index;
+            farthest_distance = distance;
+        }
+        scale = len_sq;
+    }
+
+    if farthest_distance > widening_mul(epsilon_sq, scale) {
+        compute_rdp(
+            coords,
+            first,
+            farthest_index,
+            epsilon_sq,
+            initial_min,
+            simplified_len,
+            retained,
+        );
+        compute_rdp(
+            coords,
+            farthest_index,
+            last,
+            epsilon_sq,
+            initial_min,
+            simplified_len,
+            retained,
+        );
+        return;
+    }
+
+    // Don't cull if it would leave fewer coordinates than the geometry requires.
+    let number_culled = last - first - 1;
+    if *simplified_len - number_culled < initial_min {
+        retained.extend(first + 1..=last);
+        return;
+    }
+    *simplified_len -= number_culled;
+    retained.push(last);
+}
+
+fn simplify_line_string<T: IntegerCoord>(
+    line_string: &LineString<T>,
+    epsilon: T,
+    initial_min: usize,
+) -> LineString<T> {
+    rdp_indices(&line_string.0, epsilon, initial_min)
+        .into_iter()
+        .map(|i| line_string.0[i])
+        .collect()
+}
+
+/// Simplifies a geometry with integer coordinates using the
+/// [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm).
+///
+/// This behaves like [`Simplify`](crate::Simplify), but every distance comparison is carried
+/// out exactly, so the retained coordinates do not depend on floating point rounding. That
+/// includes `i64` coordinates whose magnitude is below `2^61`.
+///
+/// Points whose distance from the simplified output is at most `epsilon` may be discarded. An
+/// `epsilon` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::IntegerSimplify;
+///
+/// let line_string = line_string![
+///     (x: 0_i32, y: 0),
+///     (x: 5, y: 4),
+///     (x: 11, y: 5),
+///     (x: 17, y: 3),
+///     (x: 27, y: 0),
+/// ];
+///
+/// let simplified = line_string.simplify_integer(2);
+///
+/// let expected = line_string![
+///     (x: 0_i32, y: 0),
+///     (x: 11, y: 5),
+///     (x: 27, y: 0),
+/// ];
+///
+/// assert_eq!(expected, simplified);
+/// ```
+pub trait IntegerSimplify<T: IntegerCoord> {
+    /// Returns the simplified representation of the geometry.
+    fn simplify_integer(&self, epsilon: T) -> Self;
+}
+
+impl<T: IntegerCoord> IntegerSimplify<T> for LineString<T> {
+    fn simplify_integer(&self, epsilon: T) -> Self {
+        simplify_line_string(self, epsilon, 2)
+    }
+}
+
+impl<T: IntegerCoord> IntegerSimplify<T> for MultiLineString<T> {
+    fn simplify_integer(&self, epsilon: T) -> Self {
+        self.iter().map(|l| l.simplify_integer(epsilon)).collect()
+    }
+}
+
+impl<T: IntegerCoord> IntegerSimplify<T> for Polygon<T> {
+    fn simplify_integer(&self, epsilon: T) -> Self {
+        Polygon::new(
+            simplify_line_string(self.exterior(), epsilon, 4),
+            self.interiors()
+                .iter()
+                .map(|ring| simplify_line_string(ring, epsilon, 4))
+                .collect(),
+        )
+    }
+}
+
+impl<T: IntegerCoord> IntegerSimplify<T> for MultiPolygon<T> {
+    fn simplify_integer(&self, epsilon: T) -> Self {
+        self.iter().map(|p| p.simplify_integer(epsilon)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        coord, line_string, polygon, wkt, Area, Convert, ConvexHull, Intersects, Simplify,
+    };
+
+    #[test]
+    fn area_matches_float_area() {
+        let poly = polygon![
+            exterior: [
+                (x: 0_i64, y: 0),
+                (x: 10, y: 0),
+                (x: 10, y: 10),
+                (x: 0, y: 10),
+                (x: 0, y: 0),
+            ],
+            interiors: [
+                [(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)],
+            ],
+        ];
+        assert_eq!(poly.twice_signed_area(), 199);
+        assert_eq!(poly.twice_unsigned_area(), 199);
+
+        let float_poly: Polygon<f64> =
+            wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)));
+        assert_eq!(float_poly.signed_area() * 2.0, 199.0);
+    }
+
+    #[test]
+    fn area_is_exact_for_large_coordinates() {
+        // f64 can't represent the area of this polygon exactly
+        let base = 1_i64 << 60;
+        let poly = polygon![
+            (x: base, y: base),
+            (x: base + 3, y: base),
+            (x: base, y: base + 3),
+            (x: base, y: base),
+        ];
+        assert_eq!(poly.twice_signed_area(), 9);
+
+        let mut reversed = poly.clone();
+        reversed.exterior_mut(|ring| ring.0.reverse());
+        assert_eq!(reversed.twice_signed_area(), -9);
+        assert_eq!(reversed.twice_unsigned_area(), 9);
+
+        // a square wound 15 times: 60 edges spanning 2^60, within the documented bound
+        let span = 1_i64 << 60;
+        let square = [(0, 0), (span, 0), (span, span), (0, span)];
+        let ring: LineString<i64> = square
+            .iter()
+            .cycle()
+            .take(60)
+            .chain(&[(0, 0)])
+            .copied()
+            .collect();
+        assert_eq!(Polygon::new(ring, vec![]).twice_signed_area(), 30 << 120);
+    }
+
+    #[test]
+    fn area_of_other_geometries() {
+        let rect = Rect::new(coord! { x: 1_i32, y: 2 }, coord! { x: 4, y: 7 });
+        assert_eq!(rect.twice_signed_area(), 30);
+
+        let triangle = Triangle::new(
+            coord! { x: 0_i32, y: 0 },
+            coord! { x: 0, y: 1 },
+            coord! { x: 1, y: 0 },
+        );
+        assert_eq!(triangle.twice_signed_area(), -1);
+        assert_eq!(triangle.twice_unsigned_area(), 1);
+
+        let geometry: Geometry<i32> = wkt!(LINESTRING(0 0,1 1)).into();
+        assert_eq!(geometry.twice_signed_area(), 0);
+
+        let collection = GeometryCollection::new_from(vec![rect.into(), triangle.into()]);
+        assert_eq!(collection.twice_unsigned_area(), 31);
+    }
+
+    #[test]
+    fn simplify_matches_float_simplify() {
+        let line_string = line_string![
+            (x: 0_i32, y: 0),
+            (x: 5, y: 4),
+            (x: 11, y: 5),
+            (x: 17, y: 3),
+            (x: 27, y: 0),
+        ];
+        let float_line_string: LineString<f64> = line_string.convert();
+        for epsilon in 1..6 {
+            let expected = float_line_string.simplify(&(epsilon as f64));
+            let actual: LineString<f64> = line_string.simplify_integer(epsilon).convert();
+            assert_eq!(expected, actual, "epsilon: {epsilon}");
+        }
+    }
+
+    #[test]
+    fn simplify_respects_minimum_size() {
+        let ring = polygon![
+            (x: 0_i64, y: 0),
+            (x: 1, y: 0),
+            (x: 1, y: 1),
+            (x: 0, y: 1),
+            (x: 0, y: 0),
+        ];
+        // Culling any coordinates would leave an invalid ring
+        assert_eq!(ring.simplify_integer(10), ring);
+
+        let line_string = line_string![(x: 0_i64, y: 0), (x: 1, y: 0), (x: 2, y: 0)];
+        assert_eq!(line_string.simplify_integer(0), line_string);
+        assert_eq!(line_string.simplify_integer(1).0.len(), 2);
+    }
+
+    #[test]
+    fn simplify_is_exact_for_large_coordinates() {
+        // The middle point is exactly `epsilon` away from the line, and must be culled.
+        let base = 1_i64 << 60;
+        let line_string = line_string![
+            (x: base, y: base),
+            (x: base + 2, y: base + 1),
+            (x: base + 4, y: base),
+        ];
+        assert_eq!(line_string.simplify_integer(1).0.len(), 2);
+        assert_eq!(
+            line_string![(x: 0_i64, y: 0), (x: 2, y: 2), (x: 4, y: 0)]
+                .simplify_integer(1)
+                .0
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn widening_mul_test() {
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul(1 << 64, 1 << 64), (1, 0));
+        assert_eq!(widening_mul(3, 7), (0, 21));
+    }
+
+    #[test]
+    fn existing_generic_algorithms_support_integers() {
+        let poly = wkt!(POLYGON((0 0,4 0,4 1,1 1,1 4,0 4,0 0)));
+        let hull: Polygon<i64> = poly.convex_hull();
+        assert_eq!(hull.exterior().0.len(), 6);
+        assert!(poly.intersects(&coord! { x: 1_i64, y: 3 }));
+        assert!(!poly.intersects(&coord! { x: 2_i64, y: 3 }));
+    }
+}
@@ -12,7 +12,7 @@ use crate::algorithm::{
 };
 use crate::geometry::*;
 use crate::sweep::{Intersections, SweepPoint};
-use crate::GeoFloat;
+use crate::{CoordNum, GeoError, GeoFloat};
 
 /// Calculation of interior points.
 
@@ -75,6 +75,17 @@ pub trait InteriorPoint {
     /// );
     /// ```
     fn interior_point(&self) -> Self::Output;
+
+    /// Like [`Self::interior_point`], but returns an error instead of `None` for an empty
+    /// geometry, or of panicking for one with a NaN or infinite coordinate.
+    fn try_interior_point<T: CoordNum>(&self) -> Result<Point<T>, GeoError>
+    where
+        Self: CoordsIter<Scalar = T>,
+        Self::Output: Into<Option<Point<T>>>,
+    {
+        GeoError::check(self)?;
+        self.interior_point().into().ok_or(GeoError::Empty)
+    }
 }
 
 impl<T> InteriorPoint for Line<T>
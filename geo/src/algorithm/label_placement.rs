@@ -0,0 +1,249 @@
+use crate::geometry::{Coord, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+use crate::{BoundingRect, CoordNum, GeoFloat};
+
+/// Where to place a label along a line, or inside a polygon, from
+/// [`LabelPlacement::label_placement`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Label<T: CoordNum> {
+    /// The center of the label.
+    pub anchor: Point<T>,
+    /// The direction of the label's baseline, in degrees counter-clockwise from the x axis,
+    /// between -90 and 90 so that the text is upright.
+    pub angle: T,
+    /// The length available to the label, centered on the anchor.
+    pub length: T,
+}
+
+/// The largest turn between consecutive segments of a line which a label can follow, in
+/// degrees.
+const MAX_TURN: f64 = 20.;
+
+/// The largest number of horizontal lines along which polygons are scanned for room.
+const MAX_SCANLINES: usize = 64;
+
+/// Find a good placement for a label, ready to be drawn by a renderer.
+///
+/// - Along a line string, the label follows the longest part which turns by at most 20° at each
+///   vertex, centered halfway along it, and in the direction from its start to its end.
+/// - Inside a polygon, the label is horizontal, centered on the widest horizontal segment through
+///   its interior, which is found by scanning halfway between the heights of its vertices, along
+///   at most 64 horizontal lines.
+///
+/// Multi-geometries are labelled at their member with the most room. Returns `None` if there's
+/// no room, e.g. for an empty geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, LabelPlacement};
+///
+/// // a road with a sharp bend, labelled along its longest straight part
+/// let road = wkt!(LINESTRING(0. 0.,2. 0.,2. 10.,2.5 20.0f64));
+/// let label = road.label_placement().unwrap();
+/// assert_eq!(label.anchor.y().round(), 10.);
+/// assert_eq!(label.angle.round(), 89.);
+/// assert_eq!(label.length.round(), 20.);
+///
+/// // an L-shaped building, labelled across its wide base
+/// let building = wkt!(POLYGON((0. 0.,10. 0.,10. 2.,2. 2.,2. 10.,0. 10.,0. 0.)));
+/// let label = building.label_placement().unwrap();
+/// assert_eq!(label.anchor, point!(x: 5., y: 1.));
+/// assert_eq!((label.angle, label.length), (0., 10.));
+/// ```
+pub trait LabelPlacement<T: GeoFloat> {
+    fn label_placement(&self) -> Option<Label<T>>;
+}
+
+impl<T: GeoFloat> LabelPlacement<T> for LineString<T> {
+    fn label_placement(&self) -> Option<Label<T>> {
+        let max_turn = T::from(MAX_TURN.to_radians()).unwrap();
+        let segments: Vec<(Coord<T>, Coord<T>)> = self
+            .lines()
+            .filter(|line| line.start != line.end)
+            .map(|line| (line.start, line.end))
+            .collect();
+        let direction =
+            |(start, end): (Coord<T>, Coord<T>)| (end.y - start.y).atan2(end.x - start.x);
+        let length = |(start, end): (Coord<T>, Coord<T>)| (end - start).x.hypot((end - start).y);
+
+        // the longest run of segments without a sharp turn
+        let mut best: Option<(std::ops::Range<usize>, T)> = None;
+        let mut run_start = 0;
+        let mut run_length = T::zero();
+        for (index, &segment) in segments.iter().enumerate() {
+            if index > 0 {
+                let pi = T::from(std::f64::consts::PI).unwrap();
+                let mut turn = (direction(segment) - direction(segments[index - 1])).abs();
+                if turn > pi {
+                    turn = pi + pi - turn;
+                }
+                if turn > max_turn {
+                    run_start = index;
+                    run_length = T::zero();
+                }
+            }
+            run_length = run_length + length(segment);
+            if best
+                .as_ref()
+                .map_or(true, |(_, longest)| run_length > *longest)
+            {
+                best = Some((run_start..index + 1, run_length));
+            }
+        }
+        let (run, run_length) = best?;
+
+        let half = run_length / T::from(2).unwrap();
+        let mut walked = T::zero();
+        let mut anchor = segments[run.end - 1].1;
+        for &segment in &segments[run.clone()] {
+            let segment_length = length(segment);
+            if walked + segment_length >= half {
+                let ratio = (half - walked) / segment_length;
+                anchor = segment.0 + (segment.1 - segment.0) * ratio;
+                break;
+            }
+            walked = walked + segment_length;
+        }
+        let chord = (segments[run.start].0, segments[run.end - 1].1);
+        Some(Label {
+            anchor: anchor.into(),
+            angle: upright(direction(chord).to_degrees()),
+            length: run_length,
+        })
+    }
+}
+
+impl<T: GeoFloat> LabelPlacement<T> for MultiLineString<T> {
+    fn label_placement(&self) -> Option<Label<T>> {
+        roomiest(self.iter().filter_map(LineString::label_placement))
+    }
+}
+
+impl<T: GeoFloat> LabelPlacement<T> for Polygon<T> {
+    fn label_placement(&self) -> Option<Label<T>> {
+        let bounds = self.bounding_rect()?;
+        let rings = || std::iter::once(self.exterior()).chain(self.interiors());
+        let mut ys: Vec<T> = rings()
+            .flat_map(|ring| ring.coords().map(|c| c.y))
+            .collect();
+        ys.sort_by(|a, b| a.total_cmp(b));
+        ys.dedup();
+        // the widths of the interior change linearly between the y of the vertices, so scan
+        // halfway between each pair of them, or evenly if there are too many
+        let two = T::from(2).unwrap();
+        let scanlines: Vec<T> = if ys.len() <= MAX_SCANLINES + 1 {
+            ys.windows(2)
+                .map(|pair| (pair[0] + pair[1]) / two)
+                .collect()
+        } else {
+            let step = bounds.height() / T::from(MAX_SCANLINES).unwrap();
+            (0..MAX_SCANLINES)
+                .map(|line| {
+                    bounds.min().y + step * (T::from(line).unwrap() + T::from(0.5).unwrap())
+                })
+                .collect()
+        };
+
+        let mut best: Option<Label<T>> = None;
+        let mut crossings = Vec::new();
+        for y in scanlines {
+            crossings.clear();
+            for segment in rings().flat_map(|ring| ring.lines()) {
+                let (start, end) = (segment.start, segment.end);
+                // half-open, so that a line through a vertex crosses one of its segments
+                if (start.y <= y) != (end.y <= y) {
+                    crossings.push(start.x + (y - start.y) / (end.y - start.y) * (end.x - start.x));
+                }
+            }
+            crossings.sort_by(|a, b| a.total_cmp(b));
+            for pair in crossings.chunks_exact(2) {
+                let width = pair[1] - pair[0];
+                if best.map_or(true, |best| width > best.length) {
+                    best = Some(Label {
+                        anchor: Point::new((pair[0] + pair[1]) / two, y),
+                        angle: T::zero(),
+                        length: width,
+                    });
+                }
+            }
+        }
+        best
+    }
+}
+
+impl<T: GeoFloat> LabelPlacement<T> for MultiPolygon<T> {
+    fn label_placement(&self) -> Option<Label<T>> {
+        roomiest(self.iter().filter_map(Polygon::label_placement))
+    }
+}
+
+/// The label with the greatest length, or the first of them.
+fn roomiest<T: GeoFloat>(labels: impl Iterator<Item = Label<T>>) -> Option<Label<T>> {
+    labels.fold(None, |best, label| match best {
+        Some(best) if best.length >= label.length => Some(best),
+        _ => Some(label),
+    })
+}
+
+/// An angle in degrees turned by half a turn if needed, so that it's between -90 and 90.
+fn upright<T: GeoFloat>(angle: T) -> T {
+    let (quarter, half) = (T::from(90).unwrap(), T::from(180).unwrap());
+    if angle > quarter {
+        angle - half
+    } else if angle <= -quarter {
+        angle + half
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    #[test]
+    fn line_strings() {
+        // drawn leftwards, but labelled upright
+        let line_string = wkt!(LINESTRING(4. 4.,2. 2.,0. 0.));
+        let label = line_string.label_placement().unwrap();
+        assert_eq!(label.anchor, point!(x: 2., y: 2.));
+        assert_relative_eq!(label.angle, 45.);
+        assert_relative_eq!(label.length, 32f64.sqrt());
+
+        // gentle curves are followed, and repeated points ignored
+        let line_string = wkt!(LINESTRING(0. 0.,0. 0.,10. 0.,20. 1.,30. 3.,30. 10.));
+        let label = line_string.label_placement().unwrap();
+        assert_relative_eq!(label.anchor.x(), 15., epsilon = 0.1);
+        assert_relative_eq!(label.angle, 0.1f64.atan().to_degrees(), epsilon = 1e-9);
+
+        let multi = MultiLineString::new(vec![wkt!(LINESTRING(0. 0.,1. 0.)), line_string]);
+        assert_eq!(multi.label_placement(), Some(label));
+        assert_eq!(wkt!(LINESTRING(1. 1.,1. 1.)).label_placement(), None);
+        assert_eq!(LineString::<f64>::new(vec![]).label_placement(), None);
+    }
+
+    #[test]
+    fn polygons() {
+        // a wide hole leaves more room beside it than above it
+        let polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (1. 1.,2. 1.,2. 9.,1. 9.,1. 1.)
+        ));
+        let label = polygon.label_placement().unwrap();
+        assert_eq!(label.length, 10.);
+        assert_eq!(label.anchor.x(), 5.);
+        assert!(label.anchor.y() < 1. || label.anchor.y() > 9.);
+
+        // more room in the second polygon
+        let multi = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,1. 1.,0. 0.)),
+            ((5. 0.,8. 0.,8. 3.,5. 3.,5. 0.))
+        ));
+        let label = multi.label_placement().unwrap();
+        assert_eq!(label.anchor, point!(x: 6.5, y: 1.5));
+        assert_eq!(label.length, 3.);
+
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).label_placement(), None);
+    }
+}
@@ -0,0 +1,173 @@
+use super::metric_spaces::{Euclidean, Haversine};
+use crate::utils::progress_chunks;
+use crate::{Coord, MEAN_EARTH_RADIUS};
+
+/// Calculate the distances from one coordinate to many at once.
+///
+/// Work which only depends on the origin, e.g. the cosine of its latitude for [`Haversine`], is
+/// done once per batch rather than once per distance, e.g. when scanning the candidates for a
+/// nearest neighbor.
+///
+/// Results can differ from [`Distance::distance`](super::Distance::distance) in the last bits,
+/// since the operations are grouped differently.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, BatchDistance, Euclidean};
+///
+/// let origin = coord! { x: 0., y: 0. };
+/// let destinations = [coord! { x: 3., y: 4. }, coord! { x: -1., y: 0. }];
+/// let mut distances = [0.; 2];
+/// Euclidean::batch_distance(origin, &destinations, &mut distances);
+/// assert_eq!(distances, [5., 1.]);
+/// ```
+pub trait BatchDistance {
+    /// Write the distance from `origin` to each of `destinations` into the corresponding element
+    /// of `distances`.
+    ///
+    /// # Panics
+    ///
+    /// If `destinations` and `distances` have different lengths.
+    fn batch_distance(origin: Coord, destinations: &[Coord], distances: &mut [f64]);
+
+    /// Like [`batch_distance`](Self::batch_distance), but calls `progress` with the number of
+    /// distances computed so far and the total after each chunk of destinations, e.g. to show a
+    /// progress bar.
+    ///
+    /// # Panics
+    ///
+    /// If `destinations` and `distances` have different lengths.
+    fn batch_distance_with_progress(
+        origin: Coord,
+        destinations: &[Coord],
+        distances: &mut [f64],
+        mut progress: impl FnMut(usize, usize),
+    ) {
+        assert_eq!(
+            destinations.len(),
+            distances.len(),
+            "expected one distance per destination"
+        );
+        for chunk in progress_chunks(destinations.len()) {
+            let end = chunk.end;
+            Self::batch_distance(origin, &destinations[chunk.clone()], &mut distances[chunk]);
+            progress(end, destinations.len());
+        }
+    }
+}
+
+/// Pair each of `destinations` with the element of `distances` it's written to.
+fn zip_distances<'a>(
+    destinations: &'a [Coord],
+    distances: &'a mut [f64],
+) -> impl Iterator<Item = (&'a Coord, &'a mut f64)> {
+    assert_eq!(
+        destinations.len(),
+        distances.len(),
+        "expected one distance per destination"
+    );
+    destinations.iter().zip(distances)
+}
+
+impl BatchDistance for Euclidean {
+    /// Euclidean distances, in the units of the coordinates.
+    fn batch_distance(origin: Coord, destinations: &[Coord], distances: &mut [f64]) {
+        for (destination, distance) in zip_distances(destinations, distances) {
+            let dx = destination.x - origin.x;
+            let dy = destination.y - origin.y;
+            *distance = (dx * dx + dy * dy).sqrt();
+        }
+    }
+}
+
+impl BatchDistance for Haversine {
+    /// Great circle distances in meters, between longitude/latitude coordinates in degrees.
+    fn batch_distance(origin: Coord, destinations: &[Coord], distances: &mut [f64]) {
+        let origin_lat = origin.y.to_radians();
+        let origin_lat_cos = origin_lat.cos();
+        for (destination, distance) in zip_distances(destinations, distances) {
+            let lat = destination.y.to_radians();
+            let half_dlat_sin = ((lat - origin_lat) / 2.).sin();
+            let half_dlon_sin = ((destination.x - origin.x).to_radians() / 2.).sin();
+            let a = half_dlat_sin * half_dlat_sin
+                + origin_lat_cos * lat.cos() * half_dlon_sin * half_dlon_sin;
+            *distance = 2. * MEAN_EARTH_RADIUS * a.sqrt().asin();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, Distance, Point};
+
+    fn destinations() -> Vec<Coord> {
+        (0..11)
+            .map(|i| {
+                let i = f64::from(i);
+                coord! { x: -170. + 31. * i, y: -80. + 15.3 * i }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn euclidean_matches_distance() {
+        let origin = coord! { x: 1.5, y: -2. };
+        let destinations = destinations();
+        let mut distances = vec![0.; destinations.len()];
+        Euclidean::batch_distance(origin, &destinations, &mut distances);
+        for (destination, distance) in destinations.iter().zip(distances) {
+            assert_relative_eq!(
+                distance,
+                Euclidean::distance(Point(origin), Point(*destination)),
+                max_relative = 1e-14
+            );
+        }
+    }
+
+    #[test]
+    fn haversine_matches_distance() {
+        let origin = coord! { x: 13.4, y: 52.5 };
+        let destinations = destinations();
+        let mut distances = vec![0.; destinations.len()];
+        Haversine::batch_distance(origin, &destinations, &mut distances);
+        for (destination, distance) in destinations.iter().zip(distances) {
+            assert_relative_eq!(
+                distance,
+                Haversine::distance(Point(origin), Point(*destination)),
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        Haversine::batch_distance(coord! { x: 0., y: 0. }, &[], &mut []);
+    }
+
+    #[test]
+    fn progress() {
+        let origin = coord! { x: 0., y: 0. };
+        let destinations = vec![coord! { x: 3., y: 4. }; 150_000];
+        let mut distances = vec![0.; destinations.len()];
+        let mut reports = vec![];
+        Euclidean::batch_distance_with_progress(
+            origin,
+            &destinations,
+            &mut distances,
+            |processed, total| reports.push((processed, total)),
+        );
+        assert!(distances.iter().all(|&distance| distance == 5.));
+        assert_eq!(
+            reports,
+            [(65_536, 150_000), (131_072, 150_000), (150_000, 150_000)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one distance per destination")]
+    fn length_mismatch() {
+        Euclidean::batch_distance(coord! { x: 0., y: 0. }, &[coord! { x: 0., y: 0. }], &mut []);
+    }
+}
@@ -0,0 +1,148 @@
+use super::{Distance, Length};
+use crate::{CoordFloat, LineString, Point};
+
+/// A `LineString` with an elevation for each of its coordinates, e.g. a GPS track.
+///
+/// `geo` geometries are 2D, so elevations are kept alongside the coordinates. Horizontal
+/// distances are measured in a given [metric space], so for longitude/latitude coordinates and
+/// elevations in meters, use e.g. [`Haversine`](crate::Haversine) or
+/// [`Geodesic`](crate::Geodesic).
+///
+/// [metric space]: crate::line_measures::metric_spaces
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, ElevationProfile, Euclidean, Haversine, Length};
+///
+/// let track = wkt!(LINESTRING(0. 0.,30. 40.,60. 80.));
+/// let profile = ElevationProfile::new(track, vec![10., 130., 10.]).unwrap();
+///
+/// assert_eq!(profile.line_string().length::<Euclidean>(), 100.);
+/// assert_eq!(profile.length_3d::<Euclidean>(), 2. * 130.);
+/// assert_eq!(profile.grades::<Euclidean>().collect::<Vec<_>>(), [2.4, -2.4]);
+/// assert_eq!(profile.ascent(), 120.);
+/// assert_eq!(profile.descent(), 120.);
+///
+/// // longitude/latitude coordinates with elevations in meters
+/// let track = wkt!(LINESTRING(6.86 45.83,6.865 45.833));
+/// let profile = ElevationProfile::new(track, vec![4_300f64, 4_808.]).unwrap();
+/// assert_eq!(profile.length_3d::<Haversine>().round(), 721.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationProfile<F: CoordFloat = f64> {
+    line_string: LineString<F>,
+    elevations: Vec<F>,
+}
+
+impl<F: CoordFloat> ElevationProfile<F> {
+    /// Pair each coordinate of `line_string` with the corresponding elevation, or return `None`
+    /// if there isn't exactly one elevation per coordinate.
+    pub fn new(line_string: LineString<F>, elevations: Vec<F>) -> Option<Self> {
+        (line_string.0.len() == elevations.len()).then_some(Self {
+            line_string,
+            elevations,
+        })
+    }
+
+    /// The 2D line string.
+    pub fn line_string(&self) -> &LineString<F> {
+        &self.line_string
+    }
+
+    /// The elevation of each coordinate of the line string.
+    pub fn elevations(&self) -> &[F] {
+        &self.elevations
+    }
+
+    /// Split into the line string and its elevations.
+    pub fn into_parts(self) -> (LineString<F>, Vec<F>) {
+        (self.line_string, self.elevations)
+    }
+
+    /// The horizontal length and the change in elevation of each segment.
+    fn segments<MetricSpace: Distance<F, Point<F>, Point<F>>>(
+        &self,
+    ) -> impl Iterator<Item = (F, F)> + '_ {
+        self.line_string
+            .lines()
+            .zip(self.elevations.windows(2))
+            .map(|(line, elevations)| (line.length::<MetricSpace>(), elevations[1] - elevations[0]))
+    }
+
+    /// The length along the slopes: the sum of the straight-line 3D lengths of the segments, with
+    /// horizontal lengths measured in `MetricSpace`.
+    ///
+    /// This is the distance actually traveled along a track over hilly terrain, which is longer
+    /// than its horizontal [`Length`].
+    pub fn length_3d<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self) -> F {
+        self.segments::<MetricSpace>()
+            .fold(F::zero(), |length, (horizontal, vertical)| {
+                length + horizontal.hypot(vertical)
+            })
+    }
+
+    /// The grade of each segment: its change in elevation divided by its horizontal length in
+    /// `MetricSpace`, which is positive uphill. Multiply by 100 for a percentage.
+    ///
+    /// A segment between two coordinates at the same place has an infinite grade if its
+    /// elevation changes, and a `NaN` grade otherwise.
+    pub fn grades<MetricSpace: Distance<F, Point<F>, Point<F>>>(
+        &self,
+    ) -> impl Iterator<Item = F> + '_ {
+        self.segments::<MetricSpace>()
+            .map(|(horizontal, vertical)| vertical / horizontal)
+    }
+
+    /// The total gain in elevation over all uphill segments.
+    pub fn ascent(&self) -> F {
+        self.elevations
+            .windows(2)
+            .map(|elevations| elevations[1] - elevations[0])
+            .filter(|&change| change > F::zero())
+            .fold(F::zero(), |ascent, change| ascent + change)
+    }
+
+    /// The total loss in elevation over all downhill segments, as a positive number.
+    pub fn descent(&self) -> F {
+        self.elevations
+            .windows(2)
+            .map(|elevations| elevations[0] - elevations[1])
+            .filter(|&change| change > F::zero())
+            .fold(F::zero(), |descent, change| descent + change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, Euclidean, Geodesic};
+
+    #[test]
+    fn flat() {
+        let track = wkt!(LINESTRING(-0.1278 51.5074,2.3522 48.8566));
+        let profile = ElevationProfile::new(track.clone(), vec![50., 50.]).unwrap();
+        assert_eq!(profile.length_3d::<Geodesic>(), track.length::<Geodesic>());
+        assert_eq!(profile.grades::<Geodesic>().collect::<Vec<_>>(), [0.]);
+        assert_eq!((profile.ascent(), profile.descent()), (0., 0.));
+    }
+
+    #[test]
+    fn segments() {
+        let track = wkt!(LINESTRING(0. 0.,3. 4.,3. 4.,6. 8.,6. 8.));
+        let profile = ElevationProfile::new(track, vec![0., 5., 10., 5., 5.]).unwrap();
+        assert_relative_eq!(
+            profile.length_3d::<Euclidean>(),
+            50f64.sqrt() + 5. + 50f64.sqrt()
+        );
+        let grades: Vec<_> = profile.grades::<Euclidean>().collect();
+        assert_eq!(grades[0], 1.);
+        assert_eq!(grades[1], f64::INFINITY);
+        assert_eq!(grades[2], -1.);
+        assert!(grades[3].is_nan());
+        assert_eq!((profile.ascent(), profile.descent()), (10., 5.));
+
+        assert!(ElevationProfile::new(LineString::<f64>::new(vec![]), vec![]).is_some());
+        assert!(ElevationProfile::new(wkt!(LINESTRING(0. 0.,1. 1.)), vec![1.]).is_none());
+    }
+}
@@ -1,15 +1,58 @@
 use super::super::{Bearing, Destination, Distance, InterpolatePoint};
 use crate::Point;
-use geographiclib_rs::{DirectGeodesic, InverseGeodesic};
 
 /// An ellipsoidal model of the earth, using methods given by [Karney (2013)].
 ///
 /// Distances are computed using [geodesic lines] and are measured in meters.
 ///
+/// Without the `geographiclib` feature, e.g. to slim down WebAssembly builds, the methods fall
+/// back to the spherical formulas of [`Haversine`](crate::Haversine), which are off by up to
+/// about 0.6%.
+///
 /// [geodesic lines]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
 /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
 pub struct Geodesic;
 
+/// The length in meters of the geodesic from `origin` to `destination`, and its azimuth at
+/// `origin` in degrees, from -180° to 180°.
+#[cfg(feature = "geographiclib")]
+pub(crate) fn inverse(origin: Point<f64>, destination: Point<f64>) -> (f64, f64) {
+    use geographiclib_rs::InverseGeodesic;
+    let (distance, azi1, _azi2, _a12) = geographiclib_rs::Geodesic::wgs84().inverse(
+        origin.y(),
+        origin.x(),
+        destination.y(),
+        destination.x(),
+    );
+    (distance, azi1)
+}
+
+#[cfg(not(feature = "geographiclib"))]
+pub(crate) fn inverse(origin: Point<f64>, destination: Point<f64>) -> (f64, f64) {
+    use crate::Haversine;
+    let bearing = Haversine::bearing(origin, destination);
+    let azimuth = if bearing > 180.0 {
+        bearing - 360.0
+    } else {
+        bearing
+    };
+    (Haversine::distance(origin, destination), azimuth)
+}
+
+/// The point `distance` meters from `origin` along the geodesic with azimuth `bearing`.
+#[cfg(feature = "geographiclib")]
+pub(crate) fn direct(origin: Point<f64>, bearing: f64, distance: f64) -> Point<f64> {
+    use geographiclib_rs::DirectGeodesic;
+    let (lat, lon) =
+        geographiclib_rs::Geodesic::wgs84().direct(origin.y(), origin.x(), bearing, distance);
+    Point::new(lon, lat)
+}
+
+#[cfg(not(feature = "geographiclib"))]
+pub(crate) fn direct(origin: Point<f64>, bearing: f64, distance: f64) -> Point<f64> {
+    crate::Haversine::destination(origin, bearing, distance)
+}
+
 impl Bearing<f64> for Geodesic {
     /// Returns the bearing from `origin` to `destination` in degrees along a [geodesic line].
     ///
@@ -37,12 +80,7 @@ impl Bearing<f64> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn bearing(origin: Point<f64>, destination: Point<f64>) -> f64 {
-        let (azi1, _, _) = geographiclib_rs::Geodesic::wgs84().inverse(
-            origin.y(),
-            origin.x(),
-            destination.y(),
-            destination.x(),
-        );
+        let (_, azi1) = inverse(origin, destination);
         (azi1 + 360.0) % 360.0
     }
 }
@@ -82,9 +120,7 @@ impl Destination<f64> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn destination(origin: Point<f64>, bearing: f64, distance: f64) -> Point<f64> {
-        let (lat, lon) =
-            geographiclib_rs::Geodesic::wgs84().direct(origin.y(), origin.x(), bearing, distance);
-        Point::new(lon, lat)
+        direct(origin, bearing, distance)
     }
 }
 
@@ -121,12 +157,7 @@ impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn distance(origin: Point<f64>, destination: Point<f64>) -> f64 {
-        geographiclib_rs::Geodesic::wgs84().inverse(
-            origin.y(),
-            origin.x(),
-            destination.y(),
-            destination.x(),
-        )
+        inverse(origin, destination).0
     }
 }
 
@@ -215,8 +246,7 @@ impl InterpolatePoint<f64> for Geodesic {
             return end;
         }
 
-        let g = geographiclib_rs::Geodesic::wgs84();
-        let (total_distance, azi1, _azi2, _a12) = g.inverse(start.y(), start.x(), end.y(), end.x());
+        let (total_distance, azi1) = inverse(start, end);
         let distance = total_distance * ratio_from_start;
         Self::destination(start, azi1, distance)
     }
@@ -241,8 +271,7 @@ impl InterpolatePoint<f64> for Geodesic {
         max_distance: f64,
         include_ends: bool,
     ) -> impl Iterator<Item = Point<f64>> {
-        let g = geographiclib_rs::Geodesic::wgs84();
-        let (total_distance, azi1, _azi2, _a12) = g.inverse(start.y(), start.x(), end.y(), end.x());
+        let (total_distance, azi1) = inverse(start, end);
 
         if total_distance <= max_distance {
             return if include_ends {
@@ -259,9 +288,7 @@ impl InterpolatePoint<f64> for Geodesic {
         let mut points = if include_ends { vec![start] } else { vec![] };
 
         while current_step < 1.0 {
-            let (lat2, lon2) = g.direct(start.y(), start.x(), azi1, total_distance * current_step);
-            let point = Point::new(lon2, lat2);
-            points.push(point);
+            points.push(direct(start, azi1, total_distance * current_step));
             current_step += interval;
         }
 
@@ -273,7 +300,8 @@ impl InterpolatePoint<f64> for Geodesic {
     }
 }
 
-#[cfg(test)]
+// the expected values are those of the ellipsoidal model
+#[cfg(all(test, feature = "geographiclib"))]
 mod tests {
     use super::*;
 
@@ -408,3 +436,33 @@ mod tests {
         }
     }
 }
+
+// without geographiclib, the results are those of a sphere, within 0.6% of the ellipsoidal ones
+#[cfg(all(test, not(feature = "geographiclib")))]
+mod spherical_tests {
+    use super::*;
+    use crate::{line_string, Length};
+
+    #[test]
+    fn distance_and_length() {
+        let new_york_city = Point::new(-74.006f64, 40.7128f64);
+        let london = Point::new(-0.1278f64, 51.5074f64);
+        let distance = Geodesic::distance(new_york_city, london);
+        assert_relative_eq!(5_585_234.0, distance, max_relative = 0.006);
+
+        let line_string = line_string![new_york_city.0, london.0, new_york_city.0];
+        assert_relative_eq!(2.0 * distance, line_string.length::<Geodesic>());
+    }
+
+    #[test]
+    fn destination() {
+        let origin = Point::new(0.0, 0.0);
+        let north = Geodesic::destination(origin, 0.0, 100_000.0);
+        assert_relative_eq!(0.9043687229127633, north.y(), max_relative = 0.006);
+        let east = Geodesic::destination(origin, 90.0, 100_000.0);
+        assert_relative_eq!(0.8983152841195217, east.x(), max_relative = 0.006);
+
+        assert_relative_eq!(90.0, Geodesic::bearing(origin, east), epsilon = 1e-9);
+        assert_relative_eq!(100_000.0, Geodesic::distance(origin, east), epsilon = 1e-6);
+    }
+}
@@ -1,7 +1,7 @@
 mod euclidean;
 pub use euclidean::Euclidean;
 
-mod geodesic;
+pub(crate) mod geodesic;
 pub use geodesic::Geodesic;
 
 mod haversine;
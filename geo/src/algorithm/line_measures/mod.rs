@@ -9,6 +9,12 @@ pub use destination::Destination;
 mod distance;
 pub use distance::Distance;
 
+mod batch_distance;
+pub use batch_distance::BatchDistance;
+
+mod nearest_join;
+pub use nearest_join::NearestJoin;
+
 mod interpolate_point;
 pub use interpolate_point::InterpolatePoint;
 
@@ -18,5 +24,8 @@ pub use length::Length;
 mod densify;
 pub use densify::Densify;
 
+mod elevation_profile;
+pub use elevation_profile::ElevationProfile;
+
 pub mod metric_spaces;
 pub use metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
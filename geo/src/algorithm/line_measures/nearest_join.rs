@@ -0,0 +1,178 @@
+use num_traits::FromPrimitive;
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeNum};
+
+use super::metric_spaces::{Euclidean, Haversine};
+use super::Distance;
+use crate::utils::map_members;
+use crate::{GeoFloat, Point};
+
+/// Find the nearest of one set of points to each of another, the "closest facility" of e.g. each
+/// address among a set of hospitals.
+///
+/// The `right` points are indexed in an R-tree, so joins take `O((n + m) log m)` time rather
+/// than the `O(n m)` of comparing every pair. With the `parallel` feature, large sets of `left`
+/// points are searched in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Euclidean, Haversine, NearestJoin};
+///
+/// let addresses = [point!(x: 0., y: 0.), point!(x: 9., y: 1.), point!(x: 100., y: 0.)];
+/// let hospitals = [point!(x: 10., y: 1.), point!(x: 1., y: 0.)];
+/// assert_eq!(
+///     Euclidean::nearest_join(&addresses, &hospitals, Some(10.)),
+///     [Some((1, 1.)), Some((0, 1.)), None]
+/// );
+///
+/// // across the antimeridian
+/// let (index, meters): (usize, f64) = Haversine::nearest_join(
+///     &[point!(x: 179.9, y: 0.)],
+///     &[point!(x: 170., y: 0.), point!(x: -179.9, y: 0.)],
+///     None,
+/// )[0]
+/// .unwrap();
+/// assert_eq!(index, 1);
+/// assert_eq!(meters.round(), 22_239.);
+/// ```
+pub trait NearestJoin<F: GeoFloat> {
+    /// The index of the nearest of the `right` points to each of the `left` points, and the
+    /// distance to it, in the order of `left`.
+    ///
+    /// A result is `None` if `right` is empty, or if the nearest point is more than
+    /// `max_distance` away. Ties are broken arbitrarily.
+    fn nearest_join(
+        left: &[Point<F>],
+        right: &[Point<F>],
+        max_distance: Option<F>,
+    ) -> Vec<Option<(usize, F)>>;
+}
+
+impl<F: GeoFloat + RTreeNum + Send + Sync> NearestJoin<F> for Euclidean {
+    /// Distances are in the units of the coordinates.
+    fn nearest_join(
+        left: &[Point<F>],
+        right: &[Point<F>],
+        max_distance: Option<F>,
+    ) -> Vec<Option<(usize, F)>> {
+        let tree = RTree::bulk_load(
+            right
+                .iter()
+                .enumerate()
+                .map(|(index, point)| GeomWithData::new([point.x(), point.y()], index))
+                .collect(),
+        );
+        map_members(left, |point| {
+            let nearest = tree.nearest_neighbor(&[point.x(), point.y()])?;
+            let distance = Euclidean::distance(*point, right[nearest.data]);
+            within(nearest.data, distance, max_distance)
+        })
+    }
+}
+
+impl<F: GeoFloat + RTreeNum + FromPrimitive + Send + Sync> NearestJoin<F> for Haversine {
+    /// Longitudes and latitudes are in degrees, and distances are in meters.
+    fn nearest_join(
+        left: &[Point<F>],
+        right: &[Point<F>],
+        max_distance: Option<F>,
+    ) -> Vec<Option<(usize, F)>> {
+        // the straight line distance between points on the unit sphere increases with the great
+        // circle distance, so it finds the same nearest points, and isn't bothered by the
+        // antimeridian or the poles
+        let tree = RTree::bulk_load(
+            right
+                .iter()
+                .enumerate()
+                .map(|(index, point)| GeomWithData::new(unit_vector(*point), index))
+                .collect(),
+        );
+        map_members(left, |point| {
+            let nearest = tree.nearest_neighbor(&unit_vector(*point))?;
+            let distance = Haversine::distance(*point, right[nearest.data]);
+            within(nearest.data, distance, max_distance)
+        })
+    }
+}
+
+/// The point on the unit sphere at a longitude and latitude in degrees.
+fn unit_vector<F: GeoFloat>(point: Point<F>) -> [F; 3] {
+    let (lon_sin, lon_cos) = point.x().to_radians().sin_cos();
+    let (lat_sin, lat_cos) = point.y().to_radians().sin_cos();
+    [lat_cos * lon_cos, lat_cos * lon_sin, lat_sin]
+}
+
+fn within<F: GeoFloat>(index: usize, distance: F, max_distance: Option<F>) -> Option<(usize, F)> {
+    match max_distance {
+        Some(max_distance) if distance > max_distance => None,
+        _ => Some((index, distance)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testkit::{uniform_point, world_point};
+    use crate::{coord, point, Rect};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// The nearest of `right` to each of `left`, by comparing every pair.
+    fn brute_force<M: Distance<f64, Point, Point>>(
+        left: &[Point],
+        right: &[Point],
+    ) -> Vec<Option<(usize, f64)>> {
+        left.iter()
+            .map(|&a| {
+                right
+                    .iter()
+                    .map(|&b| M::distance(a, b))
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn euclidean() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+        let mut points = |count| -> Vec<Point> {
+            (0..count)
+                .map(|_| uniform_point(&mut rng, bounds).into())
+                .collect()
+        };
+        let (left, right) = (points(200), points(50));
+        let joined = Euclidean::nearest_join(&left, &right, None);
+        assert_eq!(joined, brute_force::<Euclidean>(&left, &right));
+
+        let cutoff = Euclidean::nearest_join(&left, &right, Some(5.));
+        for (joined, cutoff) in joined.into_iter().zip(cutoff) {
+            let (_, distance) = joined.unwrap();
+            assert_eq!(cutoff, joined.filter(|_| distance <= 5.));
+        }
+    }
+
+    #[test]
+    fn haversine() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let left: Vec<_> = (0..200).map(|_| world_point(&mut rng)).collect();
+        let right: Vec<_> = (0..50).map(|_| world_point(&mut rng)).collect();
+        let joined = Haversine::nearest_join(&left, &right, None);
+        let expected = brute_force::<Haversine>(&left, &right);
+        for ((point, joined), expected) in left.iter().zip(joined).zip(expected) {
+            // ties may be broken differently, so compare distances rather than indices
+            let ((index, distance), (_, expected)) = (joined.unwrap(), expected.unwrap());
+            assert_relative_eq!(distance, expected, max_relative = 1e-9);
+            assert_eq!(distance, Haversine::distance(*point, right[index]));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let left = [point!(x: 0., y: 0.)];
+        assert_eq!(Euclidean::nearest_join(&left, &[], None), [None]);
+        assert_eq!(Haversine::nearest_join(&[], &left, Some(1.)), []);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{Centroid, Coord, MapCoords, Point};
+
+/// The most extreme latitude used as the origin, since the formulas for the oblique aspect
+/// break down at the poles. The projection is still equal-area, and distortion near the pole is
+/// negligible for geometries small enough to be projected locally.
+const MAX_ORIGIN_LATITUDE: f64 = 89.999;
+
+/// A [Lambert azimuthal equal-area] projection on the WGS 84 ellipsoid, centered on a given
+/// longitude/latitude.
+///
+/// Planar algorithms give wrong results on longitude/latitude coordinates, since a degree of
+/// longitude gets shorter away from the equator: buffering, simplifying or measuring in degrees
+/// isn't meaningful. Projecting a geometry about its own centroid, running the planar algorithm
+/// in meters, and projecting the result back avoids this without having to pick a suitable
+/// projection by hand. The projection preserves area exactly, and distances are accurate to
+/// within 0.1% up to about 250 km from the origin.
+///
+/// [Lambert azimuthal equal-area]: https://en.wikipedia.org/wiki/Lambert_azimuthal_equal-area_projection
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, LocalProjection, Simplify};
+///
+/// let river = wkt!(LINESTRING(13.40 52.50,13.41 52.5001,13.42 52.50,13.43 52.51));
+/// let projection = LocalProjection::for_geometry(&river).unwrap();
+///
+/// // simplify with a tolerance of 20 meters, rather than degrees
+/// let simplified = projection.apply(&river, |river| river.simplify(&20.));
+/// assert_eq!(simplified.0.len(), 3);
+///
+/// // the area of a 0.01° square, in square meters
+/// let square = wkt!(POLYGON((13.4 52.5,13.41 52.5,13.41 52.51,13.4 52.51,13.4 52.5)));
+/// let area = LocalProjection::for_geometry(&square).unwrap().project(&square).unsigned_area();
+/// assert!((area - 755_598.).abs() < 1.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalProjection {
+    origin: Coord,
+    /// The radius of the sphere with the same surface area as the ellipsoid
+    authalic_radius: f64,
+    /// A scale factor which makes the projection true to scale at the origin
+    d: f64,
+    /// The sine and cosine of the origin's authalic latitude
+    sin_beta0: f64,
+    cos_beta0: f64,
+    /// The ellipsoid's eccentricity
+    e: f64,
+    q_pole: f64,
+}
+
+impl LocalProjection {
+    /// A projection centered on a longitude/latitude coordinate.
+    pub fn new(origin: Coord) -> Self {
+        let f: f64 = 1. / 298.257_223_563;
+        let e = (f * (2. - f)).sqrt();
+        let a = crate::EQUATORIAL_EARTH_RADIUS;
+        let lat0 = origin
+            .y
+            .clamp(-MAX_ORIGIN_LATITUDE, MAX_ORIGIN_LATITUDE)
+            .to_radians();
+        let q_pole = q(1., e);
+        let authalic_radius = a * (q_pole / 2.).sqrt();
+        let beta0 = (q(lat0.sin(), e) / q_pole).asin();
+        let d = a * lat0.cos()
+            / ((1. - e * e * lat0.sin().powi(2)).sqrt() * authalic_radius * beta0.cos());
+        Self {
+            origin,
+            authalic_radius,
+            d,
+            sin_beta0: beta0.sin(),
+            cos_beta0: beta0.cos(),
+            e,
+            q_pole,
+        }
+    }
+
+    /// A projection centered on the centroid of a longitude/latitude geometry, or `None` if the
+    /// geometry is empty.
+    pub fn for_geometry<G>(geometry: &G) -> Option<Self>
+    where
+        G: Centroid,
+        G::Output: Into<Option<Point>>,
+    {
+        let centroid: Option<Point> = geometry.centroid().into();
+        centroid.map(|centroid| Self::new(centroid.0))
+    }
+
+    /// The longitude/latitude the projection is centered on.
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    /// Project a longitude/latitude geometry to meters from the origin.
+    pub fn project<G: MapCoords<f64, f64>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| self.forward(coord))
+    }
+
+    /// Unproject a geometry in meters from the origin to longitude/latitude.
+    pub fn unproject<G: MapCoords<f64, f64>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| self.inverse(coord))
+    }
+
+    /// Project a longitude/latitude geometry, apply a planar operation to it in meters, and
+    /// unproject the resulting geometry.
+    pub fn apply<G, R>(&self, geometry: &G, operation: impl FnOnce(G::Output) -> R) -> R::Output
+    where
+        G: MapCoords<f64, f64>,
+        R: MapCoords<f64, f64>,
+    {
+        self.unproject(&operation(self.project(geometry)))
+    }
+
+    fn forward(&self, coord: Coord) -> Coord {
+        let dlon = (coord.x - self.origin.x).to_radians();
+        let beta = (q(coord.y.to_radians().sin(), self.e) / self.q_pole)
+            .clamp(-1., 1.)
+            .asin();
+        let (sin_beta, cos_beta) = beta.sin_cos();
+        let b = self.authalic_radius
+            * (2. / (1. + self.sin_beta0 * sin_beta + self.cos_beta0 * cos_beta * dlon.cos()))
+                .sqrt();
+        Coord {
+            x: b * self.d * cos_beta * dlon.sin(),
+            y: b / self.d * (self.cos_beta0 * sin_beta - self.sin_beta0 * cos_beta * dlon.cos()),
+        }
+    }
+
+    fn inverse(&self, Coord { x, y }: Coord) -> Coord {
+        let rho = (x / self.d).hypot(self.d * y);
+        if rho == 0. {
+            return self.origin;
+        }
+        let c = 2. * (rho / (2. * self.authalic_radius)).min(1.).asin();
+        let (sin_c, cos_c) = c.sin_cos();
+        let beta = (cos_c * self.sin_beta0 + self.d * y * sin_c * self.cos_beta0 / rho).asin();
+        let dlon = (x * sin_c).atan2(
+            self.d * rho * self.cos_beta0 * cos_c - self.d * self.d * y * self.sin_beta0 * sin_c,
+        );
+        // authalic to geodetic latitude, by a series in the eccentricity
+        let (e2, e4, e6) = (self.e.powi(2), self.e.powi(4), self.e.powi(6));
+        let lat = beta
+            + (e2 / 3. + 31. * e4 / 180. + 517. * e6 / 5040.) * (2. * beta).sin()
+            + (23. * e4 / 360. + 251. * e6 / 3780.) * (4. * beta).sin()
+            + (761. * e6 / 45360.) * (6. * beta).sin();
+        Coord {
+            x: self.origin.x + dlon.to_degrees(),
+            y: lat.to_degrees(),
+        }
+    }
+}
+
+/// Snyder's `q` for a latitude with sine `sin_lat`, which is proportional to the area between
+/// the equator and that latitude.
+fn q(sin_lat: f64, e: f64) -> f64 {
+    let e_sin = e * sin_lat;
+    (1. - e * e)
+        * (sin_lat / (1. - e_sin * e_sin) - 1. / (2. * e) * ((1. - e_sin) / (1. + e_sin)).ln())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt, Area, Distance, Geodesic, GeodesicArea};
+
+    #[test]
+    fn origin() {
+        let projection = LocalProjection::new(coord! { x: -70., y: -33. });
+        assert_relative_eq!(
+            projection.project(&point!(x: -70., y: -33.)),
+            point!(x: 0., y: 0.),
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            projection.unproject(&point!(x: 0., y: 0.)),
+            point!(x: -70., y: -33.)
+        );
+        assert!(projection.project(&point!(x: -69.9, y: -33.)).x() > 0.);
+        assert!(projection.project(&point!(x: -70., y: -32.9)).y() > 0.);
+    }
+
+    #[test]
+    fn round_trip() {
+        for origin in [(0., 0.), (13.4, 52.5), (-70., -33.), (100., 85.)] {
+            let projection = LocalProjection::new(origin.into());
+            for offset in [(0.5, 0.3), (-2., 1.), (3., -4.)] {
+                let point = Point::from(origin) + Point::from(offset);
+                let round_trip = projection.unproject(&projection.project(&point));
+                assert_relative_eq!(round_trip, point, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn area_and_distance() {
+        let polygon = wkt!(POLYGON((10. 60.,11. 60.,11.5 60.5,10. 60.8,10. 60.)));
+        let projection = LocalProjection::for_geometry(&polygon).unwrap();
+        let projected = projection.project(&polygon);
+        assert_relative_eq!(
+            projected.unsigned_area(),
+            polygon.geodesic_area_unsigned(),
+            max_relative = 1e-4
+        );
+
+        let a = point!(x: 10., y: 60.);
+        let b = point!(x: 11.5, y: 60.5);
+        let planar = projection.project(&a) - projection.project(&b);
+        assert_relative_eq!(
+            planar.x().hypot(planar.y()),
+            Geodesic::distance(a, b),
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn apply() {
+        let line = wkt!(LINESTRING(0. 0.,0. 0.001));
+        let doubled = LocalProjection::for_geometry(&line)
+            .unwrap()
+            .apply(&line, |line| line.map_coords(|c| c * 2.));
+        assert_relative_eq!(
+            doubled,
+            wkt!(LINESTRING(0. -0.0005,0. 0.0015)),
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            LocalProjection::for_geometry(&crate::MultiPoint::<f64>::new(vec![])),
+            None
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::mem::{size_of, size_of_val};
+
+use crate::{
+    ArcGeometry, Coord, CoordNum, Crs, GeoReferenced, Geometry, GeometryCollection, Line,
+    LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Report the number of heap bytes used by a geometry.
+///
+/// This can be used to enforce memory budgets based on the actual size of geometry payloads,
+/// rather than e.g. their coordinate count.
+///
+/// The heap size of a geometry is computed recursively from the capacity of its allocations, so
+/// it includes space which has been reserved but not yet used. The only exception is the
+/// interior rings of a [`Polygon`], whose allocation isn't exposed, so their length is used
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, MemSize};
+/// use std::mem::{size_of, size_of_val};
+///
+/// let mut line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+/// line_string.0.shrink_to_fit();
+/// assert_eq!(line_string.mem_size(), 2 * size_of::<geo::Coord>());
+/// assert_eq!(
+///     line_string.total_mem_size(),
+///     size_of::<geo::LineString>() + 2 * size_of::<geo::Coord>()
+/// );
+/// ```
+pub trait MemSize {
+    /// The number of bytes allocated on the heap by this geometry, excluding
+    /// `size_of_val(self)`.
+    fn mem_size(&self) -> usize;
+
+    /// The number of bytes used by this geometry, including `size_of::<Self>()`.
+    fn total_mem_size(&self) -> usize
+    where
+        Self: Sized,
+    {
+        size_of::<Self>() + self.mem_size()
+    }
+}
+
+macro_rules! zero_mem_size_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<T: CoordNum> MemSize for $type<T> {
+                fn mem_size(&self) -> usize {
+                    0
+                }
+            }
+        )+
+    };
+}
+
+zero_mem_size_impl!(Coord, Point, Line, Rect, Triangle);
+
+impl<G: MemSize> MemSize for [G] {
+    fn mem_size(&self) -> usize {
+        self.iter().map(MemSize::mem_size).sum()
+    }
+}
+
+impl<G: MemSize> MemSize for Vec<G> {
+    fn mem_size(&self) -> usize {
+        self.capacity() * size_of::<G>() + self.as_slice().mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for LineString<T> {
+    fn mem_size(&self) -> usize {
+        self.0.mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for Polygon<T> {
+    fn mem_size(&self) -> usize {
+        self.exterior().mem_size() + size_of_val(self.interiors()) + self.interiors().mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for MultiPoint<T> {
+    fn mem_size(&self) -> usize {
+        self.0.mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for MultiLineString<T> {
+    fn mem_size(&self) -> usize {
+        self.0.mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for MultiPolygon<T> {
+    fn mem_size(&self) -> usize {
+        self.0.mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for GeometryCollection<T> {
+    fn mem_size(&self) -> usize {
+        self.0.mem_size()
+    }
+}
+
+impl<T: CoordNum> MemSize for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn mem_size(&self) -> usize;
+    }
+}
+
+/// The shared allocation is counted in full, so the heap size of several clones of the same
+/// `ArcGeometry` adds up to more than the memory actually used.
+impl<T: CoordNum> MemSize for ArcGeometry<T> {
+    fn mem_size(&self) -> usize {
+        // the strong and weak reference counts are stored alongside the geometry
+        2 * size_of::<usize>() + self.geometry().total_mem_size()
+    }
+}
+
+impl MemSize for Crs {
+    fn mem_size(&self) -> usize {
+        match self {
+            Crs::Epsg(_) => 0,
+            Crs::ProjJson(json) => json.capacity(),
+        }
+    }
+}
+
+impl<G: MemSize> MemSize for GeoReferenced<G> {
+    fn mem_size(&self) -> usize {
+        self.geometry().mem_size() + self.crs().map_or(0, MemSize::mem_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt};
+
+    const COORD: usize = size_of::<Coord>();
+    const LINE_STRING: usize = size_of::<LineString>();
+
+    #[test]
+    fn fixed_size_geometries() {
+        assert_eq!(point!(x: 1., y: 2.).mem_size(), 0);
+        assert_eq!(Rect::new((0., 0.), (1., 1.)).mem_size(), 0);
+        assert_eq!(point!(x: 1., y: 2.).total_mem_size(), size_of::<Point>());
+    }
+
+    #[test]
+    fn line_string_uses_capacity() {
+        let mut line_string: LineString = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        line_string.0.reserve_exact(8);
+        assert_eq!(line_string.mem_size(), line_string.0.capacity() * COORD);
+        assert!(line_string.mem_size() >= 10 * COORD);
+    }
+
+    #[test]
+    fn polygon_counts_rings() {
+        let polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        ];
+        let ring_heap = |ring: &LineString| ring.0.capacity() * COORD;
+        assert_eq!(
+            polygon.mem_size(),
+            ring_heap(polygon.exterior()) + LINE_STRING + ring_heap(&polygon.interiors()[0])
+        );
+    }
+
+    #[test]
+    fn nested_collections() {
+        let polygon: Polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon.clone()]);
+        let expected = multi_polygon.0.capacity() * size_of::<Polygon>() + 2 * polygon.mem_size();
+        assert_eq!(multi_polygon.mem_size(), expected);
+
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::MultiPolygon(multi_polygon.clone()),
+            Geometry::Point(point!(x: 0., y: 0.)),
+        ]);
+        assert_eq!(
+            collection.mem_size(),
+            collection.0.capacity() * size_of::<Geometry>() + expected
+        );
+        assert_eq!(Geometry::MultiPolygon(multi_polygon).mem_size(), expected);
+    }
+
+    #[test]
+    fn arc_geometry_counts_shared_allocation() {
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let shared = ArcGeometry::from(line_string.clone());
+        let expected = 2 * size_of::<usize>() + size_of::<Geometry>() + line_string.mem_size();
+        assert_eq!(shared.mem_size(), expected);
+        assert_eq!(shared.clone().mem_size(), expected);
+    }
+
+    #[test]
+    fn geo_referenced_includes_crs() {
+        let json = r#"{"type": "GeographicCRS"}"#.to_string();
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let tagged = GeoReferenced::new(line_string.clone(), Crs::ProjJson(json.clone()));
+        assert_eq!(tagged.mem_size(), line_string.mem_size() + json.capacity());
+        let tagged = GeoReferenced::new(line_string.clone(), Crs::WGS84);
+        assert_eq!(tagged.mem_size(), line_string.mem_size());
+    }
+}
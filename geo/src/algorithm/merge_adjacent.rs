@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::area::twice_signed_ring_area;
+use crate::geometry::{Coord, LineString, MultiPolygon, Polygon};
+use crate::{BoundingRect, Contains, GeoFloat, Intersects, Winding};
+
+/// Merge polygons which share edges, by removing their shared boundaries.
+///
+/// Unlike a [union](crate::BooleanOps::union), this doesn't compute any intersections: the
+/// output only has the input's vertices, so the outer boundaries are preserved exactly, and it's
+/// faster. It's meant for edge-matched inputs, such as parcel fabrics or the cells of a
+/// partition, where the polygons don't overlap, and each shared boundary has the same vertices
+/// in both polygons. Parts of boundaries which only touch without sharing vertices, e.g. at a
+/// T-junction, are not merged.
+///
+/// Polygons which only share vertices stay separate polygons, and holes which touch their
+/// exterior at a vertex stay holes. The output is the same for the same polygons, in any order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, MergeAdjacent};
+///
+/// let parcels = vec![
+///     wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.))),
+///     wkt!(POLYGON((1. 0.,2. 0.,2. 1.,1. 1.,1. 0.))),
+///     // touches the others at a corner
+///     wkt!(POLYGON((2. 1.,3. 1.,3. 2.,2. 2.,2. 1.))),
+/// ];
+/// let merged = parcels.merge_adjacent();
+/// assert_eq!(
+///     merged,
+///     wkt!(MULTIPOLYGON(
+///         ((0. 0.,1. 0.,2. 0.,2. 1.,1. 1.,0. 1.,0. 0.)),
+///         ((2. 1.,3. 1.,3. 2.,2. 2.,2. 1.))
+///     ))
+/// );
+/// ```
+pub trait MergeAdjacent<T: GeoFloat> {
+    fn merge_adjacent(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> MergeAdjacent<T> for [Polygon<T>] {
+    fn merge_adjacent(&self) -> MultiPolygon<T> {
+        merge(self)
+    }
+}
+
+impl<T: GeoFloat> MergeAdjacent<T> for MultiPolygon<T> {
+    fn merge_adjacent(&self) -> MultiPolygon<T> {
+        merge(&self.0)
+    }
+}
+
+/// A coordinate, ordered so that it can be a map key.
+#[derive(Clone, Copy, PartialEq)]
+struct Key<T: GeoFloat>(Coord<T>);
+
+impl<T: GeoFloat> Eq for Key<T> {}
+
+impl<T: GeoFloat> PartialOrd for Key<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Key<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .x
+            .total_cmp(&other.0.x)
+            .then(self.0.y.total_cmp(&other.0.y))
+    }
+}
+
+impl<T: GeoFloat> Key<T> {
+    fn new(coord: Coord<T>) -> Self {
+        // adding zero turns -0 into 0, so that they're the same key
+        Key(Coord {
+            x: coord.x + T::zero(),
+            y: coord.y + T::zero(),
+        })
+    }
+}
+
+fn merge<T: GeoFloat>(polygons: &[Polygon<T>]) -> MultiPolygon<T> {
+    // the edges of each polygon, with its interior on their left, and the number of times each
+    // appears more often than its reverse, i.e. isn't shared with a neighbour
+    let mut counts: BTreeMap<(Key<T>, Key<T>), usize> = BTreeMap::new();
+    for polygon in polygons {
+        let rings = std::iter::once((polygon.exterior(), true))
+            .chain(polygon.interiors().iter().map(|ring| (ring, false)));
+        for (ring, exterior) in rings {
+            let reverse = exterior == ring.is_cw();
+            for line in ring.lines() {
+                let (start, end) = if reverse {
+                    (Key::new(line.end), Key::new(line.start))
+                } else {
+                    (Key::new(line.start), Key::new(line.end))
+                };
+                if start == end {
+                    continue;
+                }
+                match counts.get_mut(&(end, start)) {
+                    Some(count) if *count > 1 => *count -= 1,
+                    Some(_) => {
+                        counts.remove(&(end, start));
+                    }
+                    None => *counts.entry((start, end)).or_default() += 1,
+                }
+            }
+        }
+    }
+
+    let edges: Vec<(Coord<T>, Coord<T>)> = counts
+        .into_iter()
+        .flat_map(|((start, end), count)| std::iter::repeat((start.0, end.0)).take(count))
+        .collect();
+    let mut outgoing: BTreeMap<Key<T>, Vec<usize>> = BTreeMap::new();
+    for (index, (start, _)) in edges.iter().enumerate() {
+        outgoing.entry(Key(*start)).or_default().push(index);
+    }
+    let angle = |from: Coord<T>, to: Coord<T>| (to.y - from.y).atan2(to.x - from.x);
+    // the edge after `edge` along the boundary of the same face: the first one clockwise from
+    // the way back, so that polygons touching at a vertex stay apart
+    let next = |edge: usize| {
+        let (start, end) = edges[edge];
+        let back = angle(end, start);
+        let turn = |candidate: &usize| {
+            let turn = back - angle(end, edges[*candidate].1);
+            let tau = T::from(std::f64::consts::TAU).unwrap();
+            if turn <= T::zero() {
+                turn + tau
+            } else {
+                turn
+            }
+        };
+        outgoing[&Key(end)]
+            .iter()
+            .min_by(|a, b| turn(a).total_cmp(&turn(b)))
+            .copied()
+            .unwrap()
+    };
+
+    let mut shells = Vec::new();
+    let mut holes = Vec::new();
+    let mut visited = vec![false; edges.len()];
+    for first in 0..edges.len() {
+        if visited[first] {
+            continue;
+        }
+        let mut walk = vec![];
+        let mut edge = first;
+        while !visited[edge] {
+            visited[edge] = true;
+            walk.push(edges[edge].0);
+            edge = next(edge);
+        }
+        for ring in split_walk(walk) {
+            let area = twice_signed_ring_area(&ring);
+            if area > T::zero() {
+                shells.push((Polygon::new(ring, vec![]), area));
+            } else if area < T::zero() {
+                holes.push(ring);
+            }
+        }
+    }
+
+    // each hole belongs to the smallest shell around it; rings aren't empty, so they're bounded
+    let bounds: Vec<_> = shells
+        .iter()
+        .map(|(shell, _)| shell.bounding_rect().unwrap())
+        .collect();
+    let mut interiors = vec![vec![]; shells.len()];
+    for hole in holes {
+        let hole_bounds = hole.bounding_rect().unwrap();
+        let shell = shells
+            .iter()
+            .enumerate()
+            .filter(|(index, (shell, _))| {
+                bounds[*index].intersects(&hole_bounds) && shell.contains(&hole)
+            })
+            .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+            .map(|(index, _)| index);
+        if let Some(shell) = shell {
+            interiors[shell].push(hole);
+        }
+    }
+    shells
+        .into_iter()
+        .zip(interiors)
+        .map(|((shell, _), interiors)| Polygon::new(shell.into_inner().0, interiors))
+        .collect()
+}
+
+/// Splits a closed walk along the boundary of a face into rings which don't repeat vertices,
+/// e.g. an exterior and a hole which touches it.
+fn split_walk<T: GeoFloat>(walk: Vec<Coord<T>>) -> Vec<LineString<T>> {
+    let mut rings = vec![];
+    let mut path: Vec<Coord<T>> = vec![];
+    let mut positions: BTreeMap<Key<T>, usize> = BTreeMap::new();
+    for coord in walk {
+        if let Some(&position) = positions.get(&Key(coord)) {
+            let mut ring: Vec<_> = path.drain(position..).collect();
+            for removed in &ring {
+                positions.remove(&Key(*removed));
+            }
+            ring.push(coord);
+            rings.push(LineString::new(ring));
+        }
+        positions.insert(Key(coord), path.len());
+        path.push(coord);
+    }
+    if !path.is_empty() {
+        path.push(path[0]);
+        rings.push(LineString::new(path));
+    }
+    rings
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area};
+
+    #[test]
+    fn grid() {
+        // a 3 × 3 grid without its center cell, in any order and orientation
+        let mut cells = vec![];
+        for (x, y) in [(0., 0.), (1., 0.), (2., 0.), (0., 1.), (2., 1.)] {
+            cells.push(wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.))));
+            let cell = cells.last_mut().unwrap();
+            cell.exterior_mut(|ring| {
+                for coord in ring.0.iter_mut() {
+                    *coord = *coord + (x, y).into();
+                }
+            });
+        }
+        for (x, y) in [(0., 2.), (1., 2.), (2., 2.)] {
+            let mut cell = wkt!(POLYGON((0. 0.,0. 1.,1. 1.,1. 0.,0. 0.)));
+            cell.exterior_mut(|ring| {
+                for coord in ring.0.iter_mut() {
+                    *coord = *coord + (x, y).into();
+                }
+            });
+            cells.insert(0, cell);
+        }
+        let merged = cells.merge_adjacent();
+        assert_eq!(merged.0.len(), 1);
+        assert_eq!(merged.0[0].interiors().len(), 1);
+        assert_eq!(merged.unsigned_area(), 8.);
+        assert_eq!(merged.0[0].exterior().0.len(), 13);
+        assert!(merged.0[0].exterior().is_ccw());
+        assert!(merged.0[0].interiors()[0].is_cw());
+
+        cells.reverse();
+        assert_eq!(cells.merge_adjacent(), merged);
+    }
+
+    #[test]
+    fn holes() {
+        // a hole touching the exterior of the polygon around it, and an island in the hole
+        let polygons = wkt!(MULTIPOLYGON(
+            ((0. 0.,2. 0.,2. 4.,0. 4.,0. 0.),(1. 0.,1.5 1.,1. 2.,0.5 1.,1. 0.)),
+            ((2. 0.,4. 0.,4. 4.,2. 4.,2. 0.)),
+            ((0.9 0.9,1.1 0.9,1.1 1.1,0.9 0.9))
+        ));
+        let merged = polygons.merge_adjacent();
+        assert_eq!(merged.0.len(), 2);
+        let polygon = merged.iter().find(|p| p.unsigned_area() > 1.).unwrap();
+        assert_eq!(polygon.interiors().len(), 1);
+        assert_eq!(polygon.interiors()[0].0.len(), 5);
+        assert_eq!(merged.unsigned_area(), polygons.unsigned_area());
+    }
+}
@@ -2,7 +2,7 @@ use num_traits::Float;
 
 use crate::{
     algorithm::{centroid::Centroid, rotate::Rotate, BoundingRect, CoordsIter},
-    Area, ConvexHull, CoordFloat, GeoFloat, GeoNum, LinesIter, Polygon,
+    Area, ConvexHull, CoordFloat, GeoError, GeoFloat, GeoNum, LinesIter, Polygon,
 };
 /// Return the minimum bounding rectangle(MBR) of geometry
 /// reference: <https://en.wikipedia.org/wiki/Minimum_bounding_box>
@@ -31,6 +31,18 @@ use crate::{
 pub trait MinimumRotatedRect<T> {
     type Scalar: GeoNum;
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>>;
+
+    /// Like [`Self::minimum_rotated_rect`], but returns an error instead of `None` for an empty
+    /// geometry, or of panicking for one with a NaN or infinite coordinate.
+    fn try_minimum_rotated_rect(
+        &self,
+    ) -> Result<Polygon<<Self as MinimumRotatedRect<T>>::Scalar>, GeoError>
+    where
+        Self: CoordsIter<Scalar = <Self as MinimumRotatedRect<T>>::Scalar>,
+    {
+        GeoError::check(self)?;
+        self.minimum_rotated_rect().ok_or(GeoError::Empty)
+    }
 }
 
 impl<T, G> MinimumRotatedRect<T> for G
@@ -8,12 +8,16 @@ pub use area::Area;
 
 /// Boolean Ops such as union, xor, difference.
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+pub use bool_ops::{BooleanOps, OpType, OverlayMode, RobustOverlay};
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 pub use bounding_rect::BoundingRect;
 
+/// Cache the bounding rectangle of a geometry, to quickly reject predicates.
+pub mod cached_envelope;
+pub use cached_envelope::CachedEnvelope;
+
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 pub mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
@@ -22,6 +26,10 @@ pub use minimum_rotated_rect::MinimumRotatedRect;
 pub mod centroid;
 pub use centroid::Centroid;
 
+/// Compute the area, length and centroid of a geometry from a stream of coordinates.
+pub mod accumulators;
+pub use accumulators::{AreaAccumulator, CentroidAccumulator, LengthAccumulator};
+
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 pub mod chaikin_smoothing;
 pub use chaikin_smoothing::ChaikinSmoothing;
@@ -50,6 +58,18 @@ pub use convert::{Convert, TryConvert};
 pub mod convert_angle_unit;
 pub use convert_angle_unit::{ToDegrees, ToRadians};
 
+/// Convert between longitude/latitude/height and Earth-centered, Earth-fixed coordinates.
+pub mod ecef;
+pub use ecef::Ecef;
+
+/// Extrude polygons into 3D meshes.
+///
+/// Requires the `"earcutr"` feature.
+#[cfg(feature = "earcutr")]
+pub mod extrude;
+#[cfg(feature = "earcutr")]
+pub use extrude::{Extrude, Mesh};
+
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
 pub use convex_hull::ConvexHull;
@@ -166,6 +186,10 @@ pub use intersects::Intersects;
 pub mod is_convex;
 pub use is_convex::IsConvex;
 
+/// Exact area and simplification for geometries with integer coordinates.
+pub mod integer;
+pub use integer::{IntegerArea, IntegerSimplify};
+
 /// Calculate concave hull using k-nearest algorithm
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
@@ -188,7 +212,10 @@ pub use lines_iter::LinesIter;
 
 pub mod line_measures;
 pub use line_measures::metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
-pub use line_measures::{Bearing, Densify, Destination, Distance, InterpolatePoint, Length};
+pub use line_measures::{
+    BatchDistance, Bearing, Densify, Destination, Distance, ElevationProfile, InterpolatePoint,
+    Length, NearestJoin,
+};
 
 /// Split a LineString into n segments
 pub mod linestring_segment;
@@ -198,6 +225,10 @@ pub use linestring_segment::{LineStringSegmentize, LineStringSegmentizeHaversine
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 
+/// Report the heap memory used by a geometry.
+pub mod mem_size;
+pub use mem_size::MemSize;
+
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
 pub use orient::Orient;
@@ -228,7 +259,7 @@ pub use skew::Skew;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 pub mod affine_ops;
-pub use affine_ops::{AffineOps, AffineTransform};
+pub use affine_ops::{AffineOps, AffineTransform, AffineTransform3D};
 
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
@@ -249,6 +280,18 @@ pub mod transform;
 #[cfg(feature = "use-proj")]
 pub use transform::Transform;
 
+/// Swap the x and y coordinates of a `Geometry`.
+pub mod swap_xy;
+pub use swap_xy::SwapXY;
+
+/// Transform a geometry, inserting vertices so that its edges follow their curved images.
+pub mod transform_densified;
+pub use transform_densified::TransformDensified;
+
+/// Chain coordinate transformations into a single pass over the coordinates.
+pub mod transform_pipeline;
+pub use transform_pipeline::TransformPipeline;
+
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
 pub use translate::Translate;
@@ -267,6 +310,18 @@ pub mod triangulate_spade;
 #[cfg(feature = "spade")]
 pub use triangulate_spade::TriangulateSpade;
 
+/// Interpolate elevations from a triangulated irregular network, and drape geometries onto it.
+#[cfg(feature = "spade")]
+pub mod tin;
+#[cfg(feature = "spade")]
+pub use tin::{Drape, Tin};
+
+/// Find the shortest path between two points inside a polygon.
+#[cfg(feature = "spade")]
+pub mod shortest_path;
+#[cfg(feature = "spade")]
+pub use shortest_path::ShortestPath;
+
 /// Vector Operations for 2D coordinates
 mod vector_ops;
 pub use vector_ops::Vector2DOps;
@@ -279,6 +334,22 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Project longitude/latitude geometries to meters about their centroid, for planar operations.
+pub mod local_projection;
+pub use local_projection::LocalProjection;
+
+/// Convert between WGS 84 longitude/latitude and Universal Transverse Mercator zones.
+pub mod utm;
+pub use utm::{Utm, UtmZone};
+
+/// Compute the region of a polygon visible from a point inside it.
+pub mod visibility_polygon;
+pub use visibility_polygon::VisibilityPolygon;
+
+/// Convert between WGS 84 longitude/latitude and Web Mercator.
+pub mod web_mercator;
+pub use web_mercator::WebMercator;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -299,6 +370,52 @@ pub use outlier_detection::OutlierDetection;
 pub mod monotone;
 pub use monotone::{monotone_subdivision, MonoPoly, MonotonicPolygons};
 
+/// Decompose linework into monotone chains for fast repeated intersection tests.
+pub mod monotone_chain;
+pub use monotone_chain::MonotoneChains;
+
+/// A static R-tree, bulk loaded with the Sort-Tile-Recursive algorithm.
+pub mod str_tree;
+pub use str_tree::StrTree;
+
+/// Find which of many polygons contains a point.
+pub mod point_locator;
+pub use point_locator::PointLocator;
+
+/// Build graphs of linear networks for routing.
+pub mod network;
+pub use network::{Isochrone, Network, NetworkEdge, Route, ServiceArea};
+
+/// Order coordinates along the Hilbert and Morton space-filling curves.
+pub mod space_filling_curve;
+pub use space_filling_curve::{
+    CurveGrid, Hilbert, HilbertPartition, Morton, PartitionBalance, SpaceFillingCurve,
+};
+
+/// Snap geometries to integer grids, and delta encode their coordinates.
+pub mod quantize;
+pub use quantize::{DeltaEncode, Dequantize, Quantize};
+
+/// Compare versions of polygons and line strings.
+pub mod diff;
+pub use diff::{Diff, LineStringDiff, PolygonDiff, VertexEdit};
+
+/// Merge edge-matched polygons by removing their shared boundaries.
+pub mod merge_adjacent;
+pub use merge_adjacent::MergeAdjacent;
+
+/// Find where to place labels along lines and inside polygons.
+pub mod label_placement;
+pub use label_placement::{Label, LabelPlacement};
+
+/// Generalize geometries for maps at a given scale.
+pub mod generalize;
+pub use generalize::{Generalize, GeneralizeOptions};
+
+/// Split polygons into pieces with a bounded number of vertices.
+pub mod subdivide;
+pub use subdivide::Subdivide;
+
 /// Rhumb-line-related algorithms and utils
 pub mod rhumb;
 #[allow(deprecated)]
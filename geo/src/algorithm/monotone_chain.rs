@@ -0,0 +1,334 @@
+use std::cmp::Ordering;
+
+use crate::utils::{partial_max, partial_min};
+use crate::{
+    coord, Coord, GeoNum, Intersects, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+    Rect,
+};
+
+/// The linework of a geometry, decomposed into monotone chains for fast intersection tests.
+///
+/// A monotone chain is a run of consecutive segments whose x and y coordinates both change in
+/// only one direction, so the envelope of any sub-run is given by its two end points. Two chains
+/// can therefore be tested for intersection by repeatedly halving them and discarding halves
+/// whose envelopes don't overlap, which needs far fewer segment tests than comparing every
+/// segment of one geometry with every segment of the other.
+///
+/// The envelope of each chain is stored, with the chains sorted by the minimum x of their
+/// envelopes, so only the pairs of chains whose envelopes overlap in x are compared, in a single
+/// sweep along the x axis.
+///
+/// Building the chains is linear in the number of coordinates, plus the sorting of the chains.
+/// Keep a `MonotoneChains` around when the same large geometry is tested against many others, so
+/// the decomposition is only done once.
+///
+/// Only the linework is indexed: a polygon's chains intersect another geometry if its rings do,
+/// not if the geometry lies entirely in the polygon's interior.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Intersects, MonotoneChains};
+///
+/// let coastline = MonotoneChains::from(&wkt!(LINESTRING(0. 0.,1. 1.,2. 0.,3. 1.,4. 0.)));
+/// assert_eq!(coastline.num_chains(), 4);
+///
+/// let river = MonotoneChains::from(&wkt!(LINESTRING(1.5 -1.,1.5 2.)));
+/// let lake = MonotoneChains::from(&wkt!(POLYGON((5. 0.,6. 0.,6. 1.,5. 0.))));
+/// assert!(coastline.intersects(&river));
+/// assert!(!coastline.intersects(&lake));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonotoneChains<T: GeoNum = f64> {
+    coords: Vec<Coord<T>>,
+    /// The chains, sorted by the minimum x of their envelopes
+    chains: Vec<Chain<T>>,
+    bounding_rect: Option<Rect<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Chain<T: GeoNum> {
+    /// The index of the first and last coordinate of the chain
+    span: (usize, usize),
+    envelope: Rect<T>,
+}
+
+impl<T: GeoNum> MonotoneChains<T> {
+    /// Index the segments of each of `line_strings`.
+    pub fn new<'a>(line_strings: impl IntoIterator<Item = &'a LineString<T>>) -> Self
+    where
+        T: 'a,
+    {
+        let mut coords = Vec::new();
+        let mut spans = Vec::new();
+        for line_string in line_strings {
+            if line_string.0.len() < 2 {
+                continue;
+            }
+            let offset = coords.len();
+            coords.extend_from_slice(&line_string.0);
+            let mut start = offset;
+            let mut quadrant = quadrant_of(coords[offset], coords[offset + 1]);
+            for end in offset + 1..coords.len() - 1 {
+                let next = quadrant_of(coords[end], coords[end + 1]);
+                if next != quadrant {
+                    spans.push((start, end));
+                    start = end;
+                    quadrant = next;
+                }
+            }
+            spans.push((start, coords.len() - 1));
+        }
+
+        let mut chains: Vec<_> = spans
+            .into_iter()
+            .map(|span| Chain {
+                span,
+                envelope: envelope(&coords, span),
+            })
+            .collect();
+        chains.sort_by(|a, b| {
+            a.envelope
+                .min()
+                .x
+                .partial_cmp(&b.envelope.min().x)
+                .unwrap_or(Ordering::Equal)
+        });
+        let bounding_rect = chains.iter().map(|chain| chain.envelope).reduce(|a, b| {
+            Rect::new(
+                coord! { x: partial_min(a.min().x, b.min().x), y: partial_min(a.min().y, b.min().y) },
+                coord! { x: partial_max(a.max().x, b.max().x), y: partial_max(a.max().y, b.max().y) },
+            )
+        });
+        Self {
+            coords,
+            chains,
+            bounding_rect,
+        }
+    }
+
+    /// The number of monotone chains.
+    pub fn num_chains(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// The number of segments in all chains.
+    pub fn num_segments(&self) -> usize {
+        self.chains
+            .iter()
+            .map(|chain| chain.span.1 - chain.span.0)
+            .sum()
+    }
+
+    /// The bounding rectangle of all chains, or `None` if there are none.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.bounding_rect
+    }
+
+    /// Whether the sub-chains `a` of `self` and `b` of `other` have an intersecting segment.
+    fn chains_intersect(&self, a: (usize, usize), other: &Self, b: (usize, usize)) -> bool {
+        if !envelope(&self.coords, a).intersects(&envelope(&other.coords, b)) {
+            return false;
+        }
+        let a_len = a.1 - a.0;
+        let b_len = b.1 - b.0;
+        if a_len == 1 && b_len == 1 {
+            let a = Line::new(self.coords[a.0], self.coords[a.1]);
+            let b = Line::new(other.coords[b.0], other.coords[b.1]);
+            return a.intersects(&b);
+        }
+        if a_len >= b_len {
+            let mid = a.0 + a_len / 2;
+            self.chains_intersect((a.0, mid), other, b)
+                || self.chains_intersect((mid, a.1), other, b)
+        } else {
+            let mid = b.0 + b_len / 2;
+            self.chains_intersect(a, other, (b.0, mid))
+                || self.chains_intersect(a, other, (mid, b.1))
+        }
+    }
+}
+
+/// The envelope of the coordinates `start..=end` of a chain, which is spanned by its end points
+/// since the chain is monotone.
+fn envelope<T: GeoNum>(coords: &[Coord<T>], (start, end): (usize, usize)) -> Rect<T> {
+    Rect::new(coords[start], coords[end])
+}
+
+/// The direction of a segment, as one of four quadrants. Segments along an axis are grouped
+/// with the quadrant towards positive x and y, so a run of segments in the same quadrant is
+/// monotone in both x and y.
+fn quadrant_of<T: GeoNum>(start: Coord<T>, end: Coord<T>) -> (bool, bool) {
+    (end.x >= start.x, end.y >= start.y)
+}
+
+impl<'a, T: GeoNum> From<&'a LineString<T>> for MonotoneChains<T> {
+    fn from(line_string: &'a LineString<T>) -> Self {
+        Self::new([line_string])
+    }
+}
+
+impl<'a, T: GeoNum> From<&'a MultiLineString<T>> for MonotoneChains<T> {
+    fn from(multi_line_string: &'a MultiLineString<T>) -> Self {
+        Self::new(multi_line_string)
+    }
+}
+
+impl<'a, T: GeoNum> From<&'a Polygon<T>> for MonotoneChains<T> {
+    fn from(polygon: &'a Polygon<T>) -> Self {
+        Self::new(std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+    }
+}
+
+impl<'a, T: GeoNum> From<&'a MultiPolygon<T>> for MonotoneChains<T> {
+    fn from(multi_polygon: &'a MultiPolygon<T>) -> Self {
+        Self::new(
+            multi_polygon
+                .iter()
+                .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors())),
+        )
+    }
+}
+
+impl<T: GeoNum> Intersects for MonotoneChains<T> {
+    fn intersects(&self, rhs: &Self) -> bool {
+        match (self.bounding_rect, rhs.bounding_rect) {
+            (Some(a), Some(b)) if a.intersects(&b) => {}
+            _ => return false,
+        }
+        let pair_intersects = |a: &Chain<T>, b: &Chain<T>| {
+            a.envelope.intersects(&b.envelope) && self.chains_intersect(a.span, rhs, b.span)
+        };
+
+        // Sweep along the x axis over the chains of both sides, in order of the minimum x of
+        // their envelopes. Each chain is compared with the chains of the other side which started
+        // before it and whose envelopes haven't ended yet, so every pair of chains overlapping in
+        // x is compared once.
+        let (mut a_chains, mut b_chains) =
+            (self.chains.iter().peekable(), rhs.chains.iter().peekable());
+        let (mut a_active, mut b_active): (Vec<&Chain<T>>, Vec<&Chain<T>>) = (vec![], vec![]);
+        loop {
+            let next_is_a = match (a_chains.peek(), b_chains.peek()) {
+                (Some(a), Some(b)) => a.envelope.min().x <= b.envelope.min().x,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => return false,
+            };
+            if next_is_a {
+                let a = a_chains.next().unwrap();
+                b_active.retain(|b| b.envelope.max().x >= a.envelope.min().x);
+                if b_active.iter().any(|b| pair_intersects(a, b)) {
+                    return true;
+                }
+                a_active.push(a);
+            } else {
+                let b = b_chains.next().unwrap();
+                a_active.retain(|a| a.envelope.max().x >= b.envelope.min().x);
+                if a_active.iter().any(|a| pair_intersects(a, b)) {
+                    return true;
+                }
+                b_active.push(b);
+            }
+        }
+    }
+}
+
+impl<T: GeoNum> Intersects<Line<T>> for MonotoneChains<T> {
+    fn intersects(&self, rhs: &Line<T>) -> bool {
+        self.intersects(&MonotoneChains::from(&LineString::from(*rhs)))
+    }
+}
+
+impl<T: GeoNum> Intersects<MonotoneChains<T>> for Line<T> {
+    fn intersects(&self, rhs: &MonotoneChains<T>) -> bool {
+        rhs.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, wkt};
+
+    /// A zig-zag with `n` segments, which decomposes into `n` chains, optionally along a slope so
+    /// that it's a single chain.
+    fn zig_zag(n: usize, slope: f64, offset: f64) -> LineString {
+        (0..=n)
+            .map(|i| {
+                let x = i as f64;
+                coord! { x: x, y: offset + x * slope + if i % 2 == 0 { 0. } else { 0.5 } }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chains() {
+        assert_eq!(MonotoneChains::from(&zig_zag(10, 0., 0.)).num_chains(), 10);
+        assert_eq!(MonotoneChains::from(&zig_zag(10, 1., 0.)).num_chains(), 1);
+        let polygon = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.),(0.5 0.5,0.5 1.,1. 1.,0.5 0.5)));
+        let chains = MonotoneChains::from(&polygon);
+        assert_eq!(chains.num_segments(), 7);
+        assert_eq!(chains.bounding_rect(), Some(Rect::new((0., 0.), (2., 2.))));
+        assert_eq!(MonotoneChains::<f64>::new([]).bounding_rect(), None);
+    }
+
+    #[test]
+    fn matches_line_string_intersects() {
+        let a = zig_zag(200, 0.01, 0.);
+        let chains = MonotoneChains::from(&a);
+        for offset in [-3., -0.5, 0., 0.25, 0.5, 1.9, 2.5, 3.] {
+            for slope in [-0.01, 0., 0.01, 0.02] {
+                let b = zig_zag(150, slope, offset);
+                assert_eq!(
+                    chains.intersects(&MonotoneChains::from(&b)),
+                    a.intersects(&b),
+                    "offset {offset}, slope {slope}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        // many short line strings, listed out of order along the x axis
+        let line_strings = |offset: f64| -> MultiLineString {
+            (0..50)
+                .map(|i| {
+                    let x = f64::from((i * 37) % 50) * 2. + offset;
+                    line_string![(x: x, y: 0.), (x: x + 1., y: 1.), (x: x + 1.5, y: 0.)]
+                })
+                .collect()
+        };
+        let a = line_strings(0.);
+        let chains = MonotoneChains::from(&a);
+        assert_eq!(chains.num_chains(), 100);
+        for offset in [-200., -0.75, 0.4, 0.5, 0.6, 1.2, 99.9, 200.] {
+            let b = line_strings(offset);
+            assert_eq!(
+                chains.intersects(&MonotoneChains::from(&b)),
+                a.intersects(&b),
+                "offset {offset}"
+            );
+        }
+    }
+
+    #[test]
+    fn touching() {
+        let a = MonotoneChains::from(&line_string![(x: 0, y: 0), (x: 2, y: 2)]);
+        let b = MonotoneChains::from(&line_string![(x: 2, y: 2), (x: 4, y: 0)]);
+        let c = MonotoneChains::from(&line_string![(x: 3, y: 2), (x: 4, y: 0)]);
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert!(a.intersects(&Line::new((1, 0), (1, 5))));
+        assert!(!Line::new((5, 0), (5, 5)).intersects(&b));
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty = MonotoneChains::from(&LineString::<f64>::new(vec![]));
+        let point = MonotoneChains::from(&wkt!(LINESTRING(0. 0.)));
+        assert_eq!(empty.num_chains(), 0);
+        assert_eq!(point.num_chains(), 0);
+        assert!(!empty.intersects(&MonotoneChains::from(&zig_zag(3, 0., 0.))));
+    }
+}
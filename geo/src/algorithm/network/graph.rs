@@ -0,0 +1,371 @@
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::{Coord, GeoFloat, Line, LineIntersection, LineString};
+
+/// A graph of nodes connected by edges, built from the linework of a network like roads, rivers
+/// or pipes.
+///
+/// The line strings are split wherever they cross or touch each other, and the end points of the
+/// pieces which are within the snapping tolerance of each other are merged into a single node.
+/// Each piece then becomes an edge between two nodes, and keeps its geometry, which starts and
+/// ends exactly at its nodes.
+///
+/// Nodes and edges are identified by their index in [`Network::nodes`] and [`Network::edges`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, Network};
+///
+/// // two crossing streets, and a dead end whose end almost touches one of them
+/// let network = Network::new(
+///     [
+///         wkt!(LINESTRING(0. 0.,10. 0.)),
+///         wkt!(LINESTRING(5. -5.,5. 5.)),
+///         wkt!(LINESTRING(10.01 0.,15. 5.)),
+///     ],
+///     0.1,
+/// );
+///
+/// assert_eq!(network.nodes().len(), 6);
+/// assert_eq!(network.edges().len(), 5);
+///
+/// let crossing = network.nearest_node(coord! { x: 5., y: 0. }).unwrap();
+/// assert_eq!(network.edges_at(crossing).len(), 4);
+/// assert_eq!(network.connected_components().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Network<T: GeoFloat = f64> {
+    nodes: Vec<Coord<T>>,
+    edges: Vec<NetworkEdge<T>>,
+    /// The edges at each node
+    adjacency: Vec<Vec<usize>>,
+    node_index: RTree<GeomWithData<[T; 2], usize>>,
+}
+
+/// An edge of a [`Network`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkEdge<T: GeoFloat = f64> {
+    /// The node at the start of the edge.
+    pub start: usize,
+    /// The node at the end of the edge.
+    pub end: usize,
+    /// The geometry of the edge, from its start node to its end node.
+    pub geometry: LineString<T>,
+    /// The index of the input line string that the edge is a part of.
+    pub source: usize,
+}
+
+impl<T: GeoFloat> NetworkEdge<T> {
+    /// The node at the other end of the edge from `node`.
+    pub fn other_end(&self, node: usize) -> usize {
+        if node == self.start {
+            self.end
+        } else {
+            self.start
+        }
+    }
+}
+
+/// A segment of an input line string.
+#[derive(Debug, Clone)]
+struct Segment<T: GeoFloat> {
+    line: Line<T>,
+    line_string: usize,
+    index: usize,
+}
+
+impl<T: GeoFloat> Cross for Segment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+impl<T: GeoFloat> Network<T> {
+    /// Build a network from line strings, merging end points which are at most
+    /// `snap_tolerance` apart.
+    pub fn new(line_strings: impl IntoIterator<Item = LineString<T>>, snap_tolerance: T) -> Self {
+        let line_strings: Vec<LineString<T>> = line_strings
+            .into_iter()
+            .map(|mut line_string| {
+                line_string.0.dedup();
+                line_string
+            })
+            .collect();
+
+        // the points where each line string should be split: the index of the segment, the
+        // squared distance from its start, and the point
+        let mut splits: Vec<Vec<(usize, T, Coord<T>)>> = vec![vec![]; line_strings.len()];
+        let segments = line_strings
+            .iter()
+            .enumerate()
+            .flat_map(|(line_string, ls)| {
+                ls.lines().enumerate().map(move |(index, line)| Segment {
+                    line,
+                    line_string,
+                    index,
+                })
+            });
+        for (a, b, intersection) in Intersections::from_iter(segments) {
+            let points = match intersection {
+                LineIntersection::SinglePoint { intersection, .. } => {
+                    // consecutive segments of a line string touch at their shared vertex
+                    if a.line_string == b.line_string
+                        && a.index.abs_diff(b.index) == 1
+                        && (intersection == a.line.start || intersection == a.line.end)
+                    {
+                        continue;
+                    }
+                    [intersection, intersection]
+                }
+                LineIntersection::Collinear { intersection } => {
+                    [intersection.start, intersection.end]
+                }
+            };
+            for segment in [a, b] {
+                for point in points {
+                    let offset = point - segment.line.start;
+                    splits[segment.line_string].push((
+                        segment.index,
+                        offset.x * offset.x + offset.y * offset.y,
+                        point,
+                    ));
+                }
+            }
+        }
+
+        let mut network = Network {
+            nodes: vec![],
+            edges: vec![],
+            adjacency: vec![],
+            node_index: RTree::new(),
+        };
+        for (source, (line_string, mut splits)) in line_strings.iter().zip(splits).enumerate() {
+            splits.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+            let mut splits = splits.into_iter().peekable();
+            let Some(&first) = line_string.0.first() else {
+                continue;
+            };
+            let mut piece = vec![first];
+            for (index, line) in line_string.lines().enumerate() {
+                while let Some((_, _, point)) = splits.next_if(|split| split.0 == index) {
+                    if piece.last() != Some(&point) {
+                        piece.push(point);
+                    }
+                    if piece.len() > 1 {
+                        network.add_edge(
+                            std::mem::replace(&mut piece, vec![point]),
+                            source,
+                            snap_tolerance,
+                        );
+                    }
+                }
+                if piece.last() != Some(&line.end) {
+                    piece.push(line.end);
+                }
+            }
+            if piece.len() > 1 {
+                network.add_edge(piece, source, snap_tolerance);
+            }
+        }
+        network
+    }
+
+    /// Add an edge along `coords`, snapping its ends to nodes.
+    fn add_edge(&mut self, mut coords: Vec<Coord<T>>, source: usize, snap_tolerance: T) {
+        let last = coords.len() - 1;
+        let start = self.snap(coords[0], snap_tolerance);
+        let end = self.snap(coords[last], snap_tolerance);
+        coords[0] = self.nodes[start];
+        coords[last] = self.nodes[end];
+        coords.dedup();
+        if coords.len() < 2 {
+            return;
+        }
+        let edge = self.edges.len();
+        self.adjacency[start].push(edge);
+        if end != start {
+            self.adjacency[end].push(edge);
+        }
+        self.edges.push(NetworkEdge {
+            start,
+            end,
+            geometry: LineString::new(coords),
+            source,
+        });
+    }
+
+    /// The node within `snap_tolerance` of `coord`, which is added if there's none.
+    fn snap(&mut self, coord: Coord<T>, snap_tolerance: T) -> usize {
+        if let Some(node) = self.node_index.nearest_neighbor(&[coord.x, coord.y]) {
+            let offset = coord - self.nodes[node.data];
+            if offset.x * offset.x + offset.y * offset.y <= snap_tolerance * snap_tolerance {
+                return node.data;
+            }
+        }
+        let node = self.nodes.len();
+        self.nodes.push(coord);
+        self.adjacency.push(vec![]);
+        self.node_index
+            .insert(GeomWithData::new([coord.x, coord.y], node));
+        node
+    }
+
+    /// The position of each node.
+    pub fn nodes(&self) -> &[Coord<T>] {
+        &self.nodes
+    }
+
+    /// The edges between the nodes.
+    pub fn edges(&self) -> &[NetworkEdge<T>] {
+        &self.edges
+    }
+
+    /// The edges which start or end at `node`.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such node.
+    pub fn edges_at(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// The edges at `node`, each with the node at its other end.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such node.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency[node]
+            .iter()
+            .map(move |&edge| (edge, self.edges[edge].other_end(node)))
+    }
+
+    /// The node closest to `coord`, or `None` if the network is empty.
+    pub fn nearest_node(&self, coord: Coord<T>) -> Option<usize> {
+        self.node_index
+            .nearest_neighbor(&[coord.x, coord.y])
+            .map(|node| node.data)
+    }
+
+    /// The nodes of each connected part of the network, which can't be reached from the others.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut components = vec![];
+        for start in 0..self.nodes.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut component = vec![];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                component.push(node);
+                for (_, neighbor) in self.neighbors(node) {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Euclidean, Length};
+
+    #[test]
+    fn splits_at_crossings() {
+        let network = Network::new(
+            [
+                wkt!(LINESTRING(0. 0.,10. 0.,10. 10.)),
+                wkt!(LINESTRING(5. -5.,5. 5.,15. 5.)),
+            ],
+            0.,
+        );
+        // the line strings cross twice
+        assert_eq!(network.nodes().len(), 6);
+        assert_eq!(network.edges().len(), 6);
+        let total: f64 = network
+            .edges()
+            .iter()
+            .map(|edge| edge.geometry.length::<Euclidean>())
+            .sum();
+        assert_relative_eq!(total, 40.);
+        for edge in network.edges() {
+            assert_eq!(edge.geometry.0[0], network.nodes()[edge.start]);
+            assert_eq!(edge.geometry.0.last(), Some(&network.nodes()[edge.end]));
+        }
+        let crossing = network.nearest_node(coord! { x: 10., y: 5. }).unwrap();
+        assert_eq!(network.nodes()[crossing], coord! { x: 10., y: 5. });
+        // vertices aren't nodes, so both line strings lead back to the other crossing
+        let mut neighbors: Vec<_> = network
+            .neighbors(crossing)
+            .map(|(_, node)| network.nodes()[node])
+            .collect();
+        neighbors.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        assert_eq!(
+            neighbors,
+            [
+                coord! { x: 5., y: 0. },
+                coord! { x: 5., y: 0. },
+                coord! { x: 10., y: 10. },
+                coord! { x: 15., y: 5. },
+            ]
+        );
+    }
+
+    #[test]
+    fn junctions() {
+        // a T junction at a vertex of one line string and in the middle of a segment of
+        // the other
+        let network = Network::new(
+            [
+                wkt!(LINESTRING(0. 0.,4. 0.,8. 0.)),
+                wkt!(LINESTRING(4. 0.,4. 4.)),
+                wkt!(LINESTRING(2. 4.,2. 0.)),
+            ],
+            0.,
+        );
+        assert_eq!(network.nodes().len(), 6);
+        assert_eq!(network.edges().len(), 5);
+        assert_eq!(network.connected_components(), [(0..6).collect::<Vec<_>>()]);
+        assert!(network
+            .edges()
+            .iter()
+            .all(|edge| edge.geometry.0.len() == 2));
+    }
+
+    #[test]
+    fn snapping() {
+        let line_strings = [
+            wkt!(LINESTRING(0. 0.,1. 0.)),
+            wkt!(LINESTRING(1.05 0.,2. 0.)),
+            wkt!(LINESTRING(5. 5.,6. 5.,6. 6.,5. 5.)),
+        ];
+        let network = Network::new(line_strings.clone(), 0.1);
+        assert_eq!(network.nodes().len(), 4);
+        assert_eq!(network.edges()[1].geometry, wkt!(LINESTRING(1. 0.,2. 0.)));
+        assert_eq!(network.edges()[1].source, 1);
+        assert_eq!(network.connected_components(), [vec![0, 1, 2], vec![3]]);
+        // a loop
+        assert_eq!(network.edges()[2].start, network.edges()[2].end);
+        assert_eq!(network.edges_at(3), [2]);
+
+        let network = Network::new(line_strings, 0.);
+        assert_eq!(network.connected_components().len(), 3);
+        assert!(Network::<f64>::new([], 0.)
+            .nearest_node(coord! { x: 0., y: 0. })
+            .is_none());
+    }
+}
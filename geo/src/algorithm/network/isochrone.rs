@@ -0,0 +1,211 @@
+use super::{Network, NetworkEdge};
+use crate::bool_ops::BoolOpsNum;
+use crate::{Area, BooleanOps, Coord, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// The number of segments approximating a quarter circle around the ends of reached streets.
+const QUADRANT_SEGMENTS: usize = 4;
+
+/// The area around the part of a [`Network`] within a cost budget, from
+/// [`Network::isochrones`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isochrone<T: GeoFloat = f64> {
+    /// The cost budget.
+    pub budget: T,
+    /// The area around everything reachable within the budget, which contains the areas of the
+    /// smaller budgets.
+    pub area: MultiPolygon<T>,
+    /// The part of the area which isn't in the area of the next smaller budget.
+    pub band: MultiPolygon<T>,
+}
+
+impl<T: GeoFloat + BoolOpsNum> Network<T> {
+    /// The areas within `distance` of the parts of the network which can be reached from node
+    /// `start` within each of the `budgets`, e.g. for a map of the places within a 5, 10 and 15
+    /// minute walk of a station.
+    ///
+    /// The reachable parts are the nodes and edges of [`Network::service_area`], including the
+    /// parts of edges which can only be traveled partway. The areas are built by buffering each
+    /// node and segment by `distance`, and merging the buffers. The isochrones are returned from the
+    /// smallest budget, and each area contains the smaller ones, so bands around a node are
+    /// nested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, wkt, Area, Euclidean, Length, Network};
+    ///
+    /// let network = Network::new(
+    ///     [wkt!(LINESTRING(0. 0.,100. 0.)), wkt!(LINESTRING(50. 0.,50. 100.))],
+    ///     0.,
+    /// );
+    /// let start = network.nearest_node(coord! { x: 0., y: 0. }).unwrap();
+    /// let isochrones = network.isochrones(
+    ///     start,
+    ///     &[25., 100.],
+    ///     |edge| edge.geometry.length::<Euclidean>(),
+    ///     5.,
+    /// );
+    /// // 25 along the first street
+    /// assert!(isochrones[0].area.unsigned_area() > 25. * 10.);
+    /// // the rest of it, and 50 along the second one
+    /// assert!(isochrones[1].band.unsigned_area() > (75. + 50.) * 10. - 25. * 10.);
+    /// let total: f64 = isochrones[0].area.unsigned_area() + isochrones[1].band.unsigned_area();
+    /// assert!((isochrones[1].area.unsigned_area() - total).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there's no node `start`.
+    pub fn isochrones(
+        &self,
+        start: usize,
+        budgets: &[T],
+        cost: impl Fn(&NetworkEdge<T>) -> T,
+        distance: T,
+    ) -> Vec<Isochrone<T>> {
+        let mut budgets = budgets.to_vec();
+        budgets.sort_by(|a, b| a.total_cmp(b));
+        let mut previous = MultiPolygon::new(vec![]);
+        budgets
+            .into_iter()
+            .map(|budget| {
+                let reached = self.service_area(start, budget, &cost);
+                // the nodes too, so that the start is covered even with no budget
+                let nodes = reached.nodes.iter().map(|&(node, _)| {
+                    let coord = self.nodes()[node];
+                    capsule(coord, coord, distance)
+                });
+                let buffers = reached
+                    .geometry
+                    .iter()
+                    .flat_map(|line_string| segment_buffers(line_string, distance))
+                    .chain(nodes)
+                    .collect();
+                let area = union_all(buffers);
+                let band = area.difference(&previous);
+                previous = area.clone();
+                Isochrone { budget, area, band }
+            })
+            .collect()
+    }
+}
+
+/// The buffers of the segments of a line string, or of its only point.
+fn segment_buffers<T: GeoFloat>(line_string: &LineString<T>, distance: T) -> Vec<Polygon<T>> {
+    match line_string.0[..] {
+        [] => vec![],
+        [coord] => vec![capsule(coord, coord, distance)],
+        _ => line_string
+            .lines()
+            .map(|line| capsule(line.start, line.end, distance))
+            .collect(),
+    }
+}
+
+/// The points within `distance` of the segment from `start` to `end`, with its round ends
+/// approximated by polygons.
+fn capsule<T: GeoFloat>(start: Coord<T>, end: Coord<T>, distance: T) -> Polygon<T> {
+    let delta = end - start;
+    let direction = if delta == Coord::zero() {
+        T::zero()
+    } else {
+        delta.y.atan2(delta.x)
+    };
+    let steps = 2 * QUADRANT_SEGMENTS;
+    let step = T::from(std::f64::consts::PI / steps as f64).unwrap();
+    let half_turn = T::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let arc = |center: Coord<T>, from: T| {
+        (0..=steps).map(move |index| {
+            let (sin, cos) = (from + step * T::from(index).unwrap()).sin_cos();
+            center + Coord { x: cos, y: sin } * distance
+        })
+    };
+    let ring: Vec<_> = arc(end, direction - half_turn)
+        .chain(arc(start, direction + half_turn))
+        .collect();
+    Polygon::new(LineString::new(ring), vec![])
+}
+
+/// The union of polygons, merged in pairs so that each overlay is between similar sizes.
+fn union_all<T: BoolOpsNum + GeoFloat>(polygons: Vec<Polygon<T>>) -> MultiPolygon<T> {
+    let mut parts: Vec<MultiPolygon<T>> = polygons
+        .into_iter()
+        .filter(|polygon| polygon.unsigned_area() > T::zero())
+        .map(|polygon| MultiPolygon::new(vec![polygon]))
+        .collect();
+    while parts.len() > 1 {
+        parts = parts
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a.union(b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    parts.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Contains, Euclidean, Length};
+
+    #[test]
+    fn nested_bands() {
+        // a cross of streets, 10 long from the center
+        let network = Network::new(
+            [
+                wkt!(LINESTRING(-10. 0.,0. 0.,10. 0.)),
+                wkt!(LINESTRING(0. -10.,0. 0.,0. 10.)),
+            ],
+            0.,
+        );
+        let start = network.nearest_node(coord! { x: 0., y: 0. }).unwrap();
+        let isochrones = network.isochrones(
+            start,
+            &[10., 5.],
+            |edge| edge.geometry.length::<Euclidean>(),
+            1.,
+        );
+        assert_eq!(isochrones[0].budget, 5.);
+        assert_eq!(isochrones[0].area, isochrones[0].band);
+        // two strips 2 wide, overlapping in a 2 × 2 square, with the ends of each making up a
+        // 16-gon
+        let ends = 2. * 8. * (std::f64::consts::PI / 8.).sin();
+        let cross = |arm: f64| 2. * (2. * arm * 2.) - 4. + ends;
+        assert_relative_eq!(
+            isochrones[0].area.unsigned_area(),
+            cross(5.),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            isochrones[1].area.unsigned_area(),
+            cross(10.),
+            epsilon = 1e-6
+        );
+        assert!(isochrones[1].area.contains(&isochrones[0].area));
+        assert_relative_eq!(
+            isochrones[1].band.unsigned_area(),
+            cross(10.) - cross(5.),
+            epsilon = 1e-6
+        );
+
+        assert!(network
+            .isochrones(start, &[0.], |_| 1., 1.)
+            .iter()
+            .all(|isochrone| isochrone.area.unsigned_area() > 3.));
+    }
+
+    #[test]
+    fn capsules() {
+        let capsule = capsule(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. }, 1.);
+        assert_eq!(
+            capsule.exterior().0.len(),
+            2 * (2 * QUADRANT_SEGMENTS + 1) + 1
+        );
+        assert!(capsule.exterior().is_closed());
+        assert_relative_eq!(capsule.exterior().0[0], coord! { x: 10., y: -1. });
+        assert_eq!(union_all::<f64>(vec![]), MultiPolygon::new(vec![]));
+    }
+}
@@ -0,0 +1,10 @@
+//! Graphs of linear networks, like roads, rivers or pipes, built from their [`LineString`](crate::LineString)s.
+
+mod graph;
+pub use graph::{Network, NetworkEdge};
+
+mod routing;
+pub use routing::{Route, ServiceArea};
+
+mod isochrone;
+pub use isochrone::Isochrone;
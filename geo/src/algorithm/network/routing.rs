@@ -0,0 +1,462 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::{Network, NetworkEdge};
+use crate::line_measures::{Euclidean, Length};
+use crate::{Coord, GeoFloat, LineString, MultiLineString};
+
+/// A path through a [`Network`], found by [`Network::shortest_path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route<T: GeoFloat = f64> {
+    /// The total cost of the edges.
+    pub cost: T,
+    /// The nodes along the path, from the start to the end.
+    pub nodes: Vec<usize>,
+    /// The edges along the path, from the start to the end.
+    pub edges: Vec<usize>,
+    /// The geometry of the edges, joined in the direction of travel.
+    pub geometry: LineString<T>,
+}
+
+/// The part of a [`Network`] within a cost budget from a node, found by
+/// [`Network::service_area`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceArea<T: GeoFloat = f64> {
+    /// The reachable nodes, with the cost to reach each of them, from the cheapest.
+    pub nodes: Vec<(usize, T)>,
+    /// The edges which are entirely reachable.
+    pub edges: Vec<usize>,
+    /// The reachable parts of all edges, including the parts of edges which can only be
+    /// traveled partway. Costs are assumed to be spread evenly along the length of each edge.
+    pub geometry: MultiLineString<T>,
+}
+
+impl<T: GeoFloat> Network<T> {
+    /// The cheapest path from node `start` to node `end`, using Dijkstra's algorithm, or `None`
+    /// if `end` can't be reached.
+    ///
+    /// `cost` gives the cost of traveling along an edge, which must not be negative, e.g. its
+    /// length, or its length divided by a speed for the travel time. Edges can be traveled in
+    /// both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, wkt, Euclidean, Length, Network};
+    ///
+    /// let network = Network::new(
+    ///     [
+    ///         wkt!(LINESTRING(0. 0.,10. 0.)),
+    ///         wkt!(LINESTRING(0. 0.,0. 10.,10. 10.,10. 0.)),
+    ///         wkt!(LINESTRING(10. 0.,20. 0.)),
+    ///     ],
+    ///     0.,
+    /// );
+    /// let start = network.nearest_node(coord! { x: 0., y: 0. }).unwrap();
+    /// let end = network.nearest_node(coord! { x: 20., y: 0. }).unwrap();
+    ///
+    /// let route = network
+    ///     .shortest_path(start, end, |edge| edge.geometry.length::<Euclidean>())
+    ///     .unwrap();
+    /// assert_eq!(route.cost, 20.);
+    /// assert_eq!(route.geometry, wkt!(LINESTRING(0. 0.,10. 0.,20. 0.)));
+    ///
+    /// // the first street is closed
+    /// let route = network
+    ///     .shortest_path(start, end, |edge| match edge.source {
+    ///         0 => f64::INFINITY,
+    ///         _ => edge.geometry.length::<Euclidean>(),
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(route.cost, 40.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there's no node `start` or `end`.
+    pub fn shortest_path(
+        &self,
+        start: usize,
+        end: usize,
+        cost: impl Fn(&NetworkEdge<T>) -> T,
+    ) -> Option<Route<T>> {
+        self.shortest_path_astar(start, end, cost, |_| T::zero())
+    }
+
+    /// The cheapest path from node `start` to node `end`, using the A* algorithm, or `None` if
+    /// `end` can't be reached.
+    ///
+    /// This is like [`Network::shortest_path`], but `heuristic` estimates the remaining cost from
+    /// the position of a node to `end`, so that fewer nodes in the wrong direction are explored.
+    /// The path is the cheapest one as long as the edge costs are non-negative and the heuristic
+    /// is consistent: it's zero at `end`, and it never decreases along an edge by more than the
+    /// cost of that edge, e.g. the straight-line distance to `end`, when the cost is the length of
+    /// the edges. Since each node is only settled once, a heuristic which merely never
+    /// overestimates the remaining cost isn't enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, wkt, Distance, Euclidean, Length, Network, Point};
+    ///
+    /// let network = Network::new(
+    ///     [wkt!(LINESTRING(0. 0.,10. 0.,10. 10.)), wkt!(LINESTRING(0. 0.,0. 10.))],
+    ///     0.,
+    /// );
+    /// let end = coord! { x: 10., y: 10. };
+    /// let route = network
+    ///     .shortest_path_astar(
+    ///         network.nearest_node(coord! { x: 0., y: 0. }).unwrap(),
+    ///         network.nearest_node(end).unwrap(),
+    ///         |edge| edge.geometry.length::<Euclidean>(),
+    ///         |position| Euclidean::distance(Point(position), Point(end)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(route.cost, 20.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there's no node `start` or `end`.
+    pub fn shortest_path_astar(
+        &self,
+        start: usize,
+        end: usize,
+        cost: impl Fn(&NetworkEdge<T>) -> T,
+        heuristic: impl Fn(Coord<T>) -> T,
+    ) -> Option<Route<T>> {
+        assert!(end < self.nodes().len(), "no such node");
+        let visits = self.search(
+            start,
+            cost,
+            |node| heuristic(self.nodes()[node]),
+            |node| node == end,
+            T::infinity(),
+        );
+        let total = visits[end]?.cost;
+
+        let mut nodes = vec![end];
+        let mut edges = vec![];
+        while let Some(Visit {
+            previous: Some((edge, node)),
+            ..
+        }) = visits[nodes[nodes.len() - 1]]
+        {
+            edges.push(edge);
+            nodes.push(node);
+        }
+        nodes.reverse();
+        edges.reverse();
+
+        let mut coords = vec![self.nodes()[start]];
+        for (&edge, &node) in edges.iter().zip(&nodes) {
+            let geometry = &self.edges()[edge].geometry.0;
+            if self.edges()[edge].start == node {
+                coords.extend(&geometry[1..]);
+            } else {
+                coords.extend(geometry[..geometry.len() - 1].iter().rev());
+            }
+        }
+        Some(Route {
+            cost: total,
+            nodes,
+            edges,
+            geometry: LineString::new(coords),
+        })
+    }
+
+    /// The part of the network which can be reached from node `start` without exceeding
+    /// `budget`, e.g. the streets within a 10 minute walk of a station.
+    ///
+    /// `cost` gives the cost of traveling along an edge, which must not be negative. Edges can
+    /// be traveled in both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, wkt, Euclidean, Length, Network};
+    ///
+    /// let network = Network::new(
+    ///     [wkt!(LINESTRING(0. 0.,10. 0.,20. 0.)), wkt!(LINESTRING(10. 0.,10. 10.))],
+    ///     0.,
+    /// );
+    /// let start = network.nearest_node(coord! { x: 0., y: 0. }).unwrap();
+    ///
+    /// let area = network.service_area(start, 15., |edge| edge.geometry.length::<Euclidean>());
+    /// assert_eq!(area.nodes.len(), 2);
+    /// assert_eq!(area.edges.len(), 1);
+    /// assert_eq!(area.geometry.length::<Euclidean>(), 20.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there's no node `start`.
+    pub fn service_area(
+        &self,
+        start: usize,
+        budget: T,
+        cost: impl Fn(&NetworkEdge<T>) -> T,
+    ) -> ServiceArea<T> {
+        let visits = self.search(start, &cost, |_| T::zero(), |_| false, budget);
+        let reached = |node: usize| {
+            visits[node]
+                .map(|visit| visit.cost)
+                .filter(|&c| c <= budget)
+        };
+
+        let mut nodes: Vec<(usize, T)> = (0..self.nodes().len())
+            .filter_map(|node| Some((node, reached(node)?)))
+            .collect();
+        nodes.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut edges = vec![];
+        let mut geometry = vec![];
+        for (index, edge) in self.edges().iter().enumerate() {
+            let edge_cost = cost(edge);
+            // how far along the edge can be reached from either end, as a fraction of its cost
+            let reach = |node: usize| {
+                reached(node).map_or(T::zero(), |c| {
+                    if edge_cost > T::zero() {
+                        ((budget - c) / edge_cost).min(T::one())
+                    } else {
+                        T::one()
+                    }
+                })
+            };
+            let (from_start, from_end) = (reach(edge.start), reach(edge.end));
+            if from_start + from_end >= T::one() {
+                edges.push(index);
+                geometry.push(edge.geometry.clone());
+                continue;
+            }
+            if from_start > T::zero() {
+                geometry.push(prefix(&edge.geometry, from_start));
+            }
+            if from_end > T::zero() {
+                let mut reversed = edge.geometry.clone();
+                reversed.0.reverse();
+                geometry.push(prefix(&reversed, from_end));
+            }
+        }
+        ServiceArea {
+            nodes,
+            edges,
+            geometry: MultiLineString::new(geometry),
+        }
+    }
+
+    /// Search the network from `start` until `is_goal` is true for a node, or until every node
+    /// within `budget` has been visited, returning the cheapest visit to each node.
+    fn search(
+        &self,
+        start: usize,
+        cost: impl Fn(&NetworkEdge<T>) -> T,
+        heuristic: impl Fn(usize) -> T,
+        is_goal: impl Fn(usize) -> bool,
+        budget: T,
+    ) -> Vec<Option<Visit<T>>> {
+        assert!(start < self.nodes().len(), "no such node");
+        let mut visits: Vec<Option<Visit<T>>> = vec![None; self.nodes().len()];
+        let mut done = vec![false; self.nodes().len()];
+        visits[start] = Some(Visit {
+            cost: T::zero(),
+            previous: None,
+        });
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            priority: heuristic(start),
+            node: start,
+        });
+        while let Some(Candidate { node, .. }) = heap.pop() {
+            if std::mem::replace(&mut done[node], true) {
+                continue;
+            }
+            if is_goal(node) {
+                break;
+            }
+            let node_cost = visits[node].map_or(T::zero(), |visit| visit.cost);
+            for (edge, neighbor) in self.neighbors(node) {
+                let edge_cost = cost(&self.edges()[edge]);
+                let total = node_cost + edge_cost;
+                if done[neighbor] || !total.is_finite() || total > budget {
+                    continue;
+                }
+                if visits[neighbor].map_or(true, |visit| total < visit.cost) {
+                    visits[neighbor] = Some(Visit {
+                        cost: total,
+                        previous: Some((edge, node)),
+                    });
+                    heap.push(Candidate {
+                        priority: total + heuristic(neighbor),
+                        node: neighbor,
+                    });
+                }
+            }
+        }
+        visits
+    }
+}
+
+/// The start of `line_string`, up to `fraction` of its length.
+fn prefix<T: GeoFloat>(line_string: &LineString<T>, fraction: T) -> LineString<T> {
+    let mut remaining = line_string.length::<Euclidean>() * fraction;
+    let mut coords = vec![line_string.0[0]];
+    for line in line_string.lines() {
+        let length = line.length::<Euclidean>();
+        if length >= remaining {
+            let t = if length > T::zero() {
+                remaining / length
+            } else {
+                T::zero()
+            };
+            coords.push(line.start + line.delta() * t);
+            break;
+        }
+        remaining = remaining - length;
+        coords.push(line.end);
+    }
+    LineString::new(coords)
+}
+
+/// The cheapest way found to a node: its cost, and the edge and node it was reached from.
+#[derive(Debug, Clone, Copy)]
+struct Visit<T: GeoFloat> {
+    cost: T,
+    previous: Option<(usize, usize)>,
+}
+
+struct Candidate<T: GeoFloat> {
+    priority: T,
+    node: usize,
+}
+
+impl<T: GeoFloat> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: GeoFloat> Eq for Candidate<T> {}
+
+impl<T: GeoFloat> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The cheapest candidate is the greatest, so that it's popped first from a `BinaryHeap`.
+impl<T: GeoFloat> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Distance, Line, Point};
+
+    fn grid() -> Network {
+        // a 5 × 5 grid of streets, 10 apart
+        let streets = (0..5).flat_map(|i| {
+            let i = i as f64 * 10.;
+            [
+                LineString::from(vec![(i, 0.), (i, 40.)]),
+                LineString::from(vec![(0., i), (40., i)]),
+            ]
+        });
+        Network::new(streets, 0.)
+    }
+
+    fn length(edge: &NetworkEdge) -> f64 {
+        edge.geometry.length::<Euclidean>()
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let network = grid();
+        assert_eq!(network.nodes().len(), 25);
+        let start = network.nearest_node(coord! { x: 0., y: 0. }).unwrap();
+        let end = network.nearest_node(coord! { x: 30., y: 20. }).unwrap();
+
+        let route = network.shortest_path(start, end, length).unwrap();
+        assert_eq!(route.cost, 50.);
+        assert_eq!(route.nodes.len(), 6);
+        assert_eq!(route.edges.len(), 5);
+        assert_eq!(route.geometry.0.len(), 6);
+        assert_eq!(route.geometry.length::<Euclidean>(), 50.);
+        assert_eq!(route.geometry.0[0], coord! { x: 0., y: 0. });
+        assert_eq!(route.geometry.0[5], coord! { x: 30., y: 20. });
+
+        let target = network.nodes()[end];
+        let astar = network
+            .shortest_path_astar(start, end, length, |c| {
+                Euclidean::distance(Point(c), Point(target))
+            })
+            .unwrap();
+        assert_eq!(astar.cost, 50.);
+
+        let back = network.shortest_path(end, start, length).unwrap();
+        assert_eq!(back.cost, 50.);
+        assert_eq!(back.geometry.0[0], coord! { x: 30., y: 20. });
+
+        let here = network.shortest_path(start, start, length).unwrap();
+        assert_eq!(here.cost, 0.);
+        assert_eq!(
+            here.geometry,
+            LineString::new(vec![coord! { x: 0., y: 0. }])
+        );
+    }
+
+    #[test]
+    fn unreachable() {
+        let network = Network::new(
+            [wkt!(LINESTRING(0. 0.,1. 0.)), wkt!(LINESTRING(5. 0.,6. 0.))],
+            0.,
+        );
+        assert!(network.shortest_path(0, 3, length).is_none());
+        assert!(network.shortest_path(0, 1, |_| f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn service_area() {
+        let network = grid();
+        let start = network.nearest_node(coord! { x: 20., y: 20. }).unwrap();
+        let area = network.service_area(start, 15., length);
+        assert_eq!(area.nodes[0], (start, 0.));
+        assert_eq!(area.nodes.len(), 5);
+        assert_eq!(area.edges.len(), 4);
+        // 4 streets to the next crossings, and then halfway along 12 more
+        assert_relative_eq!(area.geometry.length::<Euclidean>(), 4. * 10. + 12. * 5.);
+
+        // a triangle, whose far edge is reached from both ends
+        let network = Network::new(
+            [
+                wkt!(LINESTRING(0. 0.,10. 0.)),
+                wkt!(LINESTRING(10. 0.,5. 8.)),
+                wkt!(LINESTRING(5. 8.,0. 0.)),
+            ],
+            0.,
+        );
+        let area = network.service_area(0, 14., |_| 10.);
+        assert_eq!(area.edges.len(), 2);
+        assert_eq!(area.geometry.0.len(), 4);
+        let area = network.service_area(0, 16., |_| 10.);
+        assert_eq!(area.edges.len(), 3);
+        assert_eq!(area.geometry.0.len(), 3);
+    }
+
+    #[test]
+    fn prefixes() {
+        let line_string = wkt!(LINESTRING(0. 0.,10. 0.,10. 10.));
+        assert_eq!(
+            prefix(&line_string, 0.75),
+            wkt!(LINESTRING(0. 0.,10. 0.,10. 5.))
+        );
+        assert_eq!(prefix(&line_string, 1.), line_string);
+        assert_eq!(
+            prefix(&Line::new((0., 0.), (4., 0.)).into(), 0.5),
+            wkt!(LINESTRING(0. 0.,2. 0.))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::coordinate_position::CoordPos;
+use crate::monotone::{monotone_subdivision, MonoPoly};
+use crate::{BoundingRect, Coord, CoordinatePosition, GeoFloat, Polygon, Rect, StrTree};
+
+/// An index answering which of many polygons contains a point, such as the triangles of a
+/// triangulation or the polygons of a coverage, e.g. to assign each of many points to a zone.
+///
+/// Each polygon is split into [monotone polygons](crate::MonotonicPolygons), which contain a
+/// point or not in `O(log n)` time for `n` vertices, and these are indexed in a [`StrTree`]. A
+/// lookup only tests the few monotone polygons whose bounding rectangle contains the point, so it
+/// takes logarithmic time in both the number of polygons and their sizes.
+///
+/// The polygons are identified by their position in the input. They shouldn't overlap, but may
+/// share boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, PointLocator};
+///
+/// let zones = [
+///     wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.))),
+///     wkt!(POLYGON((10. 0.,20. 0.,20. 10.,10. 10.,10. 0.))),
+///     wkt!(POLYGON((0. 10.,20. 10.,10. 20.,0. 10.))),
+/// ];
+/// let locator = PointLocator::new(zones);
+///
+/// assert_eq!(locator.locate(coord! { x: 15., y: 5. }), Some(1));
+/// assert_eq!(locator.locate(coord! { x: 10., y: 15. }), Some(2));
+/// assert_eq!(locator.locate(coord! { x: 25., y: 5. }), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PointLocator<T: GeoFloat = f64> {
+    tree: StrTree<(usize, MonoPoly<T>), T>,
+    len: usize,
+}
+
+impl<T: GeoFloat> PointLocator<T> {
+    /// Index polygons, or anything that converts to them, like [`Triangle`](crate::Triangle)s
+    /// or [`Rect`]s.
+    ///
+    /// Each polygon must be valid: its rings must be simple and only touch each other at
+    /// points.
+    pub fn new<P: Into<Polygon<T>>>(polygons: impl IntoIterator<Item = P>) -> Self {
+        let mut len = 0;
+        let mut items = vec![];
+        for (index, polygon) in polygons.into_iter().enumerate() {
+            len += 1;
+            let polygon = polygon.into();
+            if polygon.exterior().0.len() < 4 {
+                continue;
+            }
+            items.extend(
+                monotone_subdivision([polygon])
+                    .into_iter()
+                    .map(|mono_poly| (mono_poly.bounding_rect(), (index, mono_poly))),
+            );
+        }
+        Self {
+            tree: StrTree::from_envelopes(items, crate::str_tree::DEFAULT_NODE_CAPACITY),
+            len,
+        }
+    }
+
+    /// The number of indexed polygons.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no indexed polygons.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The index of the polygon containing `coord`, or `None` if no polygon does.
+    ///
+    /// A polygon containing `coord` in its interior is preferred. If `coord` is on the boundary
+    /// between several polygons, any of them is returned.
+    pub fn locate(&self, coord: Coord<T>) -> Option<usize> {
+        let mut on_boundary = None;
+        for (index, mono_poly) in self.tree.query_rect(Rect::new(coord, coord)) {
+            match mono_poly.coordinate_position(&coord) {
+                CoordPos::Inside => return Some(*index),
+                CoordPos::OnBoundary => on_boundary = Some(*index),
+                CoordPos::Outside => {}
+            }
+        }
+        on_boundary
+    }
+
+    /// The index of the polygon containing each of `coords`, or `None` for those that no
+    /// polygon contains.
+    pub fn locate_all<'a>(
+        &'a self,
+        coords: impl IntoIterator<Item = Coord<T>> + 'a,
+    ) -> impl Iterator<Item = Option<usize>> + 'a {
+        coords.into_iter().map(|coord| self.locate(coord))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Intersects, LineString, Triangle};
+
+    #[test]
+    fn triangulation() {
+        // a 10 × 10 grid of squares, each split into two triangles
+        let triangles: Vec<_> = (0..10)
+            .flat_map(|i| (0..10).map(move |j| (i as f64, j as f64)))
+            .flat_map(|(x, y)| {
+                [
+                    Triangle::from([(x, y), (x + 1., y), (x, y + 1.)]),
+                    Triangle::from([(x + 1., y), (x + 1., y + 1.), (x, y + 1.)]),
+                ]
+            })
+            .collect();
+        let locator = PointLocator::new(triangles.clone());
+        assert_eq!(locator.len(), 200);
+
+        for i in -5..60 {
+            for j in -5..60 {
+                let c = coord! { x: i as f64 * 0.2 + 0.01, y: j as f64 * 0.2 + 0.03 };
+                match locator.locate(c) {
+                    Some(index) => assert!(triangles[index].intersects(&c)),
+                    None => assert!(c.x < 0. || c.y < 0. || c.x > 10. || c.y > 10.),
+                }
+            }
+        }
+        // vertices and edges are found too
+        assert!(locator.locate(coord! { x: 3., y: 4. }).is_some());
+        assert!(locator.locate(coord! { x: 10., y: 4.5 }).is_some());
+    }
+
+    #[test]
+    fn coverage() {
+        let polygons = [
+            wkt!(POLYGON(
+                (0. 0.,10. 0.,12. 6.,10. 10.,5. 7.,0. 10.,-2. 5.,0. 0.),
+                (3. 3.,6. 3.,6. 5.,3. 3.)
+            )),
+            wkt!(POLYGON((3. 3.,6. 3.,6. 5.,3. 3.))),
+        ];
+        let locator = PointLocator::new(polygons.clone());
+        assert_eq!(locator.locate(coord! { x: 5.5, y: 3.5 }), Some(1));
+        assert_eq!(locator.locate(coord! { x: 1., y: 1. }), Some(0));
+        assert_eq!(locator.locate(coord! { x: 5., y: 9. }), None);
+        // on the boundary of both polygons
+        assert!(locator.locate(coord! { x: 6., y: 4. }).is_some());
+        assert_eq!(
+            locator
+                .locate_all([coord! { x: 11., y: 5. }, coord! { x: 4., y: 3.5 }])
+                .collect::<Vec<_>>(),
+            [Some(0), Some(1)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let locator = PointLocator::new([
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]),
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.))),
+        ]);
+        assert_eq!(locator.len(), 2);
+        assert_eq!(locator.locate(coord! { x: 0.9, y: 0.1 }), Some(1));
+        assert!(PointLocator::<f64>::new(Vec::<Polygon>::new()).is_empty());
+    }
+}
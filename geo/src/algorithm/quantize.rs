@@ -0,0 +1,340 @@
+use crate::geometry::*;
+use crate::{CoordFloat, CoordNum, MapCoords};
+
+/// Snap geometries to a grid of integer coordinates, e.g. to store them compactly, or before
+/// [delta encoding](DeltaEncode) them for formats such as [MVT](crate::mvt) and
+/// [TWKB](crate::twkb).
+///
+/// The grid starts at the minimum corner of `extent` and its cells are `precision` wide, so a
+/// coordinate is stored as the number of cells from the corner of the extent, rounded to the
+/// closest integer. Coordinates outside of the extent are moved to its boundary, so that all
+/// quantized coordinates are between zero and the number of cells spanned by the extent.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, Dequantize, LineString, Quantize, Rect};
+///
+/// let extent = Rect::new(coord! { x: -180., y: -90. }, coord! { x: 180., y: 90. });
+/// let line_string = wkt!(LINESTRING(13.40495 52.52001,2.35222 48.85661));
+///
+/// let quantized = line_string.quantize(extent, 1e-5);
+/// assert_eq!(
+///     quantized,
+///     LineString::from(vec![(19340495, 14252001), (18235222, 13885661)])
+/// );
+///
+/// let dequantized = quantized.dequantize(extent, 1e-5);
+/// approx::assert_relative_eq!(dequantized, line_string, epsilon = 1e-9);
+/// ```
+pub trait Quantize<T: CoordFloat>: MapCoords<T, i32> {
+    /// Snap the coordinates to the grid of cells of size `precision` covering `extent`.
+    ///
+    /// # Panics
+    ///
+    /// If `precision` isn't positive, or if the extent spans more than `i32::MAX` cells along
+    /// an axis.
+    fn quantize(&self, extent: Rect<T>, precision: T) -> Self::Output {
+        assert!(precision > T::zero(), "precision must be positive");
+        let max = T::from(i32::MAX).unwrap();
+        let cells = |size: T| {
+            let cells = (size / precision).ceil();
+            assert!(cells <= max, "the extent spans too many cells");
+            cells
+        };
+        let (width, height) = (cells(extent.width()), cells(extent.height()));
+        let min = extent.min();
+        self.map_coords(|coord| {
+            let to_cell = |value: T, min: T, cells: T| {
+                let cell = ((value - min) / precision).round();
+                // NaN is moved to the minimum too
+                let cell = if cell > T::zero() {
+                    cell.min(cells)
+                } else {
+                    T::zero()
+                };
+                cell.to_i32().unwrap()
+            };
+            Coord {
+                x: to_cell(coord.x, min.x, width),
+                y: to_cell(coord.y, min.y, height),
+            }
+        })
+    }
+}
+impl<T: CoordFloat, G: MapCoords<T, i32>> Quantize<T> for G {}
+
+/// The inverse of [`Quantize`], converting grid coordinates back to the centers of their cells.
+pub trait Dequantize<T: CoordFloat>: MapCoords<i32, T> {
+    /// Convert the coordinates on the grid of cells of size `precision` covering `extent` back
+    /// to the coordinates of the extent.
+    fn dequantize(&self, extent: Rect<T>, precision: T) -> Self::Output {
+        let min = extent.min();
+        self.map_coords(|coord| Coord {
+            x: min.x + T::from(coord.x).unwrap() * precision,
+            y: min.y + T::from(coord.y).unwrap() * precision,
+        })
+    }
+}
+impl<T: CoordFloat, G: MapCoords<i32, T>> Dequantize<T> for G {}
+
+/// Replace each coordinate of a geometry by its difference from the previous coordinate, so
+/// that nearby coordinates become small integers which can be stored compactly, e.g. as
+/// variable length integers.
+///
+/// The coordinates are visited in order, and the first one is kept as is. The structure of the
+/// geometry is preserved, so that it can be decoded again with [`DeltaEncode::delta_decode`].
+/// Since the rings of a [`Polygon`] are always closed, their closing coordinate is skipped, and
+/// replaced by a copy of their first encoded coordinate.
+/// Both corners of a [`Rect`] are encoded relative to the coordinate before it, since a `Rect`
+/// keeps its minimum corner first.
+///
+/// This is meant for integer coordinates, such as those of [`Quantize`]. The differences between
+/// quantized coordinates always fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{DeltaEncode, LineString};
+///
+/// let line_string = LineString::from(vec![(1000, 2000), (1003, 1999), (1010, 2001)]);
+/// let encoded = line_string.delta_encode();
+/// assert_eq!(encoded, LineString::from(vec![(1000, 2000), (3, -1), (7, 2)]));
+/// assert_eq!(encoded.delta_decode(), line_string);
+/// ```
+pub trait DeltaEncode<T: CoordNum>: Sized {
+    /// Replace the coordinates by their differences from the previous coordinate.
+    fn delta_encode(&self) -> Self;
+
+    /// The inverse of [`Self::delta_encode`].
+    fn delta_decode(&self) -> Self;
+}
+
+impl<T: CoordNum, G: DeltaMap<T>> DeltaEncode<T> for G {
+    fn delta_encode(&self) -> Self {
+        self.delta_map(&mut Delta {
+            previous: Coord::zero(),
+            decode: false,
+        })
+    }
+
+    fn delta_decode(&self) -> Self {
+        self.delta_map(&mut Delta {
+            previous: Coord::zero(),
+            decode: true,
+        })
+    }
+}
+
+/// The state of delta encoding or decoding a sequence of coordinates.
+struct Delta<T: CoordNum> {
+    /// The last decoded coordinate.
+    previous: Coord<T>,
+    decode: bool,
+}
+
+impl<T: CoordNum> Delta<T> {
+    fn coord(&mut self, coord: Coord<T>) -> Coord<T> {
+        if self.decode {
+            self.previous = self.previous + coord;
+            self.previous
+        } else {
+            let delta = coord - self.previous;
+            self.previous = coord;
+            delta
+        }
+    }
+
+    fn coords(&mut self, coords: &[Coord<T>]) -> Vec<Coord<T>> {
+        coords.iter().map(|&coord| self.coord(coord)).collect()
+    }
+
+    /// Skips the closing coordinate of a polygon ring, and copies the first one instead.
+    fn ring(&mut self, ring: &LineString<T>) -> LineString<T> {
+        let Some((_, open)) = ring.0.split_last() else {
+            return LineString::new(vec![]);
+        };
+        let mut coords = self.coords(open);
+        coords.push(coords[0]);
+        LineString::new(coords)
+    }
+}
+
+/// Geometries which can be delta encoded.
+trait DeltaMap<T: CoordNum> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self;
+}
+
+impl<T: CoordNum> DeltaMap<T> for Point<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        Point(delta.coord(self.0))
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for Line<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        let start = delta.coord(self.start);
+        Line::new(start, delta.coord(self.end))
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for LineString<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        LineString::new(delta.coords(&self.0))
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for Polygon<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        let exterior = delta.ring(self.exterior());
+        let interiors = self
+            .interiors()
+            .iter()
+            .map(|interior| delta.ring(interior))
+            .collect();
+        Polygon::new(exterior, interiors)
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for MultiPoint<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        self.iter().map(|point| point.delta_map(delta)).collect()
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for MultiLineString<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.delta_map(delta))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for MultiPolygon<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.delta_map(delta))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for Rect<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        // both corners are relative to the same coordinate, so they stay ordered
+        let previous = delta.previous;
+        let min = delta.coord(self.min());
+        delta.previous = previous;
+        let max = delta.coord(self.max());
+        Rect::new(min, max)
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for Triangle<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        let [a, b, c] = self.to_array();
+        let a = delta.coord(a);
+        let b = delta.coord(b);
+        Triangle::new(a, b, delta.coord(c))
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for GeometryCollection<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        self.iter()
+            .map(|geometry| geometry.delta_map(delta))
+            .collect()
+    }
+}
+
+impl<T: CoordNum> DeltaMap<T> for Geometry<T> {
+    fn delta_map(&self, delta: &mut Delta<T>) -> Self {
+        match self {
+            Geometry::Point(g) => g.delta_map(delta).into(),
+            Geometry::Line(g) => g.delta_map(delta).into(),
+            Geometry::LineString(g) => g.delta_map(delta).into(),
+            Geometry::Polygon(g) => g.delta_map(delta).into(),
+            Geometry::MultiPoint(g) => g.delta_map(delta).into(),
+            Geometry::MultiLineString(g) => g.delta_map(delta).into(),
+            Geometry::MultiPolygon(g) => g.delta_map(delta).into(),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.delta_map(delta)),
+            Geometry::Rect(g) => g.delta_map(delta).into(),
+            Geometry::Triangle(g) => g.delta_map(delta).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, CoordsIter};
+
+    #[test]
+    fn quantize_clamps() {
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 5. });
+        let quantized = wkt!(MULTIPOINT(-1. 2.,3.24 3.26,12. 7.,9.99 4.99)).quantize(extent, 0.1);
+        assert_eq!(
+            quantized,
+            MultiPoint::from(vec![(0, 20), (32, 33), (100, 50), (100, 50)])
+        );
+        let nan = Point::new(f64::NAN, 1.).quantize(extent, 0.1);
+        assert_eq!(nan, Point::new(0, 10));
+        // a precision which doesn't divide the extent
+        let quantized = Point::new(10., 5.).quantize(extent, 3.);
+        assert_eq!(quantized, Point::new(3, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_cells() {
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        Point::new(0.5, 0.5).quantize(extent, 1e-10);
+    }
+
+    #[test]
+    fn delta_round_trip() {
+        let geometry: Geometry<i32> = Geometry::GeometryCollection(GeometryCollection::from(vec![
+            Geometry::from(Point::new(5, 5)),
+            Polygon::new(
+                LineString::from(vec![(0, 0), (10, 0), (10, 10), (0, 0)]),
+                vec![LineString::from(vec![(2, 1), (8, 1), (8, 7), (2, 1)])],
+            )
+            .into(),
+            Rect::new(coord! { x: 20, y: 30 }, coord! { x: 25, y: 31 }).into(),
+            Line::new(coord! { x: 1, y: 1 }, coord! { x: 0, y: 0 }).into(),
+            Triangle::from([(3, 3), (4, 3), (3, 4)]).into(),
+            Rect::new(coord! { x: -4, y: -6 }, coord! { x: -3, y: -2 }).into(),
+            MultiLineString::new(vec![LineString::from(vec![(7, 7), (6, 6)])]).into(),
+        ]));
+        let encoded = geometry.delta_encode();
+        assert_eq!(encoded.coords_count(), geometry.coords_count());
+        assert!(encoded
+            .coords_iter()
+            .skip(1)
+            .all(|coord| coord.x.abs() <= 25 && coord.y.abs() <= 30));
+        assert_eq!(encoded.delta_decode(), geometry);
+    }
+
+    #[test]
+    fn quantize_and_encode() {
+        let extent = Rect::new(coord! { x: -180., y: -90. }, coord! { x: 180., y: 90. });
+        let polygon = wkt!(POLYGON((13.4 52.5,13.41 52.5,13.41 52.51,13.4 52.5)));
+        let encoded = polygon.quantize(extent, 1e-6).delta_encode();
+        assert_eq!(
+            encoded.exterior().0[1..],
+            [
+                coord! { x: 10000, y: 0 },
+                coord! { x: 0, y: 10000 },
+                encoded.exterior().0[0],
+            ]
+        );
+        approx::assert_relative_eq!(
+            encoded.delta_decode().dequantize(extent, 1e-6),
+            polygon,
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{ConstrainedDelaunayTriangulation, Point2, PositionInTriangulation, Triangulation};
+
+use crate::line_measures::{Distance, Euclidean};
+use crate::triangulate_spade::SpadeTriangulationFloat;
+use crate::{Coord, Kernel, LineString, Orientation, Point, Polygon};
+
+/// The shortest path between two points that stays inside a polygon, going around its holes,
+/// e.g. for indoor routing or the analysis of corridors.
+///
+/// The polygon is split into a constrained Delaunay triangulation. A search through adjacent
+/// triangles finds the channel of triangles that the path crosses, and the [funnel algorithm]
+/// then pulls the path through that channel taut, so that it only bends at vertices of the
+/// polygon.
+///
+/// Without holes, there's only one channel between two points, so the path is the shortest
+/// possible. Around holes, the channel is chosen by the distance between the midpoints of the
+/// edges it crosses, so the path may occasionally go around a hole on the longer side.
+///
+/// Requires the `"spade"` feature, which is enabled by default.
+///
+/// [funnel algorithm]: https://digestingduck.blogspot.com/2010/03/simple-stupid-funnel-algorithm.html
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, ShortestPath};
+///
+/// // a U-shaped corridor
+/// let corridor = wkt!(POLYGON((
+///     0. 0.,10. 0.,10. 10.,7. 10.,7. 3.,3. 3.,3. 10.,0. 10.,0. 0.
+/// )));
+///
+/// let path = corridor
+///     .shortest_path(&point!(x: 1., y: 9.), &point!(x: 9., y: 9.))
+///     .unwrap();
+/// assert_eq!(path, wkt!(LINESTRING(1. 9.,3. 3.,7. 3.,9. 9.)));
+///
+/// assert!(corridor.shortest_path(&point!(x: 5., y: 5.), &point!(x: 9., y: 9.)).is_none());
+/// ```
+pub trait ShortestPath<T: SpadeTriangulationFloat> {
+    /// The shortest path from `start` to `end` within the geometry, or `None` unless both are in
+    /// the geometry or on its boundary.
+    fn shortest_path(&self, start: &Point<T>, end: &Point<T>) -> Option<LineString<T>>;
+}
+
+type Cdt<T> = ConstrainedDelaunayTriangulation<Point2<T>>;
+
+impl<T: SpadeTriangulationFloat> ShortestPath<T> for Polygon<T> {
+    fn shortest_path(&self, start: &Point<T>, end: &Point<T>) -> Option<LineString<T>> {
+        let mut vertices = vec![];
+        let mut constraints = vec![];
+        for ring in std::iter::once(self.exterior()).chain(self.interiors()) {
+            let first = vertices.len();
+            let len = ring.0.len().saturating_sub(1);
+            vertices.extend(ring.0[..len].iter().map(|c| Point2::new(c.x, c.y)));
+            constraints.extend((0..len).map(|i| [first + i, first + (i + 1) % len]));
+        }
+        // conflicting constraints come from invalid polygons, so skip them
+        let cdt = Cdt::try_bulk_load_cdt(vertices, constraints, |_| {}).ok()?;
+        let inside = inside_faces(&cdt);
+        let start_faces = faces_at(&cdt, &inside, start.0);
+        let end_faces = faces_at(&cdt, &inside, end.0);
+        if start_faces.is_empty() || end_faces.is_empty() {
+            return None;
+        }
+        let channel = find_channel(&cdt, &inside, start.0, &start_faces, end.0, &end_faces)?;
+
+        // the edges crossed by the channel, as seen from the inside of the channel
+        let mut portals = vec![(start.0, start.0)];
+        for faces in channel.windows(2) {
+            let edge = cdt
+                .face(faces[0])
+                .adjacent_edges()
+                .into_iter()
+                .find(|edge| {
+                    edge.rev().face().as_inner().map(|face| face.fix()) == Some(faces[1])
+                })?;
+            let [right, left] = edge.positions().map(|p| Coord { x: p.x, y: p.y });
+            portals.push((left, right));
+        }
+        portals.push((end.0, end.0));
+        Some(LineString::new(funnel(&portals)))
+    }
+}
+
+/// Whether each face, by index, is inside the polygon: faces next to the outer face are inside
+/// if the edge between them is a ring of the polygon, and crossing a ring from one face to the
+/// next switches between inside and outside.
+fn inside_faces<T: SpadeTriangulationFloat>(cdt: &Cdt<T>) -> Vec<bool> {
+    let mut inside = vec![None; cdt.num_all_faces()];
+    let mut queue = VecDeque::new();
+    for face in cdt.inner_faces() {
+        for edge in face.adjacent_edges() {
+            if edge.rev().face().is_outer() && inside[face.fix().index()].is_none() {
+                inside[face.fix().index()] = Some(edge.is_constraint_edge());
+                queue.push_back(face);
+            }
+        }
+    }
+    while let Some(face) = queue.pop_front() {
+        let face_inside = inside[face.fix().index()].unwrap_or_default();
+        for edge in face.adjacent_edges() {
+            if let Some(neighbor) = edge.rev().face().as_inner() {
+                let state = &mut inside[neighbor.fix().index()];
+                if state.is_none() {
+                    *state = Some(face_inside != edge.is_constraint_edge());
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    inside.into_iter().map(Option::unwrap_or_default).collect()
+}
+
+/// The faces inside the polygon which contain `coord`, in their interior or on their boundary.
+fn faces_at<T: SpadeTriangulationFloat>(
+    cdt: &Cdt<T>,
+    inside: &[bool],
+    coord: Coord<T>,
+) -> Vec<FixedFaceHandle<InnerTag>> {
+    let faces: Vec<_> = match cdt.locate(Point2::new(coord.x, coord.y)) {
+        PositionInTriangulation::OnFace(face) => vec![face],
+        PositionInTriangulation::OnEdge(edge) => {
+            let edge = cdt.directed_edge(edge);
+            [edge, edge.rev()]
+                .iter()
+                .filter_map(|edge| edge.face().as_inner().map(|face| face.fix()))
+                .collect()
+        }
+        PositionInTriangulation::OnVertex(vertex) => cdt
+            .vertex(vertex)
+            .out_edges()
+            .filter_map(|edge| edge.face().as_inner().map(|face| face.fix()))
+            .collect(),
+        PositionInTriangulation::OutsideOfConvexHull(_)
+        | PositionInTriangulation::NoTriangulation => {
+            vec![]
+        }
+    };
+    faces
+        .into_iter()
+        .filter(|face| inside[face.index()])
+        .collect()
+}
+
+/// Search for a sequence of adjacent faces inside the polygon from one of `start_faces` to one of
+/// `end_faces`, going through the midpoints of the edges between them.
+fn find_channel<T: SpadeTriangulationFloat>(
+    cdt: &Cdt<T>,
+    inside: &[bool],
+    start: Coord<T>,
+    start_faces: &[FixedFaceHandle<InnerTag>],
+    end: Coord<T>,
+    end_faces: &[FixedFaceHandle<InnerTag>],
+) -> Option<Vec<FixedFaceHandle<InnerTag>>> {
+    let mut best: Vec<Option<Visit<T>>> = vec![None; cdt.num_all_faces()];
+    let mut heap = BinaryHeap::new();
+    for &face in start_faces {
+        best[face.index()] = Some(Visit {
+            distance: T::zero(),
+            entry: start,
+            previous: None,
+        });
+        heap.push(Candidate {
+            distance: T::zero(),
+            face,
+            done: false,
+        });
+    }
+    while let Some(Candidate {
+        distance,
+        face,
+        done,
+    }) = heap.pop()
+    {
+        if done {
+            return Some(channel_to(&best, face));
+        }
+        let Visit {
+            distance: best_distance,
+            entry,
+            ..
+        } = best[face.index()]?;
+        if distance > best_distance {
+            continue;
+        }
+        if end_faces.contains(&face) {
+            heap.push(Candidate {
+                distance: distance + Euclidean::distance(Point(entry), Point(end)),
+                face,
+                done: true,
+            });
+        }
+        for edge in cdt.face(face).adjacent_edges() {
+            let Some(neighbor) = edge.rev().face().as_inner() else {
+                continue;
+            };
+            if edge.is_constraint_edge() || !inside[neighbor.fix().index()] {
+                continue;
+            }
+            let [from, to] = edge.positions();
+            let midpoint = Coord {
+                x: (from.x + to.x) / (T::one() + T::one()),
+                y: (from.y + to.y) / (T::one() + T::one()),
+            };
+            let distance = distance + Euclidean::distance(Point(entry), Point(midpoint));
+            let neighbor = neighbor.fix();
+            if best[neighbor.index()].map_or(true, |best| distance < best.distance) {
+                best[neighbor.index()] = Some(Visit {
+                    distance,
+                    entry: midpoint,
+                    previous: Some(face),
+                });
+                heap.push(Candidate {
+                    distance,
+                    face: neighbor,
+                    done: false,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// The faces from a start face to `face`, following the faces each was entered from.
+fn channel_to<T: SpadeTriangulationFloat>(
+    best: &[Option<Visit<T>>],
+    face: FixedFaceHandle<InnerTag>,
+) -> Vec<FixedFaceHandle<InnerTag>> {
+    let mut channel = vec![face];
+    while let Some(Visit {
+        previous: Some(previous),
+        ..
+    }) = best[channel[channel.len() - 1].index()]
+    {
+        channel.push(previous);
+    }
+    channel.reverse();
+    channel
+}
+
+/// The shortest path through a sequence of `(left, right)` portals, the first and last of which
+/// are the start and the end of the path.
+fn funnel<T: SpadeTriangulationFloat>(portals: &[(Coord<T>, Coord<T>)]) -> Vec<Coord<T>> {
+    let start = portals[0].0;
+    let end = portals[portals.len() - 1].0;
+    let mut path = vec![start];
+    let (mut apex, mut left, mut right) = (start, start, start);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+    while i < portals.len() {
+        let (new_left, new_right) = portals[i];
+        // narrow the funnel from the right, unless that crosses over the left side, in which
+        // case the path bends around the left side
+        if T::Ker::orient2d(apex, right, new_right) != Orientation::Clockwise {
+            if apex == right || T::Ker::orient2d(apex, left, new_right) == Orientation::Clockwise {
+                right = new_right;
+                right_index = i;
+            } else {
+                apex = left;
+                if path.last() != Some(&apex) {
+                    path.push(apex);
+                }
+                (right, right_index) = (apex, left_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+        // and the other way around
+        if T::Ker::orient2d(apex, left, new_left) != Orientation::CounterClockwise {
+            if apex == left
+                || T::Ker::orient2d(apex, right, new_left) == Orientation::CounterClockwise
+            {
+                left = new_left;
+                left_index = i;
+            } else {
+                apex = right;
+                if path.last() != Some(&apex) {
+                    path.push(apex);
+                }
+                (left, left_index) = (apex, right_index);
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    if path.len() == 1 || path.last() != Some(&end) {
+        path.push(end);
+    }
+    path
+}
+
+/// The shortest distance found to a face, the point it was entered from, and the face it was
+/// entered from.
+#[derive(Clone, Copy)]
+struct Visit<T: SpadeTriangulationFloat> {
+    distance: T,
+    entry: Coord<T>,
+    previous: Option<FixedFaceHandle<InnerTag>>,
+}
+
+struct Candidate<T: SpadeTriangulationFloat> {
+    distance: T,
+    face: FixedFaceHandle<InnerTag>,
+    done: bool,
+}
+
+impl<T: SpadeTriangulationFloat> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Eq for Candidate<T> {}
+
+impl<T: SpadeTriangulationFloat> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The closest candidate is the greatest, so that it's popped first from a `BinaryHeap`.
+impl<T: SpadeTriangulationFloat> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then(self.done.cmp(&other.done))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Length};
+
+    #[test]
+    fn convex() {
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,5. 3.,2. 5.,-1. 3.,0. 0.)));
+        let (start, end) = (point!(x: 0.5, y: 0.5), point!(x: 2., y: 4.5));
+        assert_eq!(
+            polygon.shortest_path(&start, &end).unwrap(),
+            wkt!(LINESTRING(0.5 0.5,2. 4.5))
+        );
+        // on vertices and edges
+        let (start, end) = (point!(x: 0., y: 0.), point!(x: 4.5, y: 1.5));
+        assert_eq!(
+            polygon.shortest_path(&start, &end).unwrap(),
+            wkt!(LINESTRING(0. 0.,4.5 1.5))
+        );
+        assert_eq!(
+            polygon.shortest_path(&start, &start).unwrap(),
+            wkt!(LINESTRING(0. 0.,0. 0.))
+        );
+    }
+
+    #[test]
+    fn spiral() {
+        let spiral = wkt!(POLYGON((
+            0. 0.,10. 0.,10. 10.,2. 10.,2. 4.,6. 4.,6. 6.,4. 6.,4. 8.,8. 8.,8. 2.,0. 2.,0. 0.
+        )));
+        let path = spiral
+            .shortest_path(&point!(x: 1., y: 1.), &point!(x: 5., y: 5.))
+            .unwrap();
+        assert_eq!(path, wkt!(LINESTRING(1. 1.,8. 2.,8. 8.,4. 8.,4. 6.,5. 5.)));
+        // the reverse path bends at the same vertices
+        let mut reverse = spiral
+            .shortest_path(&point!(x: 5., y: 5.), &point!(x: 1., y: 1.))
+            .unwrap();
+        reverse.0.reverse();
+        assert_eq!(reverse, path);
+    }
+
+    #[test]
+    fn around_a_hole() {
+        let room = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)
+        ));
+        let path = room
+            .shortest_path(&point!(x: 2., y: 5.), &point!(x: 8., y: 5.))
+            .unwrap();
+        assert_eq!(path.0.len(), 4);
+        assert_relative_eq!(path.length::<Euclidean>(), 2. * 5f64.sqrt() + 2.);
+
+        assert!(room
+            .shortest_path(&point!(x: 2., y: 5.), &point!(x: 5., y: 5.))
+            .is_none());
+        assert!(room
+            .shortest_path(&point!(x: 11., y: 5.), &point!(x: 2., y: 5.))
+            .is_none());
+    }
+}
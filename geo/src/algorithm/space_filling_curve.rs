@@ -0,0 +1,602 @@
+use std::ops::RangeInclusive;
+
+use crate::bounding_rect::bounding_rect_merge;
+use crate::{coord, BoundingRect, Coord, CoordFloat, CoordsIter, GeoFloat, Intersects, Rect};
+
+/// A curve visiting every cell of a square grid of `2^order` by `2^order` cells once, so that
+/// cells which are close on the curve are close in space. Ordering geometries by the index of
+/// their cell on the curve keeps nearby geometries together, e.g. to pack an R-tree, to shard
+/// data, or to sort it so that range queries on the index read few blocks.
+///
+/// The cells of the quadtree of the grid are each visited by a contiguous range of indices, so a
+/// cell of `2^order` by `2^order` cells, split into quarters recursively, covers the indices
+/// `k * 4^(order - level)` up to `(k + 1) * 4^(order - level) - 1` of its index `k` at its level.
+///
+/// The `order` can be at most 32, so that indices fit in a `u64`. Indices of grids of order 16 or
+/// less fit in a `u32`.
+///
+/// See [`CurveGrid`] to compute indices of coordinates rather than grid cells.
+pub trait SpaceFillingCurve {
+    /// The index on the curve of the cell in column `x` and row `y` of the grid.
+    ///
+    /// `x` and `y` must be less than `2^order`.
+    fn index(&self, x: u32, y: u32, order: u8) -> u64;
+
+    /// The column and row of the cell at `index` on the curve, the inverse of [`Self::index`].
+    ///
+    /// `index` must be less than `4^order`.
+    fn cell(&self, index: u64, order: u8) -> (u32, u32);
+}
+
+/// The [Hilbert curve], which only steps between adjacent cells, and so keeps cells which are
+/// close on the curve closer together than the [`Morton`] curve.
+///
+/// [Hilbert curve]: https://en.wikipedia.org/wiki/Hilbert_curve
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Hilbert, SpaceFillingCurve};
+///
+/// // the curve starts at the bottom left and ends at the bottom right
+/// let cells: Vec<_> = (0..4).map(|index| Hilbert.cell(index, 1)).collect();
+/// assert_eq!(cells, [(0, 0), (0, 1), (1, 1), (1, 0)]);
+/// assert_eq!(Hilbert.index(1, 1, 1), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Hilbert;
+
+impl SpaceFillingCurve for Hilbert {
+    fn index(&self, x: u32, y: u32, order: u8) -> u64 {
+        debug_assert!(order <= 32);
+        let n = 1u64 << order;
+        let (mut x, mut y) = (u64::from(x), u64::from(y));
+        let mut index = 0;
+        let mut s = n / 2;
+        while s > 0 {
+            let rx = u64::from(x & s != 0);
+            let ry = u64::from(y & s != 0);
+            index += s * s * ((3 * rx) ^ ry);
+            (x, y) = rotate(n, x, y, rx, ry);
+            s /= 2;
+        }
+        index
+    }
+
+    fn cell(&self, index: u64, order: u8) -> (u32, u32) {
+        debug_assert!(order <= 32);
+        let (mut x, mut y) = (0, 0);
+        let mut t = index;
+        for level in 0..order {
+            let s = 1u64 << level;
+            let rx = 1 & (t / 2);
+            let ry = 1 & (t ^ rx);
+            (x, y) = rotate(s, x, y, rx, ry);
+            x += s * rx;
+            y += s * ry;
+            t /= 4;
+        }
+        (x as u32, y as u32)
+    }
+}
+
+/// Rotates and flips a quadrant of the Hilbert curve.
+fn rotate(n: u64, x: u64, y: u64, rx: u64, ry: u64) -> (u64, u64) {
+    match (rx, ry) {
+        (0, 0) => (y, x),
+        (_, 0) => (n - 1 - y, n - 1 - x),
+        _ => (x, y),
+    }
+}
+
+/// The [Morton curve], or Z-order curve, which interleaves the bits of the column and row of a
+/// cell. It's cheaper to compute than the [`Hilbert`] curve, but jumps between distant cells.
+///
+/// [Morton curve]: https://en.wikipedia.org/wiki/Z-order_curve
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Morton, SpaceFillingCurve};
+///
+/// let cells: Vec<_> = (0..4).map(|index| Morton.cell(index, 1)).collect();
+/// assert_eq!(cells, [(0, 0), (1, 0), (0, 1), (1, 1)]);
+/// assert_eq!(Morton.index(0b11, 0b01, 2), 0b0111);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Morton;
+
+impl SpaceFillingCurve for Morton {
+    fn index(&self, x: u32, y: u32, order: u8) -> u64 {
+        debug_assert!(order <= 32);
+        spread(x) | (spread(y) << 1)
+    }
+
+    fn cell(&self, index: u64, order: u8) -> (u32, u32) {
+        debug_assert!(order <= 32);
+        (compact(index), compact(index >> 1))
+    }
+}
+
+/// Moves the bits of `value` to the even bits of the result.
+fn spread(value: u32) -> u64 {
+    let mut value = u64::from(value);
+    value = (value | (value << 16)) & 0x0000_ffff_0000_ffff;
+    value = (value | (value << 8)) & 0x00ff_00ff_00ff_00ff;
+    value = (value | (value << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+    (value | (value << 1)) & 0x5555_5555_5555_5555
+}
+
+/// The inverse of [`spread`], ignoring the odd bits.
+fn compact(value: u64) -> u32 {
+    let mut value = value & 0x5555_5555_5555_5555;
+    value = (value | (value >> 1)) & 0x3333_3333_3333_3333;
+    value = (value | (value >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    value = (value | (value >> 4)) & 0x00ff_00ff_00ff_00ff;
+    value = (value | (value >> 8)) & 0x0000_ffff_0000_ffff;
+    (value | (value >> 16)) as u32
+}
+
+/// A [`SpaceFillingCurve`] over a grid of `2^order` by `2^order` cells covering an extent, to
+/// compute the indices of coordinates and rectangles.
+///
+/// Coordinates outside the extent are moved to the closest cell of the grid.
+///
+/// # Examples
+///
+/// Sort points along the Hilbert curve:
+///
+/// ```
+/// use geo::{coord, CurveGrid, Hilbert, Rect};
+///
+/// let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+/// let grid = CurveGrid::new(Hilbert, extent, 16);
+///
+/// let mut coords = vec![
+///     coord! { x: 90., y: 10. },
+///     coord! { x: 10., y: 90. },
+///     coord! { x: 10., y: 10. },
+///     coord! { x: 90., y: 90. },
+/// ];
+/// coords.sort_by_key(|&coord| grid.coord_index(coord));
+/// assert_eq!(
+///     coords,
+///     [
+///         coord! { x: 10., y: 10. },
+///         coord! { x: 10., y: 90. },
+///         coord! { x: 90., y: 90. },
+///         coord! { x: 90., y: 10. },
+///     ]
+/// );
+///
+/// // the cell of an index
+/// let cell = grid.cell_bounds(grid.coord_index(coords[0]));
+/// assert!(cell.min().x <= 10. && 10. < cell.max().x);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveGrid<C: SpaceFillingCurve, T: CoordFloat = f64> {
+    curve: C,
+    extent: Rect<T>,
+    order: u8,
+}
+
+impl<C: SpaceFillingCurve, T: CoordFloat> CurveGrid<C, T> {
+    /// A grid of `2^order` by `2^order` cells covering `extent`, visited by `curve`.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is more than 32.
+    pub fn new(curve: C, extent: Rect<T>, order: u8) -> Self {
+        assert!(order <= 32, "the order of a curve can be at most 32");
+        Self {
+            curve,
+            extent,
+            order,
+        }
+    }
+
+    /// The number of cells along each axis of the grid.
+    fn cells_per_axis(&self) -> T {
+        T::from(1u64 << self.order).unwrap()
+    }
+
+    /// The column and row of the cell containing `coord`.
+    fn cell_of(&self, coord: Coord<T>) -> (u32, u32) {
+        let max = (1u64 << self.order) - 1;
+        let to_cell = |value: T, min: T, size: T| -> u32 {
+            let cell = ((value - min) / size * self.cells_per_axis())
+                .floor()
+                .to_u64()
+                .unwrap_or(0);
+            cell.min(max) as u32
+        };
+        (
+            to_cell(coord.x, self.extent.min().x, self.extent.width()),
+            to_cell(coord.y, self.extent.min().y, self.extent.height()),
+        )
+    }
+
+    /// The index on the curve of the cell containing `coord`.
+    pub fn coord_index(&self, coord: Coord<T>) -> u64 {
+        let (x, y) = self.cell_of(coord);
+        self.curve.index(x, y, self.order)
+    }
+
+    /// The index on the curve of the cell containing the center of `rect`, e.g. to sort the
+    /// bounding rectangles of geometries.
+    pub fn rect_index(&self, rect: Rect<T>) -> u64 {
+        self.coord_index(rect.center())
+    }
+
+    /// The bounds of the cell at `index` on the curve.
+    pub fn cell_bounds(&self, index: u64) -> Rect<T> {
+        let (x, y) = self.curve.cell(index, self.order);
+        self.quadtree_cell_bounds(self.order, x, y)
+    }
+
+    /// The bounds of the cell in column `x` and row `y` of the grid at quadtree `level`.
+    fn quadtree_cell_bounds(&self, level: u8, x: u32, y: u32) -> Rect<T> {
+        let n = T::from(1u64 << level).unwrap();
+        let size = coord! {
+            x: self.extent.width() / n,
+            y: self.extent.height() / n,
+        };
+        let min = self.extent.min();
+        Rect::new(
+            coord! {
+                x: min.x + size.x * T::from(x).unwrap(),
+                y: min.y + size.y * T::from(y).unwrap(),
+            },
+            coord! {
+                x: min.x + size.x * T::from(u64::from(x) + 1).unwrap(),
+                y: min.y + size.y * T::from(u64::from(y) + 1).unwrap(),
+            },
+        )
+    }
+
+    /// Sorted, disjoint ranges of indices whose cells cover `query`, e.g. to turn a window query
+    /// into range scans over data sorted by index.
+    ///
+    /// The quadtree of the grid is refined down to `max_level`, and cells at that level which
+    /// intersect `query` are included entirely, so a lower `max_level` gives fewer ranges
+    /// covering more cells outside of `query`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, CurveGrid, Morton, Rect};
+    ///
+    /// let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    /// let grid = CurveGrid::new(Morton, extent, 2);
+    ///
+    /// // the bottom half of the grid is the first half of the curve
+    /// let query = Rect::new(coord! { x: 0.5, y: 0.5 }, coord! { x: 3.5, y: 1.5 });
+    /// assert_eq!(grid.index_ranges(query, 2), [0..=7]);
+    /// // the bottom left cell only
+    /// let query = Rect::new(coord! { x: 0.5, y: 0.5 }, coord! { x: 0.6, y: 0.6 });
+    /// assert_eq!(grid.index_ranges(query, 2), [0..=0]);
+    /// assert_eq!(grid.index_ranges(query, 1), [0..=3]);
+    /// ```
+    pub fn index_ranges(&self, query: Rect<T>, max_level: u8) -> Vec<RangeInclusive<u64>> {
+        let max_level = max_level.min(self.order);
+        let mut ranges: Vec<RangeInclusive<u64>> = vec![];
+        let mut stack = vec![(0, 0, 0)];
+        while let Some((level, x, y)) = stack.pop() {
+            let bounds = self.quadtree_cell_bounds(level, x, y);
+            if !bounds.intersects(&query) {
+                continue;
+            }
+            let contained = query.min().x <= bounds.min().x
+                && query.min().y <= bounds.min().y
+                && bounds.max().x <= query.max().x
+                && bounds.max().y <= query.max().y;
+            if contained || level == max_level {
+                let shift = 2 * u32::from(self.order - level);
+                // the only cell at level 0 has index 0, which may need a shift by 64
+                let first = self
+                    .curve
+                    .index(x, y, level)
+                    .checked_shl(shift)
+                    .unwrap_or(0);
+                let last = first | u64::MAX.checked_shr(64 - shift).unwrap_or(0);
+                ranges.push(first..=last);
+            } else {
+                let (x, y) = (x * 2, y * 2);
+                stack.extend([
+                    (level + 1, x, y),
+                    (level + 1, x + 1, y),
+                    (level + 1, x, y + 1),
+                    (level + 1, x + 1, y + 1),
+                ]);
+            }
+        }
+        ranges.sort_unstable_by_key(|range| *range.start());
+        let mut merged: Vec<RangeInclusive<u64>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if last.end().checked_add(1) == Some(*range.start()) => {
+                    *last = *last.start()..=*range.end();
+                }
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+}
+
+/// How [`HilbertPartition`] balances the work between partitions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PartitionBalance {
+    /// About the same number of geometries in each partition.
+    #[default]
+    Count,
+    /// About the same total number of vertices in each partition, for work which takes longer for
+    /// larger geometries.
+    Vertices,
+}
+
+/// Split geometries into spatially coherent partitions, e.g. to shard work across threads or
+/// workers so that each one handles a compact region.
+pub trait HilbertPartition {
+    /// The partition, from `0` to `partitions - 1`, of each geometry.
+    ///
+    /// The geometries are sorted along the [`Hilbert`] curve by the center of their bounding
+    /// rectangle, and the curve is cut into `partitions` consecutive runs of geometries, balanced
+    /// by `balance`. Geometries without a bounding rectangle, such as empty ones, come last.
+    ///
+    /// # Panics
+    ///
+    /// If `partitions` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, HilbertPartition, PartitionBalance};
+    ///
+    /// let points = [
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 10., y: 10.),
+    ///     point!(x: 1., y: 0.),
+    ///     point!(x: 10., y: 9.),
+    /// ];
+    /// let partitions = points.hilbert_partition(2, PartitionBalance::Count);
+    /// assert_eq!(partitions, [0, 1, 0, 1]);
+    /// ```
+    fn hilbert_partition(&self, partitions: usize, balance: PartitionBalance) -> Vec<usize>;
+}
+
+impl<G> HilbertPartition for [G]
+where
+    G: BoundingRect<G::Scalar> + CoordsIter,
+    G::Scalar: GeoFloat,
+{
+    fn hilbert_partition(&self, partitions: usize, balance: PartitionBalance) -> Vec<usize> {
+        assert!(partitions > 0, "there must be at least one partition");
+        let bounds: Vec<Option<Rect<G::Scalar>>> = self
+            .iter()
+            .map(|geometry| geometry.bounding_rect().into())
+            .collect();
+        let extent = bounds.iter().flatten().copied().reduce(bounding_rect_merge);
+        let mut order: Vec<(u64, usize)> = match extent {
+            Some(extent) => {
+                let grid = CurveGrid::new(Hilbert, extent, 16);
+                bounds
+                    .iter()
+                    .enumerate()
+                    .map(|(i, rect)| (rect.map_or(u64::MAX, |rect| grid.rect_index(rect)), i))
+                    .collect()
+            }
+            None => (0..self.len()).map(|i| (0, i)).collect(),
+        };
+        order.sort_unstable();
+
+        let weight = |i: usize| -> u128 {
+            match balance {
+                PartitionBalance::Count => 1,
+                PartitionBalance::Vertices => self[i].coords_count() as u128,
+            }
+        };
+        let total: u128 = (0..self.len()).map(weight).sum();
+        let mut assignments = vec![0; self.len()];
+        let mut before = 0;
+        for (_, i) in order {
+            // the partition containing the middle of the geometry's share of the total
+            let weight = weight(i);
+            if total > 0 {
+                let partition = (2 * before + weight) * partitions as u128 / (2 * total);
+                assignments[i] = (partition as usize).min(partitions - 1);
+            }
+            before += weight;
+        }
+        assignments
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Geometry, LineString, MultiPoint, Point};
+
+    fn round_trip(curve: impl SpaceFillingCurve, order: u8) {
+        let n = 1u64 << order;
+        let mut visited = vec![false; (n * n) as usize];
+        for x in 0..n as u32 {
+            for y in 0..n as u32 {
+                let index = curve.index(x, y, order);
+                assert!(!visited[index as usize]);
+                visited[index as usize] = true;
+                assert_eq!(curve.cell(index, order), (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn hilbert() {
+        for order in 0..6 {
+            round_trip(Hilbert, order);
+        }
+        // consecutive cells are adjacent
+        let order = 5;
+        for index in 1..(1 << (2 * order)) {
+            let (x0, y0) = Hilbert.cell(index - 1, order);
+            let (x1, y1) = Hilbert.cell(index, order);
+            assert_eq!(x0.abs_diff(x1) + y0.abs_diff(y1), 1);
+        }
+        let cells: Vec<_> = (0..16).map(|index| Hilbert.cell(index, 2)).collect();
+        assert_eq!(
+            cells,
+            [
+                (0, 0),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 3),
+                (1, 2),
+                (2, 2),
+                (2, 3),
+                (3, 3),
+                (3, 2),
+                (3, 1),
+                (2, 1),
+                (2, 0),
+                (3, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn morton() {
+        for order in 0..6 {
+            round_trip(Morton, order);
+        }
+        assert_eq!(Morton.index(u32::MAX, 0, 32), 0x5555_5555_5555_5555);
+        assert_eq!(Morton.cell(u64::MAX, 32), (u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn full_resolution() {
+        for (x, y) in [
+            (0, 0),
+            (u32::MAX, 0),
+            (12345, u32::MAX - 6789),
+            (u32::MAX, u32::MAX),
+        ] {
+            let index = Hilbert.index(x, y, 32);
+            assert_eq!(Hilbert.cell(index, 32), (x, y));
+        }
+        assert_eq!(Hilbert.index(u32::MAX, 0, 32), u64::MAX);
+    }
+
+    #[test]
+    fn grid() {
+        let extent = Rect::new(coord! { x: -180., y: -90. }, coord! { x: 180., y: 90. });
+        let grid = CurveGrid::new(Hilbert, extent, 8);
+        let coord = coord! { x: 13.4, y: 52.5 };
+        let cell = grid.cell_bounds(grid.coord_index(coord));
+        assert!(cell.intersects(&coord));
+        assert_relative_eq!(cell.width(), 360. / 256.);
+        // outside of the extent
+        assert_eq!(
+            grid.coord_index(coord! { x: 200., y: -100. }),
+            grid.coord_index(coord! { x: 179.9, y: -89.9 })
+        );
+        assert_eq!(
+            grid.rect_index(Rect::new(coord, coord! { x: 14., y: 53. })),
+            grid.coord_index(coord! { x: 13.7, y: 52.75 })
+        );
+        let grid = CurveGrid::new(Morton, extent, 0);
+        assert_eq!(grid.coord_index(coord), 0);
+        assert_eq!(grid.cell_bounds(0), extent);
+    }
+
+    fn check_ranges(curve: impl SpaceFillingCurve) {
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 64., y: 64. });
+        let query = Rect::new(coord! { x: 10.5, y: 20.5 }, coord! { x: 30.5, y: 25.5 });
+        let grid = CurveGrid::new(curve, extent, 6);
+        let ranges = grid.index_ranges(query, 6);
+        for window in ranges.windows(2) {
+            assert!(window[0].end() + 1 < *window[1].start());
+        }
+        // exactly the cells intersecting the query
+        let count: u64 = ranges
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum();
+        assert_eq!(count, 21 * 6);
+        for range in ranges {
+            for index in range {
+                assert!(grid.cell_bounds(index).intersects(&query));
+            }
+        }
+        // coarser ranges cover more
+        let coarse = grid.index_ranges(query, 2);
+        let count: u64 = coarse
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum();
+        assert!(count > 21 * 6);
+    }
+
+    #[test]
+    fn ranges() {
+        check_ranges(Hilbert);
+        check_ranges(Morton);
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        let grid = CurveGrid::new(Hilbert, extent, 32);
+        assert_eq!(grid.index_ranges(extent, 32), [0..=u64::MAX]);
+    }
+
+    #[test]
+    fn partition() {
+        // a 10 × 10 grid of points
+        let points: Vec<Point> = (0..100)
+            .map(|i| point!(x: (i % 10) as f64, y: (i / 10) as f64))
+            .collect();
+        let partitions = points.hilbert_partition(4, PartitionBalance::Count);
+        for partition in 0..4 {
+            let members: Vec<&Point> = points
+                .iter()
+                .zip(&partitions)
+                .filter(|(_, &p)| p == partition)
+                .map(|(point, _)| point)
+                .collect();
+            assert_eq!(members.len(), 25);
+            // each partition is a quadrant
+            let bounds = MultiPoint::from(members.into_iter().copied().collect::<Vec<_>>())
+                .bounding_rect()
+                .unwrap();
+            assert_eq!((bounds.width(), bounds.height()), (4., 4.));
+        }
+        assert_eq!(
+            points.hilbert_partition(1, PartitionBalance::Count),
+            [0; 100]
+        );
+        assert_eq!(
+            points[..2].hilbert_partition(5, PartitionBalance::Count),
+            [1, 3]
+        );
+    }
+
+    #[test]
+    fn partition_by_vertices() {
+        let geometries: Vec<Geometry> = vec![
+            // 100 vertices
+            LineString::from_iter((0..100).map(|i| coord! { x: i as f64 / 100., y: 0. })).into(),
+            point!(x: 9., y: 9.).into(),
+            point!(x: 9., y: 8.).into(),
+            LineString::<f64>::new(vec![]).into(),
+            point!(x: 8., y: 9.).into(),
+        ];
+        let partitions = geometries.hilbert_partition(2, PartitionBalance::Count);
+        assert_eq!(partitions, [0, 1, 1, 1, 0]);
+        // the line string alone weighs more than all the points
+        let partitions = geometries.hilbert_partition(2, PartitionBalance::Vertices);
+        assert_eq!(partitions, [0, 1, 1, 1, 1]);
+        assert!(Vec::<Point>::new()
+            .hilbert_partition(3, PartitionBalance::Vertices)
+            .is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use geo_types::{Coord, Line, LineString, MultiPolygon, Polygon, Triangle};
 
@@ -119,6 +119,9 @@ pub trait StitchTriangles<T: GeoFloat>: private::Stitchable<T> {
     /// └─────────────────┘
     /// ```
     ///
+    /// The output is the same on every run and platform for the same triangles in the same
+    /// order.
+    ///
     /// ---
     ///
     /// If you want to do something more general like a
@@ -303,7 +306,7 @@ fn stitch_multipolygon_from_lines<F: GeoFloat>(
     }
 
     // Associates every ring with its parents (the rings that contain it)
-    let parents_of: HashMap<usize, Vec<usize>> = rings
+    let parents_of: BTreeMap<usize, Vec<usize>> = rings
         .iter()
         .enumerate()
         .map(|(ring_idx, ring)| {
@@ -313,12 +316,12 @@ fn stitch_multipolygon_from_lines<F: GeoFloat>(
         .collect();
 
     // Associates outer rings with their inner rings
-    let mut polygons_idxs: HashMap<usize, Vec<usize>> = HashMap::default();
+    let mut polygons_idxs: BTreeMap<usize, Vec<usize>> = BTreeMap::default();
 
     // the direct parent is the parent ring which has itself the most parent rings
     fn find_direct_parent(
         parent_rings: &[usize],
-        parents_of: &HashMap<usize, Vec<usize>>,
+        parents_of: &BTreeMap<usize, Vec<usize>>,
     ) -> Option<usize> {
         parent_rings
             .iter()
@@ -352,7 +355,7 @@ fn stitch_multipolygon_from_lines<F: GeoFloat>(
         let maybe_direct_parent = find_direct_parent(parent_idxs, &parents_of);
 
         // As stated above the amount of parents here is odd, so it's at least one.
-        // Since every ring is registered in the `parents` hashmap, we find at least one element
+        // Since every ring is registered in the `parents_of` map, we find at least one element
         // while iterating. Hence the `max_by_key` will always return `Some` since the iterator
         // is never empty
         debug_assert!(
@@ -442,7 +445,8 @@ fn try_stitch<F: GeoFloat>(a: &[Coord<F>], b: &[Coord<F>]) -> Option<Vec<Coord<F
 
 // ============= Tests ===========
 
-#[cfg(test)]
+// the tests stitch triangulations from earcutr
+#[cfg(all(test, feature = "earcutr"))]
 mod polygon_stitching_tests {
 
     use crate::{Relate, TriangulateEarcut, Winding};
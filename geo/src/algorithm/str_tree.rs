@@ -0,0 +1,467 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+use crate::line_measures::{Distance, Euclidean};
+use crate::utils::{partial_max, partial_min};
+use crate::{coord, BoundingRect, GeoFloat, Intersects, Point, Rect};
+
+/// The number of children of each node, unless set with [`StrTree::with_node_capacity`].
+pub const DEFAULT_NODE_CAPACITY: usize = 16;
+
+/// A static R-tree, bulk loaded with the Sort-Tile-Recursive ([STR]) algorithm.
+///
+/// All items are added at once, and can't be added or removed afterwards. In exchange, each level
+/// is packed into full nodes which barely overlap, so the tree is smaller and faster to query
+/// than one built by inserting items one by one. This suits analytic workloads which build an
+/// index once and then run many queries against it.
+///
+/// Items with an empty bounding rectangle, such as empty geometries, can't be found by any query
+/// and aren't stored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, Rect, StrTree};
+///
+/// let tree = StrTree::new(vec![
+///     wkt!(LINESTRING(0. 0.,1. 1.)),
+///     wkt!(LINESTRING(5. 5.,6. 5.)),
+///     wkt!(LINESTRING(9. 0.,9. 3.)),
+/// ]);
+///
+/// let found: Vec<_> = tree.query_rect(Rect::new((4., 4.), (10., 10.))).collect();
+/// assert_eq!(found, [&wkt!(LINESTRING(5. 5.,6. 5.))]);
+///
+/// let nearest = tree.nearest_neighbor(point!(x: 8., y: 1.));
+/// assert_eq!(nearest, Some(&wkt!(LINESTRING(9. 0.,9. 3.))));
+/// ```
+///
+/// [STR]: https://ntrs.nasa.gov/citations/19970016975
+#[derive(Debug, Clone)]
+pub struct StrTree<G, T: GeoFloat = f64> {
+    /// The items, in the order they're grouped into leaves
+    items: Vec<G>,
+    envelopes: Vec<Rect<T>>,
+    /// The nodes of each level, from the leaves up to the root. The children of a leaf are
+    /// ranges of `items`, and the children of a node at level `l > 0` are ranges of level `l - 1`.
+    levels: Vec<Vec<Node<T>>>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T: GeoFloat> {
+    envelope: Rect<T>,
+    children: Range<usize>,
+}
+
+/// A node at a given level, or an item.
+#[derive(Debug, Clone, Copy)]
+enum Entry {
+    Node { level: usize, index: usize },
+    Item(usize),
+}
+
+impl<G, T: GeoFloat> StrTree<G, T> {
+    /// Bulk load a tree from `items`, with [`DEFAULT_NODE_CAPACITY`] children per node.
+    pub fn new(items: impl IntoIterator<Item = G>) -> Self
+    where
+        G: BoundingRect<T>,
+    {
+        Self::with_node_capacity(items, DEFAULT_NODE_CAPACITY)
+    }
+
+    /// Bulk load a tree from `items`, with up to `node_capacity` children per node.
+    ///
+    /// # Panics
+    ///
+    /// If `node_capacity` is less than 2.
+    pub fn with_node_capacity(items: impl IntoIterator<Item = G>, node_capacity: usize) -> Self
+    where
+        G: BoundingRect<T>,
+    {
+        let items = items.into_iter().filter_map(|item| {
+            let envelope: Option<Rect<T>> = item.bounding_rect().into();
+            Some((envelope?, item))
+        });
+        Self::from_envelopes(items, node_capacity)
+    }
+
+    /// Bulk load a tree from items of any type with their bounding rectangles, with up to
+    /// `node_capacity` children per node.
+    ///
+    /// # Panics
+    ///
+    /// If `node_capacity` is less than 2.
+    pub fn from_envelopes(
+        items: impl IntoIterator<Item = (Rect<T>, G)>,
+        node_capacity: usize,
+    ) -> Self {
+        assert!(node_capacity >= 2, "node capacity must be at least 2");
+        let mut items: Vec<_> = items.into_iter().collect();
+        sort_tile(&mut items, |(envelope, _)| *envelope, node_capacity);
+        let (envelopes, items): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        let mut levels = Vec::new();
+        let mut level = pack(&envelopes, node_capacity);
+        while level.len() > 1 {
+            sort_tile(&mut level, |node| node.envelope, node_capacity);
+            let envelopes: Vec<_> = level.iter().map(|node| node.envelope).collect();
+            let parents = pack(&envelopes, node_capacity);
+            levels.push(level);
+            level = parents;
+        }
+        if !level.is_empty() {
+            levels.push(level);
+        }
+        Self {
+            items,
+            envelopes,
+            levels,
+        }
+    }
+
+    /// The number of items in the tree.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the tree has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items in the tree, in no particular order.
+    pub fn items(&self) -> &[G] {
+        &self.items
+    }
+
+    /// The bounding rectangle of all items, or `None` if the tree is empty.
+    pub fn envelope(&self) -> Option<Rect<T>> {
+        self.root().map(|root| self.entry_envelope(root))
+    }
+
+    /// The items whose bounding rectangle intersects `rect`.
+    pub fn query_rect(&self, rect: Rect<T>) -> impl Iterator<Item = &G> + '_ {
+        let mut stack: Vec<Entry> = self.root().into_iter().collect();
+        std::iter::from_fn(move || {
+            while let Some(entry) = stack.pop() {
+                if !self.entry_envelope(entry).intersects(&rect) {
+                    continue;
+                }
+                match entry {
+                    Entry::Item(index) => return Some(&self.items[index]),
+                    node => stack.extend(self.children(node)),
+                }
+            }
+            None
+        })
+    }
+
+    /// The item closest to `point` by Euclidean distance, or `None` if the tree is empty. If
+    /// several items are equally close, any of them is returned.
+    pub fn nearest_neighbor(&self, point: Point<T>) -> Option<&G>
+    where
+        for<'a> Euclidean: Distance<T, &'a Point<T>, &'a G>,
+    {
+        // best first search: envelopes are never further away than their contents, so once an
+        // item is popped from the heap nothing left in it can be closer
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            distance: T::zero(),
+            entry: self.root()?,
+            exact: false,
+        });
+        while let Some(Candidate {
+            distance,
+            entry,
+            exact,
+        }) = heap.pop()
+        {
+            match entry {
+                Entry::Item(index) if exact => return Some(&self.items[index]),
+                Entry::Item(index) => heap.push(Candidate {
+                    distance: partial_max(
+                        distance,
+                        Euclidean::distance(&point, &self.items[index]),
+                    ),
+                    entry,
+                    exact: true,
+                }),
+                node => heap.extend(self.children(node).map(|child| Candidate {
+                    distance: rect_distance(self.entry_envelope(child), point.0),
+                    entry: child,
+                    exact: false,
+                })),
+            }
+        }
+        None
+    }
+
+    /// All pairs of an item in `self` and an item in `other` whose bounding rectangles intersect,
+    /// found by traversing both trees together.
+    ///
+    /// This is the filter step of a spatial join: test the returned candidates with an exact
+    /// predicate, such as [`Intersects`], to find the pairs of geometries which intersect.
+    pub fn intersection_candidates_with_other_tree<'a, G2>(
+        &'a self,
+        other: &'a StrTree<G2, T>,
+    ) -> impl Iterator<Item = (&'a G, &'a G2)> + 'a {
+        let mut stack: Vec<(Entry, Entry)> = self.root().zip(other.root()).into_iter().collect();
+        std::iter::from_fn(move || {
+            while let Some((a, b)) = stack.pop() {
+                let (a_envelope, b_envelope) = (self.entry_envelope(a), other.entry_envelope(b));
+                if !a_envelope.intersects(&b_envelope) {
+                    continue;
+                }
+                // descend the higher of the two entries
+                match (a, b) {
+                    (Entry::Item(a), Entry::Item(b)) => {
+                        return Some((&self.items[a], &other.items[b]));
+                    }
+                    (a, b) if height(a) >= height(b) => {
+                        stack.extend(self.children(a).map(|a| (a, b)));
+                    }
+                    (a, b) => {
+                        stack.extend(other.children(b).map(|b| (a, b)));
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    fn root(&self) -> Option<Entry> {
+        let level = self.levels.len().checked_sub(1)?;
+        Some(Entry::Node { level, index: 0 })
+    }
+
+    fn entry_envelope(&self, entry: Entry) -> Rect<T> {
+        match entry {
+            Entry::Node { level, index } => self.levels[level][index].envelope,
+            Entry::Item(index) => self.envelopes[index],
+        }
+    }
+
+    fn children(&self, entry: Entry) -> impl Iterator<Item = Entry> {
+        let (level, children) = match entry {
+            Entry::Node { level, index } => (level, self.levels[level][index].children.clone()),
+            Entry::Item(_) => (0, 0..0),
+        };
+        children.map(move |index| match level {
+            0 => Entry::Item(index),
+            _ => Entry::Node {
+                level: level - 1,
+                index,
+            },
+        })
+    }
+}
+
+/// The number of levels below an entry, counting items as level 0.
+fn height(entry: Entry) -> usize {
+    match entry {
+        Entry::Node { level, .. } => level + 1,
+        Entry::Item(_) => 0,
+    }
+}
+
+/// Sort `entries` so that consecutive groups of `node_capacity` entries are close together: sort
+/// by x into vertical slices of about the same number of groups as there are slices, and then
+/// sort each slice by y.
+fn sort_tile<E, T: GeoFloat>(
+    entries: &mut [E],
+    envelope: impl Fn(&E) -> Rect<T>,
+    node_capacity: usize,
+) {
+    let center = |entry: &E| envelope(entry).center();
+    let node_count = entries.len().div_ceil(node_capacity);
+    let slice_count = (node_count as f64).sqrt().ceil() as usize;
+    let slice_len = node_count.div_ceil(slice_count.max(1)) * node_capacity;
+    entries.sort_by(|a, b| compare(center(a).x, center(b).x));
+    for slice in entries.chunks_mut(slice_len.max(1)) {
+        slice.sort_by(|a, b| compare(center(a).y, center(b).y));
+    }
+}
+
+/// Group consecutive `envelopes` into nodes of `node_capacity` children.
+fn pack<T: GeoFloat>(envelopes: &[Rect<T>], node_capacity: usize) -> Vec<Node<T>> {
+    (0..envelopes.len())
+        .step_by(node_capacity)
+        .map(|start| {
+            let children = start..(start + node_capacity).min(envelopes.len());
+            let envelope = envelopes[children.clone()]
+                .iter()
+                .copied()
+                .reduce(|a, b| {
+                    Rect::new(
+                        coord! { x: partial_min(a.min().x, b.min().x), y: partial_min(a.min().y, b.min().y) },
+                        coord! { x: partial_max(a.max().x, b.max().x), y: partial_max(a.max().y, b.max().y) },
+                    )
+                })
+                .expect("nodes have at least one child");
+            Node { envelope, children }
+        })
+        .collect()
+}
+
+fn compare<T: GeoFloat>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// The Euclidean distance from `coord` to the closest point of `rect`.
+fn rect_distance<T: GeoFloat>(rect: Rect<T>, coord: crate::Coord<T>) -> T {
+    let dx = partial_max(
+        partial_max(rect.min().x - coord.x, coord.x - rect.max().x),
+        T::zero(),
+    );
+    let dy = partial_max(
+        partial_max(rect.min().y - coord.y, coord.y - rect.max().y),
+        T::zero(),
+    );
+    dx.hypot(dy)
+}
+
+/// An entry of the nearest neighbor search, ordered so that the closest is popped first from a
+/// max-heap. At equal distances, exact item distances come first.
+struct Candidate<T: GeoFloat> {
+    distance: T,
+    entry: Entry,
+    exact: bool,
+}
+
+impl<T: GeoFloat> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: GeoFloat> Eq for Candidate<T> {}
+
+impl<T: GeoFloat> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(other.distance, self.distance).then(self.exact.cmp(&other.exact))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Line, Polygon};
+
+    fn grid(n: usize) -> Vec<Polygon> {
+        (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i as f64 * 2., j as f64 * 2.)))
+            .map(|(x, y)| Rect::new((x, y), (x + 1., y + 1.)).to_polygon())
+            .collect()
+    }
+
+    #[test]
+    fn structure() {
+        let tree = StrTree::with_node_capacity(grid(20), 4);
+        assert_eq!(tree.len(), 400);
+        // 100 leaves, 25 nodes, 7 nodes, 2 nodes and the root
+        let sizes: Vec<_> = tree.levels.iter().map(Vec::len).collect();
+        assert_eq!(sizes, [100, 25, 7, 2, 1]);
+        assert_eq!(tree.envelope(), Some(Rect::new((0., 0.), (39., 39.))));
+
+        let empty = StrTree::<Polygon>::new(vec![]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.envelope(), None);
+        assert_eq!(empty.query_rect(Rect::new((0., 0.), (1., 1.))).count(), 0);
+        assert_eq!(empty.nearest_neighbor(point!(x: 0., y: 0.)), None);
+    }
+
+    #[test]
+    fn query_rect_matches_brute_force() {
+        let tree = StrTree::with_node_capacity(grid(20), 4);
+        for rect in [
+            Rect::new((3.5, 3.5), (10.2, 7.)),
+            Rect::new((-5., -5.), (0., 0.)),
+            Rect::new((1.2, 1.2), (1.8, 1.8)),
+            Rect::new((0., 0.), (40., 40.)),
+        ] {
+            let mut found: Vec<_> = tree
+                .query_rect(rect)
+                .map(|polygon| polygon as *const Polygon)
+                .collect();
+            let mut expected: Vec<_> = tree
+                .items()
+                .iter()
+                .filter(|polygon| polygon.bounding_rect().unwrap().intersects(&rect))
+                .map(|polygon| polygon as *const Polygon)
+                .collect();
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected, "{rect:?}");
+        }
+    }
+
+    #[test]
+    fn nearest_neighbor() {
+        let tree = StrTree::new(grid(20));
+        for (x, y) in [(-3., -3.), (5.5, 7.9), (17.2, 40.), (12.5, 12.5)] {
+            let query = point!(x: x, y: y);
+            let nearest = tree.nearest_neighbor(query).unwrap();
+            let min = tree
+                .items()
+                .iter()
+                .map(|item| Euclidean::distance(&query, item))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(Euclidean::distance(&query, nearest), min);
+        }
+
+        // the closest envelope doesn't hold the closest line
+        let tree = StrTree::with_node_capacity(
+            vec![
+                Line::from([(0., 0.), (10., 10.)]),
+                Line::from([(6., 3.), (7., 3.)]),
+            ],
+            2,
+        );
+        assert_eq!(
+            tree.nearest_neighbor(point!(x: 8., y: 2.)),
+            Some(&Line::from([(6., 3.), (7., 3.)]))
+        );
+    }
+
+    #[test]
+    fn join() {
+        let left = StrTree::with_node_capacity(grid(10), 3);
+        let lines: Vec<_> = (0..7)
+            .map(|i| {
+                let x = f64::from(i) * 3. + 0.5;
+                Line::from([(x, -1.), (x, 30.)])
+            })
+            .collect();
+        let right = StrTree::with_node_capacity(lines, 2);
+
+        let pointers = |(a, b): (&Polygon, &Line)| (a as *const Polygon, b as *const Line);
+        let mut found: Vec<_> = left
+            .intersection_candidates_with_other_tree(&right)
+            .map(pointers)
+            .collect();
+        let mut expected: Vec<_> = left
+            .items()
+            .iter()
+            .flat_map(|polygon| right.items().iter().map(move |line| (polygon, line)))
+            .filter(|(polygon, line)| polygon.intersects(*line))
+            .map(pointers)
+            .collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected);
+        assert_eq!(found.len(), 40);
+    }
+
+    #[test]
+    fn empty_geometries_are_skipped() {
+        let tree = StrTree::new(vec![wkt!(LINESTRING EMPTY), wkt!(LINESTRING(0. 0.,1. 1.))]);
+        assert_eq!(tree.len(), 1);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::bool_ops::BoolOpsNum;
+use crate::geometry::*;
+use crate::{BooleanOps, BoundingRect, CoordsIter, GeoFloat};
+
+/// The smallest vertex budget: a closed quadrilateral.
+const MIN_VERTICES: usize = 5;
+
+/// How many times a polygon is split at most, after which pieces are kept even if they're over
+/// the vertex budget, e.g. when too many vertices are too close together.
+const MAX_DEPTH: usize = 50;
+
+/// Split polygons into pieces with at most a given number of vertices, like PostGIS's
+/// `ST_Subdivide`.
+///
+/// Polygons over the budget are split in half across the longer side of their bounding
+/// rectangle, and the halves are split again until they're small enough. The pieces cover the
+/// polygons, up to the rounding of the [overlay](BooleanOps), without overlapping, and have
+/// small, tight bounding rectangles, so indexing them speeds up point-in-polygon tests and other
+/// predicates against huge polygons, e.g. countries, and overlays of the pieces can run in
+/// parallel.
+///
+/// Vertices are counted including the closing coordinate of each ring, and budgets below 5 are
+/// raised to 5.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, CoordsIter, LineString, Polygon, Subdivide};
+///
+/// // a circle with 1000 vertices
+/// let circle = Polygon::new(
+///     (0..1000)
+///         .map(|i| {
+///             let (sin, cos) = (i as f64 / 1000. * std::f64::consts::TAU).sin_cos();
+///             (cos, sin)
+///         })
+///         .collect::<LineString>(),
+///     vec![],
+/// );
+/// let pieces = circle.subdivide(64);
+/// assert!(pieces.len() > 1000 / 64);
+/// assert!(pieces.iter().all(|piece| piece.coords_count() <= 64));
+/// let area: f64 = pieces.iter().map(|piece| piece.unsigned_area()).sum();
+/// assert!((area - circle.unsigned_area()).abs() < 1e-6);
+/// ```
+pub trait Subdivide<T: BoolOpsNum + GeoFloat> {
+    /// Split into polygons with at most `max_vertices` vertices each.
+    fn subdivide(&self, max_vertices: usize) -> Vec<Polygon<T>>;
+}
+
+impl<T: BoolOpsNum + GeoFloat> Subdivide<T> for Polygon<T> {
+    fn subdivide(&self, max_vertices: usize) -> Vec<Polygon<T>> {
+        let mut pieces = vec![];
+        split(self.clone(), max_vertices.max(MIN_VERTICES), 0, &mut pieces);
+        pieces
+    }
+}
+
+impl<T: BoolOpsNum + GeoFloat> Subdivide<T> for MultiPolygon<T> {
+    fn subdivide(&self, max_vertices: usize) -> Vec<Polygon<T>> {
+        self.iter()
+            .flat_map(|polygon| polygon.subdivide(max_vertices))
+            .collect()
+    }
+}
+
+/// Push `polygon` to `pieces` if it's within the budget, or its halves' pieces otherwise.
+fn split<T: BoolOpsNum + GeoFloat>(
+    polygon: Polygon<T>,
+    max_vertices: usize,
+    depth: usize,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    let Some(bounds) = polygon.bounding_rect() else {
+        return;
+    };
+    if polygon.coords_count() <= max_vertices || depth == MAX_DEPTH {
+        pieces.push(polygon);
+        return;
+    }
+    let (min, max) = (bounds.min(), bounds.max());
+    let two = T::one() + T::one();
+    let halves = if bounds.width() >= bounds.height() {
+        let x = (min.x + max.x) / two;
+        [
+            Rect::new(min, Coord { x, y: max.y }),
+            Rect::new(Coord { x, y: min.y }, max),
+        ]
+    } else {
+        let y = (min.y + max.y) / two;
+        [
+            Rect::new(min, Coord { x: max.x, y }),
+            Rect::new(Coord { x: min.x, y }, max),
+        ]
+    };
+    for half in halves {
+        for part in polygon.intersection(&half.to_polygon()) {
+            split(part, max_vertices, depth + 1, pieces);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testkit::polygon_with_holes;
+    use crate::{wkt, Area, Intersects, Relate};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn coverage() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let polygon = polygon_with_holes(&mut rng, 400, 5);
+        let pieces = polygon.subdivide(32);
+        assert!(pieces.iter().all(|piece| piece.coords_count() <= 32));
+
+        let area: f64 = pieces.iter().map(|piece| piece.unsigned_area()).sum();
+        assert_relative_eq!(area, polygon.unsigned_area(), max_relative = 1e-6);
+        for (index, piece) in pieces.iter().enumerate() {
+            for other in &pieces[index + 1..] {
+                assert!(!piece.relate(other).is_overlaps());
+            }
+        }
+        // nothing inside the holes
+        for interior in polygon.interiors() {
+            let hole = Polygon::new(interior.clone(), vec![]);
+            let interior_point = crate::InteriorPoint::interior_point(&hole).unwrap();
+            assert!(!pieces.iter().any(|piece| piece.intersects(&interior_point)));
+        }
+    }
+
+    #[test]
+    fn small_polygons() {
+        let square = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)));
+        assert_eq!(square.subdivide(5), vec![square.clone()]);
+        // the budget is raised to 5
+        assert_eq!(square.subdivide(0), vec![square]);
+
+        let multi = wkt!(MULTIPOLYGON(
+            ((0. 0.,4. 0.,4. 1.,3. 2.,2. 1.,1. 2.,0. 1.,0. 0.)),
+            ((10. 0.,11. 0.,11. 1.,10. 0.))
+        ));
+        let pieces = multi.subdivide(5);
+        assert!(pieces.len() > 2);
+        assert!(pieces.iter().all(|piece| piece.coords_count() <= 5));
+        let area: f64 = pieces.iter().map(|piece| piece.unsigned_area()).sum();
+        assert_relative_eq!(area, multi.unsigned_area(), max_relative = 1e-6);
+        assert!(Polygon::<f64>::new(LineString::new(vec![]), vec![])
+            .subdivide(5)
+            .is_empty());
+    }
+}
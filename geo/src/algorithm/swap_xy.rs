@@ -0,0 +1,57 @@
+use crate::{Coord, CoordNum, MapCoords, MapCoordsInPlace};
+
+/// Swap the x and y coordinates of a geometry.
+///
+/// `geo` always treats x as the longitude (or easting) and y as the latitude (or northing), but
+/// many sources list latitude first, e.g. GPS logs, and WKT or GML in CRSs like EPSG:4326 whose
+/// authority defined axis order is latitude, longitude. Reading such data as-is silently puts
+/// every geometry in the wrong place, so swap the axes on import.
+/// [`GeoReferenced::from_authority_axis_order`](crate::GeoReferenced::from_authority_axis_order)
+/// does so depending on the CRS.
+///
+/// Swapping the axes mirrors the geometry, so it also reverses the winding order of rings.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, SwapXY};
+///
+/// let lat_lon = wkt!(LINESTRING(52.5 13.4,48.9 2.4));
+/// assert_eq!(lat_lon.swap_xy(), wkt!(LINESTRING(13.4 52.5,2.4 48.9)));
+/// ```
+pub trait SwapXY<T: CoordNum>:
+    Sized + MapCoords<T, T, Output = Self> + MapCoordsInPlace<T>
+{
+    /// Swap the x and y coordinates.
+    fn swap_xy(&self) -> Self {
+        self.map_coords(|Coord { x, y }| Coord { x: y, y: x })
+    }
+
+    /// Like [`Self::swap_xy`], but in place.
+    fn swap_xy_in_place(&mut self) {
+        self.map_coords_in_place(|Coord { x, y }| Coord { x: y, y: x })
+    }
+}
+impl<T: CoordNum, G: MapCoords<T, T, Output = Self> + MapCoordsInPlace<T>> SwapXY<T> for G {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Geometry, Orient, Winding};
+
+    #[test]
+    fn swap() {
+        let mut point = point!(x: 1, y: 2);
+        point.swap_xy_in_place();
+        assert_eq!(point, point!(x: 2, y: 1));
+
+        let polygon =
+            wkt!(POLYGON((0. 0.,2. 0.,2. 1.,0. 0.))).orient(crate::orient::Direction::Default);
+        let swapped = polygon.swap_xy();
+        assert_eq!(swapped, wkt!(POLYGON((0. 0.,0. 2.,1. 2.,0. 0.))));
+        assert!(swapped.exterior().is_cw());
+
+        let geometry = Geometry::from(polygon.clone());
+        assert_eq!(geometry.swap_xy().swap_xy(), Geometry::from(polygon));
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::{line_intersection::line_intersection, Coord, LineIntersection};
+use crate::{line_intersection::line_intersection, Coord, GeoFloat, Line, LineIntersection};
 
 /// A segment of a input [`Cross`] type.
 ///
@@ -311,6 +311,73 @@ where
     }
 }
 
+/// A line, along with its position in the input of [`intersections`].
+#[derive(Debug, Clone, Copy)]
+struct IndexedLine<T: GeoFloat> {
+    index: usize,
+    line: Line<T>,
+}
+
+impl<T: GeoFloat> Cross for IndexedLine<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+/// Find all pairs of intersecting lines among `lines`.
+///
+/// Yields `(i, j, intersection)` for every pair of lines `i < j` (by their position in the input)
+/// which intersect, in no particular order. Lines which overlap along a collinear stretch are
+/// reported once, with a [`LineIntersection::Collinear`].
+///
+/// This uses the same [Bentley-Ottman] sweep as [`Intersections`], and runs in O((n + k) log n)
+/// time for `n` lines with `k` intersections, so it scales to millions of segments as long as
+/// most of them don't intersect.
+///
+/// # Examples
+///
+/// ```
+/// use geo::sweep::intersections;
+/// use geo::{coord, Line, LineIntersection};
+///
+/// let lines = [
+///     Line::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. }),
+///     Line::new(coord! { x: 5., y: 5. }, coord! { x: 6., y: 5. }),
+///     Line::new(coord! { x: 0., y: 2. }, coord! { x: 2., y: 0. }),
+/// ];
+/// let found: Vec<_> = intersections(lines).collect();
+/// assert_eq!(
+///     found,
+///     [(
+///         0,
+///         2,
+///         LineIntersection::SinglePoint {
+///             intersection: coord! { x: 1., y: 1. },
+///             is_proper: true
+///         }
+///     )]
+/// );
+/// ```
+///
+/// [Bentley-Ottman]: //en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+pub fn intersections<T: GeoFloat>(
+    lines: impl IntoIterator<Item = Line<T>>,
+) -> impl Iterator<Item = (usize, usize, LineIntersection<T>)> {
+    let lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| IndexedLine { index, line });
+    Intersections::from_iter(lines).map(|(a, b, intersection)| {
+        if a.index < b.index {
+            (a.index, b.index, intersection)
+        } else {
+            (b.index, a.index, intersection)
+        }
+    })
+}
+
 #[cfg(test)]
 pub(super) mod tests {
     use crate::Line;
@@ -378,6 +445,34 @@ pub(super) mod tests {
         assert_eq!(count, verify);
     }
 
+    #[test]
+    fn indexed_intersections() {
+        // a grid of horizontal and vertical lines, plus a diagonal and a collinear duplicate
+        let mut input: Vec<Line<f64>> = (0..10)
+            .flat_map(|i| {
+                let i = f64::from(i);
+                [
+                    Line::from([(0., i), (9., i)]),
+                    Line::from([(i + 0.5, -1.), (i + 0.5, 10.)]),
+                ]
+            })
+            .collect();
+        input.push(Line::from([(-1., -1.), (11., 11.)]));
+        input.push(Line::from([(2., 3.), (5., 3.)]));
+
+        let mut expected = vec![];
+        for (i, a) in input.iter().enumerate() {
+            for (j, b) in input.iter().enumerate().skip(i + 1) {
+                if let Some(intersection) = line_intersection(*a, *b) {
+                    expected.push((i, j, intersection));
+                }
+            }
+        }
+        let mut found: Vec<_> = intersections(input).collect();
+        found.sort_by_key(|(i, j, _)| (*i, *j));
+        assert_eq!(found, expected);
+    }
+
     #[test]
     #[ignore]
     fn check_adhoc_crossings() {
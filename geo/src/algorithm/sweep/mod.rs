@@ -26,4 +26,4 @@ mod proc;
 use proc::Sweep;
 
 mod iter;
-pub use iter::Intersections;
+pub use iter::{intersections, Intersections};
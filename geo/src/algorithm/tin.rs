@@ -0,0 +1,303 @@
+use spade::{
+    DelaunayTriangulation, FloatTriangulation, HasPosition, Intersection, LineIntersectionIterator,
+    Point2, Triangulation,
+};
+
+use crate::triangulate_spade::{SpadeTriangulationFloat, TriangulationError};
+use crate::{Coord, ElevationProfile, Line, LineString, MultiLineString, Point, Polygon};
+
+/// A triangulated irregular network: a terrain surface made of the Delaunay triangles between
+/// points of known elevation, e.g. from a survey or a lidar point cloud.
+///
+/// The elevation within each triangle is interpolated linearly between its corners, and isn't
+/// defined outside the convex hull of the points.
+///
+/// Requires the `"spade"` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Tin};
+///
+/// let tin = Tin::new([
+///     (coord! { x: 0., y: 0. }, 100.),
+///     (coord! { x: 10., y: 0. }, 110.),
+///     (coord! { x: 0., y: 10. }, 120.),
+///     (coord! { x: 10., y: 10. }, 130.),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(tin.elevation_at(coord! { x: 5., y: 5. }), Some(115.));
+/// assert_eq!(tin.elevation_at(coord! { x: 11., y: 5. }), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tin<T: SpadeTriangulationFloat = f64> {
+    triangulation: DelaunayTriangulation<TinVertex<T>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TinVertex<T: SpadeTriangulationFloat> {
+    position: Point2<T>,
+    elevation: T,
+}
+
+impl<T: SpadeTriangulationFloat> HasPosition for TinVertex<T> {
+    type Scalar = T;
+
+    fn position(&self) -> Point2<T> {
+        self.position
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Tin<T> {
+    /// Triangulate points with their elevations. If several points are at the same position,
+    /// the last one is kept.
+    ///
+    /// Returns an error if a coordinate is not finite, or too large to be triangulated.
+    pub fn new(
+        points: impl IntoIterator<Item = (Coord<T>, T)>,
+    ) -> Result<Self, TriangulationError> {
+        let vertices = points
+            .into_iter()
+            .map(|(coord, elevation)| TinVertex {
+                position: Point2::new(coord.x, coord.y),
+                elevation,
+            })
+            .collect();
+        let triangulation = DelaunayTriangulation::bulk_load_stable(vertices)
+            .map_err(TriangulationError::SpadeError)?;
+        Ok(Self { triangulation })
+    }
+
+    /// The number of points in the network.
+    pub fn len(&self) -> usize {
+        self.triangulation.num_vertices()
+    }
+
+    /// Whether the network has no points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The elevation of the surface at `coord`, or `None` outside the convex hull of the points.
+    pub fn elevation_at(&self, coord: Coord<T>) -> Option<T> {
+        self.triangulation.barycentric().interpolate(
+            |vertex| vertex.data().elevation,
+            Point2::new(coord.x, coord.y),
+        )
+    }
+
+    /// Append the coordinates where the segment from `start` to `end` crosses the edges of the
+    /// triangles, or passes through their corners, in order from `start` and excluding both ends.
+    fn crossings(&self, start: Coord<T>, end: Coord<T>, profile: &mut (Vec<Coord<T>>, Vec<T>)) {
+        let direction = end - start;
+        let cross = |a: Coord<T>, b: Coord<T>| a.x * b.y - a.y * b.x;
+        let intersections = LineIntersectionIterator::new(
+            &self.triangulation,
+            Point2::new(start.x, start.y),
+            Point2::new(end.x, end.y),
+        );
+        for intersection in intersections {
+            let (coord, elevation) = match intersection {
+                Intersection::EdgeIntersection(edge) => {
+                    let [from, to] = edge.vertices().map(|vertex| *vertex.data());
+                    let from_coord = Coord::from((from.position.x, from.position.y));
+                    let edge_direction = Coord::from((to.position.x, to.position.y)) - from_coord;
+                    let denominator = cross(edge_direction, direction);
+                    if denominator == T::zero() {
+                        continue;
+                    }
+                    let t = cross(start - from_coord, direction) / denominator;
+                    (
+                        from_coord + edge_direction * t,
+                        from.elevation + (to.elevation - from.elevation) * t,
+                    )
+                }
+                Intersection::VertexIntersection(vertex) => {
+                    let vertex = vertex.data();
+                    (
+                        Coord::from((vertex.position.x, vertex.position.y)),
+                        vertex.elevation,
+                    )
+                }
+                // the ends of an overlapping edge are reported as vertex intersections
+                Intersection::EdgeOverlap(_) => continue,
+            };
+            if coord != start && coord != end && profile.0.last() != Some(&coord) {
+                profile.0.push(coord);
+                profile.1.push(elevation);
+            }
+        }
+    }
+}
+
+/// Assign an elevation to every vertex of a 2D geometry from a [`Tin`], e.g. to drape roads or
+/// rivers onto a terrain model.
+///
+/// With `insert_crossings`, a vertex is also inserted wherever a segment crosses an edge of the
+/// network, so that the draped geometry follows the surface exactly, rather than cutting through
+/// hills and valleys between its original vertices.
+///
+/// Returns `None` if any vertex is outside the network.
+///
+/// Requires the `"spade"` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, Drape, Tin};
+/// use approx::assert_relative_eq;
+///
+/// // a ridge along x = 5
+/// let tin = Tin::new([
+///     (coord! { x: 0., y: 0. }, 0.),
+///     (coord! { x: 5., y: -5. }, 50.),
+///     (coord! { x: 5., y: 5. }, 50.),
+///     (coord! { x: 10., y: 0. }, 0.),
+/// ])
+/// .unwrap();
+///
+/// let road = wkt!(LINESTRING(1. 0.,9. 0.));
+/// let profile = road.drape(&tin, false).unwrap();
+/// assert_relative_eq!(profile.elevations(), [10., 10.].as_slice(), epsilon = 1e-9);
+///
+/// let profile = road.drape(&tin, true).unwrap();
+/// assert_eq!(profile.line_string(), &wkt!(LINESTRING(1. 0.,5. 0.,9. 0.)));
+/// assert_relative_eq!(profile.elevations(), [10., 50., 10.].as_slice(), epsilon = 1e-9);
+/// ```
+pub trait Drape<T: SpadeTriangulationFloat> {
+    type Output;
+
+    /// Interpolate the elevation of every vertex, optionally inserting vertices at the crossings
+    /// with the edges of `tin`.
+    fn drape(&self, tin: &Tin<T>, insert_crossings: bool) -> Option<Self::Output>;
+}
+
+impl<T: SpadeTriangulationFloat> Drape<T> for Point<T> {
+    type Output = T;
+
+    fn drape(&self, tin: &Tin<T>, _insert_crossings: bool) -> Option<T> {
+        tin.elevation_at(self.0)
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Drape<T> for LineString<T> {
+    type Output = ElevationProfile<T>;
+
+    fn drape(&self, tin: &Tin<T>, insert_crossings: bool) -> Option<ElevationProfile<T>> {
+        let mut profile = (
+            Vec::with_capacity(self.0.len()),
+            Vec::with_capacity(self.0.len()),
+        );
+        for (i, &coord) in self.0.iter().enumerate() {
+            if insert_crossings && i > 0 {
+                tin.crossings(self.0[i - 1], coord, &mut profile);
+            }
+            profile.0.push(coord);
+            profile.1.push(tin.elevation_at(coord)?);
+        }
+        ElevationProfile::new(LineString::new(profile.0), profile.1)
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Drape<T> for Line<T> {
+    type Output = ElevationProfile<T>;
+
+    fn drape(&self, tin: &Tin<T>, insert_crossings: bool) -> Option<ElevationProfile<T>> {
+        LineString::from(*self).drape(tin, insert_crossings)
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Drape<T> for MultiLineString<T> {
+    type Output = Vec<ElevationProfile<T>>;
+
+    fn drape(&self, tin: &Tin<T>, insert_crossings: bool) -> Option<Self::Output> {
+        self.iter()
+            .map(|line_string| line_string.drape(tin, insert_crossings))
+            .collect()
+    }
+}
+
+/// The profiles of the exterior ring, followed by those of the interior rings.
+impl<T: SpadeTriangulationFloat> Drape<T> for Polygon<T> {
+    type Output = Vec<ElevationProfile<T>>;
+
+    fn drape(&self, tin: &Tin<T>, insert_crossings: bool) -> Option<Self::Output> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(|ring| ring.drape(tin, insert_crossings))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Euclidean, Length};
+
+    fn plane(coord: Coord) -> f64 {
+        3. * coord.x - 2. * coord.y + 7.
+    }
+
+    fn grid() -> Tin {
+        Tin::new((0..5).flat_map(|i| {
+            (0..5).map(move |j| {
+                // perturb the grid so that the triangulation is unique
+                let c = coord! { x: i as f64 * 10. + (j % 2) as f64, y: j as f64 * 10. };
+                (c, plane(c))
+            })
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn interpolates_planes_exactly() {
+        let tin = grid();
+        assert_eq!(tin.len(), 25);
+        for c in [
+            coord! { x: 1., y: 0. },
+            coord! { x: 17.3, y: 21.9 },
+            coord! { x: 39., y: 39. },
+        ] {
+            assert_relative_eq!(tin.elevation_at(c).unwrap(), plane(c), epsilon = 1e-9);
+        }
+        assert_eq!(tin.elevation_at(coord! { x: -1., y: 5. }), None);
+        assert_eq!(wkt!(POINT(41. 5.)).drape(&tin, false), None);
+        assert!(Tin::<f64>::new([]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn inserts_crossings() {
+        let tin = grid();
+        let line_string = wkt!(LINESTRING(2. 3.,37. 31.,12. 35.));
+        let draped = line_string.drape(&tin, false).unwrap();
+        assert_eq!(draped.line_string(), &line_string);
+
+        let profile = line_string.drape(&tin, true).unwrap();
+        let (densified, elevations) = profile.into_parts();
+        assert!(densified.0.len() > 10);
+        assert_relative_eq!(
+            densified.length::<Euclidean>(),
+            line_string.length::<Euclidean>(),
+            epsilon = 1e-9
+        );
+        for (c, elevation) in densified.coords().zip(elevations) {
+            assert_relative_eq!(elevation, plane(*c), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn polygon() {
+        let tin = grid();
+        let polygon = wkt!(POLYGON(
+            (5. 5.,35. 5.,35. 35.,5. 5.),
+            (20. 10.,30. 10.,30. 20.,20. 10.)
+        ));
+        let profiles = polygon.drape(&tin, false).unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[1].elevations().len(), 4);
+        assert!(wkt!(POLYGON((5. 5.,50. 5.,5. 35.,5. 5.)))
+            .drape(&tin, true)
+            .is_none());
+    }
+}
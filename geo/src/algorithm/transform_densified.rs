@@ -0,0 +1,284 @@
+use crate::{
+    Coord, CoordFloat, Distance, Euclidean, Line, LineString, MultiLineString, MultiPolygon, Point,
+    Polygon, Rect, Triangle,
+};
+
+/// The number of times an edge is bisected at most, so that each edge becomes at most 65536
+/// segments even if the transformed edge is badly behaved, e.g. crosses a singularity.
+const MAX_DEPTH: u32 = 16;
+
+/// Transform the coordinates of a geometry, inserting vertices so that its transformed edges
+/// follow the curved images of the original edges.
+///
+/// A straight edge usually becomes a curve under a projection. Transforming only the vertices
+/// replaces that curve with a straight chord, which can be far off for long edges, e.g. a
+/// parallel of latitude spanning a continent. Here, each edge is bisected recursively until the
+/// transformed midpoint of every piece is within `max_error` of the chord between the transformed
+/// ends of the piece, so vertices are only added where the image is curved.
+///
+/// `max_error` is in the units of the transformed coordinates. It must be greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, LocalProjection, Point, TransformDensified};
+///
+/// // a parallel from 0° to 10° E, projected about 5° E, 60° N
+/// let parallel = wkt!(LINESTRING(0. 60.,10. 60.));
+/// let projection = LocalProjection::new((5., 60.).into());
+///
+/// let projected = parallel.transform_densified(|c| projection.project(&Point(c)).0, 100.);
+/// assert!(projected.0.len() > 2);
+///
+/// // a meridian stays straight, so needs no extra vertices
+/// let meridian = wkt!(LINESTRING(5. 50.,5. 70.));
+/// let projected = meridian.transform_densified(|c| projection.project(&Point(c)).0, 100.);
+/// assert_eq!(projected.0.len(), 2);
+/// ```
+///
+/// With the `use-proj` feature, a fallible transformation such as `proj::Proj::convert` can be
+/// used with [`try_transform_densified`](Self::try_transform_densified).
+pub trait TransformDensified<T: CoordFloat> {
+    type Output;
+
+    /// Transform the coordinates with `func`, inserting vertices so that the transformed edges
+    /// deviate from the images of the original edges by at most `max_error`.
+    fn transform_densified(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy,
+        max_error: T,
+    ) -> Self::Output {
+        self.try_transform_densified(
+            |coord| Ok::<_, std::convert::Infallible>(func(coord)),
+            max_error,
+        )
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Like [`transform_densified`](Self::transform_densified), but with a fallible
+    /// transformation, returning the first error.
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E>;
+}
+
+/// Push the transformed interior vertices of the edge from `start` to `end`, whose transformed
+/// ends are `projected_start` and `projected_end`.
+fn densify_between<T: CoordFloat, E>(
+    (start, projected_start): (Coord<T>, Coord<T>),
+    (end, projected_end): (Coord<T>, Coord<T>),
+    func: &impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    max_error: T,
+    depth: u32,
+    output: &mut Vec<Coord<T>>,
+) -> Result<(), E> {
+    if depth == MAX_DEPTH {
+        return Ok(());
+    }
+    let two = T::one() + T::one();
+    let mid = (start + end) / two;
+    let projected_mid = func(mid)?;
+    let error = Euclidean::distance(
+        &Point(projected_mid),
+        &Line::new(projected_start, projected_end),
+    );
+    if error <= max_error {
+        return Ok(());
+    }
+    let mid = (mid, projected_mid);
+    let depth = depth + 1;
+    densify_between(
+        (start, projected_start),
+        mid,
+        func,
+        max_error,
+        depth,
+        output,
+    )?;
+    output.push(projected_mid);
+    densify_between(mid, (end, projected_end), func, max_error, depth, output)
+}
+
+fn transform_densified_coords<T: CoordFloat, E>(
+    coords: &[Coord<T>],
+    func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    max_error: T,
+) -> Result<Vec<Coord<T>>, E> {
+    assert!(max_error > T::zero());
+    let mut output = Vec::with_capacity(coords.len());
+    let mut previous: Option<(Coord<T>, Coord<T>)> = None;
+    for &coord in coords {
+        let current = (coord, func(coord)?);
+        if let Some(previous) = previous {
+            densify_between(previous, current, &func, max_error, 0, &mut output)?;
+        }
+        output.push(current.1);
+        previous = Some(current);
+    }
+    Ok(output)
+}
+
+impl<T: CoordFloat> TransformDensified<T> for Line<T> {
+    type Output = LineString<T>;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        transform_densified_coords(&[self.start, self.end], func, max_error).map(LineString::new)
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for LineString<T> {
+    type Output = Self;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        transform_densified_coords(&self.0, func, max_error).map(LineString::new)
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for MultiLineString<T> {
+    type Output = Self;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        self.iter()
+            .map(|line_string| line_string.try_transform_densified(func, max_error))
+            .collect::<Result<_, _>>()
+            .map(MultiLineString::new)
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for Polygon<T> {
+    type Output = Self;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        Ok(Polygon::new(
+            self.exterior().try_transform_densified(func, max_error)?,
+            self.interiors()
+                .iter()
+                .map(|interior| interior.try_transform_densified(func, max_error))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for MultiPolygon<T> {
+    type Output = Self;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        self.iter()
+            .map(|polygon| polygon.try_transform_densified(func, max_error))
+            .collect::<Result<_, _>>()
+            .map(MultiPolygon::new)
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for Rect<T> {
+    type Output = Polygon<T>;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        self.to_polygon().try_transform_densified(func, max_error)
+    }
+}
+
+impl<T: CoordFloat> TransformDensified<T> for Triangle<T> {
+    type Output = Polygon<T>;
+
+    fn try_transform_densified<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        max_error: T,
+    ) -> Result<Self::Output, E> {
+        self.to_polygon().try_transform_densified(func, max_error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt};
+
+    fn parabola(coord: Coord) -> Coord {
+        coord! { x: coord.x, y: coord.x * coord.x }
+    }
+
+    #[test]
+    fn within_max_error() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. });
+        for max_error in [1., 0.1, 0.001] {
+            let densified = line.transform_densified(parabola, max_error);
+            assert_eq!(densified.0.first(), Some(&coord! { x: 0., y: 0. }));
+            assert_eq!(densified.0.last(), Some(&coord! { x: 4., y: 16. }));
+            for window in densified.0.windows(2) {
+                let (x0, x1) = (window[0].x, window[1].x);
+                assert!(x0 < x1);
+                // the parabola deviates from a chord the most halfway along it
+                let mid = parabola(coord! { x: (x0 + x1) / 2., y: 0. });
+                let error = Euclidean::distance(&Point(mid), &Line::new(window[0], window[1]));
+                assert!(error <= max_error);
+            }
+        }
+    }
+
+    #[test]
+    fn straight_images() {
+        let polygon = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)));
+        let affine = |c: Coord| coord! { x: 2. * c.x + c.y, y: c.y - 3. };
+        let transformed = polygon.transform_densified(affine, 1e-9);
+        assert_eq!(transformed, crate::MapCoords::map_coords(&polygon, affine));
+
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        assert_eq!(rect.transform_densified(affine, 1.).exterior().0.len(), 5);
+        assert_eq!(
+            LineString::<f64>::new(vec![]).transform_densified(affine, 1.),
+            LineString::new(vec![])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let line_string = wkt!(LINESTRING(0. 0.,1. 0.,2. 0.));
+        let result = line_string.try_transform_densified(
+            |c| {
+                if c.x > 1.5 {
+                    Err("out of bounds")
+                } else {
+                    Ok(parabola(c))
+                }
+            },
+            0.01,
+        );
+        assert_eq!(result, Err("out of bounds"));
+    }
+
+    #[test]
+    fn singularity() {
+        // the image of the edge is unbounded, so bisection stops at the maximum depth
+        let line = Line::new(coord! { x: -1., y: 0. }, coord! { x: 1., y: 0. });
+        let densified = line.transform_densified(|c| coord! { x: c.x, y: 1. / c.x }, 0.1);
+        assert!(densified.0.len() <= (1 << MAX_DEPTH) + 1);
+    }
+}
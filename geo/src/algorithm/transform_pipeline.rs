@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::algorithm::utm::Projection;
+use crate::algorithm::web_mercator::{lon_lat_to_web_mercator, web_mercator_to_lon_lat};
+use crate::utils::progress_chunks;
+use crate::{AffineTransform, Coord, CoordFloat, CoordNum, MapCoords, MapCoordsInPlace, UtmZone};
+
+/// A sequence of coordinate transformations, applied to each coordinate in a single pass.
+///
+/// Chaining e.g. [`WebMercator`](crate::WebMercator), [`AffineOps`](crate::AffineOps) and
+/// [`MapCoords`] iterates over the coordinates, and allocates a new geometry, once per step. A
+/// pipeline is built once, then applies all of its steps to each coordinate in turn, to
+/// geometries or to raw coordinate buffers. Consecutive affine transforms are composed into one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, AffineTransform, TransformPipeline};
+/// use approx::assert_relative_eq;
+///
+/// // project to Web Mercator, then to the pixels of a 512 × 512 map of the whole world
+/// let world = 20_037_508.342789244;
+/// let pipeline = TransformPipeline::new()
+///     .to_web_mercator()
+///     .affine(AffineTransform::translate(world, -world))
+///     .affine(AffineTransform::scale(256. / world, -256. / world, (0., 0.)));
+///
+/// let pixels = pipeline.transform(&wkt!(LINESTRING(-180. 85.0511287798066,0. 0.)));
+/// assert_relative_eq!(pixels, wkt!(LINESTRING(0. 0.,256. 256.)), epsilon = 1e-6);
+///
+/// // interleaved x, y coordinates, e.g. from a GeoArrow or FFI buffer
+/// let mut buffer = [0., 0., 180., -85.0511287798066];
+/// pipeline.transform_interleaved(&mut buffer);
+/// assert_relative_eq!(buffer.as_slice(), [256., 256., 512., 512.].as_slice(), epsilon = 1e-6);
+/// ```
+pub struct TransformPipeline<T: CoordNum = f64> {
+    steps: Vec<Step<T>>,
+}
+
+enum Step<T: CoordNum> {
+    Affine(AffineTransform<T>),
+    Function(Box<dyn Fn(Coord<T>) -> Coord<T> + Send + Sync>),
+}
+
+impl<T: CoordNum> TransformPipeline<T> {
+    /// An empty pipeline, which leaves coordinates unchanged.
+    pub fn new() -> Self {
+        Self { steps: vec![] }
+    }
+
+    /// Append an affine transform, composing it with the previous step if that's affine too.
+    #[must_use]
+    pub fn affine(mut self, transform: AffineTransform<T>) -> Self {
+        match self.steps.last_mut() {
+            Some(Step::Affine(previous)) => *previous = previous.compose(&transform),
+            _ => self.steps.push(Step::Affine(transform)),
+        }
+        self
+    }
+
+    /// Append an arbitrary function of each coordinate.
+    #[must_use]
+    pub fn map(mut self, func: impl Fn(Coord<T>) -> Coord<T> + Send + Sync + 'static) -> Self {
+        self.steps.push(Step::Function(Box::new(func)));
+        self
+    }
+
+    /// The number of steps, after composing consecutive affine transforms.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the pipeline has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Apply every step to a coordinate.
+    pub fn apply(&self, coord: Coord<T>) -> Coord<T> {
+        self.steps.iter().fold(coord, |coord, step| match step {
+            Step::Affine(transform) => transform.apply(coord),
+            Step::Function(func) => func(coord),
+        })
+    }
+
+    /// Apply the pipeline to a geometry.
+    pub fn transform<G: MapCoords<T, T>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| self.apply(coord))
+    }
+
+    /// Apply the pipeline to a geometry, in place.
+    pub fn transform_in_place<G: MapCoordsInPlace<T>>(&self, geometry: &mut G) {
+        geometry.map_coords_in_place(|coord| self.apply(coord))
+    }
+
+    /// Apply the pipeline to a slice of coordinates, in place.
+    pub fn transform_coords(&self, coords: &mut [Coord<T>]) {
+        for coord in coords {
+            *coord = self.apply(*coord);
+        }
+    }
+
+    /// Like [`transform_coords`](Self::transform_coords), but calls `progress` with the number
+    /// of coordinates transformed so far and the total after each chunk of coordinates, e.g. to
+    /// show a progress bar.
+    pub fn transform_coords_with_progress(
+        &self,
+        coords: &mut [Coord<T>],
+        mut progress: impl FnMut(usize, usize),
+    ) {
+        let total = coords.len();
+        for chunk in progress_chunks(total) {
+            let end = chunk.end;
+            self.transform_coords(&mut coords[chunk]);
+            progress(end, total);
+        }
+    }
+
+    /// Apply the pipeline to a buffer of interleaved `x, y` values, in place.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the buffer is odd.
+    pub fn transform_interleaved(&self, xy: &mut [T]) {
+        assert!(xy.len() % 2 == 0, "interleaved buffer has an odd length");
+        for pair in xy.chunks_exact_mut(2) {
+            let Coord { x, y } = self.apply(Coord {
+                x: pair[0],
+                y: pair[1],
+            });
+            pair[0] = x;
+            pair[1] = y;
+        }
+    }
+
+    /// Apply the pipeline to separate buffers of `x` and `y` values, in place.
+    ///
+    /// # Panics
+    ///
+    /// If the buffers have different lengths.
+    pub fn transform_separated(&self, xs: &mut [T], ys: &mut [T]) {
+        assert_eq!(xs.len(), ys.len(), "x and y buffers have different lengths");
+        for (x, y) in xs.iter_mut().zip(ys) {
+            let coord = self.apply(Coord { x: *x, y: *y });
+            (*x, *y) = (coord.x, coord.y);
+        }
+    }
+}
+
+impl<T: CoordFloat + 'static> TransformPipeline<T> {
+    /// Append a projection from longitude/latitude to [Web Mercator](crate::WebMercator).
+    #[must_use]
+    pub fn to_web_mercator(self) -> Self {
+        self.map(lon_lat_to_web_mercator)
+    }
+
+    /// Append an unprojection from [Web Mercator](crate::WebMercator) to longitude/latitude.
+    #[must_use]
+    pub fn web_mercator_to_wgs84(self) -> Self {
+        self.map(web_mercator_to_lon_lat)
+    }
+
+    /// Append a projection from longitude/latitude to a [`UtmZone`].
+    #[must_use]
+    pub fn to_utm(self, zone: UtmZone) -> Self {
+        let projection = Projection::new(zone);
+        self.map(move |coord| projection.forward(coord))
+    }
+
+    /// Append an unprojection from a [`UtmZone`] to longitude/latitude.
+    #[must_use]
+    pub fn utm_to_wgs84(self, zone: UtmZone) -> Self {
+        let projection = Projection::new(zone);
+        self.map(move |coord| projection.inverse(coord))
+    }
+}
+
+impl<T: CoordNum> Default for TransformPipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> fmt::Debug for TransformPipeline<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.steps.iter().map(|step| match step {
+                Step::Affine(transform) => format!("{transform:?}"),
+                Step::Function(_) => "Function".to_string(),
+            }))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt, AffineOps, Utm, WebMercator};
+
+    #[test]
+    fn composes_affine_steps() {
+        let pipeline = TransformPipeline::new()
+            .affine(AffineTransform::translate(1., 2.))
+            .affine(AffineTransform::scale(2., 2., (0., 0.)))
+            .map(|c| coord! { x: c.y, y: c.x })
+            .affine(AffineTransform::rotate(90., (0., 0.)));
+        assert_eq!(pipeline.len(), 3);
+
+        let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        let expected = polygon
+            .affine_transform(&AffineTransform::translate(1., 2.))
+            .affine_transform(&AffineTransform::scale(2., 2., (0., 0.)))
+            .map_coords(|c| coord! { x: c.y, y: c.x })
+            .affine_transform(&AffineTransform::rotate(90., (0., 0.)));
+        assert_relative_eq!(pipeline.transform(&polygon), expected);
+        let mut in_place = polygon;
+        pipeline.transform_in_place(&mut in_place);
+        assert_relative_eq!(in_place, expected);
+    }
+
+    #[test]
+    fn projections() {
+        let zone = UtmZone::new(33, true).unwrap();
+        let pipeline = TransformPipeline::new()
+            .web_mercator_to_wgs84()
+            .to_utm(zone)
+            .utm_to_wgs84(zone)
+            .to_web_mercator();
+        let point = point!(x: 13.4, y: 52.5);
+        let projected = point.to_web_mercator();
+        assert_relative_eq!(pipeline.transform(&projected), projected, epsilon = 1e-2);
+        assert_relative_eq!(
+            TransformPipeline::new().to_utm(zone).transform(&point),
+            point.to_utm(zone)
+        );
+        assert!(TransformPipeline::<f64>::new().is_empty());
+    }
+
+    #[test]
+    fn buffers() {
+        let pipeline = TransformPipeline::new().affine(AffineTransform::translate(1, 10));
+        let mut coords = [coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }];
+        pipeline.transform_coords(&mut coords);
+        assert_eq!(coords, [coord! { x: 1, y: 10 }, coord! { x: 2, y: 12 }]);
+
+        let mut xy = [0, 0, 1, 2];
+        pipeline.transform_interleaved(&mut xy);
+        assert_eq!(xy, [1, 10, 2, 12]);
+
+        let (mut xs, mut ys) = ([0, 1], [0, 2]);
+        pipeline.transform_separated(&mut xs, &mut ys);
+        assert_eq!((xs, ys), ([1, 2], [10, 12]));
+
+        let mut coords = vec![coord! { x: 0, y: 0 }; 100_000];
+        let mut reports = vec![];
+        pipeline.transform_coords_with_progress(&mut coords, |processed, total| {
+            reports.push((processed, total))
+        });
+        assert!(coords.iter().all(|&coord| coord == coord! { x: 1, y: 10 }));
+        assert_eq!(reports, [(65_536, 100_000), (100_000, 100_000)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn odd_interleaved_buffer() {
+        TransformPipeline::new().transform_interleaved(&mut [1., 2., 3.]);
+    }
+}
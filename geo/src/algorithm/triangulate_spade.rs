@@ -3,6 +3,7 @@ use spade::{
     ConstrainedDelaunayTriangulation, DelaunayTriangulation, Point2, SpadeNum, Triangulation,
 };
 
+use crate::utils::PROGRESS_CHUNK_LEN;
 use crate::{
     line_intersection::line_intersection, CoordsIter, Distance, Euclidean, GeoFloat,
     LineIntersection, LinesIter,
@@ -91,9 +92,16 @@ mod private {
         //
         // there's also a preprocessing step which tries to minimize the risk of failure of the algo
         // through edge cases (thin/flat triangles are prevented as much as possible & lines are deduped, ...)
-        fn cleanup_lines(lines: Vec<Line<T>>, snap_radius: T) -> TriangulationResult<Vec<Line<T>>> {
+        //
+        // `progress` is called while the intersections are searched, see
+        // `prepare_intersection_contraint`
+        fn cleanup_lines(
+            lines: Vec<Line<T>>,
+            snap_radius: T,
+            progress: &mut impl FnMut(usize, usize),
+        ) -> TriangulationResult<Vec<Line<T>>> {
             let (known_coords, lines) = preprocess_lines(lines, snap_radius);
-            prepare_intersection_contraint(lines, known_coords, snap_radius)
+            prepare_intersection_contraint(lines, known_coords, snap_radius, progress)
         }
     }
 }
@@ -201,15 +209,33 @@ where
     fn constrained_outer_triangulation(
         &'a self,
         config: SpadeTriangulationConfig<T>,
+    ) -> TriangulationResult<Triangles<T>> {
+        self.constrained_outer_triangulation_with_progress(config, |_, _| {})
+    }
+
+    /// Like `constrained_outer_triangulation`, but calls `progress` with the work done so far and
+    /// the total, e.g. to show a progress bar for large inputs.
+    ///
+    /// The work is counted in lines, twice over. First the lines are split at their
+    /// intersections, which takes most of the time for large inputs, and the count is the number
+    /// of lines which have been checked for intersections. Splitting creates new lines, so the
+    /// total grows during this phase and the count can go back. Then the count is the number of
+    /// lines plus the number inserted into the triangulation, up to twice the number of lines.
+    fn constrained_outer_triangulation_with_progress(
+        &'a self,
+        config: SpadeTriangulationConfig<T>,
+        mut progress: impl FnMut(usize, usize),
     ) -> TriangulationResult<Triangles<T>> {
         let lines = self.lines();
-        let lines = Self::cleanup_lines(lines, config.snap_radius)?;
+        let lines = Self::cleanup_lines(lines, config.snap_radius, &mut progress)?;
+        let num_lines = lines.len();
         lines
             .into_iter()
             .map(to_spade_line)
+            .enumerate()
             .try_fold(
                 ConstrainedDelaunayTriangulation::<Point2<T>>::new(),
-                |mut cdt, [start, end]| {
+                |mut cdt, (index, [start, end])| {
                     let start = cdt.insert(start).map_err(TriangulationError::SpadeError)?;
                     let end = cdt.insert(end).map_err(TriangulationError::SpadeError)?;
                     // safety check (to prevent panic) whether we can add the line
@@ -217,6 +243,7 @@ where
                         return Err(TriangulationError::ConstraintFailure);
                     }
                     cdt.add_constraint(start, end);
+                    progress(num_lines + index + 1, 2 * num_lines);
                     Ok(cdt)
                 },
             )
@@ -275,7 +302,32 @@ where
         &'a self,
         config: SpadeTriangulationConfig<T>,
     ) -> TriangulationResult<Triangles<T>> {
-        self.constrained_outer_triangulation(config)
+        self.constrained_triangulation_with_progress(config, |_, _| {})
+    }
+
+    /// Like `constrained_triangulation`, but calls `progress` like
+    /// `constrained_outer_triangulation_with_progress`.
+    ///
+    /// ```rust
+    /// use geo::{wkt, TriangulateSpade};
+    ///
+    /// let square = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)));
+    /// let mut reports = vec![];
+    /// let triangulation = square
+    ///     .constrained_triangulation_with_progress(Default::default(), |processed, total| {
+    ///         reports.push((processed, total))
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(triangulation.len(), 2);
+    /// // the lines of the square don't intersect, so there's nothing to split
+    /// assert_eq!(reports, [(5, 8), (6, 8), (7, 8), (8, 8)]);
+    /// ```
+    fn constrained_triangulation_with_progress(
+        &'a self,
+        config: SpadeTriangulationConfig<T>,
+        progress: impl FnMut(usize, usize),
+    ) -> TriangulationResult<Triangles<T>> {
+        self.constrained_outer_triangulation_with_progress(config, progress)
             .map(|triangles| {
                 triangles
                     .into_iter()
@@ -376,6 +428,7 @@ fn prepare_intersection_contraint<T: SpadeTriangulationFloat>(
     mut lines: Vec<Line<T>>,
     mut known_points: Vec<Coord<T>>,
     snap_radius: T,
+    progress: &mut impl FnMut(usize, usize),
 ) -> Result<Vec<Line<T>>, TriangulationError> {
     // Rule 2 of "Power of 10" rules (NASA)
     // safety net. We can't prove that the `while let` loop isn't going to run infinitely, so
@@ -389,8 +442,22 @@ fn prepare_intersection_contraint<T: SpadeTriangulationFloat>(
             .ok_or(TriangulationError::LoopTrap)
     };
 
+    // Each search for an intersection compares pairs of lines, in order of the first line of the
+    // pair. Whenever another chunk of pairs has been compared, report the lines before the current
+    // first line as checked, out of twice the current number of lines, since all of them are
+    // inserted into the triangulation afterwards.
+    let mut num_pairs = 0;
     while let Some((indices, intersection)) = {
-        let mut iter = iter_line_pairs(&lines);
+        let total = 2 * lines.len();
+        let mut checked = 0;
+        let mut iter = iter_line_pairs(&lines).inspect(|[(idx0, _), _]| {
+            num_pairs += 1;
+            if *idx0 != checked && num_pairs >= PROGRESS_CHUNK_LEN {
+                num_pairs = 0;
+                checked = *idx0;
+                progress(checked, total);
+            }
+        });
         iter.find_map(find_intersecting_lines_fn)
     } {
         loop_check()?;
@@ -783,4 +850,37 @@ mod spade_triangulation {
             assert_num_triangles(&constrained_triangulation, 6);
         }
     }
+
+    #[test]
+    fn progress_before_insertion() {
+        // enough lines that searching them for intersections is reported before they're inserted
+        let n = 600;
+        let circle = Polygon::new(
+            (0..n)
+                .map(|i| {
+                    let angle = std::f64::consts::TAU * f64::from(i) / f64::from(n);
+                    Coord {
+                        x: angle.cos(),
+                        y: angle.sin(),
+                    }
+                })
+                .collect(),
+            vec![],
+        );
+        let mut reports = vec![];
+        let triangulation = circle
+            .constrained_triangulation_with_progress(Default::default(), |processed, total| {
+                reports.push((processed, total))
+            });
+        assert_num_triangles(&triangulation, n as usize - 2);
+
+        let num_lines = n as usize;
+        let (splitting, inserting) = reports.split_at(reports.len() - num_lines);
+        assert!(!splitting.is_empty());
+        assert!(splitting.iter().all(|&(processed, total)| 0 < processed
+            && processed < num_lines
+            && total == 2 * num_lines));
+        assert_eq!(inserting[0], (num_lines + 1, 2 * num_lines));
+        assert_eq!(inserting.last(), Some(&(2 * num_lines, 2 * num_lines)));
+    }
 }
@@ -0,0 +1,313 @@
+use std::fmt;
+
+use crate::{BoundingRect, Coord, CoordFloat, Crs, MapCoords, MapCoordsInPlace, Rect};
+
+/// The scale factor on the central meridian of each zone.
+const SCALE_FACTOR: f64 = 0.9996;
+const FALSE_EASTING: f64 = 500_000.;
+/// The false northing of zones in the southern hemisphere.
+const FALSE_NORTHING_SOUTH: f64 = 10_000_000.;
+
+/// A [Universal Transverse Mercator] zone on the WGS 84 ellipsoid.
+///
+/// The world is divided into 60 zones, each 6° of longitude wide, and each zone has a northern
+/// and a southern hemisphere. Within a zone, the projection's scale error is at most about 0.1%,
+/// so distances and areas can be measured with planar algorithms.
+///
+/// [Universal Transverse Mercator]: https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Crs, UtmZone};
+///
+/// let zone = UtmZone::from_lon_lat(coord! { x: 13.4, y: 52.5 });
+/// assert_eq!(zone, UtmZone::new(33, true).unwrap());
+/// assert_eq!(zone.to_string(), "33N");
+/// assert_eq!(zone.crs(), Crs::Epsg(32633));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+pub struct UtmZone {
+    number: u8,
+    north: bool,
+}
+
+impl UtmZone {
+    /// The zone `number`, in the northern or southern hemisphere. `None` unless `number` is
+    /// between 1 and 60.
+    pub fn new(number: u8, north: bool) -> Option<Self> {
+        (1..=60).contains(&number).then_some(Self { number, north })
+    }
+
+    /// The zone containing a longitude/latitude coordinate, including the exceptions around
+    /// Norway and Svalbard.
+    pub fn from_lon_lat(coord: Coord) -> Self {
+        let (lon, lat) = (coord.x, coord.y);
+        let mut number = ((lon + 180.) / 6.).floor().clamp(0., 59.) as u8 + 1;
+        if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lon) {
+            number = 32;
+        } else if (72. ..=84.).contains(&lat) && (0. ..42.).contains(&lon) {
+            number = match lon {
+                lon if lon < 9. => 31,
+                lon if lon < 21. => 33,
+                lon if lon < 33. => 35,
+                _ => 37,
+            };
+        }
+        Self {
+            number,
+            north: lat >= 0.,
+        }
+    }
+
+    /// The zone containing the center of a longitude/latitude geometry's bounding rectangle, or
+    /// `None` if the geometry is empty.
+    pub fn for_geometry<T: CoordFloat>(geometry: &impl BoundingRect<T>) -> Option<Self> {
+        let rect: Rect<T> = geometry.bounding_rect().into()?;
+        let center = rect.center();
+        Some(Self::from_lon_lat(Coord {
+            x: center.x.to_f64()?,
+            y: center.y.to_f64()?,
+        }))
+    }
+
+    /// The zone number, from 1 to 60.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Whether this is the northern hemisphere of the zone.
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// The longitude of the zone's central meridian, in degrees.
+    pub fn central_meridian(&self) -> f64 {
+        f64::from(self.number) * 6. - 183.
+    }
+
+    /// The WGS 84 / UTM coordinate reference system of this zone, e.g. EPSG:32633 for zone 33N.
+    pub fn crs(&self) -> Crs {
+        let base = if self.north { 32600 } else { 32700 };
+        Crs::Epsg(base + u32::from(self.number))
+    }
+}
+
+impl fmt::Display for UtmZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.number, if self.north { 'N' } else { 'S' })
+    }
+}
+
+/// Convert between WGS 84 longitude/latitude and [`UtmZone`] coordinates.
+///
+/// UTM coordinates are eastings and northings in meters. The projection is computed with
+/// Krüger's series to third order in the ellipsoid's third flattening, which is accurate to
+/// within a millimeter inside a zone, and still to within a meter a few zones away from it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, Utm, UtmZone};
+/// use approx::assert_relative_eq;
+///
+/// // a 0.01° square in Berlin
+/// let square = wkt!(POLYGON((13.4 52.5,13.41 52.5,13.41 52.51,13.4 52.51,13.4 52.5)));
+/// let zone = UtmZone::for_geometry(&square).unwrap();
+///
+/// let projected = square.to_utm(zone);
+/// assert_relative_eq!(projected.unsigned_area(), 755_730., max_relative = 1e-3);
+/// assert_relative_eq!(projected.utm_to_wgs84(zone), square, epsilon = 1e-7);
+/// ```
+pub trait Utm<T: CoordFloat>: Sized + MapCoords<T, T, Output = Self> + MapCoordsInPlace<T> {
+    /// Project longitude/latitude coordinates in degrees to eastings and northings in `zone`.
+    fn to_utm(&self, zone: UtmZone) -> Self {
+        let projection = Projection::new(zone);
+        self.map_coords(|coord| projection.forward(coord))
+    }
+
+    /// Like [`Self::to_utm`], but in place.
+    fn to_utm_in_place(&mut self, zone: UtmZone) {
+        let projection = Projection::new(zone);
+        self.map_coords_in_place(|coord| projection.forward(coord))
+    }
+
+    /// Unproject eastings and northings in `zone` to longitude/latitude coordinates in degrees.
+    fn utm_to_wgs84(&self, zone: UtmZone) -> Self {
+        let projection = Projection::new(zone);
+        self.map_coords(|coord| projection.inverse(coord))
+    }
+
+    /// Like [`Self::utm_to_wgs84`], but in place.
+    fn utm_to_wgs84_in_place(&mut self, zone: UtmZone) {
+        let projection = Projection::new(zone);
+        self.map_coords_in_place(|coord| projection.inverse(coord))
+    }
+}
+impl<T: CoordFloat, G: MapCoords<T, T, Output = Self> + MapCoordsInPlace<T>> Utm<T> for G {}
+
+/// The transverse Mercator projection of a zone, following the formulas in
+/// <https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system>.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Projection {
+    central_meridian: f64,
+    false_northing: f64,
+    /// The radius of the rectifying sphere, times the scale factor
+    k0_a: f64,
+    n: f64,
+    alpha: [f64; 3],
+    beta: [f64; 3],
+    delta: [f64; 3],
+}
+
+impl Projection {
+    pub(crate) fn new(zone: UtmZone) -> Self {
+        let f = 1. / 298.257_223_563;
+        let n = f / (2. - f);
+        let (n2, n3) = (n * n, n * n * n);
+        let a = crate::EQUATORIAL_EARTH_RADIUS / (1. + n) * (1. + n2 / 4. + n2 * n2 / 64.);
+        Self {
+            central_meridian: zone.central_meridian().to_radians(),
+            false_northing: if zone.north { 0. } else { FALSE_NORTHING_SOUTH },
+            k0_a: SCALE_FACTOR * a,
+            n,
+            alpha: [
+                n / 2. - 2. / 3. * n2 + 5. / 16. * n3,
+                13. / 48. * n2 - 3. / 5. * n3,
+                61. / 240. * n3,
+            ],
+            beta: [
+                n / 2. - 2. / 3. * n2 + 37. / 96. * n3,
+                n2 / 48. + n3 / 15.,
+                17. / 480. * n3,
+            ],
+            delta: [
+                2. * n - 2. / 3. * n2 - 2. * n3,
+                7. / 3. * n2 - 8. / 5. * n3,
+                56. / 15. * n3,
+            ],
+        }
+    }
+
+    pub(crate) fn forward<T: CoordFloat>(&self, coord: Coord<T>) -> Coord<T> {
+        let lon = coord.x.to_f64().unwrap().to_radians() - self.central_meridian;
+        let lat = coord.y.to_f64().unwrap().to_radians();
+        let e = 2. * self.n.sqrt() / (1. + self.n);
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi = t.atan2(lon.cos());
+        let eta = (lon.sin() / (1. + t * t).sqrt()).atanh();
+        let (mut easting, mut northing) = (eta, xi);
+        for (j, alpha) in (1..).zip(self.alpha) {
+            let j = f64::from(j) * 2.;
+            easting += alpha * (j * xi).cos() * (j * eta).sinh();
+            northing += alpha * (j * xi).sin() * (j * eta).cosh();
+        }
+        Coord {
+            x: T::from(FALSE_EASTING + self.k0_a * easting).unwrap(),
+            y: T::from(self.false_northing + self.k0_a * northing).unwrap(),
+        }
+    }
+
+    pub(crate) fn inverse<T: CoordFloat>(&self, coord: Coord<T>) -> Coord<T> {
+        let xi = (coord.y.to_f64().unwrap() - self.false_northing) / self.k0_a;
+        let eta = (coord.x.to_f64().unwrap() - FALSE_EASTING) / self.k0_a;
+        let (mut xi_prime, mut eta_prime) = (xi, eta);
+        for (j, beta) in (1..).zip(self.beta) {
+            let j = f64::from(j) * 2.;
+            xi_prime -= beta * (j * xi).sin() * (j * eta).cosh();
+            eta_prime -= beta * (j * xi).cos() * (j * eta).sinh();
+        }
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let mut lat = chi;
+        for (j, delta) in (1..).zip(self.delta) {
+            lat += delta * (f64::from(j) * 2. * chi).sin();
+        }
+        let lon = self.central_meridian + eta_prime.sinh().atan2(xi_prime.cos());
+        Coord {
+            x: T::from(lon.to_degrees()).unwrap(),
+            y: T::from(lat.to_degrees()).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt, Distance, Geodesic, Point};
+
+    #[test]
+    fn zones() {
+        let zone = |x, y| UtmZone::from_lon_lat(coord! { x: x, y: y });
+        assert_eq!(zone(-180., 10.), UtmZone::new(1, true).unwrap());
+        assert_eq!(zone(180., -10.), UtmZone::new(60, false).unwrap());
+        assert_eq!(zone(-0.1, 0.), UtmZone::new(30, true).unwrap());
+        // Norway and Svalbard
+        assert_eq!(zone(5., 60.).number(), 32);
+        assert_eq!(zone(5., 50.).number(), 31);
+        assert_eq!(zone(10., 78.).number(), 33);
+        assert_eq!(zone(40., 78.).number(), 37);
+        assert_eq!(UtmZone::new(0, true), None);
+        assert_eq!(UtmZone::new(61, true), None);
+        assert_eq!(UtmZone::new(19, false).unwrap().crs(), Crs::Epsg(32719));
+        assert_eq!(UtmZone::new(19, false).unwrap().central_meridian(), -69.);
+        assert_eq!(
+            UtmZone::for_geometry(&wkt!(LINESTRING(-70. - 33., -71. - 34.))),
+            UtmZone::new(19, false)
+        );
+        assert_eq!(UtmZone::for_geometry(&crate::LineString::<f64>::new(vec![])), None);
+    }
+
+    #[test]
+    fn central_meridian() {
+        let zone = UtmZone::new(31, true).unwrap();
+        assert_relative_eq!(
+            point!(x: 3., y: 0.).to_utm(zone),
+            point!(x: 500_000., y: 0.),
+            epsilon = 1e-6
+        );
+        // the northing on the central meridian is the scaled length of the meridian arc
+        let arc = Geodesic::distance(Point::new(3., 0.), Point::new(3., 45.));
+        assert_relative_eq!(
+            point!(x: 3., y: 45.).to_utm(zone),
+            point!(x: 500_000., y: SCALE_FACTOR * arc),
+            epsilon = 1e-3
+        );
+        let south = UtmZone::new(31, false).unwrap();
+        assert_relative_eq!(
+            point!(x: 3., y: -45.).to_utm(south),
+            point!(x: 500_000., y: FALSE_NORTHING_SOUTH - SCALE_FACTOR * arc),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn scale() {
+        // the scale factor is 0.9996 on the central meridian, about 1 at 180 km from it, and
+        // grows with the square of the distance beyond that
+        let zone = UtmZone::new(33, true).unwrap();
+        for (lon, scale) in [(15., SCALE_FACTOR), (17.29, 1.)] {
+            let a = point!(x: lon, y: 45.);
+            let b = point!(x: lon + 0.001, y: 45.001);
+            let projected = Geodesic::distance(a, b);
+            let planar = (a.to_utm(zone) - b.to_utm(zone)).0;
+            assert_relative_eq!(planar.x.hypot(planar.y) / projected, scale, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for zone in [UtmZone::new(33, true), UtmZone::new(19, false)] {
+            let zone = zone.unwrap();
+            let cm = zone.central_meridian();
+            for (dlon, lat) in [(0., 0.), (-2.9, 10.), (3., 45.), (1.5, 80.), (8., 60.)] {
+                let lat = if zone.is_north() { lat } else { -lat };
+                let point = point!(x: cm + dlon, y: lat);
+                let mut projected = point;
+                projected.to_utm_in_place(zone);
+                projected.utm_to_wgs84_in_place(zone);
+                assert_relative_eq!(projected, point, epsilon = 1e-7);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+
+use crate::{Contains, Coord, GeoFloat, Kernel, Line, LineString, Orientation, Point, Polygon};
+
+/// The region of a polygon which is visible from a point inside it: the points which can be
+/// connected to it by a segment that doesn't cross the polygon's boundary. Also known as an
+/// isovist, e.g. for the coverage of a surveillance camera, or analysing sight lines in a
+/// building.
+///
+/// The visibility polygon is computed by sweeping a ray around the viewpoint, and finding the
+/// closest edge on either side of the ray through each vertex of the polygon. This takes
+/// `O(n²)` time for a polygon with `n` vertices.
+///
+/// The visibility polygon is star-shaped around the viewpoint, so it has no holes, and its
+/// exterior is counter-clockwise.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, Area, VisibilityPolygon};
+///
+/// // a room with a pillar
+/// let room = wkt!(POLYGON(
+///     (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+///     (4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)
+/// ));
+///
+/// let visible = room.visibility_polygon(&point!(x: 2., y: 5.)).unwrap();
+/// // the pillar's shadow is 26 square units
+/// assert_eq!(visible.unsigned_area(), room.unsigned_area() - 26.);
+///
+/// assert!(room.visibility_polygon(&point!(x: 5., y: 5.)).is_none());
+/// ```
+pub trait VisibilityPolygon<T: GeoFloat> {
+    /// The region visible from `viewpoint`, or `None` unless `viewpoint` is in the interior of
+    /// the geometry.
+    fn visibility_polygon(&self, viewpoint: &Point<T>) -> Option<Polygon<T>>;
+}
+
+impl<T: GeoFloat> VisibilityPolygon<T> for Polygon<T> {
+    fn visibility_polygon(&self, viewpoint: &Point<T>) -> Option<Polygon<T>> {
+        if !self.contains(viewpoint) {
+            return None;
+        }
+        let origin = viewpoint.0;
+        let rings = || std::iter::once(self.exterior()).chain(self.interiors());
+        let edges: Vec<Line<T>> = rings().flat_map(LineString::lines).collect();
+
+        // the direction to every vertex, in counter-clockwise order starting from the positive x
+        // axis, with vertices in the same direction only once
+        let mut directions: Vec<Coord<T>> = rings()
+            .flat_map(|ring| ring.0.iter().map(|&c| c - origin))
+            .collect();
+        directions.sort_by(|&a, &b| compare_directions(a, b));
+        directions.dedup_by(|a, b| compare_directions(*a, *b) == Ordering::Equal);
+
+        let mut exterior = Vec::with_capacity(2 * directions.len() + 1);
+        for direction in directions {
+            // the closest point hit by rays just clockwise and just counter-clockwise of the
+            // ray in `direction`
+            let mut clockwise: Option<(T, Coord<T>)> = None;
+            let mut counter_clockwise: Option<(T, Coord<T>)> = None;
+            let target = origin + direction;
+            for edge in &edges {
+                let start = T::Ker::orient2d(origin, target, edge.start);
+                let end = T::Ker::orient2d(origin, target, edge.end);
+                let (distance, hit, blocks_clockwise, blocks_counter_clockwise) = match (start, end)
+                {
+                    (Orientation::Collinear, Orientation::Collinear) => continue,
+                    // the edge touches the ray at one end, and only blocks rays on the side of
+                    // its other end
+                    (Orientation::Collinear, side) | (side, Orientation::Collinear) => {
+                        let hit = if start == Orientation::Collinear {
+                            edge.start
+                        } else {
+                            edge.end
+                        };
+                        let distance = dot(hit - origin, direction);
+                        let counter_clockwise = side == Orientation::CounterClockwise;
+                        (distance, hit, !counter_clockwise, counter_clockwise)
+                    }
+                    (start, end) if start == end => continue,
+                    // the edge crosses the ray's line
+                    _ => {
+                        let delta = edge.delta();
+                        let t = cross(edge.start - origin, delta) / cross(direction, delta);
+                        (
+                            t * dot(direction, direction),
+                            origin + direction * t,
+                            true,
+                            true,
+                        )
+                    }
+                };
+                if distance <= T::zero() {
+                    continue;
+                }
+                for (blocks, closest) in [
+                    (blocks_clockwise, &mut clockwise),
+                    (blocks_counter_clockwise, &mut counter_clockwise),
+                ] {
+                    if blocks && closest.map_or(true, |(closest, _)| distance < closest) {
+                        *closest = Some((distance, hit));
+                    }
+                }
+            }
+            for (_, hit) in clockwise.into_iter().chain(counter_clockwise) {
+                if exterior.last() != Some(&hit) {
+                    exterior.push(hit);
+                }
+            }
+        }
+        if exterior.len() > 1 && exterior.first() == exterior.last() {
+            exterior.pop();
+        }
+        Some(Polygon::new(LineString::new(exterior), vec![]))
+    }
+}
+
+fn cross<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.x + a.y * b.y
+}
+
+/// Order directions by their angle counter-clockwise from the positive x axis.
+fn compare_directions<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> Ordering {
+    let lower_half = |c: Coord<T>| c.y < T::zero() || (c.y == T::zero() && c.x < T::zero());
+    lower_half(a)
+        .cmp(&lower_half(b))
+        .then_with(|| match T::Ker::orient2d(Coord::zero(), a, b) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Area, Relate, Winding};
+
+    #[test]
+    fn convex() {
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,5. 3.,2. 5.,-1. 3.,0. 0.)));
+        let visible = polygon.visibility_polygon(&point!(x: 1., y: 1.)).unwrap();
+        assert!(visible.relate(&polygon).is_equal_topo());
+        assert!(visible.exterior().is_ccw());
+    }
+
+    #[test]
+    fn collinear_vertices() {
+        // the viewpoint is on the diagonal of the room and the pillar
+        let room = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)
+        ));
+        let visible = room.visibility_polygon(&point!(x: 2., y: 2.)).unwrap();
+        let expected = wkt!(POLYGON((
+            0. 0.,10. 0.,10. 6.,6. 4.,4. 4.,4. 6.,6. 10.,0. 10.,0. 0.
+        )));
+        assert!(visible.relate(&expected).is_equal_topo());
+    }
+
+    #[test]
+    fn concave() {
+        // a comb: the other teeth are hidden from inside the first one
+        let comb = wkt!(POLYGON((
+            0. 0.,10. 0.,10. 5.,8. 5.,8. 1.,6. 1.,6. 5.,4. 5.,4. 1.,2. 1.,2. 5.,0. 5.,0. 0.
+        )));
+        let visible = comb.visibility_polygon(&point!(x: 1., y: 3.)).unwrap();
+        assert!(visible.unsigned_area() < comb.unsigned_area());
+        assert!(visible.contains(&point!(x: 1., y: 4.9)));
+        assert!(visible.contains(&point!(x: 1.5, y: 0.5)));
+        assert!(!visible.contains(&point!(x: 9., y: 0.9)));
+        assert!(!visible.contains(&point!(x: 3., y: 4.)));
+        assert!(visible.relate(&comb).is_coveredby());
+    }
+
+    #[test]
+    fn outside() {
+        let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        assert!(polygon.visibility_polygon(&point!(x: 2., y: 0.)).is_none());
+        assert!(polygon.visibility_polygon(&point!(x: 0.5, y: 0.)).is_none());
+    }
+}
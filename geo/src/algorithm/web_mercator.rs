@@ -0,0 +1,118 @@
+use std::f64::consts::FRAC_PI_4;
+
+use crate::{Coord, CoordFloat, MapCoords, MapCoordsInPlace};
+
+/// The latitude limit of Web Mercator, beyond which latitudes are clamped, which makes the
+/// projected world a square.
+pub const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Convert between WGS 84 longitude/latitude ([EPSG:4326]) and Web Mercator ([EPSG:3857]), the
+/// projection used by web maps and map tiles.
+///
+/// This is the spherical Mercator projection with the radius of the WGS 84 ellipsoid, in meters,
+/// so it needs no projection library. Latitudes beyond ±[`WEB_MERCATOR_MAX_LATITUDE`], where the
+/// projection goes to infinity at the poles, are clamped.
+///
+/// The [`Crs`](crate::Crs) of a [`GeoReferenced`](crate::GeoReferenced) geometry isn't updated.
+///
+/// [EPSG:4326]: https://epsg.io/4326
+/// [EPSG:3857]: https://epsg.io/3857
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, WebMercator};
+/// use approx::assert_relative_eq;
+///
+/// let berlin = point!(x: 13.4, y: 52.5);
+/// let projected = berlin.to_web_mercator();
+/// assert_relative_eq!(projected, point!(x: 1491681.2, y: 6891041.7), epsilon = 0.1);
+/// assert_relative_eq!(projected.to_wgs84(), berlin, epsilon = 1e-9);
+/// ```
+pub trait WebMercator<T: CoordFloat>:
+    Sized + MapCoords<T, T, Output = Self> + MapCoordsInPlace<T>
+{
+    /// Project longitude/latitude coordinates in degrees to Web Mercator coordinates in meters.
+    fn to_web_mercator(&self) -> Self {
+        self.map_coords(lon_lat_to_web_mercator)
+    }
+
+    /// Like [`Self::to_web_mercator`], but in place.
+    fn to_web_mercator_in_place(&mut self) {
+        self.map_coords_in_place(lon_lat_to_web_mercator)
+    }
+
+    /// Unproject Web Mercator coordinates in meters to longitude/latitude coordinates in degrees.
+    fn to_wgs84(&self) -> Self {
+        self.map_coords(web_mercator_to_lon_lat)
+    }
+
+    /// Like [`Self::to_wgs84`], but in place.
+    fn to_wgs84_in_place(&mut self) {
+        self.map_coords_in_place(web_mercator_to_lon_lat)
+    }
+}
+impl<T: CoordFloat, G: MapCoords<T, T, Output = Self> + MapCoordsInPlace<T>> WebMercator<T> for G {}
+
+fn radius<T: CoordFloat>() -> T {
+    T::from(crate::EQUATORIAL_EARTH_RADIUS).unwrap()
+}
+
+pub(crate) fn lon_lat_to_web_mercator<T: CoordFloat>(Coord { x, y }: Coord<T>) -> Coord<T> {
+    let max_latitude = T::from(WEB_MERCATOR_MAX_LATITUDE).unwrap();
+    let lat = y.max(-max_latitude).min(max_latitude).to_radians();
+    let two = T::one() + T::one();
+    Coord {
+        x: radius::<T>() * x.to_radians(),
+        y: radius::<T>() * (T::from(FRAC_PI_4).unwrap() + lat / two).tan().ln(),
+    }
+}
+
+pub(crate) fn web_mercator_to_lon_lat<T: CoordFloat>(Coord { x, y }: Coord<T>) -> Coord<T> {
+    Coord {
+        x: (x / radius()).to_degrees(),
+        y: (y / radius()).sinh().atan().to_degrees(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    #[test]
+    fn known_values() {
+        assert_relative_eq!(
+            point!(x: 13.4, y: 52.5).to_web_mercator(),
+            point!(x: 1491681.18, y: 6891041.72),
+            epsilon = 0.01
+        );
+        let corner = point!(x: 180., y: WEB_MERCATOR_MAX_LATITUDE).to_web_mercator();
+        assert_relative_eq!(corner.x(), 20037508.342789244, epsilon = 1e-6);
+        assert_relative_eq!(corner.y(), 20037508.342789244, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn clamps_poles() {
+        let pole = point!(x: 0f64, y: 90.).to_web_mercator();
+        assert!(pole.y().is_finite());
+        assert_relative_eq!(
+            pole.to_wgs84().y(),
+            WEB_MERCATOR_MAX_LATITUDE,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let polygon = wkt!(POLYGON((-120. -30.,60. -30.,60. 70.,-120. 30.,-120. -30.)));
+        let mut projected = polygon.clone();
+        projected.to_web_mercator_in_place();
+        assert_eq!(projected, polygon.to_web_mercator());
+        projected.to_wgs84_in_place();
+        assert_relative_eq!(projected, polygon, epsilon = 1e-9);
+
+        let point = point!(x: -5.5f32, y: 40.25f32);
+        assert_relative_eq!(point.to_web_mercator().to_wgs84(), point, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MapCoords, MapCoordsInPlace,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// An immutable, reference-counted [`Geometry`] which can be cheaply cloned and shared between
+/// threads.
+///
+/// Cloning a `Geometry` deep-copies all of its coordinates, which is wasteful when the same large
+/// geometry is handed to many consumers, e.g. one polygon tested against many queries on many
+/// threads. Cloning an `ArcGeometry` only increments a reference count.
+///
+/// `ArcGeometry` dereferences to [`Geometry`], so read-only algorithms can be called on it
+/// directly. Mutation is copy-on-write: [`ArcGeometry::make_mut`] (and [`MapCoordsInPlace`])
+/// only copy the geometry if it's shared.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, ArcGeometry, Contains};
+///
+/// let polygon = ArcGeometry::from(wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.))));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let polygon = polygon.clone();
+///         std::thread::spawn(move || polygon.contains(&geo::point!(x: i as f64 + 1., y: 1.)))
+///     })
+///     .collect();
+/// assert!(handles.into_iter().all(|h| h.join().unwrap()));
+///
+/// assert_eq!(polygon.unsigned_area(), 100.);
+/// ```
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ArcGeometry<T: CoordNum = f64>(Arc<Geometry<T>>);
+
+impl<T: CoordNum> ArcGeometry<T> {
+    /// Move `geometry` into a new shared allocation.
+    pub fn new(geometry: impl Into<Geometry<T>>) -> Self {
+        Self(Arc::new(geometry.into()))
+    }
+
+    /// The shared geometry.
+    pub fn geometry(&self) -> &Geometry<T> {
+        &self.0
+    }
+
+    /// Returns `true` if both `ArcGeometry`s share the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Mutable access to the geometry, copying it first if it's shared with other
+    /// `ArcGeometry`s.
+    pub fn make_mut(&mut self) -> &mut Geometry<T> {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Returns the geometry, copying it only if it's shared with other `ArcGeometry`s.
+    pub fn into_geometry(self) -> Geometry<T> {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<T: CoordNum> Deref for ArcGeometry<T> {
+    type Target = Geometry<T>;
+
+    fn deref(&self) -> &Geometry<T> {
+        &self.0
+    }
+}
+
+impl<T: CoordNum> AsRef<Geometry<T>> for ArcGeometry<T> {
+    fn as_ref(&self) -> &Geometry<T> {
+        &self.0
+    }
+}
+
+impl<T: CoordNum> From<Arc<Geometry<T>>> for ArcGeometry<T> {
+    fn from(geometry: Arc<Geometry<T>>) -> Self {
+        Self(geometry)
+    }
+}
+
+impl<T: CoordNum> From<ArcGeometry<T>> for Arc<Geometry<T>> {
+    fn from(geometry: ArcGeometry<T>) -> Self {
+        geometry.0
+    }
+}
+
+macro_rules! from_geometry_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<T: CoordNum> From<$type<T>> for ArcGeometry<T> {
+                fn from(geometry: $type<T>) -> Self {
+                    Self::new(geometry)
+                }
+            }
+        )+
+    };
+}
+
+from_geometry_impl!(
+    Geometry,
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle
+);
+
+impl<T: CoordNum> From<GeometryCollection<T>> for ArcGeometry<T> {
+    fn from(geometry: GeometryCollection<T>) -> Self {
+        Self::new(Geometry::GeometryCollection(geometry))
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoords<T, NT> for ArcGeometry<T> {
+    type Output = ArcGeometry<NT>;
+
+    fn map_coords(&self, func: impl Fn(Coord<T>) -> Coord<NT> + Copy) -> Self::Output {
+        ArcGeometry::new(self.0.map_coords(func))
+    }
+
+    fn try_map_coords<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+    ) -> Result<Self::Output, E> {
+        Ok(ArcGeometry::new(self.0.try_map_coords(func)?))
+    }
+}
+
+impl<T: CoordNum> MapCoordsInPlace<T> for ArcGeometry<T> {
+    /// Map a function over the coordinates, copying the geometry first if it's shared.
+    fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) {
+        self.make_mut().map_coords_in_place(func)
+    }
+
+    /// Map a fallible function over the coordinates, copying the geometry first if it's shared.
+    fn try_map_coords_in_place<E>(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    ) -> Result<(), E> {
+        self.make_mut().try_map_coords_in_place(func)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, Convert};
+
+    #[test]
+    fn clones_share_storage() {
+        let a = ArcGeometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        let b = a.clone();
+        assert!(ArcGeometry::ptr_eq(&a, &b));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let mut a = ArcGeometry::from(point!(x: 1., y: 2.));
+        let b = a.clone();
+        a.map_coords_in_place(|c| c * 2.);
+        assert!(!ArcGeometry::ptr_eq(&a, &b));
+        assert_eq!(*a, Geometry::Point(point!(x: 2., y: 4.)));
+        assert_eq!(*b, Geometry::Point(point!(x: 1., y: 2.)));
+
+        // Once unshared, mutation happens in place
+        let before = Arc::as_ptr(&a.0);
+        a.make_mut();
+        assert_eq!(Arc::as_ptr(&a.0), before);
+    }
+
+    #[test]
+    fn into_geometry() {
+        let a = ArcGeometry::from(point!(x: 1., y: 2.));
+        let b = a.clone();
+        assert_eq!(a.into_geometry(), Geometry::Point(point!(x: 1., y: 2.)));
+        assert_eq!(b.into_geometry(), Geometry::Point(point!(x: 1., y: 2.)));
+    }
+
+    #[test]
+    fn convert() {
+        let a: ArcGeometry<f32> = ArcGeometry::from(point!(x: 1., y: 2.));
+        let b: ArcGeometry<f64> = a.convert();
+        assert_eq!(b.geometry(), &Geometry::Point(point!(x: 1., y: 2.)));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::algorithm::bounding_rect::bounding_rect_merge;
+use crate::utils::{partial_max, partial_min};
+use crate::{coord, BoundingRect, Coord, CoordNum, Rect};
+
+/// A bounding box which may be empty, i.e. not contain any coordinates yet.
+///
+/// `Bounds` is useful when accumulating the extent of many geometries, some of which (like an
+/// empty `LineString`) have no bounding rectangle, without having to match on `Option<Rect>`s.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, Bounds, Rect};
+///
+/// let mut bounds = Bounds::new();
+/// assert!(bounds.is_empty());
+///
+/// bounds.extend(coord! { x: 1., y: 2. });
+/// bounds.merge(Bounds::of(&line_string![(x: 3., y: 0.), (x: 4., y: 1.)]));
+/// bounds.merge(Bounds::of(&line_string![]));
+///
+/// assert_eq!(bounds.rect(), Some(Rect::new((1., 0.), (4., 2.))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+pub struct Bounds<T: CoordNum = f64>(Option<Rect<T>>);
+
+impl<T: CoordNum> Bounds<T> {
+    /// Empty bounds, which don't contain any coordinates.
+    pub fn new() -> Self {
+        Self(None)
+    }
+
+    /// The bounds of a geometry, which are empty if the geometry is.
+    pub fn of<G: BoundingRect<T>>(geometry: &G) -> Self {
+        Self(geometry.bounding_rect().into())
+    }
+
+    /// Returns `true` if no coordinates have been added to these bounds.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// The rectangle covering these bounds, or `None` if they're empty.
+    pub fn rect(&self) -> Option<Rect<T>> {
+        self.0
+    }
+
+    /// Grow these bounds to contain `coord`.
+    pub fn extend(&mut self, coord: impl Into<Coord<T>>) {
+        let coord = coord.into();
+        self.merge(Self(Some(Rect::new(coord, coord))));
+    }
+
+    /// Grow these bounds to contain `other`.
+    pub fn merge(&mut self, other: Self) {
+        self.0 = match (self.0, other.0) {
+            (Some(a), Some(b)) => Some(bounding_rect_merge(a, b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// The bounds covered by both `self` and `other`.
+    ///
+    /// Bounds which only touch have a degenerate intersection. Bounds which don't overlap at all
+    /// have an empty one.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (Some(a), Some(b)) = (self.0, other.0) else {
+            return Self::new();
+        };
+        let min = coord! {
+            x: partial_max(a.min().x, b.min().x),
+            y: partial_max(a.min().y, b.min().y),
+        };
+        let max = coord! {
+            x: partial_min(a.max().x, b.max().x),
+            y: partial_min(a.max().y, b.max().y),
+        };
+        if min.x > max.x || min.y > max.y {
+            return Self::new();
+        }
+        Self(Some(Rect::new(min, max)))
+    }
+}
+
+impl<T: CoordNum> Default for Bounds<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> From<Rect<T>> for Bounds<T> {
+    fn from(rect: Rect<T>) -> Self {
+        Self(Some(rect))
+    }
+}
+
+impl<T: CoordNum> From<Option<Rect<T>>> for Bounds<T> {
+    fn from(rect: Option<Rect<T>>) -> Self {
+        Self(rect)
+    }
+}
+
+impl<T: CoordNum> From<Bounds<T>> for Option<Rect<T>> {
+    fn from(bounds: Bounds<T>) -> Self {
+        bounds.0
+    }
+}
+
+impl<T: CoordNum, C: Into<Coord<T>>> Extend<C> for Bounds<T> {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for coord in iter {
+            Bounds::extend(self, coord);
+        }
+    }
+}
+
+impl<T: CoordNum, C: Into<Coord<T>>> FromIterator<C> for Bounds<T> {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut bounds = Self::new();
+        Extend::extend(&mut bounds, iter);
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Geometry, GeometryCollection, LineString};
+
+    #[test]
+    fn empty() {
+        let bounds: Bounds = Bounds::default();
+        assert!(bounds.is_empty());
+        assert_eq!(bounds.rect(), None);
+        assert_eq!(Bounds::of(&LineString::<f64>::new(vec![])), bounds);
+
+        let mut merged = bounds;
+        merged.merge(Bounds::new());
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn from_coords() {
+        let bounds: Bounds<i32> = [(3, 1), (-1, 4), (2, -5)].into_iter().collect();
+        assert_eq!(bounds.rect(), Some(Rect::new((-1, -5), (3, 4))));
+    }
+
+    #[test]
+    fn merge() {
+        let mut bounds = Bounds::from(Rect::new((0., 0.), (1., 1.)));
+        bounds.merge(Bounds::new());
+        assert_eq!(bounds.rect(), Some(Rect::new((0., 0.), (1., 1.))));
+
+        bounds.merge(Bounds::of(&GeometryCollection::new_from(vec![
+            Geometry::Point(point!(x: 5., y: -1.)),
+            Geometry::LineString(LineString::new(vec![])),
+        ])));
+        assert_eq!(bounds.rect(), Some(Rect::new((0., -1.), (5., 1.))));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Bounds::from(Rect::new((0., 0.), (2., 2.)));
+        let b = Bounds::from(Rect::new((1., 1.), (3., 3.)));
+        assert_eq!(
+            a.intersection(&b).rect(),
+            Some(Rect::new((1., 1.), (2., 2.)))
+        );
+
+        let touching = Bounds::from(Rect::new((2., 0.), (3., 1.)));
+        assert_eq!(
+            a.intersection(&touching).rect(),
+            Some(Rect::new((2., 0.), (2., 1.)))
+        );
+
+        let disjoint = Bounds::from(Rect::new((5., 5.), (6., 6.)));
+        assert!(a.intersection(&disjoint).is_empty());
+        assert!(a.intersection(&Bounds::new()).is_empty());
+    }
+}
@@ -0,0 +1,562 @@
+//! Coordinate reference system (CRS) metadata for geometries.
+//!
+//! `geo` geometries don't know which coordinate reference system their coordinates are in.
+//! [`GeoReferenced`] pairs any geometry with an optional [`Crs`] tag, which is preserved through
+//! [`MapCoords`] and [`Convert`](crate::Convert), so that mixing geometries from different
+//! reference systems can be detected at runtime with [`GeoReferenced::ensure_same_crs`].
+//!
+//! The CRS also tells whether coordinates are longitudes and latitudes, so
+//! [`GeoReferenced::area`], [`GeoReferenced::length`] and [`GeoReferenced::distance`] can measure
+//! in meters with either planar or geodesic algorithms, rather than in degrees.
+
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::{
+    Area, Coord, CoordNum, Distance, Euclidean, Geodesic, GeodesicArea, Length, MapCoords,
+    MapCoordsInPlace, Point, SwapXY,
+};
+
+/// An identifier for a coordinate reference system.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Crs;
+///
+/// let crs: Crs = "EPSG:4326".parse().unwrap();
+/// assert_eq!(crs, Crs::WGS84);
+/// assert_eq!(crs.epsg(), Some(4326));
+/// assert_eq!(crs.to_string(), "EPSG:4326");
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Crs {
+    /// A code from the [EPSG registry](https://epsg.org), e.g. `4326` for WGS 84.
+    ///
+    /// This is also what PostGIS calls an SRID.
+    Epsg(u32),
+    /// A [PROJJSON](https://proj.org/specifications/projjson.html) CRS definition.
+    ProjJson(String),
+}
+
+impl Crs {
+    /// WGS 84 longitude/latitude, as used by GPS and GeoJSON.
+    pub const WGS84: Crs = Crs::Epsg(4326);
+
+    /// WGS 84 / Pseudo-Mercator, as used by web map tiles.
+    pub const WEB_MERCATOR: Crs = Crs::Epsg(3857);
+
+    /// The EPSG code of this CRS, if it was specified as one.
+    pub fn epsg(&self) -> Option<u32> {
+        match self {
+            Crs::Epsg(code) => Some(*code),
+            Crs::ProjJson(_) => None,
+        }
+    }
+
+    /// Whether this is a geographic CRS, whose coordinates are longitudes and latitudes in
+    /// degrees, rather than a projected one.
+    ///
+    /// EPSG codes from 4000 to 4999 are geographic, except for the geocentric EPSG:4978, as are a
+    /// few newer codes such as NAD83(2011) and GDA2020. For PROJJSON, this checks whether the
+    /// outermost `"type"` is `"GeographicCRS"`.
+    ///
+    /// ```
+    /// use geo::{Crs, UtmZone};
+    ///
+    /// assert!(Crs::WGS84.is_geographic());
+    /// assert!(!Crs::WEB_MERCATOR.is_geographic());
+    /// assert!(!UtmZone::new(33, true).unwrap().crs().is_geographic());
+    /// ```
+    pub fn is_geographic(&self) -> bool {
+        match self {
+            Crs::Epsg(code) => matches!(code, 4000..=4999 | 6318 | 6668 | 7844) && *code != 4978,
+            Crs::ProjJson(json) => projjson_type(json) == Some("GeographicCRS"),
+        }
+    }
+
+    /// Whether the axis order defined by the CRS's authority lists y (latitude or northing)
+    /// before x (longitude or easting).
+    ///
+    /// This is the case for geographic EPSG CRSs such as EPSG:4326, and for some projected ones,
+    /// e.g. ETRS89-LAEA (EPSG:3035) and the Gauss-Krüger zones (EPSG:31466 to 31469); the list of
+    /// projected CRSs isn't exhaustive. For PROJJSON, this checks whether the direction of the
+    /// first axis is north or south.
+    ///
+    /// ```
+    /// use geo::Crs;
+    ///
+    /// assert!(Crs::WGS84.has_yx_axis_order());
+    /// assert!(!Crs::WEB_MERCATOR.has_yx_axis_order());
+    /// ```
+    pub fn has_yx_axis_order(&self) -> bool {
+        match self {
+            Crs::Epsg(code) => {
+                self.is_geographic() || matches!(code, 2180 | 3006 | 3034 | 3035 | 31466..=31469)
+            }
+            Crs::ProjJson(json) => matches!(
+                json_string_member(json, "direction"),
+                Some("north" | "south")
+            ),
+        }
+    }
+}
+
+/// The value of the first `"type"` member of a PROJJSON object, which is the outermost one, since
+/// PROJJSON writers put it before any nested objects.
+fn projjson_type(json: &str) -> Option<&str> {
+    json_string_member(json, "type")
+}
+
+/// The string value of the first member named `name`, in a JSON object serialized to `json`.
+fn json_string_member<'a>(json: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = json.split_once(&format!("\"{name}\""))?;
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    rest.split_once('"').map(|(value, _)| value)
+}
+
+impl fmt::Display for Crs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Crs::Epsg(code) => write!(f, "EPSG:{code}"),
+            Crs::ProjJson(json) => f.write_str(json),
+        }
+    }
+}
+
+/// An error returned when parsing a [`Crs`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCrsError(String);
+
+impl fmt::Display for ParseCrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an `EPSG:<code>` identifier or a PROJJSON object, found `{}`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseCrsError {}
+
+impl FromStr for Crs {
+    type Err = ParseCrsError;
+
+    /// Parses either an `EPSG:<code>` identifier (case insensitive) or a PROJJSON object.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.starts_with('{') {
+            return Ok(Crs::ProjJson(s.to_string()));
+        }
+        s.split_once(':')
+            .filter(|(authority, _)| authority.eq_ignore_ascii_case("EPSG"))
+            .and_then(|(_, code)| code.parse().ok())
+            .map(Crs::Epsg)
+            .ok_or_else(|| ParseCrsError(s.to_string()))
+    }
+}
+
+/// An error returned when two geometries which are required to share a coordinate reference
+/// system don't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrsMismatch {
+    /// The CRS of the first geometry.
+    pub left: Option<Crs>,
+    /// The CRS of the second geometry.
+    pub right: Option<Crs>,
+}
+
+impl fmt::Display for CrsMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn describe(crs: &Option<Crs>) -> String {
+            crs.as_ref()
+                .map_or_else(|| "no CRS".to_string(), |crs| crs.to_string())
+        }
+        write!(
+            f,
+            "coordinate reference systems differ: {} vs {}",
+            describe(&self.left),
+            describe(&self.right)
+        )
+    }
+}
+
+impl std::error::Error for CrsMismatch {}
+
+/// A geometry tagged with the coordinate reference system of its coordinates.
+///
+/// `GeoReferenced` dereferences to the wrapped geometry, so read-only algorithms can be called
+/// on it directly. Mapping the coordinates with [`MapCoords`] (or converting them with
+/// [`Convert`](crate::Convert)) keeps the CRS tag.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Crs, GeoReferenced, MapCoords};
+///
+/// let a = GeoReferenced::new(point!(x: 1.0, y: 2.0), Crs::WGS84);
+/// let b = GeoReferenced::new(point!(x: 1.0, y: 2.0), Crs::WEB_MERCATOR);
+///
+/// let shifted = a.map_coords(|c| c + (1.0, 1.0).into());
+/// assert_eq!(shifted.crs(), Some(&Crs::WGS84));
+/// assert_eq!(shifted.x(), 2.0);
+///
+/// assert!(a.ensure_same_crs(&shifted).is_ok());
+/// assert!(a.ensure_same_crs(&b).is_err());
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GeoReferenced<G> {
+    geometry: G,
+    crs: Option<Crs>,
+}
+
+impl<G> GeoReferenced<G> {
+    /// Tag `geometry` with `crs`.
+    pub fn new(geometry: G, crs: Crs) -> Self {
+        Self {
+            geometry,
+            crs: Some(crs),
+        }
+    }
+
+    /// Wrap a geometry whose coordinate reference system is unknown.
+    pub fn without_crs(geometry: G) -> Self {
+        Self {
+            geometry,
+            crs: None,
+        }
+    }
+
+    /// The coordinate reference system of the geometry, if known.
+    pub fn crs(&self) -> Option<&Crs> {
+        self.crs.as_ref()
+    }
+
+    /// Replace the coordinate reference system tag, without modifying the coordinates.
+    pub fn set_crs(&mut self, crs: Option<Crs>) {
+        self.crs = crs;
+    }
+
+    /// The wrapped geometry.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// Mutable access to the wrapped geometry.
+    ///
+    /// The caller is responsible for keeping the coordinates consistent with the CRS tag.
+    pub fn geometry_mut(&mut self) -> &mut G {
+        &mut self.geometry
+    }
+
+    /// Discard the CRS tag, returning the wrapped geometry.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+
+    /// Split into the wrapped geometry and its CRS tag.
+    pub fn into_parts(self) -> (G, Option<Crs>) {
+        (self.geometry, self.crs)
+    }
+
+    /// Convert the wrapped geometry, keeping the CRS tag.
+    ///
+    /// ```
+    /// use geo::{point, Crs, GeoReferenced, Geometry};
+    ///
+    /// let point = GeoReferenced::new(point!(x: 1.0, y: 2.0), Crs::WGS84);
+    /// let geometry: GeoReferenced<Geometry> = point.map(Geometry::from);
+    /// assert_eq!(geometry.crs(), Some(&Crs::WGS84));
+    /// ```
+    pub fn map<NG>(self, f: impl FnOnce(G) -> NG) -> GeoReferenced<NG> {
+        GeoReferenced {
+            geometry: f(self.geometry),
+            crs: self.crs,
+        }
+    }
+
+    /// Check that `other` is tagged with the same coordinate reference system as `self`.
+    ///
+    /// Two geometries without a CRS tag are considered compatible, but an untagged geometry is
+    /// never compatible with a tagged one.
+    pub fn ensure_same_crs<G2>(&self, other: &GeoReferenced<G2>) -> Result<(), CrsMismatch> {
+        if self.crs == other.crs {
+            Ok(())
+        } else {
+            Err(CrsMismatch {
+                left: self.crs.clone(),
+                right: other.crs.clone(),
+            })
+        }
+    }
+}
+
+impl<G> GeoReferenced<G> {
+    fn is_geographic(&self) -> bool {
+        self.crs.as_ref().is_some_and(Crs::is_geographic)
+    }
+
+    /// The unsigned area of the geometry: in square meters, computed on the WGS 84 ellipsoid, if
+    /// it's in a [geographic](Crs::is_geographic) CRS, and otherwise the planar area in the
+    /// units of the coordinates.
+    ///
+    /// ```
+    /// use geo::{wkt, Crs, GeoReferenced};
+    ///
+    /// let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)));
+    /// let planar = GeoReferenced::new(polygon.clone(), Crs::WEB_MERCATOR);
+    /// let geographic = GeoReferenced::new(polygon, Crs::WGS84);
+    /// assert_eq!(planar.area(), 1.);
+    /// assert_eq!(geographic.area().round(), 12_308_778_361.);
+    /// ```
+    pub fn area(&self) -> f64
+    where
+        G: Area<f64> + GeodesicArea<f64>,
+    {
+        if self.is_geographic() {
+            self.geometry.geodesic_area_unsigned()
+        } else {
+            self.geometry.unsigned_area()
+        }
+    }
+
+    /// The length of the geometry: in meters along geodesics on the WGS 84 ellipsoid, if it's in
+    /// a [geographic](Crs::is_geographic) CRS, and otherwise the [`Euclidean`] length in the
+    /// units of the coordinates.
+    pub fn length(&self) -> f64
+    where
+        G: Length<f64>,
+    {
+        if self.is_geographic() {
+            self.geometry.length::<Geodesic>()
+        } else {
+            self.geometry.length::<Euclidean>()
+        }
+    }
+}
+
+impl<G> GeoReferenced<G> {
+    /// Tag `geometry` with `crs`, whose coordinates are in the axis order defined by the CRS's
+    /// authority, as in WKT or GML from sources following it. The axes are swapped if the CRS
+    /// [lists y first](Crs::has_yx_axis_order), so that x is the longitude or easting.
+    ///
+    /// ```
+    /// use geo::{point, Crs, GeoReferenced};
+    ///
+    /// // EPSG:4326 is latitude, longitude
+    /// let berlin = GeoReferenced::from_authority_axis_order(point!(x: 52.5, y: 13.4), Crs::WGS84);
+    /// assert_eq!(*berlin.geometry(), point!(x: 13.4, y: 52.5));
+    /// assert_eq!(berlin.to_authority_axis_order(), point!(x: 52.5, y: 13.4));
+    /// ```
+    pub fn from_authority_axis_order<T: CoordNum>(geometry: G, crs: Crs) -> Self
+    where
+        G: SwapXY<T>,
+    {
+        if crs.has_yx_axis_order() {
+            Self::new(geometry.swap_xy(), crs)
+        } else {
+            Self::new(geometry, crs)
+        }
+    }
+
+    /// The geometry, in the axis order defined by the authority of its CRS, for writing to
+    /// formats which follow it. The inverse of [`Self::from_authority_axis_order`].
+    pub fn to_authority_axis_order<T: CoordNum>(&self) -> G
+    where
+        G: SwapXY<T>,
+    {
+        match &self.crs {
+            Some(crs) if crs.has_yx_axis_order() => self.geometry.swap_xy(),
+            _ => self.geometry.map_coords(|coord| coord),
+        }
+    }
+}
+
+impl GeoReferenced<Point<f64>> {
+    /// The distance to `other`: in meters along the geodesic on the WGS 84 ellipsoid, if the
+    /// points are in a [geographic](Crs::is_geographic) CRS, and otherwise the [`Euclidean`]
+    /// distance in the units of the coordinates.
+    ///
+    /// Returns an error if the points aren't in the same CRS.
+    ///
+    /// ```
+    /// use geo::{point, Crs, GeoReferenced};
+    ///
+    /// let london = GeoReferenced::new(point!(x: -0.1278, y: 51.5074), Crs::WGS84);
+    /// let paris = GeoReferenced::new(point!(x: 2.3522, y: 48.8566), Crs::WGS84);
+    /// assert_eq!(london.distance(&paris).unwrap().round(), 343_923.);
+    ///
+    /// let projected = GeoReferenced::new(point!(x: 0., y: 0.), Crs::WEB_MERCATOR);
+    /// assert!(london.distance(&projected).is_err());
+    /// ```
+    pub fn distance(&self, other: &GeoReferenced<Point<f64>>) -> Result<f64, CrsMismatch> {
+        self.ensure_same_crs(other)?;
+        Ok(if self.is_geographic() {
+            Geodesic::distance(self.geometry, other.geometry)
+        } else {
+            Euclidean::distance(self.geometry, other.geometry)
+        })
+    }
+}
+
+impl<G> Deref for GeoReferenced<G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.geometry
+    }
+}
+
+impl<G> From<G> for GeoReferenced<G> {
+    fn from(geometry: G) -> Self {
+        Self::without_crs(geometry)
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum, G: MapCoords<T, NT>> MapCoords<T, NT> for GeoReferenced<G> {
+    type Output = GeoReferenced<G::Output>;
+
+    fn map_coords(&self, func: impl Fn(Coord<T>) -> Coord<NT> + Copy) -> Self::Output {
+        GeoReferenced {
+            geometry: self.geometry.map_coords(func),
+            crs: self.crs.clone(),
+        }
+    }
+
+    fn try_map_coords<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+    ) -> Result<Self::Output, E> {
+        Ok(GeoReferenced {
+            geometry: self.geometry.try_map_coords(func)?,
+            crs: self.crs.clone(),
+        })
+    }
+}
+
+impl<T: CoordNum, G: MapCoordsInPlace<T>> MapCoordsInPlace<T> for GeoReferenced<G> {
+    fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) {
+        self.geometry.map_coords_in_place(func)
+    }
+
+    fn try_map_coords_in_place<E>(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    ) -> Result<(), E> {
+        self.geometry.try_map_coords_in_place(func)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, Area, Convert, LineString};
+
+    #[test]
+    fn parse_crs() {
+        assert_eq!("EPSG:3857".parse::<Crs>().unwrap(), Crs::WEB_MERCATOR);
+        assert_eq!(" epsg:4326 ".parse::<Crs>().unwrap(), Crs::WGS84);
+        assert_eq!(
+            r#"{"type": "GeographicCRS"}"#.parse::<Crs>().unwrap(),
+            Crs::ProjJson(r#"{"type": "GeographicCRS"}"#.to_string())
+        );
+        assert!("EPSG:abc".parse::<Crs>().is_err());
+        assert!("ESRI:102003".parse::<Crs>().is_err());
+        assert!("4326".parse::<Crs>().is_err());
+    }
+
+    #[test]
+    fn crs_is_preserved_through_conversions() {
+        let line_string: LineString<f32> = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+        let tagged = GeoReferenced::new(line_string, Crs::Epsg(32633));
+
+        let converted: GeoReferenced<LineString<f64>> = tagged.convert();
+        assert_eq!(converted.crs(), Some(&Crs::Epsg(32633)));
+
+        let mut in_place = converted.clone();
+        in_place.map_coords_in_place(|c| c * 2.0);
+        assert_eq!(in_place.crs(), Some(&Crs::Epsg(32633)));
+        assert_eq!(in_place.0[1], (6., 8.).into());
+
+        let failed: Result<GeoReferenced<LineString<f64>>, &str> =
+            converted.try_map_coords(|_| Err("nope"));
+        assert_eq!(failed, Err("nope"));
+    }
+
+    #[test]
+    fn deref_to_geometry() {
+        let tagged =
+            GeoReferenced::new(crate::wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 0.))), Crs::WGS84);
+        assert_eq!(tagged.unsigned_area(), 2.);
+        let (geometry, crs) = tagged.into_parts();
+        assert_eq!(geometry.exterior().0.len(), 4);
+        assert_eq!(crs, Some(Crs::WGS84));
+    }
+
+    #[test]
+    fn crs_mismatch() {
+        let a = GeoReferenced::new(crate::point!(x: 0., y: 0.), Crs::WGS84);
+        let untagged: GeoReferenced<_> = crate::point!(x: 0., y: 0.).into();
+        let err = a.ensure_same_crs(&untagged).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "coordinate reference systems differ: EPSG:4326 vs no CRS"
+        );
+        assert!(untagged.ensure_same_crs(&untagged).is_ok());
+    }
+
+    #[test]
+    fn geographic_crs() {
+        assert!(Crs::Epsg(4258).is_geographic());
+        assert!(Crs::Epsg(7844).is_geographic());
+        assert!(!Crs::Epsg(4978).is_geographic());
+        assert!(!Crs::Epsg(27700).is_geographic());
+        let geographic =
+            r#"{"type": "GeographicCRS", "datum": {"type": "GeodeticReferenceFrame"}}"#;
+        assert!(Crs::ProjJson(geographic.to_string()).is_geographic());
+        let projected = r#"{"type":"ProjectedCRS","base_crs":{"type":"GeographicCRS"}}"#;
+        assert!(!Crs::ProjJson(projected.to_string()).is_geographic());
+        assert!(!Crs::ProjJson("{}".to_string()).is_geographic());
+    }
+
+    #[test]
+    fn axis_order() {
+        assert!(Crs::Epsg(4258).has_yx_axis_order());
+        assert!(Crs::Epsg(3035).has_yx_axis_order());
+        assert!(!Crs::Epsg(32633).has_yx_axis_order());
+        let lat_lon = r#"{"type": "GeographicCRS", "coordinate_system": {"axis": [
+            {"name": "Geodetic latitude", "direction": "north"},
+            {"name": "Geodetic longitude", "direction": "east"}]}}"#;
+        assert!(Crs::ProjJson(lat_lon.to_string()).has_yx_axis_order());
+        let lon_lat = lat_lon.replacen("north", "up", 1);
+        assert!(!Crs::ProjJson(lon_lat).has_yx_axis_order());
+
+        let line_string: LineString = crate::wkt!(LINESTRING(1. 2.,3. 4.));
+        let projected =
+            GeoReferenced::from_authority_axis_order(line_string.clone(), Crs::WEB_MERCATOR);
+        assert_eq!(projected.geometry(), &line_string);
+        assert_eq!(projected.to_authority_axis_order(), line_string);
+        let untagged = GeoReferenced::without_crs(line_string.clone());
+        assert_eq!(untagged.to_authority_axis_order(), line_string);
+    }
+
+    #[test]
+    fn measures() {
+        let line_string: LineString = crate::wkt!(LINESTRING(0. 0.,0. 1.,1. 1.));
+        let untagged = GeoReferenced::without_crs(line_string.clone());
+        assert_eq!(untagged.length(), 2.);
+        let projected = GeoReferenced::new(line_string.clone(), Crs::Epsg(32631));
+        assert_eq!(projected.length(), 2.);
+        let geographic = GeoReferenced::new(line_string, Crs::WGS84);
+        assert_relative_eq!(geographic.length(), 110_574.4 + 111_302.6, epsilon = 1.);
+
+        let a = GeoReferenced::new(crate::point!(x: 0., y: 0.), Crs::Epsg(32631));
+        let b = GeoReferenced::new(crate::point!(x: 3., y: 4.), Crs::Epsg(32631));
+        assert_eq!(a.distance(&b), Ok(5.));
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use num_traits::ToPrimitive;
+
+use crate::CoordsIter;
+
+/// Why an algorithm rejected its input.
+///
+/// This is returned by the `try_` variants of algorithms which would otherwise panic, or return
+/// a meaningless result such as a `NaN` coordinate, on degenerate input, e.g.
+/// [`Centroid::try_centroid`](crate::Centroid::try_centroid) and
+/// [`ConvexHull::try_convex_hull`](crate::ConvexHull::try_convex_hull). Services can use them to
+/// reject bad input instead of catching panics. It's also returned by the `_cancellable` variants
+/// of long-running algorithms, which stop early when their cancellation flag is set, e.g. by
+/// another thread when a request times out.
+///
+/// More variants may be added, so matches on it need a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Centroid, GeoError, LineString};
+///
+/// let mut line_string = wkt!(LINESTRING(0. 0.,1. 1.,2. 0.));
+/// assert!(line_string.try_centroid().is_ok());
+///
+/// line_string.0[1].x = f64::NAN;
+/// assert_eq!(line_string.try_centroid(), Err(GeoError::NonFiniteCoord { index: 1 }));
+///
+/// let empty = LineString::<f64>::new(vec![]);
+/// assert_eq!(empty.try_centroid(), Err(GeoError::Empty));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeoError {
+    /// The geometry has no coordinates.
+    Empty,
+    /// The coordinate at `index`, in the order of [`CoordsIter::coords_iter`], is NaN or
+    /// infinite.
+    NonFiniteCoord { index: usize },
+    /// The cancellation flag passed to a `_cancellable` algorithm, e.g.
+    /// [`FrechetDistance::frechet_distance_cancellable`](crate::FrechetDistance::frechet_distance_cancellable),
+    /// was set before it finished.
+    Interrupted,
+    /// A [robust overlay](crate::BooleanOps::boolean_op_robust) failed, even with snap-rounded
+    /// coordinates.
+    OverlayFailed,
+}
+
+impl GeoError {
+    /// Checks that a geometry has coordinates, and that they're all finite.
+    pub(crate) fn check<G: CoordsIter + ?Sized>(geometry: &G) -> Result<(), GeoError> {
+        let mut coords = geometry.coords_iter().peekable();
+        if coords.peek().is_none() {
+            return Err(GeoError::Empty);
+        }
+        let finite = |value: G::Scalar| value.to_f64().is_some_and(f64::is_finite);
+        match coords.position(|coord| !(finite(coord.x) && finite(coord.y))) {
+            Some(index) => Err(GeoError::NonFiniteCoord { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks whether the cancellation flag of a long-running algorithm has been set. Algorithms
+    /// call this periodically, e.g. once per row of a distance matrix, so that checking doesn't
+    /// slow them down.
+    pub(crate) fn check_cancel(cancel: &AtomicBool) -> Result<(), GeoError> {
+        if cancel.load(Ordering::Relaxed) {
+            Err(GeoError::Interrupted)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for GeoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoError::Empty => write!(f, "geometry is empty"),
+            GeoError::NonFiniteCoord { index } => write!(f, "coordinate {index} is not finite"),
+            GeoError::Interrupted => write!(f, "operation was interrupted"),
+            GeoError::OverlayFailed => write!(f, "overlay failed on every snapping grid"),
+        }
+    }
+}
+
+impl std::error::Error for GeoError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        point, wkt, Centroid, ConvexHull, Geometry, InteriorPoint, LineString, MinimumRotatedRect,
+        Polygon,
+    };
+
+    #[test]
+    fn degenerate_input() {
+        let mut polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        assert_eq!(polygon.try_centroid(), Ok(point!(x: 2., y: 2.)));
+        assert!(polygon.try_convex_hull().is_ok());
+        assert!(polygon.try_interior_point().is_ok());
+        assert!(polygon.try_minimum_rotated_rect().is_ok());
+
+        // these panic or return NaN coordinates without the checks
+        polygon.exterior_mut(|exterior| exterior.0[2].y = f64::NAN);
+        let error = Some(GeoError::NonFiniteCoord { index: 2 });
+        assert_eq!(polygon.try_centroid().err(), error);
+        assert_eq!(polygon.try_convex_hull().err(), error);
+        assert_eq!(polygon.try_interior_point().err(), error);
+        assert_eq!(polygon.try_minimum_rotated_rect().err(), error);
+
+        let geometry: Geometry = wkt!(MULTIPOINT(0. 0.,1. 1.)).into();
+        assert!(geometry.try_centroid().is_ok());
+        let geometry: Geometry = point!(x: f64::INFINITY, y: 0.).into();
+        assert_eq!(
+            geometry.try_centroid(),
+            Err(GeoError::NonFiniteCoord { index: 0 })
+        );
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.try_convex_hull(), Err(GeoError::Empty));
+        assert_eq!(empty.try_interior_point(), Err(GeoError::Empty));
+        assert_eq!(GeoError::Empty.to_string(), "geometry is empty");
+    }
+}
@@ -0,0 +1,313 @@
+//! Encode and decode [geohashes], and find the geohashes covering a geometry.
+//!
+//! A geohash names a cell of a grid over longitude/latitude, by repeatedly halving the world
+//! along alternating axes. Each character adds 5 bits, so a geohash of precision `n` names one of
+//! `32^n` cells, and every geohash is a prefix of the geohashes of the cells inside it. This makes
+//! them convenient keys for spatial indexes in databases.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::geohash::{decode_geohash, encode_geohash};
+//! use geo::coord;
+//!
+//! let hash = encode_geohash(coord! { x: -5.6, y: 42.6 }, 5).unwrap();
+//! assert_eq!(hash, "ezs42");
+//!
+//! let cell = decode_geohash(&hash).unwrap();
+//! assert!(cell.min().x <= -5.6 && -5.6 <= cell.max().x);
+//! ```
+//!
+//! [geohashes]: https://en.wikipedia.org/wiki/Geohash
+
+use std::fmt;
+
+use crate::dimensions::Dimensions;
+use crate::{coord, BoundingRect, Coord, HasDimensions, Intersects, Rect, Relate};
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest supported geohash. At 12 characters, cells are a few centimeters across, which is
+/// about the precision of an `f64` longitude/latitude pair.
+pub const MAX_PRECISION: usize = 12;
+
+/// An error returned for an invalid geohash, precision or coordinate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeohashError {
+    /// The precision isn't between 1 and [`MAX_PRECISION`].
+    InvalidPrecision(usize),
+    /// The geohash contains a character which isn't in the geohash alphabet.
+    InvalidCharacter(char),
+    /// The coordinate isn't a valid longitude/latitude.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeohashError::InvalidPrecision(precision) => write!(
+                f,
+                "geohash precision must be between 1 and {MAX_PRECISION}, not {precision}"
+            ),
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character: {c:?}"),
+            GeohashError::InvalidCoordinate => {
+                write!(f, "coordinate is outside the longitude/latitude range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+fn check_precision(precision: usize) -> Result<(), GeohashError> {
+    if !(1..=MAX_PRECISION).contains(&precision) {
+        return Err(GeohashError::InvalidPrecision(precision));
+    }
+    Ok(())
+}
+
+/// Encode a longitude/latitude coordinate as a geohash of `precision` characters.
+pub fn encode_geohash(coord: Coord, precision: usize) -> Result<String, GeohashError> {
+    check_precision(precision)?;
+    if !(-180. ..=180.).contains(&coord.x) || !(-90. ..=90.).contains(&coord.y) {
+        return Err(GeohashError::InvalidCoordinate);
+    }
+    let mut lon = (-180., 180.);
+    let mut lat = (-90., 90.);
+    let mut hash = String::with_capacity(precision);
+    let mut bit = 0;
+    for _ in 0..precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = if bit % 2 == 0 {
+                (&mut lon, coord.x)
+            } else {
+                (&mut lat, coord.y)
+            };
+            let mid = (range.0 + range.1) / 2.;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            bit += 1;
+        }
+        hash.push(char::from(BASE32[index]));
+    }
+    Ok(hash)
+}
+
+/// Decode a geohash to the cell it names.
+///
+/// Decoding is case insensitive.
+pub fn decode_geohash(hash: &str) -> Result<Rect, GeohashError> {
+    check_precision(hash.len())?;
+    let mut lon = (-180., 180.);
+    let mut lat = (-90., 90.);
+    let mut bit = 0;
+    for c in hash.chars() {
+        let index = BASE32
+            .iter()
+            .position(|&b| char::from(b) == c.to_ascii_lowercase())
+            .ok_or(GeohashError::InvalidCharacter(c))?;
+        for shift in (0..5).rev() {
+            let range = if bit % 2 == 0 { &mut lon } else { &mut lat };
+            let mid = (range.0 + range.1) / 2.;
+            if index >> shift & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            bit += 1;
+        }
+    }
+    Ok(Rect::new(
+        coord! { x: lon.0, y: lat.0 },
+        coord! { x: lon.1, y: lat.1 },
+    ))
+}
+
+/// The geohashes covering a geometry, as returned by [`geohash_coverage`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GeohashCoverage {
+    /// The cells which lie entirely within the geometry.
+    pub interior: Vec<String>,
+    /// The cells which intersect the geometry's boundary, so only part of them is covered.
+    pub boundary: Vec<String>,
+}
+
+/// Find the geohashes of `precision` characters whose cells intersect a longitude/latitude
+/// geometry, distinguishing cells which are entirely covered from those on the boundary.
+///
+/// Cells which only share an edge or corner with a polygon aren't included, since no part of
+/// their area is covered.
+///
+/// Both lists are sorted. Cells are refined from the coarsest precision down, so large areas are
+/// cheap to cover, although the number of interior cells still grows by a factor of 32 with each
+/// extra character of precision.
+///
+/// # Examples
+///
+/// ```
+/// use geo::geohash::geohash_coverage;
+/// use geo::wkt;
+///
+/// let polygon = wkt!(POLYGON((-90. 0.,-45. 0.,-45. 45.,-90. 45.,-90. 0.)));
+/// let coverage = geohash_coverage(&polygon, 1).unwrap();
+/// assert_eq!(coverage.interior, vec!["d"]);
+/// assert!(coverage.boundary.is_empty());
+///
+/// // a polygon slightly larger than cell "d" also covers part of its 8 neighbours
+/// let polygon = wkt!(POLYGON((-91. -1.,-44. -1.,-44. 46.,-91. 46.,-91. -1.)));
+/// let coverage = geohash_coverage(&polygon, 1).unwrap();
+/// assert_eq!(coverage.interior, vec!["d"]);
+/// assert_eq!(coverage.boundary.len(), 8);
+/// ```
+pub fn geohash_coverage<G>(geometry: &G, precision: usize) -> Result<GeohashCoverage, GeohashError>
+where
+    G: BoundingRect<f64> + Relate<f64> + HasDimensions,
+{
+    check_precision(precision)?;
+    let mut coverage = GeohashCoverage::default();
+    let Some(bounds) = geometry.bounding_rect().into() else {
+        return Ok(coverage);
+    };
+    let mut hash = String::with_capacity(precision);
+    cover(geometry, bounds, &mut hash, precision, &mut coverage);
+    Ok(coverage)
+}
+
+/// Adds the children of `hash` which intersect `geometry` to `coverage`, refining them until
+/// they're either within the geometry or at `precision`.
+fn cover<G>(
+    geometry: &G,
+    bounds: Rect,
+    hash: &mut String,
+    precision: usize,
+    coverage: &mut GeohashCoverage,
+) where
+    G: Relate<f64> + HasDimensions,
+{
+    let areal = geometry.dimensions() == Dimensions::TwoDimensional;
+    for &c in BASE32 {
+        hash.push(char::from(c));
+        let cell = decode_geohash(hash).expect("valid geohash");
+        if bounds.intersects(&cell) {
+            let matrix = geometry.relate(&cell);
+            if !matrix.is_intersects() || areal && matrix.is_touches() {
+                // no part of the cell is covered
+            } else if matrix.is_covers() {
+                fill(hash, precision, &mut coverage.interior);
+            } else if hash.len() == precision {
+                coverage.boundary.push(hash.clone());
+            } else {
+                cover(geometry, bounds, hash, precision, coverage);
+            }
+        }
+        hash.pop();
+    }
+}
+
+/// Adds all descendants of `hash` with `precision` characters to `hashes`.
+fn fill(hash: &mut String, precision: usize, hashes: &mut Vec<String>) {
+    if hash.len() == precision {
+        hashes.push(hash.clone());
+        return;
+    }
+    for &c in BASE32 {
+        hash.push(char::from(c));
+        fill(hash, precision, hashes);
+        hash.pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Area, BooleanOps, Contains, MultiPolygon};
+
+    #[test]
+    fn encode() {
+        // from geohash.org
+        assert_eq!(
+            encode_geohash(coord! { x: 10.40744, y: 57.64911 }, 11).unwrap(),
+            "u4pruydqqvj"
+        );
+        assert_eq!(encode_geohash(coord! { x: 180., y: 90. }, 2).unwrap(), "zz");
+        assert_eq!(
+            encode_geohash(coord! { x: -180., y: -90. }, 2).unwrap(),
+            "00"
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let cell = decode_geohash("u4pruydqqvj").unwrap();
+        assert!(cell.contains(&point!(x: 10.40744, y: 57.64911)));
+        assert!(cell.width() < 1e-5);
+
+        assert_eq!(
+            decode_geohash("D").unwrap(),
+            Rect::new((-90., 0.), (-45., 45.))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            encode_geohash(coord! { x: 0., y: 0. }, 0),
+            Err(GeohashError::InvalidPrecision(0))
+        );
+        assert_eq!(
+            encode_geohash(coord! { x: 181., y: 0. }, 5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            decode_geohash("ezs4a"),
+            Err(GeohashError::InvalidCharacter('a'))
+        );
+        assert_eq!(decode_geohash(""), Err(GeohashError::InvalidPrecision(0)));
+    }
+
+    #[test]
+    fn coverage_area() {
+        let polygon = wkt!(POLYGON((-5.6 42.6,-5.3 42.6,-5.4 42.9,-5.6 42.6)));
+        let coverage = geohash_coverage(&polygon, 5).unwrap();
+        assert!(!coverage.interior.is_empty());
+        let cells = |hashes: &[String]| {
+            MultiPolygon::new(
+                hashes
+                    .iter()
+                    .map(|hash| decode_geohash(hash).unwrap().to_polygon())
+                    .collect(),
+            )
+        };
+        // interior cells are covered by the polygon, and all cells together cover it
+        let interior = cells(&coverage.interior);
+        assert!(interior.unsigned_area() < polygon.unsigned_area());
+        assert!(polygon.contains(&interior));
+        let covered_area: f64 = coverage
+            .interior
+            .iter()
+            .chain(&coverage.boundary)
+            .map(|hash| {
+                let cell = decode_geohash(hash).unwrap().to_polygon();
+                polygon.intersection(&cell).unsigned_area()
+            })
+            .sum();
+        assert_relative_eq!(covered_area, polygon.unsigned_area(), epsilon = 1e-9);
+
+        for hash in &coverage.boundary {
+            assert!(!coverage.interior.contains(hash));
+        }
+    }
+
+    #[test]
+    fn coverage_of_point() {
+        let coverage = geohash_coverage(&point!(x: -5.6, y: 42.6), 5).unwrap();
+        assert!(coverage.interior.is_empty());
+        assert!(coverage.boundary.contains(&"ezs42".to_string()));
+    }
+}
@@ -0,0 +1,423 @@
+//! Read the features of a GeoJSON `FeatureCollection` one at a time.
+//!
+//! [`FeatureReader`] pulls features from any [`io::Read`], only buffering the feature being read,
+//! so multi-gigabyte files can be processed in constant memory. Members of the collection other
+//! than `features` (e.g. `bbox` or foreign members) are skipped.
+//!
+//! Geometries are read with [`geo_types::serde_geojson`], so positions with an altitude are
+//! accepted but only `x` and `y` are kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::geojson::FeatureReader;
+//! use geo::{point, Geometry};
+//!
+//! let json = r#"{
+//!     "type": "FeatureCollection",
+//!     "features": [
+//!         {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]}, "properties": {"name": "a"}},
+//!         {"type": "Feature", "geometry": null, "properties": null}
+//!     ]
+//! }"#;
+//!
+//! let mut features = FeatureReader::new(json.as_bytes());
+//!
+//! let first = features.next().unwrap().unwrap();
+//! assert_eq!(first.geometry, Some(Geometry::Point(point!(x: 1., y: 2.))));
+//! assert_eq!(first.properties["name"], "a");
+//!
+//! let second = features.next().unwrap().unwrap();
+//! assert_eq!(second.geometry, None);
+//!
+//! assert!(features.next().is_none());
+//! ```
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+use geo_types::serde_geojson::GeoJson;
+use serde_json::Value;
+
+use crate::Geometry;
+
+/// A GeoJSON feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feature {
+    /// The feature's `id`, which is a string or number if present.
+    pub id: Option<Value>,
+    /// The feature's geometry, which is `None` for an unlocated feature.
+    pub geometry: Option<Geometry>,
+    /// The feature's `properties`, which is usually an object, or `null` if absent.
+    pub properties: Value,
+}
+
+#[derive(Deserialize)]
+enum FeatureType {
+    Feature,
+}
+
+#[derive(Deserialize)]
+struct RawFeature {
+    #[serde(rename = "type")]
+    _type: FeatureType,
+    #[serde(default)]
+    id: Option<Value>,
+    geometry: Option<GeoJson<Geometry>>,
+    #[serde(default)]
+    properties: Value,
+}
+
+/// An error returned while reading a GeoJSON `FeatureCollection`.
+#[derive(Debug)]
+pub enum GeoJsonReadError {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+    /// The document isn't valid JSON at this byte offset.
+    Syntax {
+        /// What was expected at `offset`
+        expected: &'static str,
+        /// The byte offset of the error
+        offset: u64,
+    },
+    /// The document isn't a `FeatureCollection`.
+    NotAFeatureCollection,
+    /// A feature, or its geometry, isn't valid GeoJSON.
+    InvalidFeature(serde_json::Error),
+}
+
+impl fmt::Display for GeoJsonReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonReadError::Io(e) => write!(f, "error reading GeoJSON: {e}"),
+            GeoJsonReadError::Syntax { expected, offset } => {
+                write!(f, "invalid JSON at byte {offset}: expected {expected}")
+            }
+            GeoJsonReadError::NotAFeatureCollection => {
+                write!(f, "GeoJSON document isn't a FeatureCollection")
+            }
+            GeoJsonReadError::InvalidFeature(e) => write!(f, "invalid GeoJSON feature: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GeoJsonReadError::Io(e) => Some(e),
+            GeoJsonReadError::InvalidFeature(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GeoJsonReadError {
+    fn from(e: io::Error) -> Self {
+        GeoJsonReadError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Before the opening brace of the collection
+    Start,
+    /// Inside the `features` array, before the first feature
+    FirstFeature,
+    /// Inside the `features` array, after a feature
+    NextFeature,
+    /// After the end of the collection, or after an error
+    Done,
+}
+
+/// An iterator over the features of a GeoJSON `FeatureCollection`, read incrementally.
+///
+/// After an error, the iterator returns `None`.
+pub struct FeatureReader<R> {
+    reader: BufReader<R>,
+    offset: u64,
+    state: State,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> FeatureReader<R> {
+    /// Read features from `reader`, which doesn't need to be buffered.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            offset: 0,
+            state: State::Start,
+            buf: Vec::new(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, GeoJsonReadError> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn bump(&mut self) {
+        self.reader.consume(1);
+        self.offset += 1;
+    }
+
+    /// Skips whitespace, then returns the next byte without consuming it.
+    fn peek_token(&mut self) -> Result<Option<u8>, GeoJsonReadError> {
+        while let Some(byte) = self.peek()? {
+            if !byte.is_ascii_whitespace() {
+                return Ok(Some(byte));
+            }
+            self.bump();
+        }
+        Ok(None)
+    }
+
+    fn expect(&mut self, byte: u8, expected: &'static str) -> Result<(), GeoJsonReadError> {
+        if self.peek_token()? != Some(byte) {
+            return Err(self.syntax_error(expected));
+        }
+        self.bump();
+        Ok(())
+    }
+
+    fn syntax_error(&self, expected: &'static str) -> GeoJsonReadError {
+        GeoJsonReadError::Syntax {
+            expected,
+            offset: self.offset,
+        }
+    }
+
+    /// Reads the next JSON value into `self.buf`, without parsing it.
+    fn read_value(&mut self) -> Result<(), GeoJsonReadError> {
+        self.buf.clear();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        match self.peek_token()? {
+            None => return Err(self.syntax_error("a value")),
+            Some(b'}' | b']' | b',' | b':') => return Err(self.syntax_error("a value")),
+            _ => {}
+        }
+        loop {
+            let Some(byte) = self.peek()? else {
+                if depth == 0 && !in_string {
+                    return Ok(());
+                }
+                return Err(self.syntax_error("the end of a value"));
+            };
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth == 0 => return Ok(()),
+                    b'}' | b']' => depth -= 1,
+                    b',' if depth == 0 => return Ok(()),
+                    byte if byte.is_ascii_whitespace() && depth == 0 => return Ok(()),
+                    _ => {}
+                }
+            }
+            self.buf.push(byte);
+            self.bump();
+            if depth == 0 && !in_string && matches!(byte, b'}' | b']' | b'"') {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Reads an object key and the following colon.
+    fn read_key(&mut self) -> Result<String, GeoJsonReadError> {
+        if self.peek_token()? != Some(b'"') {
+            return Err(self.syntax_error("an object key"));
+        }
+        self.read_value()?;
+        let key = serde_json::from_slice(&self.buf).map_err(|_| self.syntax_error("a string"))?;
+        self.expect(b':', "':'")?;
+        Ok(key)
+    }
+
+    /// Reads members of the collection until the start of the `features` array, or the end of
+    /// the collection.
+    fn seek_features(&mut self, mut first: bool) -> Result<State, GeoJsonReadError> {
+        loop {
+            if self.peek_token()? == Some(b'}') {
+                self.bump();
+                if self.peek_token()?.is_some() {
+                    return Err(self.syntax_error("the end of the document"));
+                }
+                return Ok(State::Done);
+            }
+            if !first {
+                self.expect(b',', "',' or '}'")?;
+            }
+            first = false;
+            let key = self.read_key()?;
+            match key.as_str() {
+                "features" => {
+                    self.expect(b'[', "an array of features")?;
+                    return Ok(State::FirstFeature);
+                }
+                "type" => {
+                    self.read_value()?;
+                    if self.buf != br#""FeatureCollection""# {
+                        return Err(GeoJsonReadError::NotAFeatureCollection);
+                    }
+                }
+                _ => self.read_value()?,
+            }
+        }
+    }
+
+    fn read_feature(&mut self) -> Result<Option<Feature>, GeoJsonReadError> {
+        loop {
+            match self.state {
+                State::Start => {
+                    if self.peek_token()? != Some(b'{') {
+                        return Err(GeoJsonReadError::NotAFeatureCollection);
+                    }
+                    self.bump();
+                    self.state = self.seek_features(true)?;
+                }
+                State::FirstFeature | State::NextFeature => {
+                    if self.peek_token()? == Some(b']') {
+                        self.bump();
+                        self.state = self.seek_features(false)?;
+                        continue;
+                    }
+                    if self.state == State::NextFeature {
+                        self.expect(b',', "',' or ']'")?;
+                    }
+                    self.read_value()?;
+                    let feature: RawFeature = serde_json::from_slice(&self.buf)
+                        .map_err(GeoJsonReadError::InvalidFeature)?;
+                    self.state = State::NextFeature;
+                    return Ok(Some(Feature {
+                        id: feature.id,
+                        geometry: feature.geometry.map(|g| g.0),
+                        properties: feature.properties,
+                    }));
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for FeatureReader<R> {
+    type Item = Result<Feature, GeoJsonReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_feature() {
+            Ok(feature) => feature.map(Ok),
+            Err(e) => {
+                self.state = State::Done;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    fn read_all(json: &str) -> Result<Vec<Feature>, GeoJsonReadError> {
+        FeatureReader::new(json.as_bytes()).collect()
+    }
+
+    #[test]
+    fn members_around_features() {
+        let json = r#"{"bbox": [0, 0, 1, 1], "features": [
+            {"type": "Feature", "id": 7, "properties": {"a": [1, "]}"]},
+             "geometry": {"type": "LineString", "coordinates": [[0, 0], [1, 1]]}}
+        ], "type": "FeatureCollection", "name": "x"}"#;
+        let features = read_all(json).unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].id, Some(Value::from(7)));
+        assert_eq!(
+            features[0].geometry,
+            Some(wkt!(LINESTRING(0. 0.,1. 1.)).into())
+        );
+        assert_eq!(features[0].properties["a"][1], "]}");
+    }
+
+    #[test]
+    fn empty() {
+        assert!(read_all(r#"{"type": "FeatureCollection", "features": []}"#)
+            .unwrap()
+            .is_empty());
+        assert!(read_all(r#"{"type": "FeatureCollection"}"#)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn missing_properties() {
+        let features =
+            read_all(r#"{"features": [{"type": "Feature", "geometry": null}]}"#).unwrap();
+        assert_eq!(features[0].properties, Value::Null);
+        assert_eq!(features[0].id, None);
+    }
+
+    #[test]
+    fn small_reads() {
+        struct OneByte<'a>(&'a [u8]);
+        impl Read for OneByte<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let Some((&first, rest)) = self.0.split_first() else {
+                    return Ok(0);
+                };
+                buf[0] = first;
+                self.0 = rest;
+                Ok(1)
+            }
+        }
+        let json = r#"{"features": [
+            {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]}, "properties": {}},
+            {"type": "Feature", "geometry": {"type": "Point", "coordinates": [3, 4]}, "properties": {}}
+        ]}"#;
+        let features = FeatureReader::new(OneByte(json.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1].geometry, Some(wkt!(POINT(3. 4.)).into()));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            read_all(r#"[]"#),
+            Err(GeoJsonReadError::NotAFeatureCollection)
+        ));
+        assert!(matches!(
+            read_all(r#"{"type": "Feature", "features": []}"#),
+            Err(GeoJsonReadError::NotAFeatureCollection)
+        ));
+        assert!(matches!(
+            read_all(r#"{"features": [{"type": "Point", "coordinates": [1, 2]}]}"#),
+            Err(GeoJsonReadError::InvalidFeature(_))
+        ));
+        assert!(matches!(
+            read_all(r#"{"features": [{"type": "Feature", "geometry": null} {}]}"#),
+            Err(GeoJsonReadError::Syntax { offset: 52, .. })
+        ));
+        assert!(matches!(
+            read_all(r#"{"features": [{"type": "Feature", "geometry": null}"#),
+            Err(GeoJsonReadError::Syntax { .. })
+        ));
+
+        // features before the error are still returned
+        let mut reader = FeatureReader::new(
+            r#"{"features": [{"type": "Feature", "geometry": null}, ]}"#.as_bytes(),
+        );
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
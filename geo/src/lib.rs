@@ -45,6 +45,7 @@
 //! ### Operations on Metric Spaces
 //!
 //! - **[`Distance`]**: Calculate the minimum distance between two geometries.
+//! - **[`BatchDistance`]**: Calculate the distances from one coordinate to many in a single call.
 //! - **[`Length`]**: Calculate the length of a `Line`, `LineString`, or `MultiLineString`.
 //! - **[`Bearing`]**: Calculate the bearing between two points.
 //!
@@ -64,6 +65,7 @@
 //! - **[`Area`]**: Calculate the planar area of a geometry
 //! - **[`ChamberlainDuquetteArea`]**: Calculate the geodesic area of a geometry on a sphere using the algorithm presented in _Some Algorithms for Polygons on a Sphere_ by Chamberlain and Duquette (2007)
 //! - **[`GeodesicArea`]**: Calculate the geodesic area and perimeter of a geometry on an ellipsoid using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
+//! - **[`IntegerArea`]**: Calculate the exact planar area of a geometry with integer coordinates
 //!
 //! ## Boolean Operations
 //!
@@ -80,6 +82,7 @@
 //! - **[`SimplifyVw`]**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwPreserve`]**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`]**: Calculate a simplified geometry using the Visvalingam-Whyatt algorithm, returning coordinate indices
+//! - **[`IntegerSimplify`]**: Simplify a geometry with integer coordinates using the Ramer–Douglas–Peucker algorithm and exact arithmetic
 //!
 //! ## Query
 //!
@@ -165,6 +168,7 @@
 //! - **[`LineStringSegmentizeHaversine`]**: Segment a LineString using Haversine distance.
 //! - **[`Transform`]**: Transform a geometry using Proj.
 //! - **[`RemoveRepeatedPoints`]**: Remove repeated points from a geometry.
+//! - **[`MemSize`]**: Report the heap memory used by a geometry, e.g. to enforce memory budgets
 //!
 //! # Spatial Indexing
 //!
@@ -190,9 +194,31 @@
 //! - `use-serde`:
 //!     - Allows geometry types to be serialized and deserialized with [Serde].
 //!     - ☐ Disabled by default.
+//! - `use-geojson`:
+//!     - Enables the `geojson` module, for streaming the features of large GeoJSON files. Implies `use-serde`.
+//!     - ☐ Disabled by default.
+//! - `geographiclib`:
+//!     - Enables the [`geographiclib-rs`] crate, which computes the ellipsoidal distances, bearings
+//!       and areas of [`Geodesic`] and [`GeodesicArea`]. Without it, they fall back to spherical
+//!       approximations, e.g. to slim down builds for `wasm32-unknown-unknown`, which are best
+//!       made with `default-features = false`.
+//!     - ☑ Enabled by default.
 //! - `multithreading`:
 //!     - Enables multithreading support for the `i_overlay` crate.
 //!     - ☑ Enabled by default.
+//! - `parallel`:
+//!     - Uses [rayon] to process the members of collections with at least 32 members in parallel,
+//!       currently when computing [`GeodesicArea`] of a `MultiPolygon` or `GeometryCollection`,
+//!       and the [`NearestJoin`] of many points. Simplification is still sequential, since its
+//!       generic coordinate types aren't required to be `Send` and `Sync`.
+//!       Smaller collections are processed sequentially, since distributing the work would cost
+//!       more than it saves. Sums may differ from sequential ones in the last bits.
+//!     - ☐ Disabled by default.
+//! - `testkit`:
+//!     - Enables the `testkit` module of random geometry generators, e.g. polygons with holes,
+//!       nearly degenerate and world-spanning geodesic cases, for property tests of downstream
+//!       algorithms.
+//!     - ☐ Disabled by default.
 //!
 //! # Ecosystem
 //!
@@ -226,6 +252,8 @@
 //! [proj crate file download]: https://docs.rs/proj/*/proj/#grid-file-download
 //! [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
 //! [Serde]: https://serde.rs/
+//! [rayon]: https://docs.rs/rayon
+//! [`geographiclib-rs`]: https://docs.rs/geographiclib-rs
 
 #[cfg(feature = "use-serde")]
 #[macro_use]
@@ -241,6 +269,31 @@ pub use geo_types::{coord, line_string, point, polygon, wkt, CoordFloat, CoordNu
 pub mod geometry;
 pub use geometry::*;
 
+pub mod crs;
+pub use crs::{Crs, GeoReferenced};
+
+mod arc_geometry;
+pub use arc_geometry::ArcGeometry;
+
+mod bounds;
+pub use bounds::Bounds;
+
+mod error;
+pub use error::GeoError;
+
+pub mod wkb;
+pub mod twkb;
+pub mod polyline;
+pub mod tile;
+pub mod mvt;
+pub mod raster;
+pub mod geohash;
+pub mod trajectory;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
+#[cfg(feature = "use-geojson")]
+pub mod geojson;
+
 /// This module includes all the functions of geometric calculations
 pub mod algorithm;
 mod geometry_cow;
@@ -0,0 +1,639 @@
+//! Encode and decode geometries for [Mapbox Vector Tiles] (MVT).
+//!
+//! A vector tile feature stores its geometry as a stream of integer commands in the tile's own
+//! coordinate system, which covers `0..extent` on both axes with `y` pointing south.
+//! [`encode_mvt_geometry`] projects a longitude/latitude geometry into a tile, clips it to the
+//! tile and its buffer, snaps it to the integer grid and encodes the result.
+//! [`decode_mvt_geometry`] reads commands back into tile coordinates, which
+//! [`tile_to_wgs84`] converts back to longitude/latitude.
+//!
+//! Only the geometry is handled here: the protobuf layers, features and attributes of a tile are
+//! left to a protobuf library.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::mvt::{decode_mvt_geometry, encode_mvt_geometry, tile_to_wgs84, MvtOptions};
+//! use geo::tile::TileId;
+//! use geo::{wkt, Geometry};
+//!
+//! let tile = TileId::new(12, 2200, 1343);
+//! let options = MvtOptions::default();
+//! let line_string: Geometry = wkt!(LINESTRING(13.3776 52.5163,13.4050 52.5200)).into();
+//!
+//! let encoded = encode_mvt_geometry(&line_string, tile, &options).unwrap();
+//! let decoded = decode_mvt_geometry(encoded.geometry_type, &encoded.commands).unwrap();
+//!
+//! // decoded coordinates are accurate to the tile's grid, about 2.4m here
+//! let Geometry::LineString(round_trip) = tile_to_wgs84(&decoded, tile, options.extent) else {
+//!     panic!("expected a line string");
+//! };
+//! approx::assert_relative_eq!(round_trip.0[0].x, 13.3776, epsilon = 1e-4);
+//! ```
+//!
+//! [Mapbox Vector Tiles]: https://github.com/mapbox/vector-tile-spec/tree/master/2.1
+
+use std::fmt;
+
+use crate::tile::TileId;
+use crate::{
+    BooleanOps, Coord, Geometry, LineString, MapCoords, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect,
+};
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// The type of a vector tile feature's geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MvtGeometryType {
+    /// One or more points
+    Point = 1,
+    /// One or more line strings
+    LineString = 2,
+    /// One or more polygons
+    Polygon = 3,
+}
+
+/// The encoded geometry of a vector tile feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MvtGeometry {
+    /// The feature's `type`
+    pub geometry_type: MvtGeometryType,
+    /// The feature's `geometry` command stream
+    pub commands: Vec<u32>,
+}
+
+/// Options for [`encode_mvt_geometry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MvtOptions {
+    /// The size of the tile's integer grid along each axis.
+    pub extent: u32,
+    /// How far geometries may extend beyond the edges of the tile before being clipped, in grid
+    /// units. This avoids rendering artifacts like line caps at tile edges.
+    pub buffer: u32,
+}
+
+impl Default for MvtOptions {
+    /// An extent of 4096 with a buffer of 64, the defaults of most tile generators.
+    fn default() -> Self {
+        Self {
+            extent: 4096,
+            buffer: 64,
+        }
+    }
+}
+
+/// An error returned when decoding an invalid command stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MvtError {
+    /// The command at this index is unknown or not valid for the geometry type.
+    InvalidCommand(usize),
+    /// The command stream ended in the middle of a command.
+    UnexpectedEnd,
+    /// The geometry has no parts.
+    Empty,
+}
+
+impl fmt::Display for MvtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MvtError::InvalidCommand(index) => write!(f, "invalid MVT command at index {index}"),
+            MvtError::UnexpectedEnd => write!(f, "MVT command stream ended unexpectedly"),
+            MvtError::Empty => write!(f, "MVT geometry is empty"),
+        }
+    }
+}
+
+impl std::error::Error for MvtError {}
+
+/// Encode a longitude/latitude geometry as the geometry of a feature in `tile`.
+///
+/// The geometry is projected to Web Mercator, clipped to the tile and its buffer, and snapped to
+/// the tile's grid. Parts which become degenerate, i.e. line strings with fewer than two distinct
+/// points and rings without area, are dropped. Polygon rings are oriented as the specification
+/// requires.
+///
+/// Returns `None` if nothing is left to encode, or for a [`GeometryCollection`], which has to be
+/// encoded as one feature per member.
+///
+/// [`GeometryCollection`]: crate::GeometryCollection
+pub fn encode_mvt_geometry(
+    geometry: &Geometry,
+    tile: TileId,
+    options: &MvtOptions,
+) -> Option<MvtGeometry> {
+    let extent = f64::from(options.extent);
+    let buffer = f64::from(options.buffer);
+    let clip = Rect::new(
+        Coord {
+            x: -buffer,
+            y: -buffer,
+        },
+        Coord {
+            x: extent + buffer,
+            y: extent + buffer,
+        },
+    );
+    let projected = geometry.map_coords(|coord| tile.to_tile_coord(coord, extent));
+    encode_mvt_commands(&clip_and_quantize(to_multi(projected)?, clip))
+}
+
+/// Converts a geometry to the multi-geometry of its type, or `None` for a
+/// [`GeometryCollection`](crate::GeometryCollection).
+pub(crate) fn to_multi(geometry: Geometry) -> Option<Geometry> {
+    Some(match geometry {
+        Geometry::Point(point) => MultiPoint::new(vec![point]).into(),
+        Geometry::MultiPoint(multi_point) => multi_point.into(),
+        Geometry::Line(line) => MultiLineString::new(vec![line.into()]).into(),
+        Geometry::LineString(line_string) => MultiLineString::new(vec![line_string]).into(),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string.into(),
+        Geometry::Polygon(polygon) => MultiPolygon::new(vec![polygon]).into(),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon.into(),
+        Geometry::Rect(rect) => MultiPolygon::new(vec![rect.to_polygon()]).into(),
+        Geometry::Triangle(triangle) => MultiPolygon::new(vec![triangle.to_polygon()]).into(),
+        Geometry::GeometryCollection(_) => return None,
+    })
+}
+
+/// Clips a multi-geometry from [`to_multi`] to `clip`, and rounds its coordinates to integers.
+pub(crate) fn clip_and_quantize(geometry: Geometry, clip: Rect) -> Geometry<i32> {
+    match geometry {
+        Geometry::MultiPoint(multi_point) => multi_point
+            .into_iter()
+            .filter(|point| clip.min().x <= point.x() && point.x() <= clip.max().x)
+            .filter(|point| clip.min().y <= point.y() && point.y() <= clip.max().y)
+            .map(|point| Point(quantize(point.0)))
+            .collect::<MultiPoint<i32>>()
+            .into(),
+        Geometry::MultiLineString(multi_line_string) => MultiLineString::new(
+            clip.to_polygon()
+                .clip(&multi_line_string, false)
+                .iter()
+                .map(quantize_line_string)
+                .filter(|line_string| line_string.0.len() >= 2)
+                .collect(),
+        )
+        .into(),
+        Geometry::MultiPolygon(multi_polygon) => {
+            let clipped = multi_polygon.intersection(&clip.to_polygon());
+            MultiPolygon::new(
+                clipped
+                    .iter()
+                    .map(|polygon| {
+                        Polygon::new(
+                            quantize_line_string(polygon.exterior()),
+                            polygon
+                                .interiors()
+                                .iter()
+                                .map(quantize_line_string)
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )
+            .into()
+        }
+        _ => unreachable!("geometries are converted to multi-geometries before clipping"),
+    }
+}
+
+fn quantize(coord: Coord) -> Coord<i32> {
+    Coord {
+        x: coord.x.round() as i32,
+        y: coord.y.round() as i32,
+    }
+}
+
+/// Snaps a line string to the grid, removing repeated points.
+fn quantize_line_string(line_string: &LineString) -> LineString<i32> {
+    let mut coords: Vec<Coord<i32>> = line_string.coords().map(|&c| quantize(c)).collect();
+    coords.dedup();
+    LineString::new(coords)
+}
+
+/// Twice the area of a closed ring, by the shoelace formula. This is positive for a ring which
+/// is clockwise when `y` points south.
+fn twice_area(ring: &[Coord<i32>]) -> i64 {
+    ring.windows(2)
+        .map(|w| i64::from(w[0].x) * i64::from(w[1].y) - i64::from(w[1].x) * i64::from(w[0].y))
+        .sum()
+}
+
+/// Returns the ring without its closing coordinate, oriented so its area has the sign of
+/// `sign`, or `None` if it has no area.
+fn oriented_ring(ring: &LineString<i32>, sign: i64) -> Option<Vec<Coord<i32>>> {
+    let mut coords = ring.0.clone();
+    if coords.first() != coords.last() {
+        coords.push(coords[0]);
+    }
+    let area = twice_area(&coords);
+    if coords.len() < 4 || area == 0 {
+        return None;
+    }
+    coords.pop();
+    if area.signum() != sign {
+        coords[1..].reverse();
+    }
+    Some(coords)
+}
+
+struct CommandWriter {
+    commands: Vec<u32>,
+    cursor: Coord<i32>,
+}
+
+impl CommandWriter {
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push(id | (count as u32) << 3);
+    }
+
+    fn coord(&mut self, coord: Coord<i32>) {
+        let dx = coord.x.wrapping_sub(self.cursor.x);
+        let dy = coord.y.wrapping_sub(self.cursor.y);
+        self.commands.push(((dx << 1) ^ (dx >> 31)) as u32);
+        self.commands.push(((dy << 1) ^ (dy >> 31)) as u32);
+        self.cursor = coord;
+    }
+
+    fn path(&mut self, coords: &[Coord<i32>]) {
+        self.command(MOVE_TO, 1);
+        self.coord(coords[0]);
+        self.command(LINE_TO, coords.len() - 1);
+        for &coord in &coords[1..] {
+            self.coord(coord);
+        }
+    }
+}
+
+/// Encode a geometry which is already in tile coordinates, without clipping it.
+///
+/// Like [`encode_mvt_geometry`], degenerate parts are dropped, polygon rings are oriented as the
+/// specification requires, and `None` is returned if nothing is left or for a
+/// [`GeometryCollection`](crate::GeometryCollection).
+pub fn encode_mvt_commands(geometry: &Geometry<i32>) -> Option<MvtGeometry> {
+    let mut writer = CommandWriter {
+        commands: Vec::new(),
+        cursor: Coord { x: 0, y: 0 },
+    };
+    let geometry_type = match geometry {
+        Geometry::Point(point) => {
+            writer.command(MOVE_TO, 1);
+            writer.coord(point.0);
+            MvtGeometryType::Point
+        }
+        Geometry::MultiPoint(multi_point) => {
+            if multi_point.0.is_empty() {
+                return None;
+            }
+            writer.command(MOVE_TO, multi_point.0.len());
+            for point in multi_point {
+                writer.coord(point.0);
+            }
+            MvtGeometryType::Point
+        }
+        Geometry::Line(line) => {
+            writer.path(&[line.start, line.end]);
+            MvtGeometryType::LineString
+        }
+        Geometry::LineString(line_string) => {
+            return encode_mvt_commands(&MultiLineString::new(vec![line_string.clone()]).into())
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string {
+                let mut coords = line_string.0.clone();
+                coords.dedup();
+                if coords.len() >= 2 {
+                    writer.path(&coords);
+                }
+            }
+            MvtGeometryType::LineString
+        }
+        Geometry::Polygon(polygon) => {
+            return encode_mvt_commands(&MultiPolygon::new(vec![polygon.clone()]).into())
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon {
+                let Some(exterior) = oriented_ring(polygon.exterior(), 1) else {
+                    continue;
+                };
+                let interiors = polygon
+                    .interiors()
+                    .iter()
+                    .filter_map(|ring| oriented_ring(ring, -1));
+                for ring in std::iter::once(exterior).chain(interiors) {
+                    writer.path(&ring);
+                    writer.command(CLOSE_PATH, 1);
+                }
+            }
+            MvtGeometryType::Polygon
+        }
+        Geometry::Rect(rect) => return encode_mvt_commands(&rect.to_polygon().into()),
+        Geometry::Triangle(triangle) => return encode_mvt_commands(&triangle.to_polygon().into()),
+        Geometry::GeometryCollection(_) => return None,
+    };
+    if writer.commands.is_empty() {
+        return None;
+    }
+    Some(MvtGeometry {
+        geometry_type,
+        commands: writer.commands,
+    })
+}
+
+struct CommandReader<'a> {
+    commands: &'a [u32],
+    pos: usize,
+    cursor: Coord<i32>,
+}
+
+impl CommandReader<'_> {
+    /// Reads the next command, returning its index, id and count.
+    fn command(&mut self) -> Option<(usize, u32, usize)> {
+        let &command = self.commands.get(self.pos)?;
+        self.pos += 1;
+        Some((self.pos - 1, command & 0x7, (command >> 3) as usize))
+    }
+
+    fn expect(&mut self, id: u32) -> Result<usize, MvtError> {
+        match self.command() {
+            Some((_, found, count)) if found == id => Ok(count),
+            Some((index, _, _)) => Err(MvtError::InvalidCommand(index)),
+            None => Err(MvtError::UnexpectedEnd),
+        }
+    }
+
+    fn param(&mut self) -> Result<i32, MvtError> {
+        let &value = self.commands.get(self.pos).ok_or(MvtError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok((value >> 1) as i32 ^ -((value & 1) as i32))
+    }
+
+    fn coord(&mut self) -> Result<Coord<i32>, MvtError> {
+        self.cursor.x = self.cursor.x.wrapping_add(self.param()?);
+        self.cursor.y = self.cursor.y.wrapping_add(self.param()?);
+        Ok(self.cursor)
+    }
+
+    /// Reads a MoveTo command followed by a LineTo command.
+    fn path(&mut self, min_line_to: usize) -> Result<Vec<Coord<i32>>, MvtError> {
+        let mut coords = vec![self.coord()?];
+        let index = self.pos;
+        let count = self.expect(LINE_TO)?;
+        if count < min_line_to {
+            return Err(MvtError::InvalidCommand(index));
+        }
+        for _ in 0..count {
+            coords.push(self.coord()?);
+        }
+        Ok(coords)
+    }
+}
+
+/// Decode the geometry of a vector tile feature into tile coordinates.
+///
+/// Single points, line strings and polygons are returned as [`Point`], [`LineString`] and
+/// [`Polygon`], and several as the corresponding multi-geometry. Polygon rings are grouped into
+/// polygons by their orientation: each exterior ring starts a new polygon.
+pub fn decode_mvt_geometry(
+    geometry_type: MvtGeometryType,
+    commands: &[u32],
+) -> Result<Geometry<i32>, MvtError> {
+    let mut reader = CommandReader {
+        commands,
+        pos: 0,
+        cursor: Coord { x: 0, y: 0 },
+    };
+    match geometry_type {
+        MvtGeometryType::Point => {
+            let mut points = Vec::new();
+            while let Some((index, id, count)) = reader.command() {
+                if id != MOVE_TO || count == 0 {
+                    return Err(MvtError::InvalidCommand(index));
+                }
+                for _ in 0..count {
+                    points.push(Point(reader.coord()?));
+                }
+            }
+            match points.len() {
+                0 => Err(MvtError::Empty),
+                1 => Ok(points[0].into()),
+                _ => Ok(MultiPoint::new(points).into()),
+            }
+        }
+        MvtGeometryType::LineString => {
+            let mut line_strings = Vec::new();
+            while let Some((index, id, count)) = reader.command() {
+                if id != MOVE_TO || count != 1 {
+                    return Err(MvtError::InvalidCommand(index));
+                }
+                line_strings.push(LineString::new(reader.path(1)?));
+            }
+            match line_strings.len() {
+                0 => Err(MvtError::Empty),
+                1 => Ok(line_strings.remove(0).into()),
+                _ => Ok(MultiLineString::new(line_strings).into()),
+            }
+        }
+        MvtGeometryType::Polygon => {
+            let mut polygons: Vec<Polygon<i32>> = Vec::new();
+            while let Some((index, id, count)) = reader.command() {
+                if id != MOVE_TO || count != 1 {
+                    return Err(MvtError::InvalidCommand(index));
+                }
+                let mut ring = reader.path(2)?;
+                let close_index = reader.pos;
+                if reader.expect(CLOSE_PATH)? != 1 {
+                    return Err(MvtError::InvalidCommand(close_index));
+                }
+                ring.push(ring[0]);
+                let area = twice_area(&ring);
+                match polygons.last_mut() {
+                    _ if area == 0 => {}
+                    Some(polygon) if area < 0 => polygon.interiors_push(ring),
+                    _ => polygons.push(Polygon::new(LineString::new(ring), vec![])),
+                }
+            }
+            match polygons.len() {
+                0 => Err(MvtError::Empty),
+                1 => Ok(polygons.remove(0).into()),
+                _ => Ok(MultiPolygon::new(polygons).into()),
+            }
+        }
+    }
+}
+
+/// Convert a decoded geometry from the coordinates of `tile` to longitude/latitude.
+pub fn tile_to_wgs84(geometry: &Geometry<i32>, tile: TileId, extent: u32) -> Geometry {
+    let extent = f64::from(extent);
+    geometry.map_coords(|coord| {
+        tile.to_lon_lat(
+            Coord {
+                x: coord.x.into(),
+                y: coord.y.into(),
+            },
+            extent,
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    // Examples are from section 4.3.5 of the specification.
+
+    #[test]
+    fn spec_point() {
+        let point: Geometry<i32> = point!(x: 25, y: 17).into();
+        let encoded = encode_mvt_commands(&point).unwrap();
+        assert_eq!(encoded.commands, [9, 50, 34]);
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Point, &encoded.commands).unwrap(),
+            point
+        );
+
+        let multi_point: Geometry<i32> = wkt!(MULTIPOINT(5 7,3 2)).into();
+        assert_eq!(
+            encode_mvt_commands(&multi_point).unwrap().commands,
+            [17, 10, 14, 3, 9]
+        );
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Point, &[17, 10, 14, 3, 9]).unwrap(),
+            multi_point
+        );
+    }
+
+    #[test]
+    fn spec_line_string() {
+        let line_string: Geometry<i32> = wkt!(LINESTRING(2 2,2 10,10 10)).into();
+        let encoded = encode_mvt_commands(&line_string).unwrap();
+        assert_eq!(encoded.geometry_type, MvtGeometryType::LineString);
+        assert_eq!(encoded.commands, [9, 4, 4, 18, 0, 16, 16, 0]);
+
+        let multi_line_string: Geometry<i32> =
+            wkt!(MULTILINESTRING((2 2,2 10,10 10),(1 1,3 5))).into();
+        let commands = [9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8];
+        assert_eq!(
+            encode_mvt_commands(&multi_line_string).unwrap().commands,
+            commands
+        );
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::LineString, &commands).unwrap(),
+            multi_line_string
+        );
+    }
+
+    #[test]
+    fn spec_polygon() {
+        let polygon: Geometry<i32> = wkt!(POLYGON((3 6,8 12,20 34,3 6))).into();
+        let commands = [9, 6, 12, 18, 10, 12, 24, 44, 15];
+        assert_eq!(encode_mvt_commands(&polygon).unwrap().commands, commands);
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Polygon, &commands).unwrap(),
+            polygon
+        );
+    }
+
+    #[test]
+    fn spec_multi_polygon() {
+        let multi_polygon: Geometry<i32> = wkt!(MULTIPOLYGON(
+            ((0 0,10 0,10 10,0 10,0 0)),
+            ((11 11,20 11,20 20,11 20,11 11),(13 13,13 17,17 17,17 13,13 13))
+        ))
+        .into();
+        let commands = [
+            9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4, 13,
+            26, 0, 8, 8, 0, 0, 7, 15,
+        ];
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Polygon, &commands).unwrap(),
+            multi_polygon
+        );
+        assert_eq!(
+            encode_mvt_commands(&multi_polygon).unwrap().commands,
+            commands
+        );
+    }
+
+    #[test]
+    fn orients_rings() {
+        // counter-clockwise in tile coordinates, so it must be reversed
+        let polygon: Geometry<i32> = wkt!(POLYGON((3 6,20 34,8 12,3 6))).into();
+        assert_eq!(
+            encode_mvt_commands(&polygon).unwrap().commands,
+            [9, 6, 12, 18, 10, 12, 24, 44, 15]
+        );
+    }
+
+    #[test]
+    fn drops_degenerate_parts() {
+        let collapsed: Geometry<i32> = wkt!(POLYGON((0 0,1 1,2 2,0 0))).into();
+        assert_eq!(encode_mvt_commands(&collapsed), None);
+        let line_string: Geometry<i32> = wkt!(LINESTRING(1 1,1 1)).into();
+        assert_eq!(encode_mvt_commands(&line_string), None);
+    }
+
+    #[test]
+    fn clip_to_buffer() {
+        let tile = TileId::new(0, 0, 0);
+        let options = MvtOptions {
+            extent: 256,
+            buffer: 8,
+        };
+        // crosses the whole world and beyond, so it's clipped to the buffer on both sides
+        let line_string: Geometry = wkt!(LINESTRING(-200. 0.,200. 0.)).into();
+        let encoded = encode_mvt_geometry(&line_string, tile, &options).unwrap();
+        assert_eq!(
+            decode_mvt_geometry(encoded.geometry_type, &encoded.commands).unwrap(),
+            wkt!(LINESTRING(-8 128,264 128)).into()
+        );
+
+        // outside the tile and its buffer
+        let point: Geometry = point!(x: 0., y: 89.).into();
+        let encoded = encode_mvt_geometry(&point, TileId::new(1, 0, 1), &options);
+        assert_eq!(encoded, None);
+    }
+
+    #[test]
+    fn polygon_round_trip() {
+        let tile = TileId::new(0, 0, 0);
+        let options = MvtOptions::default();
+        let polygon: Geometry =
+            wkt!(POLYGON((-10. -10.,10. -10.,10. 10.,-10. 10.,-10. -10.))).into();
+        let encoded = encode_mvt_geometry(&polygon, tile, &options).unwrap();
+        assert_eq!(encoded.geometry_type, MvtGeometryType::Polygon);
+        let Geometry::Polygon(decoded) = tile_to_wgs84(
+            &decode_mvt_geometry(encoded.geometry_type, &encoded.commands).unwrap(),
+            tile,
+            options.extent,
+        ) else {
+            panic!("expected a polygon");
+        };
+        let rect = crate::BoundingRect::bounding_rect(&decoded).unwrap();
+        assert_relative_eq!(rect.min(), Coord { x: -10., y: -10. }, epsilon = 0.1);
+        assert_relative_eq!(rect.max(), Coord { x: 10., y: 10. }, epsilon = 0.1);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Point, &[]),
+            Err(MvtError::Empty)
+        );
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Point, &[9, 50]),
+            Err(MvtError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::LineString, &[9, 4, 4, 9, 0, 16]),
+            Err(MvtError::InvalidCommand(3))
+        );
+        assert_eq!(
+            decode_mvt_geometry(MvtGeometryType::Polygon, &[9, 6, 12, 18, 10, 12, 24, 44]),
+            Err(MvtError::UnexpectedEnd)
+        );
+    }
+}
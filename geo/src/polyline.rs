@@ -0,0 +1,219 @@
+//! Encode and decode line strings as Google [encoded polylines].
+//!
+//! Encoded polylines are used by many routing and mapping APIs. Each coordinate is rounded to
+//! `precision` decimal places (usually 5, or 6 for e.g. OSRM and Valhalla) and stored as the
+//! difference from the previous one, using printable ASCII characters.
+//!
+//! Polylines store latitude before longitude, i.e. `y` before `x`; this module takes care of the
+//! swap, so line strings are in the usual `x`/`y` (longitude/latitude) order.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::polyline::{decode_polyline, encode_polyline};
+//! use geo::wkt;
+//!
+//! let line_string = wkt!(LINESTRING(-120.2 38.5,-120.95 40.7,-126.453 43.252));
+//! let polyline = encode_polyline(&line_string, 5);
+//! assert_eq!(polyline, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//!
+//! approx::assert_relative_eq!(decode_polyline(&polyline, 5).unwrap(), line_string);
+//! ```
+//!
+//! [encoded polylines]: https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+
+use std::fmt;
+
+use crate::{Coord, LineString, MultiLineString};
+
+/// An error returned when decoding an invalid polyline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolylineError {
+    /// The character at this byte index isn't valid in a polyline.
+    InvalidCharacter(usize),
+    /// The polyline ended in the middle of a coordinate.
+    UnexpectedEnd,
+    /// A value was too long to fit in 64 bits, starting at this byte index.
+    Overflow(usize),
+}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolylineError::InvalidCharacter(index) => {
+                write!(f, "invalid polyline character at index {index}")
+            }
+            PolylineError::UnexpectedEnd => {
+                write!(f, "polyline ended in the middle of a coordinate")
+            }
+            PolylineError::Overflow(index) => {
+                write!(f, "polyline value at index {index} is out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolylineError {}
+
+/// Encode a line string as a polyline, rounding coordinates to `precision` decimal places.
+pub fn encode_polyline(line_string: &LineString, precision: u32) -> String {
+    let scale = 10f64.powi(precision as i32);
+    let mut polyline = String::new();
+    let mut prev = [0, 0];
+    for coord in line_string {
+        let scaled = [
+            (coord.y * scale).round() as i64,
+            (coord.x * scale).round() as i64,
+        ];
+        for (value, prev) in scaled.into_iter().zip(prev) {
+            encode_value(&mut polyline, value - prev);
+        }
+        prev = scaled;
+    }
+    polyline
+}
+
+/// Decode a polyline whose coordinates were rounded to `precision` decimal places.
+pub fn decode_polyline(polyline: &str, precision: u32) -> Result<LineString, PolylineError> {
+    let scale = 10f64.powi(precision as i32);
+    let bytes = polyline.as_bytes();
+    let mut pos = 0;
+    let mut prev = [0i64, 0];
+    let mut coords = Vec::new();
+    while pos < bytes.len() {
+        for value in &mut prev {
+            let delta = decode_value(bytes, &mut pos)?;
+            *value = value
+                .checked_add(delta)
+                .ok_or(PolylineError::Overflow(pos))?;
+        }
+        coords.push(Coord {
+            x: prev[1] as f64 / scale,
+            y: prev[0] as f64 / scale,
+        });
+    }
+    Ok(LineString::new(coords))
+}
+
+/// Encode each line string of a multi line string as a separate polyline.
+pub fn encode_polylines(multi_line_string: &MultiLineString, precision: u32) -> Vec<String> {
+    multi_line_string
+        .iter()
+        .map(|line_string| encode_polyline(line_string, precision))
+        .collect()
+}
+
+/// Decode a batch of polylines, e.g. the legs of a route, into a multi line string.
+pub fn decode_polylines<'a>(
+    polylines: impl IntoIterator<Item = &'a str>,
+    precision: u32,
+) -> Result<MultiLineString, PolylineError> {
+    polylines
+        .into_iter()
+        .map(|polyline| decode_polyline(polyline, precision))
+        .collect::<Result<_, _>>()
+        .map(MultiLineString::new)
+}
+
+fn encode_value(polyline: &mut String, value: i64) {
+    let mut value = if value < 0 {
+        !((value as u64) << 1)
+    } else {
+        (value as u64) << 1
+    };
+    while value >= 0x20 {
+        polyline.push(char::from((0x20 | (value & 0x1f) as u8) + 63));
+        value >>= 5;
+    }
+    polyline.push(char::from(value as u8 + 63));
+}
+
+fn decode_value(bytes: &[u8], pos: &mut usize) -> Result<i64, PolylineError> {
+    let start = *pos;
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*pos).ok_or(PolylineError::UnexpectedEnd)?;
+        if !(63..=126).contains(&byte) {
+            return Err(PolylineError::InvalidCharacter(*pos));
+        }
+        if shift >= 64 {
+            return Err(PolylineError::Overflow(start));
+        }
+        let chunk = u64::from(byte - 63);
+        value |= (chunk & 0x1f) << shift;
+        shift += 5;
+        *pos += 1;
+        if chunk < 0x20 {
+            break;
+        }
+    }
+    Ok(if value & 1 == 1 {
+        !(value >> 1) as i64
+    } else {
+        (value >> 1) as i64
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn precision_6() {
+        let line_string =
+            wkt!(LINESTRING(13.388860 52.517037,13.397634 52.529407,13.428555 52.523219));
+        let polyline = encode_polyline(&line_string, 6);
+        assert_eq!(decode_polyline(&polyline, 6).unwrap(), line_string);
+        // decoding at the wrong precision scales the coordinates
+        let wrong = decode_polyline(&polyline, 5).unwrap();
+        assert_relative_eq!(wrong.0[0].x, 133.8886);
+    }
+
+    #[test]
+    fn rounding() {
+        let line_string = wkt!(LINESTRING(0.000004 -0.000006,0.00001 0.));
+        assert_eq!(
+            decode_polyline(&encode_polyline(&line_string, 5), 5).unwrap(),
+            wkt!(LINESTRING(0. -0.00001,0.00001 0.))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(encode_polyline(&LineString::new(vec![]), 5), "");
+        assert_eq!(decode_polyline("", 5).unwrap(), LineString::new(vec![]));
+    }
+
+    #[test]
+    fn multi_line_string() {
+        let multi_line_string = wkt!(MULTILINESTRING((-120.2 38.5,-120.95 40.7),(1. 2.,3. 4.)));
+        let polylines = encode_polylines(&multi_line_string, 5);
+        assert_eq!(polylines[0], "_p~iF~ps|U_ulLnnqC");
+        assert_eq!(
+            decode_polylines(polylines.iter().map(String::as_str), 5).unwrap(),
+            multi_line_string
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            decode_polyline("_p~iF", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decode_polyline("_p~iF~ps|", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decode_polyline("_p iF~ps|U", 5),
+            Err(PolylineError::InvalidCharacter(2))
+        );
+        assert_eq!(
+            decode_polyline("~~~~~~~~~~~~~~?", 5),
+            Err(PolylineError::Overflow(0))
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::RasterGrid;
+use crate::coordinate_position::CoordPos;
+use crate::orient::Direction;
+use crate::{
+    coord, Area, BoundingRect, Contains, Coord, CoordinatePosition, LineString, MapCoords,
+    MultiLineString, MultiPolygon, Orient, Polygon, Simplify,
+};
+
+/// Options for [`RasterGrid::contour_lines`] and [`RasterGrid::contour_polygons`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContourOptions {
+    /// Simplify the results with the [Ramer–Douglas–Peucker](Simplify) algorithm, removing
+    /// vertices closer than this to the simplified lines, in the units of the raster. Zero keeps
+    /// every vertex. Polygons simplified with a tolerance larger than the pixels may become
+    /// invalid.
+    pub simplify: f64,
+}
+
+impl Default for ContourOptions {
+    /// No simplification.
+    fn default() -> Self {
+        Self { simplify: 0. }
+    }
+}
+
+/// An edge between two adjacent samples: the first one's column and row, and whether the second
+/// one is in the next row rather than the next column.
+type EdgeKey = (usize, usize, bool);
+
+impl RasterGrid {
+    /// The lines where the values of a raster cross `level`, computed with the [marching squares]
+    /// algorithm, e.g. the contour lines of an elevation model.
+    ///
+    /// The values are sampled at the centers of the pixels and interpolated linearly between
+    /// them, so the lines stay within the centers of the outer pixels. Each line has the values
+    /// at least `level` on its left in pixel coordinates, and is closed if it doesn't reach the
+    /// edge of the grid. `NaN` values are below every level.
+    ///
+    /// [marching squares]: https://en.wikipedia.org/wiki/Marching_squares
+    ///
+    /// # Panics
+    ///
+    /// If there isn't one value per pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::raster::{ContourOptions, RasterGrid};
+    /// use geo::{coord, Rect};
+    ///
+    /// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 3. });
+    /// let grid = RasterGrid::from_bounds(bounds, 3, 3);
+    /// #[rustfmt::skip]
+    /// let values = [
+    ///     0., 0., 0.,
+    ///     0., 4., 0.,
+    ///     0., 0., 0.,
+    /// ];
+    ///
+    /// let lines = grid.contour_lines(&values, 2., &ContourOptions::default());
+    /// // a diamond around the center of the middle pixel
+    /// assert_eq!(lines.0.len(), 1);
+    /// assert!(lines.0[0].is_closed());
+    /// assert_eq!(lines.0[0].0.len(), 5);
+    /// ```
+    pub fn contour_lines(
+        &self,
+        values: &[f64],
+        level: f64,
+        options: &ContourOptions,
+    ) -> MultiLineString {
+        let samples = Samples::new(self, values, level, false);
+        let lines = MultiLineString::new(
+            samples
+                .chains()
+                .into_iter()
+                .map(|chain| samples.to_world(self, chain))
+                .filter(|line_string| line_string.0.len() >= 2)
+                .collect(),
+        );
+        if options.simplify > 0. {
+            lines.simplify(&options.simplify)
+        } else {
+            lines
+        }
+    }
+
+    /// The regions where the values of a raster are at least `level`, e.g. to turn a grid of
+    /// flood depths into the polygons of the flooded area.
+    ///
+    /// The boundaries of the regions are the [contour lines](Self::contour_lines) at `level`,
+    /// closed along the centers of the outer pixels of the grid. Exteriors are counter-clockwise
+    /// and holes clockwise. `NaN` values are outside of every region.
+    ///
+    /// # Panics
+    ///
+    /// If there isn't one value per pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::raster::{ContourOptions, RasterGrid};
+    /// use geo::{coord, Area, Rect};
+    ///
+    /// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    /// let grid = RasterGrid::from_bounds(bounds, 4, 4);
+    /// #[rustfmt::skip]
+    /// let depths = [
+    ///     1., 1., 1., 1.,
+    ///     1., 0., 0., 1.,
+    ///     1., 0., 0., 1.,
+    ///     1., 1., 1., 1.,
+    /// ];
+    ///
+    /// let flooded = grid.contour_polygons(&depths, 0.5, &ContourOptions::default());
+    /// assert_eq!(flooded.0.len(), 1);
+    /// // the centers of the outer pixels, around an octagon halfway to the dry pixels
+    /// assert_eq!(flooded.0[0].interiors().len(), 1);
+    /// assert_eq!(flooded.unsigned_area(), 9. - 3.5);
+    /// ```
+    pub fn contour_polygons(
+        &self,
+        values: &[f64],
+        level: f64,
+        options: &ContourOptions,
+    ) -> MultiPolygon {
+        let samples = Samples::new(self, values, level, true);
+        let mut exteriors = vec![];
+        let mut holes = vec![];
+        for chain in samples.chains() {
+            let mut ring: LineString = chain.iter().map(|&key| samples.crossing(key)).collect();
+            ring.0.dedup();
+            let polygon = Polygon::new(ring, vec![]);
+            let area = polygon.signed_area();
+            if polygon.exterior().0.len() < 4 || area == 0. {
+                continue;
+            }
+            if area > 0. {
+                exteriors.push((area, polygon));
+            } else {
+                holes.push(polygon.into_inner().0);
+            }
+        }
+        // the holes of a region are inside its exterior, and regions can be nested in holes
+        exteriors.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for hole in holes {
+            let (Some(bounds), Some(&vertex)) = (hole.bounding_rect(), hole.0.first()) else {
+                continue;
+            };
+            let exterior = exteriors.iter_mut().find(|(_, exterior)| {
+                exterior
+                    .bounding_rect()
+                    .is_some_and(|rect| rect.contains(&bounds))
+                    && exterior.coordinate_position(&vertex) != CoordPos::Outside
+            });
+            if let Some((_, exterior)) = exterior {
+                exterior.interiors_push(hole);
+            }
+        }
+        let polygons = MultiPolygon::new(
+            exteriors
+                .into_iter()
+                .map(|(_, polygon)| polygon.map_coords(|c| samples.pixel_to_world(self, c)))
+                .collect(),
+        );
+        let polygons = if options.simplify > 0. {
+            polygons.simplify(&options.simplify)
+        } else {
+            polygons
+        };
+        polygons.orient(Direction::Default)
+    }
+}
+
+/// The values of a raster, compared to a contour level.
+struct Samples<'a> {
+    values: &'a [f64],
+    level: f64,
+    /// The number of columns and rows of samples.
+    width: usize,
+    height: usize,
+    /// Whether there's an extra row or column of samples below the level around the grid.
+    padded: bool,
+}
+
+impl<'a> Samples<'a> {
+    fn new(grid: &RasterGrid, values: &'a [f64], level: f64, padded: bool) -> Self {
+        assert_eq!(
+            values.len(),
+            grid.len(),
+            "there must be one value per pixel"
+        );
+        let padding = if padded { 2 } else { 0 };
+        Self {
+            values,
+            level,
+            width: grid.width + padding,
+            height: grid.height + padding,
+            padded,
+        }
+    }
+
+    /// The value of a sample, where `NaN` and padding are negative infinity.
+    fn value(&self, col: usize, row: usize) -> f64 {
+        let (col, row, width, height) = if self.padded {
+            if col == 0 || row == 0 || col == self.width - 1 || row == self.height - 1 {
+                return f64::NEG_INFINITY;
+            }
+            (col - 1, row - 1, self.width - 2, self.height - 2)
+        } else {
+            (col, row, self.width, self.height)
+        };
+        debug_assert!(col < width && row < height);
+        let value = self.values[row * width + col];
+        if value.is_nan() {
+            f64::NEG_INFINITY
+        } else {
+            value
+        }
+    }
+
+    fn above(&self, col: usize, row: usize) -> bool {
+        self.value(col, row) >= self.level
+    }
+
+    /// Where the contour crosses an edge between a sample above the level and one below it, in
+    /// sample coordinates.
+    fn crossing(&self, (col, row, vertical): EdgeKey) -> Coord {
+        let (next_col, next_row) = if vertical {
+            (col, row + 1)
+        } else {
+            (col + 1, row)
+        };
+        let (a, b) = (self.value(col, row), self.value(next_col, next_row));
+        let t = if a == f64::NEG_INFINITY {
+            1.
+        } else if b == f64::NEG_INFINITY {
+            0.
+        } else {
+            (self.level - a) / (b - a)
+        };
+        coord! {
+            x: col as f64 + t * (next_col - col) as f64,
+            y: row as f64 + t * (next_row - row) as f64,
+        }
+    }
+
+    /// Converts sample coordinates to the coordinates of the raster.
+    fn pixel_to_world(&self, grid: &RasterGrid, coord: Coord) -> Coord {
+        let offset = if self.padded { -0.5 } else { 0.5 };
+        grid.transform.apply(coord! {
+            x: coord.x + offset,
+            y: coord.y + offset,
+        })
+    }
+
+    fn to_world(&self, grid: &RasterGrid, chain: Vec<EdgeKey>) -> LineString {
+        let mut coords: Vec<Coord> = chain
+            .into_iter()
+            .map(|key| self.pixel_to_world(grid, self.crossing(key)))
+            .collect();
+        coords.dedup();
+        LineString::new(coords)
+    }
+
+    /// The contours, as the edges they cross, from the samples below the level to those above.
+    ///
+    /// Each cell between four samples has a segment of a contour from each edge where the
+    /// samples go from above to below the level, counter-clockwise around the cell, to an edge
+    /// where they go from below to above, so that the samples above the level are on the left.
+    /// The segments are then joined into chains at the edges they share.
+    fn chains(&self) -> Vec<Vec<EdgeKey>> {
+        let mut next: BTreeMap<EdgeKey, EdgeKey> = BTreeMap::new();
+        for row in 0..self.height.saturating_sub(1) {
+            for col in 0..self.width.saturating_sub(1) {
+                let corners = [
+                    (col, row),
+                    (col + 1, row),
+                    (col + 1, row + 1),
+                    (col, row + 1),
+                ];
+                let above = corners.map(|(col, row)| self.above(col, row));
+                let edges = [
+                    (col, row, false),
+                    (col + 1, row, true),
+                    (col, row + 1, false),
+                    (col, row, true),
+                ];
+                // the crossed edges counter-clockwise, and whether the contour exits the
+                // region above the level through them
+                let crossings: Vec<(EdgeKey, bool)> = (0..4)
+                    .filter(|&i| above[i] != above[(i + 1) % 4])
+                    .map(|i| (edges[i], above[i]))
+                    .collect();
+                // at a saddle, the mean of the corners decides whether the corners above the
+                // level are connected, or those below it
+                let center_above = crossings.len() == 4 && {
+                    let sum: f64 = corners.iter().map(|&(col, row)| self.value(col, row)).sum();
+                    sum / 4. >= self.level
+                };
+                let n = crossings.len();
+                for (i, &(edge, exits)) in crossings.iter().enumerate() {
+                    if exits {
+                        let entry = if center_above {
+                            crossings[(i + 1) % n].0
+                        } else {
+                            crossings[(i + n - 1) % n].0
+                        };
+                        next.insert(edge, entry);
+                    }
+                }
+            }
+        }
+
+        let mut chains = vec![];
+        // open chains start at the edge of the grid
+        let ends: BTreeSet<EdgeKey> = next.values().copied().collect();
+        let starts: Vec<EdgeKey> = next
+            .keys()
+            .filter(|key| !ends.contains(key))
+            .copied()
+            .collect();
+        for start in starts {
+            let mut chain = vec![start];
+            let mut key = start;
+            while let Some(following) = next.remove(&key) {
+                chain.push(following);
+                key = following;
+            }
+            chains.push(chain);
+        }
+        // the remaining chains are closed
+        while let Some((&start, _)) = next.first_key_value() {
+            let mut chain = vec![start];
+            let mut key = start;
+            while let Some(following) = next.remove(&key) {
+                chain.push(following);
+                key = following;
+            }
+            chains.push(chain);
+        }
+        chains
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Rect, Relate};
+
+    fn grid(width: usize, height: usize) -> RasterGrid {
+        let bounds = Rect::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: width as f64, y: height as f64 },
+        );
+        RasterGrid::from_bounds(bounds, width, height)
+    }
+
+    #[test]
+    fn open_lines() {
+        let grid = grid(4, 2);
+        #[rustfmt::skip]
+        let values = [
+            0., 1., 2., 3.,
+            0., 1., 2., 3.,
+        ];
+        let lines = grid.contour_lines(&values, 1.5, &ContourOptions::default());
+        // a vertical line between the second and third columns, between the centers of the
+        // pixels of the first and second rows
+        assert_eq!(
+            lines,
+            MultiLineString::new(vec![wkt!(LINESTRING(2. 0.5,2. 1.5))])
+        );
+        assert!(grid
+            .contour_lines(&values, 10., &ContourOptions::default())
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn saddle() {
+        let grid = grid(2, 2);
+        #[rustfmt::skip]
+        let values = [
+            1., 0.,
+            0., 1.,
+        ];
+        // the mean is above the level, so the high corners are connected, and the contour
+        // lines cut off the low corners
+        let lines = grid.contour_lines(&values, 0.4, &ContourOptions::default());
+        assert_eq!(lines.0.len(), 2);
+        assert!(lines.iter().all(|line| line.0.len() == 2));
+        let polygons = grid.contour_polygons(&values, 0.4, &ContourOptions::default());
+        assert_eq!(polygons.0.len(), 1);
+        let polygons = grid.contour_polygons(&values, 0.6, &ContourOptions::default());
+        assert_eq!(polygons.0.len(), 2);
+    }
+
+    #[test]
+    fn nested_regions() {
+        let grid = grid(7, 7);
+        #[rustfmt::skip]
+        let values = [
+            1., 1., 1., 1., 1., 1., 1.,
+            1., 0., 0., 0., 0., 0., 1.,
+            1., 0., 1., 1., 1., 0., 1.,
+            1., 0., 1., f64::NAN, 1., 0., 1.,
+            1., 0., 1., 1., 1., 0., 1.,
+            1., 0., 0., 0., 0., 0., 1.,
+            1., 1., 1., 1., 1., 1., 1.,
+        ];
+        let polygons = grid.contour_polygons(&values, 0.5, &ContourOptions::default());
+        assert_eq!(polygons.0.len(), 2);
+        let width = |polygon: &&Polygon| polygon.bounding_rect().unwrap().width();
+        let outer = polygons.iter().find(|p| width(p) > 5.).unwrap();
+        let inner = polygons.iter().find(|p| width(p) < 5.).unwrap();
+        assert_eq!(outer.interiors().len(), 1);
+        assert_eq!(inner.interiors().len(), 1);
+        // the inner ring is in the hole of the outer one
+        assert!(Polygon::new(outer.interiors()[0].clone(), vec![]).contains(inner));
+        assert!(!outer.relate(inner).is_intersects());
+    }
+
+    #[test]
+    fn simplify() {
+        let grid = grid(20, 20);
+        let values: Vec<f64> = (0..400)
+            .map(|i| {
+                let (x, y) = ((i % 20) as f64 - 9.5, (i / 20) as f64 - 9.5);
+                100. - (x * x + y * y)
+            })
+            .collect();
+        let circle = grid.contour_polygons(&values, 50., &ContourOptions::default());
+        let simplified = grid.contour_polygons(&values, 50., &ContourOptions { simplify: 0.5 });
+        assert_eq!(circle.0.len(), 1);
+        assert_eq!(simplified.0.len(), 1);
+        assert!(simplified.0[0].exterior().0.len() < circle.0[0].exterior().0.len());
+        assert_relative_eq!(
+            circle.unsigned_area(),
+            std::f64::consts::PI * 50.,
+            max_relative = 0.02
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{coord, AffineOps, AffineTransform, BoundingRect, Coord, Rect};
+
+/// The pixels of a raster: `width` columns and `height` rows, placed by an affine transform from
+/// pixel coordinates to the coordinates of the raster's geometries.
+///
+/// In pixel coordinates, the pixel in column `col` and row `row` covers `col..col + 1` along `x`
+/// and `row..row + 1` along `y`. Its value is at index `row * width + col` of the raster's
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::raster::RasterGrid;
+/// use geo::{coord, Rect};
+///
+/// // 100 × 50 pixels covering the world, with the first row in the north
+/// let extent = Rect::new(coord! { x: -180., y: -90. }, coord! { x: 180., y: 90. });
+/// let grid = RasterGrid::from_bounds(extent, 100, 50);
+///
+/// assert_eq!(grid.pixel_center(0, 0), coord! { x: -178.2, y: 88.2 });
+/// assert_eq!(grid.pixel_at(coord! { x: 0.1, y: -0.1 }), Some((50, 25)));
+/// assert_eq!(grid.pixel_at(coord! { x: 0., y: 91. }), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterGrid {
+    /// The number of columns.
+    pub width: usize,
+    /// The number of rows.
+    pub height: usize,
+    /// The transform from pixel coordinates to the raster's coordinates. It must be invertible.
+    pub transform: AffineTransform,
+}
+
+impl RasterGrid {
+    /// A grid of `width` by `height` pixels placed by `transform`.
+    pub fn new(width: usize, height: usize, transform: AffineTransform) -> Self {
+        Self {
+            width,
+            height,
+            transform,
+        }
+    }
+
+    /// A grid placed by a [GDAL geotransform], `[x_origin, pixel_width, row_rotation, y_origin,
+    /// column_rotation, pixel_height]`, where `pixel_height` is usually negative.
+    ///
+    /// [GDAL geotransform]: https://gdal.org/en/stable/tutorials/geotransforms_tut.html
+    pub fn from_geotransform(width: usize, height: usize, geotransform: [f64; 6]) -> Self {
+        let [xoff, a, b, yoff, d, e] = geotransform;
+        Self::new(width, height, AffineTransform::new(a, b, xoff, d, e, yoff))
+    }
+
+    /// A grid of `width` by `height` pixels covering `bounds`, with the first row at the top,
+    /// i.e. at the maximum `y`, as in most image formats.
+    pub fn from_bounds(bounds: Rect, width: usize, height: usize) -> Self {
+        Self::new(
+            width,
+            height,
+            AffineTransform::new(
+                bounds.width() / width as f64,
+                0.,
+                bounds.min().x,
+                0.,
+                -bounds.height() / height as f64,
+                bounds.max().y,
+            ),
+        )
+    }
+
+    /// The [GDAL geotransform](Self::from_geotransform) of the grid.
+    pub fn geotransform(&self) -> [f64; 6] {
+        let t = &self.transform;
+        [t.xoff(), t.a(), t.b(), t.yoff(), t.d(), t.e()]
+    }
+
+    /// The number of pixels.
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Whether the grid has no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The center of the pixel in column `col` and row `row`.
+    pub fn pixel_center(&self, col: usize, row: usize) -> Coord {
+        self.transform.apply(coord! {
+            x: col as f64 + 0.5,
+            y: row as f64 + 0.5,
+        })
+    }
+
+    /// The column and row of the pixel containing `coord`, or `None` if it's outside of the grid,
+    /// NaN, or the transform can't be inverted.
+    pub fn pixel_at(&self, coord: Coord) -> Option<(usize, usize)> {
+        let pixel = self.transform.inverse()?.apply(coord);
+        // also rejects NaN, which would otherwise be cast to 0
+        if !(pixel.x >= 0. && pixel.y >= 0.) {
+            return None;
+        }
+        let (col, row) = (pixel.x as usize, pixel.y as usize);
+        (col < self.width && row < self.height).then_some((col, row))
+    }
+
+    /// The bounding rectangle of the grid.
+    pub fn bounds(&self) -> Rect {
+        let pixels = Rect::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: self.width as f64, y: self.height as f64 },
+        );
+        pixels
+            .to_polygon()
+            .affine_transform(&self.transform)
+            .bounding_rect()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn geotransform() {
+        let geotransform = [400_000., 10., 0., 500_000., 0., -10.];
+        let grid = RasterGrid::from_geotransform(20, 30, geotransform);
+        assert_eq!(grid.geotransform(), geotransform);
+        assert_eq!(grid.len(), 600);
+        assert_eq!(
+            grid.bounds(),
+            Rect::new(
+                coord! { x: 400_000., y: 499_700. },
+                coord! { x: 400_200., y: 500_000. }
+            )
+        );
+        assert_eq!(
+            grid.pixel_center(19, 29),
+            coord! { x: 400_195., y: 499_705. }
+        );
+        assert_eq!(
+            grid.pixel_at(coord! { x: 400_195., y: 499_705. }),
+            Some((19, 29))
+        );
+        assert_eq!(grid.pixel_at(coord! { x: 399_999., y: 499_705. }), None);
+        assert_eq!(grid.pixel_at(coord! { x: f64::NAN, y: 499_705. }), None);
+        assert_eq!(grid.pixel_at(coord! { x: 400_195., y: f64::NAN }), None);
+
+        // a rotated grid
+        let grid = RasterGrid::from_geotransform(2, 2, [0., 1., -1., 0., 1., 1.]);
+        assert_eq!(
+            grid.bounds(),
+            Rect::new(coord! { x: -2., y: 0. }, coord! { x: 2., y: 4. })
+        );
+        assert_eq!(grid.pixel_at(coord! { x: 0., y: 1. }), Some((0, 0)));
+    }
+}
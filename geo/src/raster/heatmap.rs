@@ -0,0 +1,155 @@
+use std::f64::consts::PI;
+
+use super::RasterGrid;
+use crate::{coord, AffineOps, BoundingRect, Point, Rect};
+
+/// The shape of the bump that each point adds to a [heatmap](RasterGrid::heatmap), as a function
+/// of the distance `d` from the point, relative to the radius `r`.
+///
+/// Each kernel is zero beyond the radius, and scaled so that its integral is one: the heatmap of
+/// points with a total weight of `w` integrates to `w`, and its values are densities per unit of
+/// area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DensityKernel {
+    /// A constant within the radius.
+    Uniform,
+    /// `1 - d / r`, a cone.
+    Triangular,
+    /// `1 - (d / r)²`, which minimizes the mean squared error of the density estimate.
+    Epanechnikov,
+    /// `(1 - (d / r)²)²`, a smooth bump, and the default of most GIS tools.
+    #[default]
+    Quartic,
+}
+
+impl DensityKernel {
+    /// The value of the kernel at `distance` from a point, for a radius of `radius`.
+    pub fn density(self, distance: f64, radius: f64) -> f64 {
+        if distance > radius {
+            return 0.;
+        }
+        let u = distance / radius;
+        let (shape, scale) = match self {
+            DensityKernel::Uniform => (1., 1.),
+            DensityKernel::Triangular => (1. - u, 3.),
+            DensityKernel::Epanechnikov => (1. - u * u, 2.),
+            DensityKernel::Quartic => ((1. - u * u) * (1. - u * u), 3.),
+        };
+        shape * scale / (PI * radius * radius)
+    }
+}
+
+impl RasterGrid {
+    /// A smoothed density surface of weighted points, by kernel density estimation: each point
+    /// adds a bump of its weight, spread within `radius` of it by `kernel`, to the pixels whose
+    /// center is within the radius.
+    ///
+    /// Returns the density at the center of each pixel, row by row. Set the weights to one for
+    /// the density of the points themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::raster::{DensityKernel, RasterGrid};
+    /// use geo::{coord, point, Rect};
+    ///
+    /// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+    /// let grid = RasterGrid::from_bounds(bounds, 100, 100);
+    ///
+    /// let points = [point!(x: 30., y: 30.), point!(x: 70.5, y: 60.5), point!(x: 72.5, y: 60.5)];
+    /// let density = grid.heatmap(points.map(|point| (point, 1.)), 10., DensityKernel::Quartic);
+    ///
+    /// // the hottest pixel is between the two close points
+    /// let hottest = (0..density.len())
+    ///     .max_by(|&a, &b| density[a].total_cmp(&density[b]))
+    ///     .unwrap();
+    /// assert_eq!((hottest % 100, hottest / 100), (71, 39));
+    /// // each unit square pixel has the density of its area
+    /// approx::assert_relative_eq!(density.iter().sum::<f64>(), 3., epsilon = 0.01);
+    /// ```
+    pub fn heatmap(
+        &self,
+        points: impl IntoIterator<Item = (Point, f64)>,
+        radius: f64,
+        kernel: DensityKernel,
+    ) -> Vec<f64> {
+        let mut values = vec![0.; self.len()];
+        let Some(to_pixels) = self.transform.inverse() else {
+            return values;
+        };
+        for (point, weight) in points {
+            // the pixels whose centers may be within the radius
+            let disk = Rect::new(
+                coord! { x: point.x() - radius, y: point.y() - radius },
+                coord! { x: point.x() + radius, y: point.y() + radius },
+            );
+            let pixels = disk
+                .to_polygon()
+                .affine_transform(&to_pixels)
+                .bounding_rect()
+                .unwrap();
+            let (min, max) = (pixels.min(), pixels.max());
+            let cols = (min.x - 0.5).ceil().max(0.) as usize
+                ..((max.x - 0.5).floor() + 1.).clamp(0., self.width as f64) as usize;
+            let rows = (min.y - 0.5).ceil().max(0.) as usize
+                ..((max.y - 0.5).floor() + 1.).clamp(0., self.height as f64) as usize;
+            for row in rows {
+                for col in cols.clone() {
+                    let offset = self.pixel_center(col, row) - point.0;
+                    let distance = offset.x.hypot(offset.y);
+                    values[row * self.width + col] += weight * kernel.density(distance, radius);
+                }
+            }
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn kernels_integrate_to_one() {
+        let step = 0.01;
+        for kernel in [
+            DensityKernel::Uniform,
+            DensityKernel::Triangular,
+            DensityKernel::Epanechnikov,
+            DensityKernel::Quartic,
+        ] {
+            // the integral over rings around the point
+            let integral: f64 = (0..200)
+                .map(|i| {
+                    let distance = (i as f64 + 0.5) * step;
+                    kernel.density(distance, 2.) * 2. * PI * distance * step
+                })
+                .sum();
+            assert_relative_eq!(integral, 1., epsilon = 1e-3);
+            assert_eq!(kernel.density(2.1, 2.), 0.);
+        }
+    }
+
+    #[test]
+    fn weights_and_edges() {
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. });
+        let grid = RasterGrid::from_bounds(bounds, 20, 20);
+        let points = [(point!(x: 5., y: 5.), 2.), (point!(x: 0., y: 0.), 1.)];
+        let density = grid.heatmap(points, 2., DensityKernel::Epanechnikov);
+        let pixel_area = 0.25;
+        let total: f64 = density.iter().sum::<f64>() * pixel_area;
+        // all of the first point's weight, and about a quarter of the second one's, which is in
+        // the corner of the grid
+        assert_relative_eq!(total, 2. + 0.25, epsilon = 0.05);
+        let (col, row) = grid.pixel_at(coord! { x: 5.1, y: 5.1 }).unwrap();
+        assert_relative_eq!(
+            density[row * grid.width + col],
+            2. * DensityKernel::Epanechnikov.density(0.25f64.hypot(0.25), 2.),
+            epsilon = 1e-9
+        );
+        // far away points don't matter
+        let far = grid.heatmap([(point!(x: 1000., y: 5.), 1.)], 2., DensityKernel::Uniform);
+        assert!(far.iter().all(|&value| value == 0.));
+    }
+}
@@ -0,0 +1,21 @@
+//! Grids of values covering an area, such as elevation models or masks, and conversions between
+//! them and vector geometries.
+//!
+//! A [`RasterGrid`] describes the pixels of a raster: how many there are, and where they are. The
+//! values of a raster are stored separately, row by row from the first row of the grid, as a
+//! slice of `width * height` values.
+
+mod grid;
+pub use grid::RasterGrid;
+
+mod rasterize;
+pub use rasterize::RasterizeOptions;
+
+mod contour;
+pub use contour::ContourOptions;
+
+mod heatmap;
+pub use heatmap::DensityKernel;
+
+mod zonal;
+pub use zonal::ZonalStats;
@@ -0,0 +1,336 @@
+use super::RasterGrid;
+use crate::{AffineOps, BoundingRect, Geometry, Line, LineString, Polygon};
+
+/// Options for [`RasterGrid::rasterize`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterizeOptions {
+    /// Burn every pixel which a polygon touches, instead of only those whose center is inside
+    /// it. Lines and points always burn every pixel they touch.
+    pub all_touched: bool,
+    /// The value of the pixels which no geometry burns.
+    pub fill: f64,
+}
+
+impl Default for RasterizeOptions {
+    /// Burn the pixels whose center is inside a polygon, and fill the others with zero.
+    fn default() -> Self {
+        Self {
+            all_touched: false,
+            fill: 0.,
+        }
+    }
+}
+
+impl RasterGrid {
+    /// Burn geometries into the pixels of the grid, each with its own value, e.g. to make a
+    /// mask, or to label the pixels of each zone for zonal statistics.
+    ///
+    /// Returns the values of the pixels, row by row. When geometries overlap, the value of the
+    /// last one is kept.
+    ///
+    /// A polygon burns the pixels whose center is inside it, or with
+    /// [`all_touched`](RasterizeOptions::all_touched), all the pixels it touches. A line burns
+    /// the pixels it passes through, and a point the pixel containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::raster::{RasterGrid, RasterizeOptions};
+    /// use geo::{coord, wkt, Rect};
+    ///
+    /// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 3. });
+    /// let grid = RasterGrid::from_bounds(bounds, 4, 3);
+    ///
+    /// let features = [
+    ///     (wkt!(POLYGON((0.2 0.2,2.8 0.2,2.8 2.2,0.2 2.2,0.2 0.2))).into(), 1.),
+    ///     (wkt!(POINT(3.5 2.5)).into(), 2.),
+    /// ];
+    /// let values = grid.rasterize(&features, &RasterizeOptions::default());
+    /// #[rustfmt::skip]
+    /// assert_eq!(values, [
+    ///     0., 0., 0., 2.,
+    ///     1., 1., 1., 0.,
+    ///     1., 1., 1., 0.,
+    /// ]);
+    ///
+    /// // the polygon touches the top row too
+    /// let options = RasterizeOptions { all_touched: true, ..Default::default() };
+    /// let values = grid.rasterize(&features[..1], &options);
+    /// assert_eq!(values.iter().filter(|&&value| value == 1.).count(), 9);
+    /// ```
+    pub fn rasterize<'a>(
+        &self,
+        features: impl IntoIterator<Item = &'a (Geometry, f64)>,
+        options: &RasterizeOptions,
+    ) -> Vec<f64> {
+        let mut values = vec![options.fill; self.len()];
+        let Some(to_pixels) = self.transform.inverse() else {
+            return values;
+        };
+        for (geometry, value) in features {
+            Burner::new(self, options.all_touched, |index| values[index] = *value)
+                .geometry(&geometry.affine_transform(&to_pixels));
+        }
+        values
+    }
+}
+
+/// Finds the pixels that geometries in pixel coordinates burn, and passes their index to `burn`.
+pub(super) struct Burner<F: FnMut(usize)> {
+    width: usize,
+    height: usize,
+    all_touched: bool,
+    burn: F,
+}
+
+impl<F: FnMut(usize)> Burner<F> {
+    pub(super) fn new(grid: &RasterGrid, all_touched: bool, burn: F) -> Self {
+        Self {
+            width: grid.width,
+            height: grid.height,
+            all_touched,
+            burn,
+        }
+    }
+
+    fn pixel(&mut self, col: i64, row: i64) {
+        if (0..self.width as i64).contains(&col) && (0..self.height as i64).contains(&row) {
+            (self.burn)(row as usize * self.width + col as usize);
+        }
+    }
+
+    pub(super) fn geometry(&mut self, geometry: &Geometry) {
+        match geometry {
+            Geometry::Point(point) => {
+                self.pixel(point.x().floor() as i64, point.y().floor() as i64)
+            }
+            Geometry::Line(line) => self.line(*line),
+            Geometry::LineString(line_string) => self.line_string(line_string),
+            Geometry::Polygon(polygon) => self.polygon(polygon),
+            Geometry::MultiPoint(multi_point) => {
+                for point in multi_point {
+                    self.pixel(point.x().floor() as i64, point.y().floor() as i64);
+                }
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string {
+                    self.line_string(line_string);
+                }
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon {
+                    self.polygon(polygon);
+                }
+            }
+            Geometry::GeometryCollection(collection) => {
+                for geometry in collection {
+                    self.geometry(geometry);
+                }
+            }
+            Geometry::Rect(rect) => self.polygon(&rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.polygon(&triangle.to_polygon()),
+        }
+    }
+
+    pub(super) fn line_string(&mut self, line_string: &LineString) {
+        for line in line_string.lines() {
+            self.line(line);
+        }
+    }
+
+    /// Burns the pixels that a line passes through, by walking from pixel to pixel along it.
+    fn line(&mut self, line: Line) {
+        let Some(line) = self.clip(line) else {
+            return;
+        };
+        let (start, end) = (line.start, line.end);
+        let (mut col, mut row) = (start.x.floor() as i64, start.y.floor() as i64);
+        let (end_col, end_row) = (end.x.floor() as i64, end.y.floor() as i64);
+        let delta = line.delta();
+        // the fraction of the line after which it crosses the next column and row, and how much
+        // the fraction increases from one column or row to the next
+        let axis = |start: f64, delta: f64, cell: i64| -> (i64, f64, f64) {
+            if delta > 0. {
+                (1, (cell as f64 + 1. - start) / delta, 1. / delta)
+            } else if delta < 0. {
+                (-1, (cell as f64 - start) / delta, -1. / delta)
+            } else {
+                (0, f64::INFINITY, f64::INFINITY)
+            }
+        };
+        let (step_col, mut next_col, col_step) = axis(start.x, delta.x, col);
+        let (step_row, mut next_row, row_step) = axis(start.y, delta.y, row);
+        self.pixel(col, row);
+        for _ in 0..(end_col - col).abs() + (end_row - row).abs() {
+            if next_col < next_row {
+                col += step_col;
+                next_col += col_step;
+            } else {
+                row += step_row;
+                next_row += row_step;
+            }
+            self.pixel(col, row);
+        }
+    }
+
+    /// Clips a line to the grid with the Liang–Barsky algorithm, so that lines far outside of
+    /// it don't take long to walk.
+    fn clip(&self, line: Line) -> Option<Line> {
+        let (mut t0, mut t1) = (0f64, 1f64);
+        let delta = line.delta();
+        for (p, q) in [
+            (-delta.x, line.start.x),
+            (delta.x, self.width as f64 - line.start.x),
+            (-delta.y, line.start.y),
+            (delta.y, self.height as f64 - line.start.y),
+        ] {
+            if p == 0. {
+                if q < 0. {
+                    return None;
+                }
+            } else if p < 0. {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+        (t0 <= t1).then(|| Line::new(line.start + delta * t0, line.start + delta * t1))
+    }
+
+    /// Burns the pixels whose center is inside a polygon, by finding where each row of centers
+    /// crosses the polygon's rings.
+    pub(super) fn polygon(&mut self, polygon: &Polygon) {
+        let Some(bounds) = polygon.bounding_rect() else {
+            return;
+        };
+        let rings = || std::iter::once(polygon.exterior()).chain(polygon.interiors());
+        let first_row = (bounds.min().y - 0.5).ceil().max(0.) as usize;
+        let last_row = ((bounds.max().y - 0.5).floor() as i64).min(self.height as i64 - 1);
+        let mut crossings = vec![];
+        for row in first_row as i64..=last_row {
+            let y = row as f64 + 0.5;
+            crossings.clear();
+            for line in rings().flat_map(LineString::lines) {
+                if (line.start.y <= y) != (line.end.y <= y) {
+                    let t = (y - line.start.y) / (line.end.y - line.start.y);
+                    crossings.push(line.start.x + t * (line.end.x - line.start.x));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                // the pixels whose center is in the span
+                let first = (span[0] - 0.5).ceil().max(0.) as i64;
+                let last = ((span[1] - 0.5).ceil() as i64).min(self.width as i64);
+                for col in first..last {
+                    self.pixel(col, row);
+                }
+            }
+        }
+        if self.all_touched {
+            for ring in rings() {
+                self.line_string(ring);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Rect};
+
+    fn grid(width: usize, height: usize) -> RasterGrid {
+        let bounds = Rect::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: width as f64, y: height as f64 },
+        );
+        RasterGrid::from_bounds(bounds, width, height)
+    }
+
+    /// The burned pixels as rows of characters, the first row at the top.
+    fn render(grid: &RasterGrid, values: &[f64]) -> Vec<String> {
+        values
+            .chunks(grid.width)
+            .map(|row| {
+                row.iter()
+                    .map(|&value| if value == 0. { '.' } else { '#' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let grid = grid(7, 7);
+        let polygon = wkt!(POLYGON(
+            (0.6 0.6,6.4 0.6,6.4 6.4,0.6 6.4,0.6 0.6),
+            (2.6 2.6,4.4 2.6,4.4 4.4,2.6 4.4,2.6 2.6)
+        ));
+        let features = [(polygon.into(), 1.)];
+        let values = grid.rasterize(&features, &RasterizeOptions::default());
+        assert_eq!(
+            render(&grid, &values),
+            [".......", ".#####.", ".#####.", ".##.##.", ".#####.", ".#####.", "......."]
+        );
+        let options = RasterizeOptions {
+            all_touched: true,
+            ..Default::default()
+        };
+        // the pixel in the middle of the hole doesn't touch the polygon
+        let values = grid.rasterize(&features, &options);
+        assert_eq!(
+            render(&grid, &values),
+            ["#######", "#######", "#######", "###.###", "#######", "#######", "#######"]
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let grid = grid(6, 4);
+        let features = [
+            (wkt!(LINESTRING(0.5 3.5,5.5 0.5)).into(), 1.),
+            // outside of the grid, except for one pixel
+            (wkt!(LINESTRING(-1000. 0.5,0.5 0.5)).into(), 2.),
+            (wkt!(LINESTRING(100. 100.,200. 200.)).into(), 3.),
+        ];
+        let values = grid.rasterize(&features, &RasterizeOptions::default());
+        assert_eq!(
+            render(&grid, &values),
+            ["##....", ".##...", "..###.", "#...##"]
+        );
+        assert_eq!(values[0], 1.);
+        assert_eq!(values[18], 2.);
+        assert!(!values.contains(&3.));
+    }
+
+    #[test]
+    fn overlapping_features() {
+        let grid = grid(3, 1);
+        let features = [
+            (wkt!(POLYGON((0. 0.,3. 0.,3. 1.,0. 1.,0. 0.))).into(), 1.),
+            (wkt!(MULTIPOINT(1.5 0.5,9. 9.)).into(), 2.),
+        ];
+        let options = RasterizeOptions {
+            fill: f64::NAN,
+            ..Default::default()
+        };
+        assert_eq!(grid.rasterize(&features, &options), [1., 2., 1.]);
+        let values = grid.rasterize(&features[1..], &options);
+        assert!(values[0].is_nan());
+        assert_eq!(values[1], 2.);
+    }
+
+    #[test]
+    fn geographic_grid() {
+        // a north-up grid with one degree pixels
+        let grid = RasterGrid::from_geotransform(360, 180, [-180., 1., 0., 90., 0., -1.]);
+        let features = [(
+            Geometry::from(wkt!(POLYGON((10. 40.,20. 40.,20. 50.,10. 50.,10. 40.)))),
+            1.,
+        )];
+        let values = grid.rasterize(&features, &RasterizeOptions::default());
+        assert_eq!(values.iter().sum::<f64>(), 100.);
+        let (col, row) = grid.pixel_at(coord! { x: 15.5, y: 45.5 }).unwrap();
+        assert_eq!(values[row * grid.width + col], 1.);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+
+use super::rasterize::Burner;
+use super::RasterGrid;
+use crate::{coord, AffineOps, Area, BooleanOps, Polygon, Rect};
+
+/// Summary statistics of the values of a raster within a zone, from
+/// [`RasterGrid::zonal_stats`].
+///
+/// The statistics of a zone without any pixel are `NaN`, except for `count` and `sum`, which are
+/// zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZonalStats {
+    /// The number of pixels in the zone. With coverage weighting, partially covered pixels count
+    /// for the fraction of their area in the zone.
+    pub count: f64,
+    /// The sum of the values, each multiplied by its pixel's coverage with coverage weighting.
+    pub sum: f64,
+    /// The mean of the values, weighted by the pixels' coverage with coverage weighting.
+    pub mean: f64,
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+}
+
+impl ZonalStats {
+    fn new() -> Self {
+        Self {
+            count: 0.,
+            sum: 0.,
+            mean: f64::NAN,
+            min: f64::NAN,
+            max: f64::NAN,
+        }
+    }
+
+    fn add(&mut self, value: f64, weight: f64) {
+        if value.is_nan() || weight <= 0. {
+            return;
+        }
+        self.count += weight;
+        self.sum += value * weight;
+        self.mean = self.sum / self.count;
+        // `f64::min` and `f64::max` ignore `NaN`
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+}
+
+impl RasterGrid {
+    /// The count, sum, mean, minimum and maximum of the values of a raster within each polygon,
+    /// e.g. the mean elevation of each district.
+    ///
+    /// A polygon contains the pixels whose center is inside it. With `weighted`, it contains all
+    /// the pixels it touches instead, each weighted by the fraction of its area inside the
+    /// polygon, which is more accurate for polygons which are small compared to the pixels, but
+    /// slower. `NaN` values are ignored, e.g. for pixels without data.
+    ///
+    /// The polygons may overlap.
+    ///
+    /// # Panics
+    ///
+    /// If there isn't one value per pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::raster::RasterGrid;
+    /// use geo::{coord, wkt, Rect};
+    ///
+    /// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 2. });
+    /// let grid = RasterGrid::from_bounds(bounds, 3, 2);
+    /// #[rustfmt::skip]
+    /// let values = [
+    ///     1., 2., 3.,
+    ///     4., 5., 6.,
+    /// ];
+    ///
+    /// // the bottom row, and the left half of the last pixel of the top row
+    /// let zones = [wkt!(POLYGON((0. 0.,3. 0.,3. 1.,2.5 1.,2.5 2.,2. 2.,2. 1.,0. 1.,0. 0.)))];
+    ///
+    /// let stats = grid.zonal_stats(&values, &zones, false);
+    /// assert_eq!(stats[0].count, 3.);
+    /// assert_eq!(stats[0].mean, 5.);
+    ///
+    /// let stats = grid.zonal_stats(&values, &zones, true);
+    /// assert_eq!(stats[0].count, 3.5);
+    /// assert_eq!(stats[0].sum, 4. + 5. + 6. + 3. * 0.5);
+    /// assert_eq!(stats[0].min, 3.);
+    /// ```
+    pub fn zonal_stats<'a>(
+        &self,
+        values: &[f64],
+        polygons: impl IntoIterator<Item = &'a Polygon>,
+        weighted: bool,
+    ) -> Vec<ZonalStats> {
+        assert_eq!(
+            values.len(),
+            self.len(),
+            "there must be one value per pixel"
+        );
+        let to_pixels = self.transform.inverse();
+        polygons
+            .into_iter()
+            .map(|polygon| {
+                let mut stats = ZonalStats::new();
+                let Some(to_pixels) = to_pixels else {
+                    return stats;
+                };
+                let polygon = polygon.affine_transform(&to_pixels);
+                let mut inside = BTreeSet::new();
+                Burner::new(self, false, |index| {
+                    inside.insert(index);
+                })
+                .polygon(&polygon);
+                if !weighted {
+                    for index in inside {
+                        stats.add(values[index], 1.);
+                    }
+                    return stats;
+                }
+
+                // the pixels crossed by the boundary are partially covered
+                let mut boundary = BTreeSet::new();
+                let mut burner = Burner::new(self, true, |index| {
+                    boundary.insert(index);
+                });
+                for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                    burner.line_string(ring);
+                }
+                for &index in &boundary {
+                    let (col, row) = ((index % self.width) as f64, (index / self.width) as f64);
+                    let pixel = Rect::new(
+                        coord! { x: col, y: row },
+                        coord! { x: col + 1., y: row + 1. },
+                    );
+                    let coverage = polygon.intersection(&pixel.to_polygon()).unsigned_area();
+                    stats.add(values[index], coverage.min(1.));
+                }
+                for index in inside.difference(&boundary) {
+                    stats.add(values[*index], 1.);
+                }
+                stats
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn overlapping_zones() {
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. });
+        let grid = RasterGrid::from_bounds(bounds, 10, 10);
+        let values: Vec<f64> = (0..100).map(f64::from).collect();
+        let zones = [
+            wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.))),
+            // the top left pixel, with value 0
+            wkt!(POLYGON((0. 9.,1. 9.,1. 10.,0. 10.,0. 9.))),
+            // outside of the grid
+            wkt!(POLYGON((20. 20.,30. 20.,30. 30.,20. 20.))),
+        ];
+        for weighted in [false, true] {
+            let stats = grid.zonal_stats(&values, &zones, weighted);
+            assert_eq!(
+                stats[0],
+                ZonalStats {
+                    count: 100.,
+                    sum: 4950.,
+                    mean: 49.5,
+                    min: 0.,
+                    max: 99.,
+                }
+            );
+            assert_eq!(stats[1].count, 1.);
+            assert_eq!(stats[1].max, 0.);
+            assert_eq!(stats[2].count, 0.);
+            assert!(stats[2].mean.is_nan());
+        }
+    }
+
+    #[test]
+    fn small_polygon() {
+        let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+        let grid = RasterGrid::from_bounds(bounds, 2, 2);
+        let values = [1., 2., 3., f64::NAN];
+        // a square between the centers of the pixels, covering 16% of each
+        let zone = [wkt!(POLYGON((1.2 1.2,2.8 1.2,2.8 2.8,1.2 2.8,1.2 1.2)))];
+        let stats = grid.zonal_stats(&values, &zone, false);
+        assert_eq!(stats[0].count, 0.);
+        let stats = grid.zonal_stats(&values, &zone, true);
+        assert_relative_eq!(stats[0].count, 0.48, epsilon = 1e-6);
+        assert_relative_eq!(stats[0].mean, 2., epsilon = 1e-6);
+        assert_eq!((stats[0].min, stats[0].max), (1., 3.));
+    }
+}
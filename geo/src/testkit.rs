@@ -0,0 +1,302 @@
+//! Random geometry generators for property tests and benchmarks, enabled by the `testkit`
+//! feature.
+//!
+//! The generators take any [`Rng`], so seed one, e.g. a [`StdRng`](rand::rngs::StdRng), for
+//! reproducible cases. Unless documented otherwise, polygons are valid, with counter-clockwise
+//! exteriors and clockwise interiors.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::testkit::{clustered_points, polygon_with_holes};
+//! use geo::{coord, Rect};
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let polygon = polygon_with_holes(&mut rng, 32, 3);
+//! assert_eq!(polygon.interiors().len(), 3);
+//!
+//! let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+//! let points = clustered_points(&mut rng, bounds, 4, 25, 1.);
+//! assert_eq!(points.0.len(), 100);
+//! ```
+
+use std::f64::consts::PI;
+
+use rand::Rng;
+use rand_distr::{Distribution, Normal, Standard};
+
+use crate::geometry::*;
+use crate::{BoundingRect, MapCoords, Rotate};
+
+/// A point drawn uniformly from `bounds`.
+pub fn uniform_point<R: Rng>(rng: &mut R, bounds: Rect<f64>) -> Coord<f64> {
+    let coords: [f64; 2] = rng.sample(Standard);
+    let dims = bounds.max() - bounds.min();
+    Coord {
+        x: bounds.min().x + dims.x * coords[0],
+        y: bounds.min().y + dims.y * coords[1],
+    }
+}
+
+/// A line between two points drawn uniformly from `bounds`.
+pub fn uniform_line<R: Rng>(rng: &mut R, bounds: Rect<f64>) -> Line<f64> {
+    Line::new(uniform_point(rng, bounds), uniform_point(rng, bounds))
+}
+
+/// A line of `length` in a random direction, starting at a point drawn uniformly from `bounds`.
+pub fn uniform_line_with_length<R: Rng>(rng: &mut R, bounds: Rect<f64>, length: f64) -> Line<f64> {
+    let start = uniform_point(rng, bounds);
+    let line = Line::new(start, start + (length, 0.).into());
+    let angle = rng.sample::<f64, _>(Standard) * 2. * PI;
+    line.rotate_around_point(angle, start.into())
+}
+
+/// A star-shaped polygon around the origin with `steps` vertices at evenly spaced angles, each at
+/// a random distance of at least 0.1.
+pub fn circular_polygon<R: Rng>(rng: &mut R, steps: usize) -> Polygon<f64> {
+    let normal = Normal::<f64>::new(0., 1.).unwrap();
+    Polygon::new(star_ring(steps, || normal.sample(rng).abs() + 0.1), vec![])
+}
+
+/// A polygon with `2 * steps` vertices which zigzags up and down, with thin teeth pointing
+/// inwards, scaled to fill the `[-1, 1] × [-1, 1]` square.
+pub fn steppy_polygon<R: Rng>(rng: &mut R, steps: usize) -> Polygon<f64> {
+    let mut ring = Vec::with_capacity(2 * steps + 2);
+
+    let y_step = 10.0;
+    let mut y = 0.0;
+    let x_shift = 100.0;
+
+    ring.push((0.0, 0.0).into());
+    (0..steps).for_each(|_| {
+        let x: f64 = rng.sample::<f64, _>(Standard);
+        y += y_step;
+        ring.push((x, y).into());
+    });
+    ring.push((x_shift, y).into());
+    (0..steps).for_each(|_| {
+        let x: f64 = rng.sample::<f64, _>(Standard);
+        y -= y_step;
+        ring.push((x_shift + x, y).into());
+    });
+
+    normalize_polygon(Polygon::new(LineString(ring), vec![]))
+}
+
+/// A star-shaped polygon around the origin with at least 8 exterior vertices, at distances of
+/// at least one, and `holes` star-shaped interiors which neither overlap each other nor the
+/// exterior.
+pub fn polygon_with_holes<R: Rng>(rng: &mut R, steps: usize, holes: usize) -> Polygon<f64> {
+    // with 8 vertices, the exterior stays more than 0.9 away from the origin
+    let normal = Normal::<f64>::new(0., 0.5).unwrap();
+    let exterior = star_ring(steps.max(8), || normal.sample(rng).abs() + 1.);
+    // the holes are centered on a circle of radius 0.5, and small enough to stay apart
+    let radius = if holes == 1 {
+        0.3
+    } else {
+        (0.45 * (PI / holes as f64).sin()).min(0.3)
+    };
+    let interiors = (0..holes)
+        .map(|index| {
+            let angle = (index as f64 + 0.5) * 2. * PI / holes as f64;
+            let center = Coord {
+                x: 0.5 * angle.cos(),
+                y: 0.5 * angle.sin(),
+            };
+            let mut ring = star_ring((steps / 4).max(3), || {
+                radius * (1. - rng.sample::<f64, _>(Standard) / 2.)
+            });
+            ring.0.reverse();
+            ring.map_coords(|coord| center + coord)
+        })
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+/// A nearly degenerate polygon: a strip along the x axis from 0 to 1, with `steps` vertices on
+/// each side, and at most `width` wide, with nearly collinear vertices when `width` is small.
+pub fn sliver_polygon<R: Rng>(rng: &mut R, steps: usize, width: f64) -> Polygon<f64> {
+    let steps = steps.max(2);
+    let x = |index: usize| index as f64 / (steps - 1) as f64;
+    let bottom = (0..steps).map(|index| Coord { x: x(index), y: 0. });
+    let top = (0..steps).rev().map(|index| Coord {
+        x: x(index),
+        // never zero, so that the sides don't touch
+        y: width * (1. - rng.sample::<f64, _>(Standard) / 2.),
+    });
+    Polygon::new(bottom.chain(top.collect::<Vec<_>>()).collect(), vec![])
+}
+
+/// A nearly degenerate line string: `points` points at evenly spaced x from 0 to 1, each moved
+/// off the x axis by normally distributed noise with a standard deviation of `noise`.
+pub fn nearly_collinear_line_string<R: Rng>(
+    rng: &mut R,
+    points: usize,
+    noise: f64,
+) -> LineString<f64> {
+    let normal = Normal::new(0., noise).unwrap();
+    (0..points)
+        .map(|index| Coord {
+            x: index as f64 / (points.max(2) - 1) as f64,
+            y: normal.sample(rng),
+        })
+        .collect()
+}
+
+/// `clusters` clusters of `points_per_cluster` points each, around centers drawn uniformly from
+/// `bounds`, with normally distributed offsets with a standard deviation of `spread` along each
+/// axis. The points of each cluster are consecutive.
+pub fn clustered_points<R: Rng>(
+    rng: &mut R,
+    bounds: Rect<f64>,
+    clusters: usize,
+    points_per_cluster: usize,
+    spread: f64,
+) -> MultiPoint<f64> {
+    let normal = Normal::new(0., spread).unwrap();
+    let mut points = Vec::with_capacity(clusters * points_per_cluster);
+    for _ in 0..clusters {
+        let center = uniform_point(rng, bounds);
+        for _ in 0..points_per_cluster {
+            points.push(Point::new(
+                center.x + normal.sample(rng),
+                center.y + normal.sample(rng),
+            ));
+        }
+    }
+    MultiPoint(points)
+}
+
+/// A longitude/latitude point drawn uniformly from the surface of the sphere, rather than from
+/// the rectangle of coordinates, which would crowd the poles.
+pub fn world_point<R: Rng>(rng: &mut R) -> Point<f64> {
+    let (u, v): (f64, f64) = (rng.sample(Standard), rng.sample(Standard));
+    Point::new(360. * u - 180., (2. * v - 1.).asin().to_degrees())
+}
+
+/// A pair of longitude/latitude points for testing geodesic algorithms, which is equally likely
+/// to be drawn uniformly from the sphere, to cross the antimeridian, to be within a tenth of a
+/// degree of a pole, or to be nearly antipodal.
+pub fn geodesic_pair<R: Rng>(rng: &mut R) -> (Point<f64>, Point<f64>) {
+    // uniform between `-scale / 2` and `scale / 2`
+    let offset = |rng: &mut R, scale: f64| (rng.sample::<f64, _>(Standard) - 0.5) * scale;
+    match rng.gen_range(0..4) {
+        0 => (world_point(rng), world_point(rng)),
+        1 => {
+            let east = Point::new(180. - offset(rng, 2.).abs(), offset(rng, 160.));
+            let west = Point::new(-180. + offset(rng, 2.).abs(), offset(rng, 160.));
+            (east, west)
+        }
+        2 => {
+            let pole: f64 = if rng.gen() { 90. } else { -90. };
+            let mut near_pole = || {
+                let lat = pole - pole.signum() * rng.sample::<f64, _>(Standard) * 0.1;
+                Point::new(360. * rng.sample::<f64, _>(Standard) - 180., lat)
+            };
+            (near_pole(), near_pole())
+        }
+        _ => {
+            let point = world_point(rng);
+            let lon = if point.x() > 0. {
+                point.x() - 180.
+            } else {
+                point.x() + 180.
+            };
+            let antipode = Point::new(
+                (lon + offset(rng, 0.02)).clamp(-180., 180.),
+                (offset(rng, 0.02) - point.y()).clamp(-90., 90.),
+            );
+            (point, antipode)
+        }
+    }
+}
+
+/// Scales and moves a polygon to fit and fill the `[-1, 1] × [-1, 1]` square.
+pub fn normalize_polygon(poly: Polygon<f64>) -> Polygon<f64> {
+    let bounds = poly.bounding_rect().unwrap();
+    let dims = bounds.max() - bounds.min();
+    let x_scale = 2. / dims.x;
+    let y_scale = 2. / dims.y;
+
+    let x_shift = -bounds.min().x * x_scale - 1.;
+    let y_shift = -bounds.min().y * y_scale - 1.;
+    poly.map_coords(|mut c| {
+        c.x *= x_scale;
+        c.x += x_shift;
+        c.y *= y_scale;
+        c.y += y_shift;
+        c
+    })
+}
+
+/// A counter-clockwise ring around the origin with `steps` vertices at evenly spaced angles,
+/// each at a distance drawn from `radius`.
+fn star_ring(steps: usize, mut radius: impl FnMut() -> f64) -> LineString<f64> {
+    let angle_step = 2. * PI / steps as f64;
+    (0..steps)
+        .map(|step| {
+            let r = radius();
+            let (sin, cos) = (step as f64 * angle_step).sin_cos();
+            Coord {
+                x: r * cos,
+                y: r * sin,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Contains, Distance, Geodesic, Intersects, Winding};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn valid_polygons_with_holes() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for holes in 0..6 {
+            let mut polygon = polygon_with_holes(&mut rng, 24, holes);
+            assert_eq!(polygon.interiors().len(), holes);
+            assert!(polygon.exterior().is_ccw());
+            let exterior = Polygon::new(polygon.exterior().clone(), vec![]);
+            polygon.interiors_mut(|interiors| {
+                for (index, interior) in interiors.iter().enumerate() {
+                    assert!(interior.is_cw());
+                    assert!(exterior.contains(interior));
+                    for other in &interiors[index + 1..] {
+                        assert!(!interior.intersects(other));
+                    }
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn degenerate_cases() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let sliver = sliver_polygon(&mut rng, 10, 1e-9);
+        assert_eq!(sliver.exterior().0.len(), 21);
+        let bounds = sliver.bounding_rect().unwrap();
+        assert!(bounds.height() <= 1e-9 && bounds.width() == 1.);
+
+        let line_string = nearly_collinear_line_string(&mut rng, 5, 1e-12);
+        assert_eq!(line_string.0.len(), 5);
+        assert!(line_string.coords().all(|coord| coord.y.abs() < 1e-10));
+    }
+
+    #[test]
+    fn geodesic_pairs() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..100 {
+            let (a, b) = geodesic_pair(&mut rng);
+            for point in [a, b] {
+                assert!((-180. ..=180.).contains(&point.x()));
+                assert!((-90. ..=90.).contains(&point.y()));
+            }
+            assert!(Geodesic::distance(a, b).is_finite());
+        }
+    }
+}
@@ -0,0 +1,692 @@
+//! Tiles of the [XYZ] ("slippy map") tiling scheme used by web maps.
+//!
+//! At zoom level `z`, the Web Mercator projection of the world is divided into `2^z` by `2^z`
+//! square tiles. `x` increases eastwards from the antimeridian, and `y` increases southwards from
+//! the north edge of the projection, at about 85.0511° latitude.
+//!
+//! [XYZ]: https://wiki.openstreetmap.org/wiki/Slippy_map_tilenames
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::f64::consts::PI;
+use std::ops::RangeInclusive;
+use std::sync::atomic::AtomicBool;
+
+use crate::line_measures::{Euclidean, Length};
+use crate::mvt::{clip_and_quantize, to_multi};
+use crate::web_mercator::WEB_MERCATOR_MAX_LATITUDE;
+use crate::{
+    coord, Area, BoundingRect, Coord, CoordsIter, GeoError, Geometry, Intersects, MapCoords, Rect,
+    Simplify,
+};
+
+/// The length of the equator in Web Mercator, in meters.
+const WEB_MERCATOR_EXTENT: f64 = 2. * PI * crate::EQUATORIAL_EARTH_RADIUS;
+
+/// The deepest zoom level supported, the deepest at which every tile's `x` and `y` fit in a
+/// `u32`.
+pub const MAX_ZOOM: u8 = 32;
+
+/// The address of a tile in the XYZ tiling scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+pub struct TileId {
+    /// The zoom level
+    pub z: u8,
+    /// The column, from west to east
+    pub x: u32,
+    /// The row, from north to south
+    pub y: u32,
+}
+
+impl TileId {
+    /// Creates a tile id. `z` should be at most [`MAX_ZOOM`], and `x` and `y` less than `2^z`.
+    pub const fn new(z: u8, x: u32, y: u32) -> Self {
+        Self { z, x, y }
+    }
+
+    /// Parses a [quadkey], as used by Bing Maps, where each digit picks one of the four
+    /// children of the previous tile. Returns `None` if the quadkey contains anything but the
+    /// digits `0` to `3`, or has more than 32 digits.
+    ///
+    /// [quadkey]: https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::tile::TileId;
+    ///
+    /// let tile = TileId::from_quadkey("120210233").unwrap();
+    /// assert_eq!(tile, TileId::new(9, 275, 167));
+    /// assert_eq!(tile.quadkey(), "120210233");
+    /// assert_eq!(TileId::from_quadkey(""), Some(TileId::new(0, 0, 0)));
+    /// assert_eq!(TileId::from_quadkey("124"), None);
+    /// ```
+    pub fn from_quadkey(quadkey: &str) -> Option<Self> {
+        if quadkey.len() > 32 {
+            return None;
+        }
+        quadkey.chars().try_fold(Self::new(0, 0, 0), |tile, digit| {
+            let digit = digit.to_digit(4)?;
+            let children = tile.children()?;
+            Some(children[digit as usize])
+        })
+    }
+
+    /// The [quadkey](Self::from_quadkey) of this tile, which has one digit per zoom level.
+    pub fn quadkey(self) -> String {
+        (1..=self.z)
+            .rev()
+            .map(|level| {
+                let mask = 1 << (level - 1);
+                let digit = u32::from(self.x & mask != 0) + 2 * u32::from(self.y & mask != 0);
+                char::from_digit(digit, 4).unwrap()
+            })
+            .collect()
+    }
+
+    /// The tile at the previous zoom level which contains this tile, or `None` at zoom level 0.
+    pub fn parent(self) -> Option<Self> {
+        let z = self.z.checked_sub(1)?;
+        Some(Self::new(z, self.x / 2, self.y / 2))
+    }
+
+    /// The four tiles at the next zoom level which this tile contains: the north-west,
+    /// north-east, south-west and south-east quarters, in the order of their quadkey digits.
+    /// Returns `None` at [`MAX_ZOOM`].
+    pub fn children(self) -> Option<[Self; 4]> {
+        if self.z >= MAX_ZOOM {
+            return None;
+        }
+        let (z, x, y) = (self.z + 1, self.x * 2, self.y * 2);
+        Some([
+            Self::new(z, x, y),
+            Self::new(z, x + 1, y),
+            Self::new(z, x, y + 1),
+            Self::new(z, x + 1, y + 1),
+        ])
+    }
+
+    /// The longitude/latitude ([EPSG:4326]) bounds of the tile.
+    ///
+    /// [EPSG:4326]: https://epsg.io/4326
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::tile::TileId;
+    /// use geo::web_mercator::WEB_MERCATOR_MAX_LATITUDE;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let bounds = TileId::new(1, 1, 0).bounds();
+    /// assert_relative_eq!(bounds.min().x, 0.);
+    /// assert_relative_eq!(bounds.max().x, 180.);
+    /// assert_relative_eq!(bounds.min().y, 0.);
+    /// assert_relative_eq!(bounds.max().y, WEB_MERCATOR_MAX_LATITUDE, epsilon = 1e-9);
+    /// ```
+    pub fn bounds(self) -> Rect {
+        let world = self.world_bounds();
+        Rect::new(world_to_lon_lat(world.min()), world_to_lon_lat(world.max()))
+    }
+
+    /// The Web Mercator ([EPSG:3857]) bounds of the tile, in meters.
+    ///
+    /// [EPSG:3857]: https://epsg.io/3857
+    pub fn web_mercator_bounds(self) -> Rect {
+        let world = self.world_bounds();
+        let to_web_mercator = |coord: Coord| {
+            coord! {
+                x: (coord.x - 0.5) * WEB_MERCATOR_EXTENT,
+                y: (0.5 - coord.y) * WEB_MERCATOR_EXTENT,
+            }
+        };
+        Rect::new(to_web_mercator(world.min()), to_web_mercator(world.max()))
+    }
+
+    /// Projects a longitude/latitude geometry into this tile, and rounds its coordinates to the
+    /// closest point of a grid of `extent` by `extent` cells covering the tile, with `y` pointing
+    /// south.
+    ///
+    /// The geometry isn't clipped to the tile, and repeated coordinates aren't removed. See
+    /// [`encode_mvt_geometry`](crate::mvt::encode_mvt_geometry) to encode a geometry for a
+    /// vector tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::tile::TileId;
+    /// use geo::{point, Geometry, Point};
+    ///
+    /// let tile = TileId::new(1, 1, 0);
+    /// let point: Geometry = point!(x: 90., y: 0.).into();
+    /// assert_eq!(
+    ///     tile.quantize(&point, 4096),
+    ///     Point::new(2048, 4096).into()
+    /// );
+    /// ```
+    pub fn quantize(self, geometry: &Geometry, extent: u32) -> Geometry<i32> {
+        let extent = f64::from(extent);
+        geometry.map_coords(|coord| {
+            let coord = self.to_tile_coord(coord, extent);
+            coord! { x: coord.x.round() as i32, y: coord.y.round() as i32 }
+        })
+    }
+
+    /// The bounds of the tile in the world coordinates of [`lon_lat_to_world`].
+    fn world_bounds(self) -> Rect {
+        let n = self.tiles_per_axis();
+        Rect::new(
+            coord! { x: f64::from(self.x) / n, y: f64::from(self.y) / n },
+            coord! { x: (f64::from(self.x) + 1.) / n, y: (f64::from(self.y) + 1.) / n },
+        )
+    }
+
+    /// The number of tiles along each axis at this tile's zoom level.
+    pub(crate) fn tiles_per_axis(self) -> f64 {
+        2f64.powi(self.z.into())
+    }
+
+    /// Converts a longitude/latitude coordinate to this tile's coordinates, where the tile covers
+    /// `0..extent` on both axes and `y` points south.
+    pub(crate) fn to_tile_coord(self, coord: Coord, extent: f64) -> Coord {
+        let world = lon_lat_to_world(coord);
+        let n = self.tiles_per_axis();
+        coord! {
+            x: (world.x * n - f64::from(self.x)) * extent,
+            y: (world.y * n - f64::from(self.y)) * extent,
+        }
+    }
+
+    /// The inverse of [`Self::to_tile_coord`].
+    pub(crate) fn to_lon_lat(self, coord: Coord, extent: f64) -> Coord {
+        let n = self.tiles_per_axis();
+        world_to_lon_lat(coord! {
+            x: (coord.x / extent + f64::from(self.x)) / n,
+            y: (coord.y / extent + f64::from(self.y)) / n,
+        })
+    }
+}
+
+/// The tiles at zoom level `zoom` which a longitude/latitude geometry intersects, sorted by `x`
+/// and then `y`.
+///
+/// The geometry is projected to Web Mercator and tested exactly against the tiles, so a line
+/// crossing a tile diagonally doesn't cover the tiles at the other corners of its bounding
+/// rectangle. Tiles are closed, so a geometry touching the edge of a tile covers the tiles on
+/// both sides of it. This is useful to seed a tile pyramid, or to find the cached tiles which
+/// must be rendered again after a geometry changed.
+///
+/// Starting from the tile covering the whole world, each tile which the geometry intersects is
+/// refined into its four children, until reaching `zoom`. Empty geometries cover no tiles.
+/// Returns `None` if `zoom` is above [`MAX_ZOOM`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::tile::{tiles_covering, TileId};
+/// use geo::wkt;
+///
+/// // from Lisbon to Berlin, which doesn't cross the tile south of Berlin
+/// let line = wkt!(LINESTRING(-9.1 38.7,13.4 52.5)).into();
+/// assert_eq!(
+///     tiles_covering(&line, 4).unwrap(),
+///     [TileId::new(4, 7, 5), TileId::new(4, 7, 6), TileId::new(4, 8, 5)]
+/// );
+/// assert_eq!(tiles_covering(&line, 0).unwrap(), [TileId::new(0, 0, 0)]);
+/// ```
+pub fn tiles_covering(geometry: &Geometry, zoom: u8) -> Option<Vec<TileId>> {
+    (zoom <= MAX_ZOOM).then(|| tiles_covering_world(&geometry.map_coords(lon_lat_to_world), zoom))
+}
+
+/// [`tiles_covering`] for a geometry already in the world coordinates of [`lon_lat_to_world`].
+/// `zoom` must be at most [`MAX_ZOOM`].
+fn tiles_covering_world(world: &Geometry, zoom: u8) -> Vec<TileId> {
+    let Some(bounds) = world.bounding_rect() else {
+        return vec![];
+    };
+    let mut tiles = vec![TileId::new(0, 0, 0)];
+    for _ in 0..zoom {
+        tiles = tiles
+            .into_iter()
+            .flat_map(|tile| tile.children().expect("zoom is at most MAX_ZOOM"))
+            .filter(|tile| {
+                let rect = tile.world_bounds();
+                // the bounding rectangles are compared first, as it's much cheaper
+                bounds.intersects(&rect) && world.intersects(&rect)
+            })
+            .collect();
+    }
+    tiles.sort_unstable_by_key(|tile| (tile.x, tile.y));
+    tiles
+}
+
+/// Options for [`tile_pyramid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PyramidOptions {
+    /// The size of each tile's integer grid along each axis.
+    pub extent: u32,
+    /// How far geometries may extend beyond the edges of a tile before being clipped, in grid
+    /// units.
+    pub buffer: u32,
+    /// The tolerance of the [Ramer–Douglas–Peucker](crate::Simplify) simplification of lines
+    /// and polygons, in grid units. Zero keeps every vertex.
+    pub simplify: f64,
+    /// Line strings shorter than this, and polygons with less area than its square, are dropped,
+    /// in grid units. Zero keeps everything.
+    pub min_size: f64,
+}
+
+impl Default for PyramidOptions {
+    /// The [`MvtOptions`](crate::mvt::MvtOptions) defaults, simplified with a tolerance of one
+    /// grid unit, keeping parts of at least one grid unit.
+    fn default() -> Self {
+        Self {
+            extent: 4096,
+            buffer: 64,
+            simplify: 1.,
+            min_size: 1.,
+        }
+    }
+}
+
+/// A feature's geometry in a tile of a [`tile_pyramid`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileFeature {
+    /// The index of the feature among the features passed to [`tile_pyramid`].
+    pub index: usize,
+    /// The feature's geometry in the tile's grid, as a multi-point, multi-line string or
+    /// multi-polygon.
+    pub geometry: Geometry<i32>,
+}
+
+/// The geometries of longitude/latitude features in each tile of the zoom levels in `zooms`,
+/// the core of a vector tile builder.
+///
+/// At each zoom level, each feature is projected to Web Mercator, simplified with a tolerance
+/// relative to the tiles of that level, and its line strings and polygons which are too small to
+/// see are dropped. What's left is clipped to each tile it touches, with a buffer, and snapped to
+/// the tile's grid as by [`TileId::quantize`], with `y` pointing south. Generalizing a feature
+/// once per level rather than once per tile keeps it continuous across tile edges.
+///
+/// Tiles without any feature are left out, and the features of each tile are in the order they
+/// were given. Geometry collections are skipped, as vector tiles store each of their members as
+/// a feature of its own. Encode the geometries with
+/// [`encode_mvt_commands`](crate::mvt::encode_mvt_commands).
+///
+/// # Panics
+///
+/// If `zooms` ends above [`MAX_ZOOM`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::tile::{tile_pyramid, PyramidOptions, TileId};
+/// use geo::{wkt, Geometry};
+///
+/// let features: [Geometry; 2] = [
+///     // a lake in Berlin, about 1km wide
+///     wkt!(POLYGON((13.30 52.50,13.31 52.50,13.31 52.51,13.30 52.51,13.30 52.50))).into(),
+///     wkt!(POINT(2.35 48.85)).into(),
+/// ];
+/// let tiles = tile_pyramid(&features, 0..=10, &PyramidOptions::default());
+///
+/// // the lake is too small to see at zoom level 0, but the point is kept
+/// let world = &tiles[&TileId::new(0, 0, 0)];
+/// assert_eq!(world.len(), 1);
+/// assert_eq!(world[0].index, 1);
+/// // the lake is in one tile at zoom level 10
+/// let lake: Vec<_> = tiles
+///     .iter()
+///     .filter(|(tile, features)| tile.z == 10 && features.iter().any(|f| f.index == 0))
+///     .collect();
+/// assert_eq!(lake.len(), 1);
+/// ```
+pub fn tile_pyramid<'a>(
+    features: impl IntoIterator<Item = &'a Geometry>,
+    zooms: RangeInclusive<u8>,
+    options: &PyramidOptions,
+) -> BTreeMap<TileId, Vec<TileFeature>> {
+    tile_pyramid_cancellable(features, zooms, options, &AtomicBool::new(false))
+        .expect("the pyramid can't be cancelled")
+}
+
+/// Like [`tile_pyramid`], but returns [`GeoError::Interrupted`] as soon as `cancel` is set, e.g.
+/// by another thread when a request times out. It's checked before each feature is tiled at each
+/// zoom level.
+///
+/// # Panics
+///
+/// If `zooms` ends above [`MAX_ZOOM`].
+pub fn tile_pyramid_cancellable<'a>(
+    features: impl IntoIterator<Item = &'a Geometry>,
+    zooms: RangeInclusive<u8>,
+    options: &PyramidOptions,
+    cancel: &AtomicBool,
+) -> Result<BTreeMap<TileId, Vec<TileFeature>>, GeoError> {
+    assert!(
+        *zooms.end() <= MAX_ZOOM,
+        "zoom levels must be at most MAX_ZOOM"
+    );
+    let extent = f64::from(options.extent);
+    let buffer = f64::from(options.buffer);
+    let clip = Rect::new(
+        coord! { x: -buffer, y: -buffer },
+        coord! { x: extent + buffer, y: extent + buffer },
+    );
+    let mut tiles: BTreeMap<TileId, Vec<TileFeature>> = BTreeMap::new();
+    for (index, feature) in features.into_iter().enumerate() {
+        let Some(world) = to_multi(feature.map_coords(lon_lat_to_world)) else {
+            continue;
+        };
+        for zoom in zooms.clone() {
+            GeoError::check_cancel(cancel)?;
+            // the coordinates of the grids of all the tiles of the level
+            let scale = 2f64.powi(zoom.into()) * extent;
+            let Some(generalized) = generalize(world.map_coords(|c| c * scale), options) else {
+                continue;
+            };
+            // the tiles whose buffer the feature may reach
+            let n = 1i64 << zoom;
+            let mut candidates = BTreeSet::new();
+            for tile in tiles_covering_world(&world, zoom) {
+                let reach = if buffer > 0. { 1 } else { 0 };
+                for dx in -reach..=reach {
+                    for dy in -reach..=reach {
+                        let (x, y) = (i64::from(tile.x) + dx, i64::from(tile.y) + dy);
+                        if (0..n).contains(&x) && (0..n).contains(&y) {
+                            candidates.insert(TileId::new(zoom, x as u32, y as u32));
+                        }
+                    }
+                }
+            }
+            for tile in candidates {
+                let origin = coord! {
+                    x: f64::from(tile.x) * extent,
+                    y: f64::from(tile.y) * extent,
+                };
+                let geometry = clip_and_quantize(generalized.map_coords(|c| c - origin), clip);
+                if geometry.coords_count() > 0 {
+                    tiles
+                        .entry(tile)
+                        .or_default()
+                        .push(TileFeature { index, geometry });
+                }
+            }
+        }
+    }
+    Ok(tiles)
+}
+
+/// Simplifies a multi-geometry from [`to_multi`], and drops its parts smaller than
+/// [`PyramidOptions::min_size`], returning `None` if none is left.
+fn generalize(geometry: Geometry, options: &PyramidOptions) -> Option<Geometry> {
+    let generalized: Geometry = match geometry {
+        Geometry::MultiLineString(mut multi_line_string) => {
+            if options.simplify > 0. {
+                multi_line_string = multi_line_string.simplify(&options.simplify);
+            }
+            multi_line_string
+                .0
+                .retain(|line_string| line_string.length::<Euclidean>() >= options.min_size);
+            multi_line_string.into()
+        }
+        Geometry::MultiPolygon(mut multi_polygon) => {
+            if options.simplify > 0. {
+                multi_polygon = multi_polygon.simplify(&options.simplify);
+            }
+            let min_area = options.min_size * options.min_size;
+            multi_polygon
+                .0
+                .retain(|polygon| polygon.unsigned_area() >= min_area);
+            multi_polygon.into()
+        }
+        geometry => geometry,
+    };
+    (generalized.coords_count() > 0).then_some(generalized)
+}
+
+/// Projects a longitude/latitude coordinate to Web Mercator, scaled so that the world covers
+/// `0..1` on both axes with `y` pointing south. Latitudes beyond the poles of the projection are
+/// clamped.
+pub(crate) fn lon_lat_to_world(coord: Coord) -> Coord {
+    let lat = coord
+        .y
+        .clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE)
+        .to_radians();
+    coord! {
+        x: (coord.x + 180.) / 360.,
+        y: (1. - (lat.tan() + 1. / lat.cos()).ln() / PI) / 2.,
+    }
+}
+
+/// The inverse of [`lon_lat_to_world`].
+pub(crate) fn world_to_lon_lat(coord: Coord) -> Coord {
+    coord! {
+        x: coord.x * 360. - 180.,
+        y: (PI * (1. - 2. * coord.y)).sinh().atan().to_degrees(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::web_mercator::lon_lat_to_web_mercator;
+    use crate::{wkt, LineString, Point};
+
+    #[test]
+    fn world_projection() {
+        assert_relative_eq!(
+            lon_lat_to_world(coord! { x: 0., y: 0. }),
+            coord! { x: 0.5, y: 0.5 }
+        );
+        assert_relative_eq!(
+            lon_lat_to_world(coord! { x: -180., y: WEB_MERCATOR_MAX_LATITUDE }),
+            coord! { x: 0., y: 0. },
+            epsilon = 1e-12
+        );
+        let coord = coord! { x: 13.4, y: 52.5 };
+        assert_relative_eq!(
+            world_to_lon_lat(lon_lat_to_world(coord)),
+            coord,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn covering() {
+        let point: Geometry = wkt!(POINT(13.4 52.5)).into();
+        assert_eq!(
+            tiles_covering(&point, 10).unwrap(),
+            [TileId::new(10, 550, 335)]
+        );
+
+        // a polygon covers the tiles inside it, not only those along its boundary
+        let polygon: Geometry =
+            wkt!(POLYGON((-170. -80.,170. -80.,170. 80.,-170. 80.,-170. -80.))).into();
+        assert_eq!(tiles_covering(&polygon, 3).unwrap().len(), 64);
+
+        // a diagonal line doesn't cover its bounding rectangle
+        let line: Geometry = wkt!(LINESTRING(-179. 84.,179. -84.)).into();
+        let tiles = tiles_covering(&line, 5).unwrap();
+        assert!(tiles.len() >= 32 && tiles.len() < 100);
+        assert!(tiles.contains(&TileId::new(5, 0, 0)));
+        assert!(tiles.contains(&TileId::new(5, 31, 31)));
+        assert!(!tiles.contains(&TileId::new(5, 0, 31)));
+
+        // on the edge between two tiles, and beyond the poles of the projection
+        let point: Geometry = wkt!(POINT(0. 89.)).into();
+        assert_eq!(
+            tiles_covering(&point, 1).unwrap(),
+            [TileId::new(1, 0, 0), TileId::new(1, 1, 0)]
+        );
+        assert_eq!(
+            tiles_covering(&Geometry::from(wkt!(LINESTRING EMPTY)), 3),
+            Some(vec![])
+        );
+
+        // the deepest zoom level, at the south-east corner of the world
+        let corner: Geometry = Point::new(180., -89.).into();
+        let max = u32::MAX;
+        assert_eq!(
+            tiles_covering(&corner, MAX_ZOOM),
+            Some(vec![TileId::new(MAX_ZOOM, max, max)])
+        );
+        assert_eq!(tiles_covering(&corner, MAX_ZOOM + 1), None);
+        assert_eq!(tiles_covering(&corner, u8::MAX), None);
+    }
+
+    #[test]
+    fn quadkeys() {
+        for quadkey in ["0", "3", "0123", "333", "1202102332221212"] {
+            let tile = TileId::from_quadkey(quadkey).unwrap();
+            assert_eq!(usize::from(tile.z), quadkey.len());
+            assert_eq!(tile.quadkey(), quadkey);
+        }
+        assert_eq!(TileId::from_quadkey("3"), Some(TileId::new(1, 1, 1)));
+        assert_eq!(TileId::new(0, 0, 0).quadkey(), "");
+        assert_eq!(TileId::from_quadkey("0x"), None);
+        assert_eq!(TileId::from_quadkey(&"3".repeat(33)), None);
+        let deepest = TileId::from_quadkey(&"3".repeat(32)).unwrap();
+        assert_eq!(deepest, TileId::new(MAX_ZOOM, u32::MAX, u32::MAX));
+        assert_eq!(deepest.quadkey(), "3".repeat(32));
+    }
+
+    #[test]
+    fn navigation() {
+        let tile = TileId::new(10, 550, 335);
+        for child in tile.children().unwrap() {
+            assert_eq!(child.parent(), Some(tile));
+            assert!(child.quadkey().starts_with(&tile.quadkey()));
+        }
+        assert_eq!(TileId::new(0, 0, 0).parent(), None);
+
+        // the children cover their parent
+        let bounds = tile.bounds();
+        let children = tile.children().unwrap().map(TileId::bounds);
+        assert_eq!(children[0].min().x, bounds.min().x);
+        assert_eq!(children[0].max().y, bounds.max().y);
+        assert_eq!(children[3].max().x, bounds.max().x);
+        assert_eq!(children[3].min().y, bounds.min().y);
+        assert!(bounds.intersects(&coord! { x: 13.4, y: 52.5 }));
+
+        let deepest = TileId::new(MAX_ZOOM, u32::MAX, u32::MAX);
+        assert_eq!(deepest.children(), None);
+        let parent = deepest.parent().unwrap();
+        assert_eq!(parent.children().unwrap()[3], deepest);
+        assert_relative_eq!(deepest.bounds().max().x, 180.);
+    }
+
+    #[test]
+    fn web_mercator_bounds() {
+        let world = TileId::new(0, 0, 0).web_mercator_bounds();
+        assert_relative_eq!(
+            world.min(),
+            coord! { x: -20037508.34, y: -20037508.34 },
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            world.max(),
+            coord! { x: 20037508.34, y: 20037508.34 },
+            epsilon = 0.01
+        );
+
+        let tile = TileId::new(10, 550, 335);
+        let bounds = tile.bounds();
+        assert_relative_eq!(
+            tile.web_mercator_bounds(),
+            Rect::new(
+                lon_lat_to_web_mercator(bounds.min()),
+                lon_lat_to_web_mercator(bounds.max())
+            ),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn tile_coords() {
+        // Berlin is in tile 10/550/335
+        let tile = TileId::new(10, 550, 335);
+        let berlin = tile.to_tile_coord(coord! { x: 13.4, y: 52.5 }, 4096.);
+        assert!((0. ..4096.).contains(&berlin.x));
+        assert!((0. ..4096.).contains(&berlin.y));
+        assert_relative_eq!(
+            tile.to_lon_lat(berlin, 4096.),
+            coord! { x: 13.4, y: 52.5 },
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn pyramid() {
+        let features: Vec<Geometry> = vec![
+            // across the prime meridian
+            wkt!(LINESTRING(-10. 10.,10. 10.)).into(),
+            // within the buffer of the tile east of the prime meridian
+            wkt!(POINT(-0.5 10.)).into(),
+            // a zigzag with a small amplitude
+            LineString::from_iter((0..=100).map(|i| {
+                let y = if i % 2 == 0 { 20. } else { 20.001 };
+                coord! { x: 20. + f64::from(i) * 0.01, y: y }
+            }))
+            .into(),
+            // about 10m long
+            wkt!(LINESTRING(30. - 10., 30.0001 - 10.)).into(),
+        ];
+        let options = PyramidOptions::default();
+        let tiles = tile_pyramid(&features, 0..=16, &options);
+        let feature = |tile: TileId, index: usize| {
+            tiles
+                .get(&tile)
+                .and_then(|features| features.iter().find(|f| f.index == index))
+                .map(|f| f.geometry.clone())
+        };
+
+        let west = feature(TileId::new(1, 0, 0), 0).unwrap();
+        let east = feature(TileId::new(1, 1, 0), 0).unwrap();
+        let Geometry::MultiLineString(west) = west else {
+            panic!("expected a multi line string");
+        };
+        // clipped at the edge of the buffer
+        assert_eq!(west.0.len(), 1);
+        assert_eq!(west.0[0].0.last().unwrap().x, 4096 + 64);
+        let Geometry::MultiLineString(east) = east else {
+            panic!("expected a multi line string");
+        };
+        assert_eq!(east.0[0].0.first().unwrap().x, -64);
+
+        assert!(feature(TileId::new(1, 0, 0), 1).is_some());
+        assert!(feature(TileId::new(1, 1, 0), 1).is_some());
+        assert!(feature(TileId::new(4, 8, 7), 1).is_none());
+
+        // the zigzag is a straight line until the amplitude is larger than a grid unit
+        let vertices = |zoom: u8| {
+            tiles
+                .iter()
+                .filter(|(tile, _)| tile.z == zoom)
+                .flat_map(|(_, features)| features.iter().filter(|f| f.index == 2))
+                .map(|f| f.geometry.coords_count())
+                .sum::<usize>()
+        };
+        assert_eq!(vertices(2), 2);
+        assert!(vertices(16) > 100);
+
+        // the short line only shows at high zoom levels
+        let zooms: BTreeSet<u8> = tiles
+            .iter()
+            .filter(|(_, features)| features.iter().any(|f| f.index == 3))
+            .map(|(tile, _)| tile.z)
+            .collect();
+        assert_eq!(zooms.first(), Some(&10));
+        assert_eq!(zooms.last(), Some(&16));
+
+        let cancelled =
+            tile_pyramid_cancellable(&features, 0..=16, &options, &AtomicBool::new(true));
+        assert_eq!(cancelled, Err(GeoError::Interrupted));
+    }
+
+    #[test]
+    #[should_panic(expected = "zoom levels must be at most MAX_ZOOM")]
+    fn pyramid_too_deep() {
+        let point: Geometry = wkt!(POINT(0. 0.)).into();
+        tile_pyramid([&point], 0..=MAX_ZOOM + 1, &PyramidOptions::default());
+    }
+}
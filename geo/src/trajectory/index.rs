@@ -0,0 +1,209 @@
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+use super::Trajectory;
+use crate::line_measures::Distance;
+use crate::{Euclidean, GeoFloat, Intersects, Line, Point, Rect};
+
+/// The space-time box of a segment of a trajectory, with the indices of the trajectory and of
+/// the segment's first sample.
+type SegmentBox<T> = GeomWithData<Rectangle<[T; 3]>, (usize, usize)>;
+
+/// An R-tree of the segments of trajectories in `(x, y, time)`, to find which trajectories were
+/// in an area during a time window without scanning all of them.
+///
+/// Positions between samples are interpolated along straight lines at a constant speed, and
+/// distances are Euclidean, so longitude/latitude tracks should be
+/// [projected](crate::algorithm::utm) first for distance queries.
+///
+/// # Examples
+///
+/// ```
+/// use geo::trajectory::{Trajectory, TrajectoryIndex};
+/// use geo::{coord, point, wkt, Rect};
+///
+/// let index = TrajectoryIndex::new(vec![
+///     // eastwards along y = 0, from 2 pm to 3 pm
+///     Trajectory::try_new(wkt!(LINESTRING(0. 0.,100. 0.)), vec![14., 15.]).unwrap(),
+///     // northwards along x = 50, from 3 pm to 4 pm
+///     Trajectory::try_new(wkt!(LINESTRING(50. -50.,50. 50.)), vec![15., 16.]).unwrap(),
+/// ]);
+///
+/// // which vehicles were near the crossing between 2 and 3 pm?
+/// assert_eq!(index.near(point!(x: 50., y: 0.), 5., 14., 15.), vec![0]);
+/// // and between 3 and 4 pm?
+/// assert_eq!(index.near(point!(x: 50., y: 0.), 5., 15., 16.), vec![1]);
+///
+/// let area = Rect::new(coord! { x: 40., y: -10. }, coord! { x: 60., y: 10. });
+/// assert_eq!(index.within(area, 14., 16.), vec![0, 1]);
+///
+/// // who was closest to the origin at 3:30 pm?
+/// assert_eq!(index.nearest_at(point!(x: 0., y: 0.), 15.5), Some((1, point!(x: 50., y: 0.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrajectoryIndex<T: GeoFloat + RTreeNum = f64> {
+    trajectories: Vec<Trajectory<T>>,
+    tree: RTree<SegmentBox<T>>,
+}
+
+impl<T: GeoFloat + RTreeNum> TrajectoryIndex<T> {
+    /// An index of the segments of `trajectories`, which queries refer to by their position in
+    /// the vector. A trajectory with a single sample is indexed as a point.
+    pub fn new(trajectories: Vec<Trajectory<T>>) -> Self {
+        let mut boxes = Vec::new();
+        for (index, trajectory) in trajectories.iter().enumerate() {
+            let samples: Vec<_> = trajectory.samples().collect();
+            if let [(coord, time)] = samples[..] {
+                let corner = [coord.x, coord.y, time];
+                boxes.push(SegmentBox::new(
+                    Rectangle::from_corners(corner, corner),
+                    (index, 0),
+                ));
+            }
+            for (segment, pair) in samples.windows(2).enumerate() {
+                let [(start, start_time), (end, end_time)] = [pair[0], pair[1]];
+                let rectangle = Rectangle::from_corners(
+                    [start.x, start.y, start_time],
+                    [end.x, end.y, end_time],
+                );
+                boxes.push(SegmentBox::new(rectangle, (index, segment)));
+            }
+        }
+        Self {
+            trajectories,
+            tree: RTree::bulk_load(boxes),
+        }
+    }
+
+    /// The indexed trajectories.
+    pub fn trajectories(&self) -> &[Trajectory<T>] {
+        &self.trajectories
+    }
+
+    /// The indices, in increasing order, of the trajectories which were inside or on the boundary
+    /// of `bounds` at some time between `start` and `end`.
+    pub fn within(&self, bounds: Rect<T>, start: T, end: T) -> Vec<usize> {
+        let (min, max) = (bounds.min(), bounds.max());
+        let envelope = AABB::from_corners([min.x, min.y, start], [max.x, max.y, end]);
+        self.matches(envelope, start, end, |line| line.intersects(&bounds))
+    }
+
+    /// The indices, in increasing order, of the trajectories which were within `max_distance` of
+    /// `point` at some time between `start` and `end`.
+    pub fn near(&self, point: Point<T>, max_distance: T, start: T, end: T) -> Vec<usize> {
+        let envelope = AABB::from_corners(
+            [point.x() - max_distance, point.y() - max_distance, start],
+            [point.x() + max_distance, point.y() + max_distance, end],
+        );
+        self.matches(envelope, start, end, |line| {
+            Euclidean::distance(point.0, &line) <= max_distance
+        })
+    }
+
+    /// The index and position of the trajectory which was closest to `point` at `time`, among
+    /// those which had started and not yet ended, or `None` if there's none.
+    ///
+    /// This only considers the segments which span `time`, so it's fast for moments when few of
+    /// the trajectories are under way.
+    pub fn nearest_at(&self, point: Point<T>, time: T) -> Option<(usize, Point<T>)> {
+        let (min, max) = (T::neg_infinity(), T::infinity());
+        let envelope = AABB::from_corners([min, min, time], [max, max, time]);
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|segment| {
+                let (index, first) = segment.data;
+                let position = self.trajectories[index].interpolate_euclidean(first, time);
+                (index, position, Euclidean::distance(point, position))
+            })
+            // break ties by index, since the tree's order is arbitrary
+            .min_by(|a, b| a.2.total_cmp(&b.2).then(a.0.cmp(&b.0)))
+            .map(|(index, position, _)| (index, position))
+    }
+
+    /// The trajectories with a segment in `envelope` whose part between `start` and `end`
+    /// matches `predicate`.
+    fn matches(
+        &self,
+        envelope: AABB<[T; 3]>,
+        start: T,
+        end: T,
+        predicate: impl Fn(Line<T>) -> bool,
+    ) -> Vec<usize> {
+        if start > end {
+            return vec![];
+        }
+        let mut indices: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .filter(|segment| {
+                let (index, first) = segment.data;
+                let trajectory = &self.trajectories[index];
+                let times = &trajectory.times;
+                let clipped_start = times[first].max(start);
+                let clipped_end = times.get(first + 1).map_or(times[first], |t| t.min(end));
+                predicate(Line::new(
+                    trajectory.interpolate_euclidean(first, clipped_start),
+                    trajectory.interpolate_euclidean(first, clipped_end),
+                ))
+            })
+            .map(|segment| segment.data.0)
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+impl<T: GeoFloat> Trajectory<T> {
+    /// The position at `time` along a straight line on the segment starting at sample `segment`.
+    fn interpolate_euclidean(&self, segment: usize, time: T) -> Point<T> {
+        let start = self.line_string.0[segment];
+        let Some(&end) = self.line_string.0.get(segment + 1) else {
+            return start.into();
+        };
+        let duration = self.times[segment + 1] - self.times[segment];
+        if duration <= T::zero() {
+            return start.into();
+        }
+        let ratio = (time - self.times[segment]) / duration;
+        (start + (end - start) * ratio).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt};
+
+    #[test]
+    fn queries() {
+        let index = TrajectoryIndex::new(vec![
+            // passes through the area while it's being queried
+            Trajectory::try_new(wkt!(LINESTRING(0. 0.,10. 10.,20. 20.)), vec![0., 10., 20.])
+                .unwrap(),
+            // passes through the area after it's queried
+            Trajectory::try_new(wkt!(LINESTRING(0. 10.,10. 0.)), vec![20., 30.]).unwrap(),
+            // stays in the area
+            Trajectory::try_new(wkt!(LINESTRING(5. 6.)), vec![15.]).unwrap(),
+        ]);
+        let area = Rect::new(coord! { x: 4., y: 4. }, coord! { x: 6., y: 6. });
+        assert_eq!(index.within(area, 0., 10.), vec![0]);
+        // the first trajectory has left the area by then
+        assert_eq!(index.within(area, 8., 16.), vec![2]);
+        assert_eq!(index.within(area, 0., 30.), vec![0, 1, 2]);
+        assert!(index.within(area, 10., 0.).is_empty());
+
+        assert_eq!(index.near(point!(x: 5., y: 5.), 1., 25., 25.), vec![1]);
+        assert_eq!(index.near(point!(x: 5., y: 5.), 0.5, 0., 30.), vec![0, 1]);
+
+        assert_eq!(
+            index.nearest_at(point!(x: 20., y: 20.), 20.),
+            Some((0, point!(x: 20., y: 20.)))
+        );
+        assert_eq!(
+            index.nearest_at(point!(x: 10., y: 0.), 25.),
+            Some((1, point!(x: 5., y: 5.)))
+        );
+        assert_eq!(index.nearest_at(point!(x: 0., y: 0.), 40.), None);
+    }
+}
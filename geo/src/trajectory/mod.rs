@@ -0,0 +1,409 @@
+//! Trajectories of moving objects, such as GPS tracks: line strings whose vertices are
+//! timestamped.
+//!
+//! A [`Trajectory`] stores its timestamps in the same scalar type as its coordinates, e.g. as
+//! seconds since the Unix epoch in an `f64`. Distances, and so speeds and the interpolation
+//! between samples, are measured in a [metric space](crate::algorithm::line_measures::metric_spaces)
+//! chosen by the caller, e.g. [`Haversine`](crate::Haversine) for longitude/latitude tracks.
+
+use std::fmt;
+
+use crate::line_measures::{Distance, InterpolatePoint};
+use crate::{Coord, CoordNum, GeoFloat, LineString, Point};
+
+mod index;
+pub use index::TrajectoryIndex;
+
+mod simplify;
+
+mod stay_points;
+pub use stay_points::StayPoint;
+
+/// Why a line string and timestamps don't make a [`Trajectory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrajectoryError {
+    /// There isn't one timestamp per coordinate.
+    LengthMismatch { coords: usize, times: usize },
+    /// The timestamp at `index` is NaN or infinite.
+    NonFiniteTime { index: usize },
+    /// The timestamp at `index` is earlier than the one before it.
+    DecreasingTime { index: usize },
+}
+
+impl fmt::Display for TrajectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrajectoryError::LengthMismatch { coords, times } => {
+                write!(f, "{coords} coordinates but {times} timestamps")
+            }
+            TrajectoryError::NonFiniteTime { index } => {
+                write!(f, "timestamp {index} is not finite")
+            }
+            TrajectoryError::DecreasingTime { index } => {
+                write!(f, "timestamp {index} is earlier than the one before it")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TrajectoryError {}
+
+/// A line string with a timestamp per vertex, in non-decreasing order, e.g. a GPS track.
+///
+/// # Examples
+///
+/// ```
+/// use geo::trajectory::Trajectory;
+/// use geo::{wkt, Euclidean};
+///
+/// let track = Trajectory::try_new(wkt!(LINESTRING(0. 0.,10. 0.,10. 5.)), vec![0., 5., 10.]).unwrap();
+/// assert_eq!(track.duration(), 10.);
+/// assert_eq!(track.speeds::<Euclidean>(), vec![2., 1.]);
+///
+/// let resampled = track.resample::<Euclidean>(2.5);
+/// assert_eq!(resampled.times(), [0., 2.5, 5., 7.5, 10.]);
+/// assert_eq!(resampled.line_string(), &wkt!(LINESTRING(0. 0.,5. 0.,10. 0.,10. 2.5,10. 5.)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory<T: CoordNum = f64> {
+    line_string: LineString<T>,
+    times: Vec<T>,
+}
+
+impl<T: GeoFloat> Trajectory<T> {
+    /// A trajectory through the coordinates of `line_string`, reached at `times`, which must be
+    /// finite and in non-decreasing order.
+    pub fn try_new(line_string: LineString<T>, times: Vec<T>) -> Result<Self, TrajectoryError> {
+        if line_string.0.len() != times.len() {
+            return Err(TrajectoryError::LengthMismatch {
+                coords: line_string.0.len(),
+                times: times.len(),
+            });
+        }
+        if let Some(index) = times.iter().position(|time| !time.is_finite()) {
+            return Err(TrajectoryError::NonFiniteTime { index });
+        }
+        if let Some(index) = times.windows(2).position(|pair| pair[1] < pair[0]) {
+            return Err(TrajectoryError::DecreasingTime { index: index + 1 });
+        }
+        Ok(Self { line_string, times })
+    }
+
+    /// The path of the trajectory.
+    pub fn line_string(&self) -> &LineString<T> {
+        &self.line_string
+    }
+
+    /// The timestamp of each vertex of the path.
+    pub fn times(&self) -> &[T] {
+        &self.times
+    }
+
+    /// The path and timestamps of the trajectory.
+    pub fn into_inner(self) -> (LineString<T>, Vec<T>) {
+        (self.line_string, self.times)
+    }
+
+    /// The number of samples.
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Whether the trajectory has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    /// The coordinate and timestamp of each sample.
+    pub fn samples(&self) -> impl Iterator<Item = (Coord<T>, T)> + '_ {
+        self.line_string
+            .0
+            .iter()
+            .copied()
+            .zip(self.times.iter().copied())
+    }
+
+    /// The time between the first and the last sample, or zero if there are fewer than two.
+    pub fn duration(&self) -> T {
+        match (self.times.first(), self.times.last()) {
+            (Some(first), Some(last)) => *last - *first,
+            _ => T::zero(),
+        }
+    }
+
+    /// The average speed between each pair of consecutive samples, in units of the metric
+    /// space's distances per unit of time.
+    ///
+    /// The speed between two samples at the same time is zero if they're at the same place, and
+    /// infinite otherwise.
+    pub fn speeds<MetricSpace: Distance<T, Point<T>, Point<T>>>(&self) -> Vec<T> {
+        self.line_string
+            .points()
+            .zip(self.line_string.points().skip(1))
+            .zip(self.times.windows(2))
+            .map(|((start, end), times)| {
+                let distance = MetricSpace::distance(start, end);
+                let duration = times[1] - times[0];
+                if duration > T::zero() {
+                    distance / duration
+                } else if distance > T::zero() {
+                    T::infinity()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect()
+    }
+
+    /// The trajectory sampled every `interval` from its first sample, and at its last sample,
+    /// interpolating between the original samples in the given metric space, e.g. to compare
+    /// tracks recorded at different rates.
+    ///
+    /// # Panics
+    ///
+    /// If `interval` isn't positive.
+    pub fn resample<MetricSpace: InterpolatePoint<T>>(&self, interval: T) -> Trajectory<T> {
+        assert!(interval > T::zero(), "the interval must be positive");
+        let (Some(&first), Some(&last)) = (self.times.first(), self.times.last()) else {
+            return self.clone();
+        };
+        let mut coords = Vec::new();
+        let mut times = Vec::new();
+        let mut segment = 0;
+        for step in 0.. {
+            // multiply rather than accumulate, so that rounding errors don't add up
+            let time = first + interval * T::from(step).unwrap();
+            if time >= last {
+                break;
+            }
+            while self.times[segment + 1] < time {
+                segment += 1;
+            }
+            coords.push(self.interpolate::<MetricSpace>(segment, time).0);
+            times.push(time);
+        }
+        coords.push(self.line_string.0[self.len() - 1]);
+        times.push(last);
+        Trajectory {
+            line_string: LineString::new(coords),
+            times,
+        }
+    }
+
+    /// Where the trajectory was at `time`, interpolated between the samples before and after it
+    /// in the given metric space, e.g. along a straight line with [`Euclidean`](crate::Euclidean)
+    /// or along a great circle with [`Geodesic`](crate::Geodesic), assuming a constant speed
+    /// between samples.
+    ///
+    /// Returns `None` before the first sample, after the last one, and for a NaN `time`. At the
+    /// time of several samples, returns the position of the last of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::trajectory::Trajectory;
+    /// use geo::{point, wkt, Euclidean, Geodesic};
+    ///
+    /// let flight = Trajectory::try_new(wkt!(LINESTRING(-60. 60.,60. 60.)), vec![0., 10.]).unwrap();
+    /// assert_eq!(flight.position_at::<Euclidean>(5.), Some(point!(x: 0., y: 60.)));
+    /// // the great circle passes much closer to the pole
+    /// let position = flight.position_at::<Geodesic>(5.).unwrap();
+    /// assert!(position.y() > 70.);
+    /// assert_eq!(flight.position_at::<Euclidean>(11.), None);
+    /// ```
+    pub fn position_at<MetricSpace: InterpolatePoint<T>>(&self, time: T) -> Option<Point<T>> {
+        let (&first, &last) = (self.times.first()?, self.times.last()?);
+        // also rejects NaN, which would otherwise fall before the first sample
+        if !(time >= first && time <= last) {
+            return None;
+        }
+        let segment = self.times.partition_point(|&sample| sample <= time) - 1;
+        Some(self.interpolate::<MetricSpace>(segment, time))
+    }
+
+    /// The part of the trajectory between `start` and `end`, with positions interpolated as by
+    /// [`Trajectory::position_at`] where they fall between samples, e.g. to extract the part of
+    /// a track within a time window.
+    ///
+    /// The window is clipped to the time of the trajectory. Returns `None` if it's empty, outside
+    /// of the trajectory, or either end is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::trajectory::Trajectory;
+    /// use geo::{wkt, Euclidean};
+    ///
+    /// let track = Trajectory::try_new(wkt!(LINESTRING(0. 0.,10. 0.,10. 10.)), vec![0., 10., 20.]).unwrap();
+    /// let slice = track.slice_between::<Euclidean>(5., 15.).unwrap();
+    /// assert_eq!(slice.line_string(), &wkt!(LINESTRING(5. 0.,10. 0.,10. 5.)));
+    /// assert_eq!(slice.times(), [5., 10., 15.]);
+    /// ```
+    pub fn slice_between<MetricSpace: InterpolatePoint<T>>(
+        &self,
+        start: T,
+        end: T,
+    ) -> Option<Trajectory<T>> {
+        let (&first, &last) = (self.times.first()?, self.times.last()?);
+        // `max` and `min` would replace a NaN with the time of the trajectory
+        if start.is_nan() || end.is_nan() {
+            return None;
+        }
+        let (start, end) = (start.max(first), end.min(last));
+        if start > end {
+            return None;
+        }
+        let inner = self.times.partition_point(|&sample| sample <= start)
+            ..self.times.partition_point(|&sample| sample < end);
+        let mut coords = vec![self.position_at::<MetricSpace>(start)?.0];
+        let mut times = vec![start];
+        if start < end {
+            coords.extend_from_slice(&self.line_string.0[inner.clone()]);
+            times.extend_from_slice(&self.times[inner]);
+            coords.push(self.position_at::<MetricSpace>(end)?.0);
+            times.push(end);
+        }
+        Some(Trajectory {
+            line_string: LineString::new(coords),
+            times,
+        })
+    }
+
+    /// The position at `time` on the segment starting at sample `segment`.
+    fn interpolate<MetricSpace: InterpolatePoint<T>>(&self, segment: usize, time: T) -> Point<T> {
+        let start = Point(self.line_string.0[segment]);
+        let Some(&end) = self.line_string.0.get(segment + 1) else {
+            return start;
+        };
+        let duration = self.times[segment + 1] - self.times[segment];
+        if duration <= T::zero() {
+            return start;
+        }
+        let ratio = (time - self.times[segment]) / duration;
+        MetricSpace::point_at_ratio_between(start, Point(end), ratio)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Euclidean, Geodesic, Haversine};
+
+    #[test]
+    fn invalid() {
+        let line_string = wkt!(LINESTRING(0. 0.,1. 1.,2. 2.));
+        let error = |times: Vec<f64>| Trajectory::try_new(line_string.clone(), times).unwrap_err();
+        assert_eq!(
+            error(vec![0., 1.]),
+            TrajectoryError::LengthMismatch {
+                coords: 3,
+                times: 2
+            }
+        );
+        assert_eq!(
+            error(vec![0., f64::NAN, 2.]),
+            TrajectoryError::NonFiniteTime { index: 1 }
+        );
+        assert_eq!(
+            error(vec![0., 2., 1.]),
+            TrajectoryError::DecreasingTime { index: 2 }
+        );
+        // repeated timestamps are allowed
+        assert!(Trajectory::try_new(line_string, vec![0., 0., 1.]).is_ok());
+    }
+
+    #[test]
+    fn speeds() {
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(0. 0.,3. 4.,3. 4.,4. 4.)),
+            vec![0., 1., 1., 1.],
+        )
+        .unwrap();
+        assert_eq!(track.speeds::<Euclidean>(), vec![5., 0., f64::INFINITY]);
+
+        // a degree of longitude along the equator in an hour
+        let track = Trajectory::try_new(wkt!(LINESTRING(0. 0.,1. 0.)), vec![0., 3600.]).unwrap();
+        assert_relative_eq!(track.speeds::<Haversine>()[0], 30.887, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn resample() {
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(0. 0.,4. 0.,4. 0.,4. 4.)),
+            vec![10., 14., 16., 20.],
+        )
+        .unwrap();
+        let resampled = track.resample::<Euclidean>(3.);
+        assert_eq!(resampled.times(), [10., 13., 16., 19., 20.]);
+        assert_eq!(
+            resampled.line_string(),
+            &wkt!(LINESTRING(0. 0.,3. 0.,4. 0.,4. 3.,4. 4.))
+        );
+
+        let single = Trajectory::try_new(wkt!(LINESTRING(1. 1.)), vec![5.]).unwrap();
+        assert_eq!(single.resample::<Euclidean>(1.), single);
+        let empty = Trajectory::<f64>::try_new(LineString::new(vec![]), vec![]).unwrap();
+        assert!(empty.resample::<Euclidean>(1.).is_empty());
+    }
+
+    #[test]
+    fn position_at() {
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(0. 0.,0. 10.,5. 10.,5. 20.)),
+            vec![0., 10., 10., 20.],
+        )
+        .unwrap();
+        assert_eq!(
+            track.position_at::<Euclidean>(0.),
+            Some(point!(x: 0., y: 0.))
+        );
+        assert_eq!(
+            track.position_at::<Euclidean>(2.5),
+            Some(point!(x: 0., y: 2.5))
+        );
+        // the later of two samples at the same time
+        assert_eq!(
+            track.position_at::<Euclidean>(10.),
+            Some(point!(x: 5., y: 10.))
+        );
+        assert_eq!(
+            track.position_at::<Euclidean>(20.),
+            Some(point!(x: 5., y: 20.))
+        );
+        assert_eq!(track.position_at::<Euclidean>(-1.), None);
+        assert_eq!(track.position_at::<Euclidean>(20.5), None);
+        assert_eq!(track.position_at::<Euclidean>(f64::NAN), None);
+
+        // along the equator, the great circle is the straight line
+        let equator = Trajectory::try_new(wkt!(LINESTRING(0. 0.,90. 0.)), vec![0., 1.]).unwrap();
+        let position = equator.position_at::<Geodesic>(0.5).unwrap();
+        assert_relative_eq!(position, point!(x: 45., y: 0.), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn slice_between() {
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(0. 0.,10. 0.,10. 10.,0. 10.)),
+            vec![0., 10., 20., 30.],
+        )
+        .unwrap();
+        // ending on a sample
+        let slice = track.slice_between::<Euclidean>(15., 30.).unwrap();
+        assert_eq!(
+            slice.line_string(),
+            &wkt!(LINESTRING(10. 5.,10. 10.,0. 10.))
+        );
+        assert_eq!(slice.times(), [15., 20., 30.]);
+        // clipped to the trajectory
+        assert_eq!(track.slice_between::<Euclidean>(-10., 40.).unwrap(), track);
+        let slice = track.slice_between::<Euclidean>(-10., 5.).unwrap();
+        assert_eq!(slice.times(), [0., 5.]);
+        // an instant
+        let slice = track.slice_between::<Euclidean>(12., 12.).unwrap();
+        assert_eq!(slice.line_string(), &wkt!(LINESTRING(10. 2.)));
+        assert_eq!(track.slice_between::<Euclidean>(31., 40.), None);
+        assert_eq!(track.slice_between::<Euclidean>(20., 10.), None);
+        assert_eq!(track.slice_between::<Euclidean>(f64::NAN, 10.), None);
+        assert_eq!(track.slice_between::<Euclidean>(0., f64::NAN), None);
+    }
+}
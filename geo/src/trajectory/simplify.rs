@@ -0,0 +1,108 @@
+use super::Trajectory;
+use crate::line_measures::Distance;
+use crate::{Euclidean, GeoFloat, LineString, SimplifyIdx};
+
+impl<T: GeoFloat> Trajectory<T> {
+    /// The trajectory through a subset of its samples, chosen with the
+    /// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm, as by [`SimplifyIdx`], which keeps the path within `epsilon` of the original
+    /// one. The kept samples keep their timestamps, but the speeds along the path may change.
+    pub fn simplify(&self, epsilon: T) -> Trajectory<T> {
+        self.select(self.line_string.simplify_idx(&epsilon))
+    }
+
+    /// The trajectory through a subset of its samples, chosen such that each removed sample is
+    /// within `epsilon` of where the simplified trajectory is at the same time, in Euclidean
+    /// space.
+    ///
+    /// This is the top-down time-ratio variant of the Ramer–Douglas–Peucker algorithm. Unlike
+    /// [`Trajectory::simplify`], it keeps the samples where the speed changes, e.g. the stops
+    /// along a straight road.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::trajectory::Trajectory;
+    /// use geo::wkt;
+    ///
+    /// // a stop half way along a straight road
+    /// let track = Trajectory::try_new(
+    ///     wkt!(LINESTRING(0. 0.,5. 0.,5. 0.,10. 0.)),
+    ///     vec![0., 5., 15., 20.],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(track.simplify(0.1).len(), 2);
+    /// assert_eq!(track.simplify_synchronized(0.1).times(), [0., 5., 15., 20.]);
+    /// ```
+    pub fn simplify_synchronized(&self, epsilon: T) -> Trajectory<T> {
+        if self.len() < 3 {
+            return self.clone();
+        }
+        let mut kept = vec![0, self.len() - 1];
+        let mut stack = vec![(0, self.len() - 1)];
+        while let Some((first, last)) = stack.pop() {
+            let farthest = (first + 1..last)
+                .map(|index| (index, self.synchronized_distance(first, last, index)))
+                .fold(
+                    None,
+                    |farthest: Option<(usize, T)>, (index, distance)| match farthest {
+                        Some((_, max)) if max >= distance => farthest,
+                        _ => Some((index, distance)),
+                    },
+                );
+            if let Some((index, distance)) = farthest {
+                if distance > epsilon {
+                    kept.push(index);
+                    stack.push((first, index));
+                    stack.push((index, last));
+                }
+            }
+        }
+        kept.sort_unstable();
+        self.select(kept)
+    }
+
+    /// The distance from sample `index` to where a straight, constant speed trip from sample
+    /// `first` to sample `last` would be at the same time.
+    fn synchronized_distance(&self, first: usize, last: usize, index: usize) -> T {
+        let coords = &self.line_string.0;
+        let duration = self.times[last] - self.times[first];
+        let ratio = if duration > T::zero() {
+            (self.times[index] - self.times[first]) / duration
+        } else {
+            T::zero()
+        };
+        let synchronized = coords[first] + (coords[last] - coords[first]) * ratio;
+        Euclidean::distance(synchronized, coords[index])
+    }
+
+    fn select(&self, indices: Vec<usize>) -> Trajectory<T> {
+        Trajectory {
+            line_string: LineString::new(indices.iter().map(|&i| self.line_string.0[i]).collect()),
+            times: indices.iter().map(|&i| self.times[i]).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn synchronized() {
+        // constant speed along a straight line, then slower, with a small wiggle
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(0. 0.,1. 0.,2. 0.,3. 0.05,3.5 0.,4. 0.)),
+            vec![0., 1., 2., 4., 5., 6.],
+        )
+        .unwrap();
+        let simplified = track.simplify_synchronized(0.1);
+        assert_eq!(simplified.times(), [0., 2., 6.]);
+        assert_eq!(
+            simplified.line_string(),
+            &wkt!(LINESTRING(0. 0.,2. 0.,4. 0.))
+        );
+        assert_eq!(track.simplify(0.1).times(), [0., 6.]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+use super::Trajectory;
+use crate::line_measures::Distance;
+use crate::{Coord, CoordNum, GeoFloat, Point};
+
+/// A place where a [`Trajectory`] stayed, from [`Trajectory::stay_points`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StayPoint<T: CoordNum = f64> {
+    /// The mean position of the samples during the stay.
+    pub location: Point<T>,
+    /// The time of the first sample of the stay.
+    pub arrival: T,
+    /// The time of the last sample of the stay.
+    pub departure: T,
+    /// The indices of the samples of the stay.
+    pub samples: Range<usize>,
+}
+
+impl<T: GeoFloat> Trajectory<T> {
+    /// The places where the trajectory stayed within `max_distance` for at least
+    /// `min_duration`, e.g. the stops of a delivery vehicle, in order.
+    ///
+    /// A stay starts at a sample, and lasts as long as the following samples are within
+    /// `max_distance` of it, in the given metric space. Stays don't overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::trajectory::Trajectory;
+    /// use geo::{point, wkt, Euclidean};
+    ///
+    /// let track = Trajectory::try_new(
+    ///     wkt!(LINESTRING(0. 0.,10. 0.,10. 1.,11. 0.,20. 0.)),
+    ///     vec![0., 10., 100., 200., 210.],
+    /// )
+    /// .unwrap();
+    /// let stays = track.stay_points::<Euclidean>(2., 60.);
+    /// assert_eq!(stays.len(), 1);
+    /// assert_eq!(stays[0].samples, 1..4);
+    /// assert_eq!((stays[0].arrival, stays[0].departure), (10., 200.));
+    /// ```
+    pub fn stay_points<MetricSpace: Distance<T, Point<T>, Point<T>>>(
+        &self,
+        max_distance: T,
+        min_duration: T,
+    ) -> Vec<StayPoint<T>> {
+        let points: Vec<Point<T>> = self.line_string.points().collect();
+        let mut stays = Vec::new();
+        let mut start = 0;
+        while start < points.len() {
+            let end = (start + 1..points.len())
+                .find(|&index| MetricSpace::distance(points[start], points[index]) > max_distance)
+                .unwrap_or(points.len());
+            if self.times[end - 1] - self.times[start] < min_duration {
+                start += 1;
+                continue;
+            }
+            let count = T::from(end - start).unwrap();
+            let sum = points[start..end]
+                .iter()
+                .fold(Coord::zero(), |sum, point| sum + point.0);
+            stays.push(StayPoint {
+                location: Point(sum / count),
+                arrival: self.times[start],
+                departure: self.times[end - 1],
+                samples: start..end,
+            });
+            start = end;
+        }
+        stays
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Haversine};
+
+    #[test]
+    fn stays() {
+        // two stops, a few metres across, in a long walk
+        let track = Trajectory::try_new(
+            wkt!(LINESTRING(
+                0. 0.,
+                0.01 0.,0.01001 0.,0.01 0.00001,
+                0.02 0.,
+                0.03 0.,0.03001 0.00001
+            )),
+            vec![0., 600., 700., 1200., 1800., 2400., 2700.],
+        )
+        .unwrap();
+        let stays = track.stay_points::<Haversine>(10., 300.);
+        assert_eq!(stays.len(), 2);
+        assert_eq!(stays[0].samples, 1..4);
+        assert_relative_eq!(
+            stays[0].location,
+            point!(x: 0.0100033, y: 0.0000033),
+            epsilon = 1e-7
+        );
+        assert_eq!((stays[1].arrival, stays[1].departure), (2400., 2700.));
+
+        // too short
+        assert_eq!(track.stay_points::<Haversine>(10., 600.).len(), 1);
+    }
+}
@@ -0,0 +1,660 @@
+//! Read and write geometries as [Tiny WKB] (TWKB).
+//!
+//! TWKB is a compact binary format for transferring geometries over a network. Coordinates are
+//! rounded to a fixed number of decimal places, and each one is stored as the variable-length
+//! difference from the previous one, so nearby vertices take only a few bytes.
+//!
+//! `geo` geometries are two dimensional, so Z and M values are discarded when reading.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::twkb::{read_twkb, write_twkb, TwkbWriteOptions};
+//! use geo::{wkt, Geometry};
+//!
+//! let line_string = wkt!(LINESTRING(-71.0589 42.3601,-71.0598 42.3612));
+//! let options = TwkbWriteOptions {
+//!     precision: 4,
+//!     ..Default::default()
+//! };
+//! let twkb = write_twkb(&line_string.clone().into(), &options);
+//! assert_eq!(twkb.len(), 11);
+//!
+//! approx::assert_relative_eq!(read_twkb(&twkb).unwrap(), Geometry::LineString(line_string));
+//! ```
+//!
+//! [Tiny WKB]: https://github.com/TWKB/Specification/blob/master/twkb.md
+
+use std::fmt;
+
+use crate::{
+    BoundingRect, Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, MAX_COLLECTION_DEPTH,
+};
+
+const POINT: u8 = 1;
+const LINE_STRING: u8 = 2;
+const POLYGON: u8 = 3;
+const MULTI_POINT: u8 = 4;
+const MULTI_LINE_STRING: u8 = 5;
+const MULTI_POLYGON: u8 = 6;
+const GEOMETRY_COLLECTION: u8 = 7;
+
+const HAS_BBOX: u8 = 0x01;
+const HAS_SIZE: u8 = 0x02;
+const HAS_IDS: u8 = 0x04;
+const HAS_EXTENDED_DIMS: u8 = 0x08;
+const IS_EMPTY: u8 = 0x10;
+
+/// Options for [`write_twkb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TwkbWriteOptions {
+    /// The number of decimal places to keep, between -7 and 7. Negative values round to tens,
+    /// hundreds, etc.
+    pub precision: i8,
+    /// Write the bounding box of the geometry, so that it can be read without decoding the
+    /// coordinates.
+    pub bbox: bool,
+    /// Write the size of the geometry in bytes, so that readers can skip over it.
+    pub size: bool,
+}
+
+impl Default for TwkbWriteOptions {
+    /// Round to whole numbers, without a bounding box or size.
+    fn default() -> Self {
+        Self {
+            precision: 0,
+            bbox: false,
+            size: false,
+        }
+    }
+}
+
+/// An error returned when reading invalid or unsupported TWKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwkbError {
+    /// The input ended in the middle of a geometry.
+    UnexpectedEof,
+    /// A variable length integer was longer than 64 bits.
+    InvalidVarint,
+    /// The geometry type isn't one of the 7 basic Simple Features types.
+    UnsupportedGeometryType(u8),
+    /// There were bytes left over after the geometry.
+    TrailingBytes,
+    /// Geometry collections were nested more than 128 deep.
+    TooDeeplyNested,
+    /// Adding up the deltas of a coordinate overflowed a 64 bit integer.
+    Overflow,
+}
+
+impl fmt::Display for TwkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwkbError::UnexpectedEof => write!(f, "unexpected end of TWKB"),
+            TwkbError::InvalidVarint => write!(f, "invalid TWKB variable length integer"),
+            TwkbError::UnsupportedGeometryType(code) => {
+                write!(f, "unsupported TWKB geometry type: {code}")
+            }
+            TwkbError::TrailingBytes => write!(f, "unexpected bytes after TWKB geometry"),
+            TwkbError::TooDeeplyNested => write!(f, "TWKB geometry collections nested too deeply"),
+            TwkbError::Overflow => write!(f, "TWKB coordinate overflowed a 64 bit integer"),
+        }
+    }
+}
+
+impl std::error::Error for TwkbError {}
+
+/// Read a geometry from TWKB, ignoring any id list.
+///
+/// An empty point is read as an empty [`MultiPoint`], since [`Point`] can't be empty.
+pub fn read_twkb(buf: &[u8]) -> Result<Geometry, TwkbError> {
+    read_twkb_with_ids(buf).map(|(geometry, _)| geometry)
+}
+
+/// Read a geometry from TWKB, along with the ids of the members of a multi-geometry or geometry
+/// collection, if they were written.
+pub fn read_twkb_with_ids(buf: &[u8]) -> Result<(Geometry, Option<Vec<i64>>), TwkbError> {
+    let mut reader = Reader { buf, pos: 0 };
+    let result = reader.geometry(0)?;
+    if reader.pos != buf.len() {
+        return Err(TwkbError::TrailingBytes);
+    }
+    Ok(result)
+}
+
+/// Write a geometry as TWKB.
+///
+/// A [`Line`](crate::Line) is written as a `LineString`, and a [`Rect`](crate::Rect) or
+/// [`Triangle`](crate::Triangle) as a `Polygon`.
+///
+/// # Panics
+///
+/// If `options.precision` isn't between -7 and 7.
+pub fn write_twkb(geometry: &Geometry, options: &TwkbWriteOptions) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geometry, None, options);
+    buf
+}
+
+/// Write a multi-geometry or geometry collection as TWKB, with an id for each of its members.
+///
+/// # Panics
+///
+/// If `geometry` isn't a multi-geometry or geometry collection, if `ids` doesn't have one id for
+/// each of its members, or if `options.precision` isn't between -7 and 7.
+pub fn write_twkb_with_ids(
+    geometry: &Geometry,
+    ids: &[i64],
+    options: &TwkbWriteOptions,
+) -> Vec<u8> {
+    let members = match geometry {
+        Geometry::MultiPoint(g) => g.0.len(),
+        Geometry::MultiLineString(g) => g.0.len(),
+        Geometry::MultiPolygon(g) => g.0.len(),
+        Geometry::GeometryCollection(g) => g.0.len(),
+        _ => panic!("only multi-geometries and geometry collections have ids"),
+    };
+    assert_eq!(members, ids.len(), "expected one id per member");
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geometry, Some(ids), options);
+    buf
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_signed(buf: &mut Vec<u8>, value: i64) {
+    write_varint(buf, zigzag(value));
+}
+
+fn write_geometry(
+    buf: &mut Vec<u8>,
+    geometry: &Geometry,
+    ids: Option<&[i64]>,
+    options: &TwkbWriteOptions,
+) {
+    assert!(
+        (-7..=7).contains(&options.precision),
+        "TWKB precision must be between -7 and 7"
+    );
+    let (code, empty) = match geometry {
+        Geometry::Point(_) => (POINT, false),
+        Geometry::Line(_) => (LINE_STRING, false),
+        Geometry::LineString(g) => (LINE_STRING, g.0.is_empty()),
+        Geometry::Polygon(g) => (POLYGON, g.exterior().0.is_empty()),
+        Geometry::MultiPoint(g) => (MULTI_POINT, g.0.is_empty()),
+        Geometry::MultiLineString(g) => (MULTI_LINE_STRING, g.0.is_empty()),
+        Geometry::MultiPolygon(g) => (MULTI_POLYGON, g.0.is_empty()),
+        Geometry::GeometryCollection(g) => (GEOMETRY_COLLECTION, g.0.is_empty()),
+        Geometry::Rect(_) | Geometry::Triangle(_) => (POLYGON, false),
+    };
+    buf.push(code | (zigzag(options.precision.into()) as u8) << 4);
+
+    let bbox = options.bbox && !empty && code != POINT;
+    let mut metadata = 0;
+    if bbox {
+        metadata |= HAS_BBOX;
+    }
+    if options.size {
+        metadata |= HAS_SIZE;
+    }
+    if ids.is_some() && !empty {
+        metadata |= HAS_IDS;
+    }
+    if empty {
+        metadata |= IS_EMPTY;
+    }
+    buf.push(metadata);
+
+    let mut writer = Writer {
+        buf: Vec::new(),
+        scale: 10f64.powi(options.precision.into()),
+        prev: [0, 0],
+    };
+    if bbox {
+        let rect = geometry.bounding_rect().expect("geometry isn't empty");
+        let min = writer.scaled(rect.min());
+        let max = writer.scaled(rect.max());
+        for (min, max) in min.into_iter().zip(max) {
+            write_signed(&mut writer.buf, min);
+            write_signed(&mut writer.buf, max - min);
+        }
+    }
+    if !empty {
+        writer.body(geometry, ids, options);
+    }
+    if options.size {
+        write_varint(buf, writer.buf.len() as u64);
+    }
+    buf.extend(writer.buf);
+}
+
+/// Writes the body of a geometry, after its header and bounding box.
+struct Writer {
+    buf: Vec<u8>,
+    scale: f64,
+    /// The previous coordinate, which the next one is written relative to
+    prev: [i64; 2],
+}
+
+impl Writer {
+    fn scaled(&self, coord: Coord) -> [i64; 2] {
+        [
+            (coord.x * self.scale).round() as i64,
+            (coord.y * self.scale).round() as i64,
+        ]
+    }
+
+    fn len(&mut self, len: usize) {
+        write_varint(&mut self.buf, len as u64);
+    }
+
+    fn ids(&mut self, ids: Option<&[i64]>) {
+        for &id in ids.into_iter().flatten() {
+            write_signed(&mut self.buf, id);
+        }
+    }
+
+    fn coord(&mut self, coord: Coord) {
+        let scaled = self.scaled(coord);
+        for (value, prev) in scaled.into_iter().zip(self.prev) {
+            write_signed(&mut self.buf, value - prev);
+        }
+        self.prev = scaled;
+    }
+
+    fn coords(&mut self, coords: &[Coord]) {
+        self.len(coords.len());
+        for &coord in coords {
+            self.coord(coord);
+        }
+    }
+
+    fn rings(&mut self, polygon: &Polygon) {
+        if polygon.exterior().0.is_empty() {
+            self.len(0);
+            return;
+        }
+        self.len(1 + polygon.interiors().len());
+        self.coords(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.coords(&interior.0);
+        }
+    }
+
+    fn body(&mut self, geometry: &Geometry, ids: Option<&[i64]>, options: &TwkbWriteOptions) {
+        match geometry {
+            Geometry::Point(g) => self.coord(g.0),
+            Geometry::Line(g) => self.coords(&[g.start, g.end]),
+            Geometry::LineString(g) => self.coords(&g.0),
+            Geometry::Polygon(g) => self.rings(g),
+            Geometry::MultiPoint(g) => {
+                self.len(g.0.len());
+                self.ids(ids);
+                for point in g {
+                    self.coord(point.0);
+                }
+            }
+            Geometry::MultiLineString(g) => {
+                self.len(g.0.len());
+                self.ids(ids);
+                for line_string in g {
+                    self.coords(&line_string.0);
+                }
+            }
+            Geometry::MultiPolygon(g) => {
+                self.len(g.0.len());
+                self.ids(ids);
+                for polygon in g {
+                    self.rings(polygon);
+                }
+            }
+            Geometry::GeometryCollection(g) => {
+                self.len(g.0.len());
+                self.ids(ids);
+                for geometry in g {
+                    write_geometry(&mut self.buf, geometry, None, options);
+                }
+            }
+            Geometry::Rect(g) => self.rings(&g.to_polygon()),
+            Geometry::Triangle(g) => self.rings(&g.to_polygon()),
+        }
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+/// The layout of the coordinates of a geometry being read.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    dims: usize,
+    scale: f64,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, TwkbError> {
+        let byte = *self.buf.get(self.pos).ok_or(TwkbError::UnexpectedEof)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<u64, TwkbError> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(TwkbError::InvalidVarint)
+    }
+
+    fn signed(&mut self) -> Result<i64, TwkbError> {
+        self.varint().map(unzigzag)
+    }
+
+    fn len(&mut self) -> Result<usize, TwkbError> {
+        let len = self.varint()?;
+        // every element takes at least one byte, so larger lengths are certainly truncated
+        if len > (self.buf.len() - self.pos) as u64 {
+            return Err(TwkbError::UnexpectedEof);
+        }
+        Ok(len as usize)
+    }
+
+    fn ids(&mut self, has_ids: bool, len: usize) -> Result<Option<Vec<i64>>, TwkbError> {
+        if !has_ids {
+            return Ok(None);
+        }
+        (0..len)
+            .map(|_| self.signed())
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    fn coord(&mut self, layout: Layout, prev: &mut [i64; 4]) -> Result<Coord, TwkbError> {
+        for value in &mut prev[..layout.dims] {
+            *value = value
+                .checked_add(self.signed()?)
+                .ok_or(TwkbError::Overflow)?;
+        }
+        Ok(Coord {
+            x: prev[0] as f64 / layout.scale,
+            y: prev[1] as f64 / layout.scale,
+        })
+    }
+
+    fn line_string(
+        &mut self,
+        layout: Layout,
+        prev: &mut [i64; 4],
+    ) -> Result<LineString, TwkbError> {
+        let len = self.len()?;
+        (0..len)
+            .map(|_| self.coord(layout, prev))
+            .collect::<Result<_, _>>()
+            .map(LineString)
+    }
+
+    fn polygon(&mut self, layout: Layout, prev: &mut [i64; 4]) -> Result<Polygon, TwkbError> {
+        let len = self.len()?;
+        let mut rings = (0..len)
+            .map(|_| self.line_string(layout, prev))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    /// Read a geometry, which is nested in `depth` geometry collections.
+    fn geometry(&mut self, depth: usize) -> Result<(Geometry, Option<Vec<i64>>), TwkbError> {
+        let header = self.byte()?;
+        let code = header & 0x0f;
+        let precision = unzigzag((header >> 4).into());
+        let metadata = self.byte()?;
+        let mut dims = 2;
+        if metadata & HAS_EXTENDED_DIMS != 0 {
+            let extended = self.byte()?;
+            dims += usize::from(extended & 0x01) + usize::from(extended >> 1 & 0x01);
+        }
+        if metadata & HAS_SIZE != 0 {
+            self.varint()?;
+        }
+        if metadata & HAS_BBOX != 0 {
+            for _ in 0..2 * dims {
+                self.varint()?;
+            }
+        }
+        let empty = metadata & IS_EMPTY != 0;
+        let has_ids = metadata & HAS_IDS != 0;
+        let layout = Layout {
+            dims,
+            scale: 10f64.powi(precision as i32),
+        };
+        if code == GEOMETRY_COLLECTION && depth == MAX_COLLECTION_DEPTH {
+            return Err(TwkbError::TooDeeplyNested);
+        }
+        let mut prev = [0; 4];
+        let mut ids = None;
+        let geometry = match code {
+            POINT if empty => Geometry::MultiPoint(MultiPoint::new(vec![])),
+            POINT => Geometry::Point(Point(self.coord(layout, &mut prev)?)),
+            LINE_STRING if empty => Geometry::LineString(LineString::new(vec![])),
+            LINE_STRING => Geometry::LineString(self.line_string(layout, &mut prev)?),
+            POLYGON if empty => Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![])),
+            POLYGON => Geometry::Polygon(self.polygon(layout, &mut prev)?),
+            MULTI_POINT if empty => Geometry::MultiPoint(MultiPoint::new(vec![])),
+            MULTI_POINT => {
+                let len = self.len()?;
+                ids = self.ids(has_ids, len)?;
+                let points = (0..len)
+                    .map(|_| self.coord(layout, &mut prev).map(Point))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPoint(MultiPoint::new(points))
+            }
+            MULTI_LINE_STRING if empty => Geometry::MultiLineString(MultiLineString::new(vec![])),
+            MULTI_LINE_STRING => {
+                let len = self.len()?;
+                ids = self.ids(has_ids, len)?;
+                let line_strings = (0..len)
+                    .map(|_| self.line_string(layout, &mut prev))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString::new(line_strings))
+            }
+            MULTI_POLYGON if empty => Geometry::MultiPolygon(MultiPolygon::new(vec![])),
+            MULTI_POLYGON => {
+                let len = self.len()?;
+                ids = self.ids(has_ids, len)?;
+                let polygons = (0..len)
+                    .map(|_| self.polygon(layout, &mut prev))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon::new(polygons))
+            }
+            GEOMETRY_COLLECTION if empty => {
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![]))
+            }
+            GEOMETRY_COLLECTION => {
+                let len = self.len()?;
+                ids = self.ids(has_ids, len)?;
+                let geometries = (0..len)
+                    .map(|_| self.geometry(depth + 1).map(|(geometry, _)| geometry))
+                    .collect::<Result<_, _>>()?;
+                Geometry::GeometryCollection(GeometryCollection::new_from(geometries))
+            }
+            _ => return Err(TwkbError::UnsupportedGeometryType(code)),
+        };
+        Ok((geometry, ids))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Rect};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Expected values are from PostGIS' ST_AsTWKB.
+
+    #[test]
+    fn point() {
+        // ST_AsTWKB('POINT(1 2)')
+        let twkb = hex("01000204");
+        let point = Geometry::Point(point!(x: 1., y: 2.));
+        assert_eq!(read_twkb(&twkb).unwrap(), point);
+        assert_eq!(write_twkb(&point, &TwkbWriteOptions::default()), twkb);
+    }
+
+    #[test]
+    fn line_string_deltas() {
+        // ST_AsTWKB('LINESTRING(1 1,5 5)')
+        let twkb = hex("02000202020808");
+        let line_string = Geometry::LineString(wkt!(LINESTRING(1. 1.,5. 5.)));
+        assert_eq!(read_twkb(&twkb).unwrap(), line_string);
+        assert_eq!(write_twkb(&line_string, &TwkbWriteOptions::default()), twkb);
+    }
+
+    #[test]
+    fn precision() {
+        let point = Geometry::Point(point!(x: 1.2345, y: -6.789));
+        for precision in [-1, 0, 2, 5] {
+            let options = TwkbWriteOptions {
+                precision,
+                ..Default::default()
+            };
+            let read = read_twkb(&write_twkb(&point, &options)).unwrap();
+            let scale = 10f64.powi(precision.into());
+            let Geometry::Point(read) = read else {
+                panic!("expected a point")
+            };
+            assert_eq!(read.x(), (1.2345 * scale).round() / scale);
+            assert_eq!(read.y(), (-6.789 * scale).round() / scale);
+        }
+    }
+
+    #[test]
+    fn bbox_and_size() {
+        let polygon: Geometry =
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.))).into();
+        let plain = write_twkb(&polygon, &TwkbWriteOptions::default());
+        let options = TwkbWriteOptions {
+            precision: 0,
+            bbox: true,
+            size: true,
+        };
+        let twkb = write_twkb(&polygon, &options);
+        assert_eq!(twkb[1], HAS_BBOX | HAS_SIZE);
+        // the size counts the bounding box and body
+        assert_eq!(twkb[2] as usize, twkb.len() - 3);
+        // xmin 0, dx 4, ymin 0, dy 4
+        assert_eq!(twkb[3..7], [0, 8, 0, 8]);
+        assert_eq!(twkb[7..], plain[2..]);
+        assert_eq!(read_twkb(&twkb).unwrap(), polygon);
+    }
+
+    #[test]
+    fn ids() {
+        let multi_point: Geometry = wkt!(MULTIPOINT(0. 0.,1. 1.)).into();
+        let twkb = write_twkb_with_ids(&multi_point, &[10, -3], &TwkbWriteOptions::default());
+        assert_eq!(
+            read_twkb_with_ids(&twkb).unwrap(),
+            (multi_point.clone(), Some(vec![10, -3]))
+        );
+        assert_eq!(
+            read_twkb_with_ids(&write_twkb(&multi_point, &TwkbWriteOptions::default())).unwrap(),
+            (multi_point, None)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. -3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.)))).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 2.).into(),
+                wkt!(LINESTRING(3. 4.,5. 6.)).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+            wkt!(LINESTRING EMPTY).into(),
+            Polygon::new(LineString::new(vec![]), vec![]).into(),
+        ];
+        let options = TwkbWriteOptions {
+            precision: 0,
+            bbox: true,
+            size: true,
+        };
+        for geometry in geometries {
+            assert_eq!(
+                read_twkb(&write_twkb(&geometry, &options)).unwrap(),
+                geometry
+            );
+        }
+
+        let rect = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(
+            read_twkb(&write_twkb(&rect.into(), &options)).unwrap(),
+            Geometry::Polygon(rect.to_polygon())
+        );
+    }
+
+    #[test]
+    fn extended_dims_are_dropped() {
+        // ST_AsTWKB('POINT Z (1 2 3)'): extended dims byte 0x01 (has z, z precision 0)
+        let twkb = hex("010801020406");
+        assert_eq!(
+            read_twkb(&twkb).unwrap(),
+            Geometry::Point(point!(x: 1., y: 2.))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(read_twkb(&hex("0100")), Err(TwkbError::UnexpectedEof));
+        assert_eq!(read_twkb(&hex("01000204FF")), Err(TwkbError::TrailingBytes));
+        assert_eq!(
+            read_twkb(&hex("0800")),
+            Err(TwkbError::UnsupportedGeometryType(8))
+        );
+        assert_eq!(read_twkb(&hex("0200FF")), Err(TwkbError::UnexpectedEof));
+        // LINESTRING whose x deltas are both i64::MAX
+        assert_eq!(
+            read_twkb(&hex("020002FEFFFFFFFFFFFFFFFF0100FEFFFFFFFFFFFFFFFF0100")),
+            Err(TwkbError::Overflow)
+        );
+    }
+
+    #[test]
+    fn nesting_limit() {
+        use geo_test_fixtures::nested_collections_twkb as nested;
+
+        assert!(read_twkb(&nested(MAX_COLLECTION_DEPTH)).is_ok());
+        assert_eq!(
+            read_twkb(&nested(MAX_COLLECTION_DEPTH + 1)),
+            Err(TwkbError::TooDeeplyNested)
+        );
+        assert_eq!(read_twkb(&nested(200_000)), Err(TwkbError::TooDeeplyNested));
+    }
+}
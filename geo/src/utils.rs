@@ -162,6 +162,63 @@ pub fn normalize_longitude<T: CoordFloat + FromPrimitive>(coord: T) -> T {
     ((coord + five_forty) % three_sixty) - one_eighty
 }
 
+/// Collections with at least this many members are processed in parallel by the algorithms
+/// which support the `parallel` feature. For smaller collections, handing the work to the thread
+/// pool costs more than it saves.
+#[cfg(feature = "parallel")]
+pub const PARALLEL_MIN_MEMBERS: usize = 32;
+
+/// Map each of `members` to a result, and combine the results starting from `identity`.
+///
+/// With the `parallel` feature, collections of at least `PARALLEL_MIN_MEMBERS` are processed
+/// with rayon, so `combine` should be associative. Floating point sums may differ in the last
+/// bits from the sequential result.
+pub fn fold_members<G, R>(
+    members: &[G],
+    identity: R,
+    map: impl Fn(&G) -> R + Sync + Send,
+    combine: impl Fn(R, R) -> R + Sync + Send,
+) -> R
+where
+    G: Sync,
+    R: Copy + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if members.len() >= PARALLEL_MIN_MEMBERS {
+        use rayon::prelude::*;
+        return members.par_iter().map(&map).reduce(|| identity, &combine);
+    }
+    members.iter().map(map).fold(identity, combine)
+}
+
+/// Map each of `members` to a result, in order.
+///
+/// With the `parallel` feature, collections of at least `PARALLEL_MIN_MEMBERS` are processed
+/// with rayon.
+pub fn map_members<G, R>(members: &[G], map: impl Fn(&G) -> R + Sync + Send) -> Vec<R>
+where
+    G: Sync,
+    R: Send,
+{
+    #[cfg(feature = "parallel")]
+    if members.len() >= PARALLEL_MIN_MEMBERS {
+        use rayon::prelude::*;
+        return members.par_iter().map(map).collect();
+    }
+    members.iter().map(map).collect()
+}
+
+/// Operations which report their progress process this many items between calls to the progress
+/// callback, so that reporting doesn't slow them down.
+pub const PROGRESS_CHUNK_LEN: usize = 1 << 16;
+
+/// Split `len` items into the ranges processed between progress reports.
+pub fn progress_chunks(len: usize) -> impl Iterator<Item = std::ops::Range<usize>> {
+    (0..len)
+        .step_by(PROGRESS_CHUNK_LEN)
+        .map(move |start| start..len.min(start + PROGRESS_CHUNK_LEN))
+}
+
 #[cfg(test)]
 mod test {
     use super::{partial_max, partial_min};
@@ -0,0 +1,956 @@
+//! Read and write geometries as [Well-Known Binary] (WKB).
+//!
+//! Both the ISO flavour of WKB, which marks Z and M coordinates by adding 1000, 2000 or 3000 to
+//! the geometry type, and PostGIS's [Extended WKB] (EWKB), which marks them with high bits of the
+//! geometry type and can embed an SRID, are read. Either byte order is accepted, and may change
+//! between nested geometries.
+//!
+//! `geo` geometries are two dimensional, so Z and M values are discarded when reading. Written
+//! WKB is always two dimensional, and is only EWKB if an SRID is requested.
+//!
+//! To filter many WKB geometries by their coordinates or bounding rectangle, use [`LazyWkb`],
+//! which indexes a geometry without decoding it.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::wkb::{read_ewkb, read_wkb, write_wkb, WkbWriteOptions};
+//! use geo::{point, Crs, Geometry};
+//!
+//! let point = Geometry::Point(point!(x: 1., y: 2.));
+//!
+//! let wkb = write_wkb(&point, &WkbWriteOptions::default());
+//! assert_eq!(read_wkb(&wkb).unwrap(), point);
+//!
+//! let options = WkbWriteOptions {
+//!     srid: Some(4326),
+//!     ..Default::default()
+//! };
+//! let ewkb = write_wkb(&point, &options);
+//! let georeferenced = read_ewkb(&ewkb).unwrap();
+//! assert_eq!(georeferenced.crs(), Some(&Crs::WGS84));
+//! ```
+//!
+//! [Well-Known Binary]: https://libgeos.org/specifications/wkb/
+//! [Extended WKB]: https://libgeos.org/specifications/wkb/#extended-wkb
+
+use std::fmt;
+
+use crate::utils::{partial_max, partial_min};
+use crate::{
+    coord, BoundingRect, Coord, Crs, GeoReferenced, Geometry, GeometryCollection, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, MAX_COLLECTION_DEPTH,
+};
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+/// The byte order of WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endianness {
+    /// Most significant byte first, also known as XDR.
+    BigEndian,
+    /// Least significant byte first, also known as NDR. This is the native order of most
+    /// platforms.
+    #[default]
+    LittleEndian,
+}
+
+/// Options for [`write_wkb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WkbWriteOptions {
+    /// The byte order to write.
+    pub endianness: Endianness,
+    /// Write EWKB with this SRID embedded in the outermost geometry.
+    pub srid: Option<u32>,
+}
+
+/// An error returned when reading invalid or unsupported WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WkbError {
+    /// The input ended in the middle of a geometry.
+    UnexpectedEof,
+    /// A byte order marker was neither 0 (big endian) nor 1 (little endian).
+    InvalidByteOrder(u8),
+    /// The geometry type isn't one of the 7 basic Simple Features types.
+    UnsupportedGeometryType(u32),
+    /// A geometry other than a point was found in a multi point, or similarly for other
+    /// multi-geometries.
+    UnexpectedGeometryType { expected: u32, found: u32 },
+    /// There were bytes left over after the geometry.
+    TrailingBytes,
+    /// Geometry collections were nested more than 128 deep.
+    TooDeeplyNested,
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEof => write!(f, "unexpected end of WKB"),
+            WkbError::InvalidByteOrder(byte) => write!(f, "invalid WKB byte order: {byte}"),
+            WkbError::UnsupportedGeometryType(code) => {
+                write!(f, "unsupported WKB geometry type: {code}")
+            }
+            WkbError::UnexpectedGeometryType { expected, found } => {
+                write!(f, "expected WKB geometry type {expected}, found {found}")
+            }
+            WkbError::TrailingBytes => write!(f, "unexpected bytes after WKB geometry"),
+            WkbError::TooDeeplyNested => write!(f, "WKB geometry collections nested too deeply"),
+        }
+    }
+}
+
+impl std::error::Error for WkbError {}
+
+/// Read a geometry from ISO WKB or EWKB, ignoring any SRID.
+///
+/// An empty point, which WKB represents with NaN coordinates, is read as an empty
+/// [`MultiPoint`], since [`Point`] can't be empty.
+pub fn read_wkb(buf: &[u8]) -> Result<Geometry, WkbError> {
+    read_ewkb(buf).map(GeoReferenced::into_inner)
+}
+
+/// Read a geometry from ISO WKB or EWKB, tagging it with the EPSG code of its SRID if there is
+/// one.
+pub fn read_ewkb(buf: &[u8]) -> Result<GeoReferenced<Geometry>, WkbError> {
+    let mut reader = Reader { buf, pos: 0 };
+    let (header, srid) = reader.header()?;
+    let geometry = reader.geometry_body(header, 0)?;
+    if reader.pos != buf.len() {
+        return Err(WkbError::TrailingBytes);
+    }
+    Ok(match srid {
+        Some(srid) => GeoReferenced::new(geometry, Crs::Epsg(srid)),
+        None => GeoReferenced::without_crs(geometry),
+    })
+}
+
+/// Write a geometry as two dimensional WKB.
+///
+/// A [`Line`](crate::Line) is written as a `LineString`, and a [`Rect`] or
+/// [`Triangle`](crate::Triangle) as a `Polygon`.
+pub fn write_wkb(geometry: &Geometry, options: &WkbWriteOptions) -> Vec<u8> {
+    let mut writer = Writer {
+        buf: Vec::new(),
+        endianness: options.endianness,
+    };
+    writer.geometry(geometry, options.srid);
+    writer.buf
+}
+
+/// A WKB geometry which has been scanned, but not decoded.
+///
+/// [`LazyWkb::new`] validates the WKB in a single pass without allocating the geometry, recording
+/// where each run of coordinates starts and the bounding rectangle on the way. Coordinates can
+/// then be read one at a time, and the bounding rectangle is available for free, so pipelines
+/// which filter many geometries only pay for fully decoding the ones they keep.
+///
+/// Coordinates are numbered in the order they appear in the WKB, which is the same order as
+/// [`CoordsIter::coords_iter`](crate::CoordsIter::coords_iter) on the decoded geometry. Empty
+/// points aren't counted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::wkb::{write_wkb, LazyWkb, WkbWriteOptions};
+/// use geo::{coord, wkt, BoundingRect, Geometry, Rect};
+///
+/// let polygon = Geometry::Polygon(wkt!(POLYGON((0. 0.,4. 0.,4. 3.,0. 0.))));
+/// let wkb = write_wkb(&polygon, &WkbWriteOptions::default());
+///
+/// let lazy = LazyWkb::new(&wkb).unwrap();
+/// assert_eq!(lazy.num_points(), 4);
+/// assert_eq!(lazy.coord(2), Some(coord! { x: 4., y: 3. }));
+/// assert_eq!(lazy.bounding_rect(), Some(Rect::new((0., 0.), (4., 3.))));
+/// assert_eq!(lazy.decode(), polygon);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyWkb<'a> {
+    buf: &'a [u8],
+    srid: Option<u32>,
+    /// The non-empty coordinate sequences, in order
+    runs: Vec<CoordRun>,
+    num_points: usize,
+    bounding_rect: Option<Rect>,
+}
+
+/// A sequence of coordinates in a WKB buffer.
+#[derive(Debug, Clone, Copy)]
+struct CoordRun {
+    /// The number of coordinates before this sequence
+    start: usize,
+    /// The byte offset of the first coordinate
+    offset: usize,
+    header: Header,
+}
+
+impl<'a> LazyWkb<'a> {
+    /// Scan and validate ISO WKB or EWKB.
+    pub fn new(buf: &'a [u8]) -> Result<Self, WkbError> {
+        let mut lazy = LazyWkb {
+            buf,
+            srid: None,
+            runs: Vec::new(),
+            num_points: 0,
+            bounding_rect: None,
+        };
+        let mut reader = Reader { buf, pos: 0 };
+        let (header, srid) = reader.header()?;
+        lazy.srid = srid;
+        reader.scan_body(header, &mut lazy, 0)?;
+        if reader.pos != buf.len() {
+            return Err(WkbError::TrailingBytes);
+        }
+        Ok(lazy)
+    }
+
+    /// The SRID embedded in EWKB, if there is one.
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
+    /// The number of coordinates in the geometry, not counting empty points.
+    pub fn num_points(&self) -> usize {
+        self.num_points
+    }
+
+    /// The `index`th coordinate of the geometry, or `None` if `index` isn't less than
+    /// [`LazyWkb::num_points`].
+    pub fn coord(&self, index: usize) -> Option<Coord> {
+        if index >= self.num_points {
+            return None;
+        }
+        let run = self.runs[self.runs.partition_point(|run| run.start <= index) - 1];
+        let mut reader = Reader {
+            buf: self.buf,
+            pos: run.offset + (index - run.start) * run.header.dims * 8,
+        };
+        Some(reader.coord(run.header).expect("coordinates were scanned"))
+    }
+
+    /// The `index`th coordinate of the geometry as a point, like [`LazyWkb::coord`].
+    pub fn point(&self, index: usize) -> Option<Point> {
+        self.coord(index).map(Point)
+    }
+
+    /// Decode the geometry, discarding any SRID.
+    pub fn decode(&self) -> Geometry {
+        read_wkb(self.buf).expect("WKB was validated when it was scanned")
+    }
+
+    /// The WKB this was scanned from.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    fn push_run(&mut self, run: CoordRun, bounding_rect: Rect, len: usize) {
+        self.runs.push(run);
+        self.num_points += len;
+        self.bounding_rect = Some(match self.bounding_rect {
+            None => bounding_rect,
+            Some(rect) => expand(rect, bounding_rect),
+        });
+    }
+}
+
+impl BoundingRect<f64> for LazyWkb<'_> {
+    type Output = Option<Rect>;
+
+    /// The bounding rectangle found while scanning, or `None` if the geometry is empty.
+    fn bounding_rect(&self) -> Self::Output {
+        self.bounding_rect
+    }
+}
+
+fn expand(a: Rect, b: Rect) -> Rect {
+    Rect::new(
+        coord! { x: partial_min(a.min().x, b.min().x), y: partial_min(a.min().y, b.min().y) },
+        coord! { x: partial_max(a.max().x, b.max().x), y: partial_max(a.max().y, b.max().y) },
+    )
+}
+
+/// A geometry type header, with the byte order of the geometry's body.
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    code: u32,
+    dims: usize,
+    endianness: Endianness,
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or(WkbError::UnexpectedEof)?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("slice has length N"))
+    }
+
+    fn u32(&mut self, endianness: Endianness) -> Result<u32, WkbError> {
+        let bytes = self.bytes()?;
+        Ok(match endianness {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self, endianness: Endianness) -> Result<f64, WkbError> {
+        let bytes = self.bytes()?;
+        Ok(match endianness {
+            Endianness::BigEndian => f64::from_be_bytes(bytes),
+            Endianness::LittleEndian => f64::from_le_bytes(bytes),
+        })
+    }
+
+    /// Read a byte order, geometry type, and SRID if there is one.
+    fn header(&mut self) -> Result<(Header, Option<u32>), WkbError> {
+        let endianness = match self.bytes::<1>()?[0] {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            byte => return Err(WkbError::InvalidByteOrder(byte)),
+        };
+        let raw = self.u32(endianness)?;
+        let srid = if raw & EWKB_SRID != 0 {
+            Some(self.u32(endianness)?)
+        } else {
+            None
+        };
+        let iso = raw & 0x0fff_ffff;
+        let code = iso % 1000;
+        let (iso_z, iso_m) = match iso / 1000 {
+            0 => (false, false),
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => return Err(WkbError::UnsupportedGeometryType(raw)),
+        };
+        let z = iso_z || raw & EWKB_Z != 0;
+        let m = iso_m || raw & EWKB_M != 0;
+        if !(POINT..=GEOMETRY_COLLECTION).contains(&code) {
+            return Err(WkbError::UnsupportedGeometryType(raw));
+        }
+        let header = Header {
+            code,
+            dims: 2 + usize::from(z) + usize::from(m),
+            endianness,
+        };
+        Ok((header, srid))
+    }
+
+    /// Read the header of a member of a multi-geometry, which must have type `expected`.
+    fn member_header(&mut self, expected: u32) -> Result<Header, WkbError> {
+        let (header, _) = self.header()?;
+        if header.code != expected {
+            return Err(WkbError::UnexpectedGeometryType {
+                expected,
+                found: header.code,
+            });
+        }
+        Ok(header)
+    }
+
+    fn count(&mut self, header: Header) -> Result<usize, WkbError> {
+        let count = self.u32(header.endianness)? as usize;
+        // every element takes at least 4 bytes, so larger counts are certainly truncated
+        if count > (self.buf.len() - self.pos) / 4 {
+            return Err(WkbError::UnexpectedEof);
+        }
+        Ok(count)
+    }
+
+    fn coord(&mut self, header: Header) -> Result<Coord, WkbError> {
+        let x = self.f64(header.endianness)?;
+        let y = self.f64(header.endianness)?;
+        for _ in 2..header.dims {
+            self.f64(header.endianness)?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn point(&mut self, header: Header) -> Result<Option<Point>, WkbError> {
+        let coord = self.coord(header)?;
+        Ok((!(coord.x.is_nan() && coord.y.is_nan())).then_some(Point(coord)))
+    }
+
+    fn line_string(&mut self, header: Header) -> Result<LineString, WkbError> {
+        let count = self.count(header)?;
+        (0..count)
+            .map(|_| self.coord(header))
+            .collect::<Result<_, _>>()
+            .map(LineString)
+    }
+
+    fn polygon(&mut self, header: Header) -> Result<Polygon, WkbError> {
+        let count = self.count(header)?;
+        let mut rings = (0..count)
+            .map(|_| self.line_string(header))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    /// Read the geometry after `header`, which is nested in `depth` geometry collections.
+    fn geometry_body(&mut self, header: Header, depth: usize) -> Result<Geometry, WkbError> {
+        Ok(match header.code {
+            POINT => match self.point(header)? {
+                Some(point) => Geometry::Point(point),
+                None => Geometry::MultiPoint(MultiPoint::new(vec![])),
+            },
+            LINE_STRING => Geometry::LineString(self.line_string(header)?),
+            POLYGON => Geometry::Polygon(self.polygon(header)?),
+            MULTI_POINT => {
+                let count = self.count(header)?;
+                let mut points = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let header = self.member_header(POINT)?;
+                    points.extend(self.point(header)?);
+                }
+                Geometry::MultiPoint(MultiPoint::new(points))
+            }
+            MULTI_LINE_STRING => {
+                let count = self.count(header)?;
+                let line_strings = (0..count)
+                    .map(|_| {
+                        let header = self.member_header(LINE_STRING)?;
+                        self.line_string(header)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString::new(line_strings))
+            }
+            MULTI_POLYGON => {
+                let count = self.count(header)?;
+                let polygons = (0..count)
+                    .map(|_| {
+                        let header = self.member_header(POLYGON)?;
+                        self.polygon(header)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon::new(polygons))
+            }
+            GEOMETRY_COLLECTION => {
+                if depth == MAX_COLLECTION_DEPTH {
+                    return Err(WkbError::TooDeeplyNested);
+                }
+                let count = self.count(header)?;
+                let geometries = (0..count)
+                    .map(|_| {
+                        let (header, _) = self.header()?;
+                        self.geometry_body(header, depth + 1)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::GeometryCollection(GeometryCollection::new_from(geometries))
+            }
+            _ => unreachable!("geometry type was checked when reading the header"),
+        })
+    }
+
+    /// Skip over `count` coordinates, adding them to `lazy`.
+    fn scan_coords(
+        &mut self,
+        header: Header,
+        count: usize,
+        lazy: &mut LazyWkb,
+    ) -> Result<(), WkbError> {
+        let run = CoordRun {
+            start: lazy.num_points,
+            offset: self.pos,
+            header,
+        };
+        let mut bounding_rect: Option<Rect> = None;
+        for _ in 0..count {
+            let coord = self.coord(header)?;
+            bounding_rect = Some(match bounding_rect {
+                None => Rect::new(coord, coord),
+                Some(rect) => expand(rect, Rect::new(coord, coord)),
+            });
+        }
+        if let Some(bounding_rect) = bounding_rect {
+            lazy.push_run(run, bounding_rect, count);
+        }
+        Ok(())
+    }
+
+    fn scan_point(&mut self, header: Header, lazy: &mut LazyWkb) -> Result<(), WkbError> {
+        let offset = self.pos;
+        if let Some(point) = self.point(header)? {
+            let run = CoordRun {
+                start: lazy.num_points,
+                offset,
+                header,
+            };
+            lazy.push_run(run, Rect::new(point.0, point.0), 1);
+        }
+        Ok(())
+    }
+
+    fn scan_line_string(&mut self, header: Header, lazy: &mut LazyWkb) -> Result<(), WkbError> {
+        let count = self.count(header)?;
+        self.scan_coords(header, count, lazy)
+    }
+
+    fn scan_polygon(&mut self, header: Header, lazy: &mut LazyWkb) -> Result<(), WkbError> {
+        let count = self.count(header)?;
+        for _ in 0..count {
+            self.scan_line_string(header, lazy)?;
+        }
+        Ok(())
+    }
+
+    /// Like [`Self::geometry_body`], but only records the coordinates in `lazy`.
+    fn scan_body(
+        &mut self,
+        header: Header,
+        lazy: &mut LazyWkb,
+        depth: usize,
+    ) -> Result<(), WkbError> {
+        match header.code {
+            POINT => self.scan_point(header, lazy),
+            LINE_STRING => self.scan_line_string(header, lazy),
+            POLYGON => self.scan_polygon(header, lazy),
+            MULTI_POINT | MULTI_LINE_STRING | MULTI_POLYGON | GEOMETRY_COLLECTION => {
+                if header.code == GEOMETRY_COLLECTION && depth == MAX_COLLECTION_DEPTH {
+                    return Err(WkbError::TooDeeplyNested);
+                }
+                let count = self.count(header)?;
+                for _ in 0..count {
+                    match header.code {
+                        MULTI_POINT => {
+                            let header = self.member_header(POINT)?;
+                            self.scan_point(header, lazy)?;
+                        }
+                        MULTI_LINE_STRING => {
+                            let header = self.member_header(LINE_STRING)?;
+                            self.scan_line_string(header, lazy)?;
+                        }
+                        MULTI_POLYGON => {
+                            let header = self.member_header(POLYGON)?;
+                            self.scan_polygon(header, lazy)?;
+                        }
+                        _ => {
+                            let (header, _) = self.header()?;
+                            self.scan_body(header, lazy, depth + 1)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            _ => unreachable!("geometry type was checked when reading the header"),
+        }
+    }
+}
+
+struct Writer {
+    buf: Vec<u8>,
+    endianness: Endianness,
+}
+
+impl Writer {
+    fn u32(&mut self, value: u32) {
+        match self.endianness {
+            Endianness::BigEndian => self.buf.extend(value.to_be_bytes()),
+            Endianness::LittleEndian => self.buf.extend(value.to_le_bytes()),
+        }
+    }
+
+    fn f64(&mut self, value: f64) {
+        match self.endianness {
+            Endianness::BigEndian => self.buf.extend(value.to_be_bytes()),
+            Endianness::LittleEndian => self.buf.extend(value.to_le_bytes()),
+        }
+    }
+
+    fn header(&mut self, code: u32, srid: Option<u32>) {
+        self.buf.push(match self.endianness {
+            Endianness::BigEndian => 0,
+            Endianness::LittleEndian => 1,
+        });
+        match srid {
+            Some(srid) => {
+                self.u32(code | EWKB_SRID);
+                self.u32(srid);
+            }
+            None => self.u32(code),
+        }
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u32(
+            len.try_into()
+                .expect("WKB can't store more than u32::MAX elements"),
+        );
+    }
+
+    fn coords(&mut self, coords: &[Coord]) {
+        self.len(coords.len());
+        for coord in coords {
+            self.f64(coord.x);
+            self.f64(coord.y);
+        }
+    }
+
+    fn point(&mut self, point: &Point, srid: Option<u32>) {
+        self.header(POINT, srid);
+        self.f64(point.x());
+        self.f64(point.y());
+    }
+
+    fn line_string(&mut self, coords: &[Coord], srid: Option<u32>) {
+        self.header(LINE_STRING, srid);
+        self.coords(coords);
+    }
+
+    fn polygon(&mut self, polygon: &Polygon, srid: Option<u32>) {
+        self.header(POLYGON, srid);
+        if polygon.exterior().0.is_empty() {
+            self.len(0);
+            return;
+        }
+        self.len(1 + polygon.interiors().len());
+        self.coords(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.coords(&interior.0);
+        }
+    }
+
+    fn geometry(&mut self, geometry: &Geometry, srid: Option<u32>) {
+        match geometry {
+            Geometry::Point(g) => self.point(g, srid),
+            Geometry::Line(g) => self.line_string(&[g.start, g.end], srid),
+            Geometry::LineString(g) => self.line_string(&g.0, srid),
+            Geometry::Polygon(g) => self.polygon(g, srid),
+            Geometry::MultiPoint(g) => {
+                self.header(MULTI_POINT, srid);
+                self.len(g.0.len());
+                for point in g {
+                    self.point(point, None);
+                }
+            }
+            Geometry::MultiLineString(g) => {
+                self.header(MULTI_LINE_STRING, srid);
+                self.len(g.0.len());
+                for line_string in g {
+                    self.line_string(&line_string.0, None);
+                }
+            }
+            Geometry::MultiPolygon(g) => {
+                self.header(MULTI_POLYGON, srid);
+                self.len(g.0.len());
+                for polygon in g {
+                    self.polygon(polygon, None);
+                }
+            }
+            Geometry::GeometryCollection(g) => {
+                self.header(GEOMETRY_COLLECTION, srid);
+                self.len(g.0.len());
+                for geometry in g {
+                    self.geometry(geometry, None);
+                }
+            }
+            Geometry::Rect(g) => self.polygon(&g.to_polygon(), srid),
+            Geometry::Triangle(g) => self.polygon(&g.to_polygon(), srid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt, Line, Rect};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn write(geometry: impl Into<Geometry>, endianness: Endianness, srid: Option<u32>) -> Vec<u8> {
+        write_wkb(&geometry.into(), &WkbWriteOptions { endianness, srid })
+    }
+
+    // Expected values are from PostGIS' ST_AsBinary and ST_AsEWKB.
+
+    #[test]
+    fn point() {
+        // POINT(1 2)
+        let wkb = hex("0101000000000000000000F03F0000000000000040");
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(read_wkb(&wkb).unwrap(), Geometry::Point(point));
+        assert_eq!(write(point, Endianness::LittleEndian, None), wkb);
+
+        let big_endian = hex("00000000013FF00000000000004000000000000000");
+        assert_eq!(read_wkb(&big_endian).unwrap(), Geometry::Point(point));
+        assert_eq!(write(point, Endianness::BigEndian, None), big_endian);
+    }
+
+    #[test]
+    fn ewkb_srid() {
+        // SRID=4326;POINT(1 2)
+        let ewkb = hex("0101000020E6100000000000000000F03F0000000000000040");
+        let point = point!(x: 1., y: 2.);
+        let read = read_ewkb(&ewkb).unwrap();
+        assert_eq!(read.crs(), Some(&Crs::WGS84));
+        assert_eq!(read.geometry(), &Geometry::Point(point));
+        assert_eq!(write(point, Endianness::LittleEndian, Some(4326)), ewkb);
+
+        assert_eq!(
+            read_ewkb(&write(point, Endianness::LittleEndian, None))
+                .unwrap()
+                .crs(),
+            None
+        );
+    }
+
+    #[test]
+    fn z_and_m_are_dropped() {
+        let expected = Geometry::Point(point!(x: 1., y: 2.));
+        // ISO POINT Z (1 2 3)
+        let iso_z = hex("01E9030000000000000000F03F00000000000000400000000000000840");
+        assert_eq!(read_wkb(&iso_z).unwrap(), expected);
+        // ISO POINT ZM (1 2 3 4)
+        let iso_zm =
+            hex("01B90B0000000000000000F03F000000000000004000000000000008400000000000001040");
+        assert_eq!(read_wkb(&iso_zm).unwrap(), expected);
+        // EWKB POINT Z (1 2 3)
+        let ewkb_z = hex("0101000080000000000000F03F00000000000000400000000000000840");
+        assert_eq!(read_wkb(&ewkb_z).unwrap(), expected);
+        // EWKB SRID=4326;POINTM(1 2 4)
+        let ewkb_m = hex("0101000060E6100000000000000000F03F00000000000000400000000000001040");
+        assert_eq!(read_ewkb(&ewkb_m).unwrap().into_inner(), expected);
+
+        // ISO LINESTRING Z (0 0 0, 1 1 1)
+        let line_string_z = hex(concat!(
+            "01EA03000002000000",
+            "000000000000000000000000000000000000000000000000",
+            "000000000000F03F000000000000F03F000000000000F03F"
+        ));
+        assert_eq!(
+            read_wkb(&line_string_z).unwrap(),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)])
+        );
+    }
+
+    #[test]
+    fn polygon() {
+        // POLYGON((0 0,1 0,1 1,0 0))
+        let wkb = hex(concat!(
+            "01030000000100000004000000",
+            "00000000000000000000000000000000",
+            "000000000000F03F0000000000000000",
+            "000000000000F03F000000000000F03F",
+            "00000000000000000000000000000000"
+        ));
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(read_wkb(&wkb).unwrap(), Geometry::Polygon(polygon.clone()));
+        assert_eq!(write(polygon, Endianness::LittleEndian, None), wkb);
+
+        // POLYGON EMPTY
+        let empty = hex("010300000000000000");
+        assert_eq!(
+            read_wkb(&empty).unwrap(),
+            Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![]))
+        );
+        assert_eq!(
+            write(read_wkb(&empty).unwrap(), Endianness::LittleEndian, None),
+            empty
+        );
+    }
+
+    #[test]
+    fn multi_point() {
+        // MULTIPOINT((1 2),(3 4))
+        let wkb = hex(concat!(
+            "010400000002000000",
+            "0101000000000000000000F03F0000000000000040",
+            "010100000000000000000008400000000000001040"
+        ));
+        let multi_point = wkt!(MULTIPOINT(1. 2.,3. 4.));
+        assert_eq!(
+            read_wkb(&wkb).unwrap(),
+            Geometry::MultiPoint(multi_point.clone())
+        );
+        assert_eq!(write(multi_point, Endianness::LittleEndian, None), wkb);
+    }
+
+    #[test]
+    fn empty_point() {
+        // POINT EMPTY
+        let wkb = hex("0101000000000000000000F87F000000000000F87F");
+        assert_eq!(
+            read_wkb(&wkb).unwrap(),
+            Geometry::MultiPoint(MultiPoint::new(vec![]))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(LINESTRING(0. 0.,1. 1.,2. 0.)).into(),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.))).into(),
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. 3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.)))).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 2.).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+        ];
+        for geometry in geometries {
+            for endianness in [Endianness::BigEndian, Endianness::LittleEndian] {
+                let wkb = write(geometry.clone(), endianness, Some(3857));
+                let read = read_ewkb(&wkb).unwrap();
+                assert_eq!(read.crs(), Some(&Crs::WEB_MERCATOR));
+                assert_eq!(read.into_inner(), geometry);
+            }
+        }
+    }
+
+    #[test]
+    fn line_rect_and_triangle() {
+        let line = Line::new((0., 0.), (1., 1.));
+        assert_eq!(
+            read_wkb(&write(line, Endianness::LittleEndian, None)).unwrap(),
+            Geometry::LineString(line.into())
+        );
+        let rect = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(
+            read_wkb(&write(rect, Endianness::LittleEndian, None)).unwrap(),
+            Geometry::Polygon(rect.to_polygon())
+        );
+    }
+
+    #[test]
+    fn lazy() {
+        use crate::CoordsIter;
+
+        let collection = Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(
+            POINT(9. -1.),
+            MULTIPOLYGON(((0. 0.,4. 0.,4. 3.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.),(5.5 5.2,5.8 5.2,5.8 5.5,5.5 5.2))),
+            LINESTRING EMPTY,
+            MULTIPOINT(1. 7.,2. 8.)
+        )));
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let wkb = write(collection.clone(), endianness, Some(4326));
+            let lazy = LazyWkb::new(&wkb).unwrap();
+            assert_eq!(lazy.srid(), Some(4326));
+            assert_eq!(lazy.num_points(), collection.coords_count());
+            for (i, coord) in collection.coords_iter().enumerate() {
+                assert_eq!(lazy.coord(i), Some(coord));
+            }
+            assert_eq!(lazy.coord(lazy.num_points()), None);
+            assert_eq!(lazy.bounding_rect(), collection.bounding_rect());
+            assert_eq!(lazy.decode(), collection);
+        }
+
+        // EWKB MULTIPOINT Z (1 2 3, EMPTY, 4 5 6), where the second point has NaN coordinates
+        let point_z = |coords: [f64; 3]| {
+            let mut wkb = hex("0101000080");
+            wkb.extend(coords.iter().flat_map(|c| c.to_le_bytes()));
+            wkb
+        };
+        let wkb = [
+            hex("010400008003000000"),
+            point_z([1., 2., 3.]),
+            point_z([f64::NAN, f64::NAN, f64::NAN]),
+            point_z([4., 5., 6.]),
+        ]
+        .concat();
+        let lazy = LazyWkb::new(&wkb).unwrap();
+        assert_eq!(lazy.num_points(), 2);
+        assert_eq!(lazy.point(1), Some(point!(x: 4., y: 5.)));
+        assert_eq!(lazy.bounding_rect(), Some(Rect::new((1., 2.), (4., 5.))));
+
+        let empty = write(
+            MultiPolygon::<f64>::new(vec![]),
+            Endianness::LittleEndian,
+            None,
+        );
+        let lazy = LazyWkb::new(&empty).unwrap();
+        assert_eq!(lazy.num_points(), 0);
+        assert_eq!(lazy.bounding_rect(), None);
+
+        assert_eq!(
+            LazyWkb::new(&hex("010400000001000000010200000000000000")).unwrap_err(),
+            WkbError::UnexpectedGeometryType {
+                expected: POINT,
+                found: LINE_STRING
+            }
+        );
+        assert_eq!(
+            LazyWkb::new(&wkb[..wkb.len() - 1]).unwrap_err(),
+            WkbError::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let wkb = hex("0101000000000000000000F03F0000000000000040");
+        assert_eq!(read_wkb(&wkb[..10]), Err(WkbError::UnexpectedEof));
+        assert_eq!(
+            read_wkb(&[wkb.clone(), vec![0]].concat()),
+            Err(WkbError::TrailingBytes)
+        );
+        assert_eq!(read_wkb(&hex("02")), Err(WkbError::InvalidByteOrder(2)));
+        assert_eq!(
+            read_wkb(&hex("0111000000")),
+            Err(WkbError::UnsupportedGeometryType(17))
+        );
+        // MULTIPOINT containing a LINESTRING
+        assert_eq!(
+            read_wkb(&hex("010400000001000000010200000000000000")),
+            Err(WkbError::UnexpectedGeometryType {
+                expected: POINT,
+                found: LINE_STRING
+            })
+        );
+        // a huge count with no data behind it
+        assert_eq!(
+            read_wkb(&hex("0102000000FFFFFFFF")),
+            Err(WkbError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn nesting_limit() {
+        use geo_test_fixtures::nested_collections_wkb as nested_collections;
+
+        let geometry = read_wkb(&nested_collections(MAX_COLLECTION_DEPTH)).unwrap();
+        assert_eq!(
+            write_wkb(&geometry, &Default::default()),
+            nested_collections(MAX_COLLECTION_DEPTH)
+        );
+        assert_eq!(
+            read_wkb(&nested_collections(MAX_COLLECTION_DEPTH + 1)),
+            Err(WkbError::TooDeeplyNested)
+        );
+        assert_eq!(
+            read_wkb(&nested_collections(200_000)),
+            Err(WkbError::TooDeeplyNested)
+        );
+
+        assert!(LazyWkb::new(&nested_collections(MAX_COLLECTION_DEPTH)).is_ok());
+        assert_eq!(
+            LazyWkb::new(&nested_collections(MAX_COLLECTION_DEPTH + 1)).unwrap_err(),
+            WkbError::TooDeeplyNested
+        );
+        assert_eq!(
+            LazyWkb::new(&nested_collections(200_000)).unwrap_err(),
+            WkbError::TooDeeplyNested
+        );
+    }
+}